@@ -0,0 +1,21 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Balances of a ledger account on its balance side.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AccountBalance {
+    pub account_id: Uuid,
+    pub time: DateTime<Utc>,
+    /// Balance of the effective postings.
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub booked: BigDecimal,
+    /// Sum of the holds active at `time`.
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub held: BigDecimal,
+    /// Booked balance less the funds held.
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub available: BigDecimal,
+}
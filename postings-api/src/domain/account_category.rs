@@ -3,6 +3,7 @@ use crate::domain::balance_side::BalanceSide;
 use std::fmt;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum AccountCategory {
     RE, // Revenue
     EX, // Expense
@@ -0,0 +1,46 @@
+use regex::Regex;
+
+/// Pattern ledger account codes have to match. The default accepts digit groups separated by
+/// dots, such as `1200` or `4000.10`.
+#[derive(Debug, Clone)]
+pub struct AccountCodeScheme {
+    pattern: Regex,
+}
+
+impl AccountCodeScheme {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self { pattern: Regex::new(pattern)? })
+    }
+
+    pub fn is_valid(&self, code: &str) -> bool {
+        self.pattern.is_match(code)
+    }
+}
+
+impl Default for AccountCodeScheme {
+    fn default() -> Self {
+        Self::new(r"^[0-9]+(\.[0-9]+)*$").expect("default account code pattern compiles")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_scheme() {
+        let scheme = AccountCodeScheme::default();
+        assert!(scheme.is_valid("1200"));
+        assert!(scheme.is_valid("4000.10"));
+        assert!(!scheme.is_valid("4000."));
+        assert!(!scheme.is_valid("Cash"));
+        assert!(!scheme.is_valid(""));
+    }
+
+    #[test]
+    fn test_custom_scheme() {
+        let scheme = AccountCodeScheme::new(r"^[A-Z]{2}-[0-9]{4}$").unwrap();
+        assert!(scheme.is_valid("AS-1200"));
+        assert!(!scheme.is_valid("1200"));
+    }
+}
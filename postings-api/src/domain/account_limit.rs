@@ -0,0 +1,46 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Bounds on the balance of a ledger account, taken on its balance side: debits minus credits
+/// for `Dr` and `DrCr` accounts, credits minus debits for `Cr` accounts. Effective postings that
+/// would move the balance past a bound are rejected; a missing bound is not enforced.
+///
+/// A wallet that must not be overdrawn has a minimum of zero, a credit line of 500 a minimum of
+/// -500.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountLimit {
+    pub account_id: Uuid,
+    pub min_balance: Option<BigDecimal>,
+    pub max_balance: Option<BigDecimal>,
+}
+
+impl AccountLimit {
+    pub fn is_valid(&self) -> bool {
+        match (&self.min_balance, &self.max_balance) {
+            (Some(min), Some(max)) => min <= max,
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn limit(min: Option<i64>, max: Option<i64>) -> AccountLimit {
+        AccountLimit {
+            account_id: Uuid::new_v4(),
+            min_balance: min.map(BigDecimal::from),
+            max_balance: max.map(BigDecimal::from),
+        }
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(limit(Some(0), Some(100)).is_valid());
+        assert!(limit(Some(100), Some(100)).is_valid());
+        assert!(limit(None, Some(-100)).is_valid());
+        assert!(!limit(Some(100), Some(0)).is_valid());
+    }
+}
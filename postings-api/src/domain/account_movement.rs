@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::posting_line::PostingLine;
+
+/// Position in the change feed of an account. Subscribing with the cursor of the last movement
+/// processed resumes right after it, also across restarts.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct FeedCursor(pub u64);
+
+impl FeedCursor {
+    /// Before the account's first line.
+    pub const START: FeedCursor = FeedCursor(0);
+}
+
+/// A line appended to an account, in the order the lines were committed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountMovement {
+    pub cursor: FeedCursor,
+    pub line: PostingLine,
+}
@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+
+/// Lifecycle state of a ledger account. Accounts without any recorded state change are open.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum AccountState {
+    #[default]
+    Open,
+    /// No further debits; credits are still accepted.
+    DebitBlocked,
+    /// No further credits; debits are still accepted.
+    CreditBlocked,
+    /// Neither debits nor credits, until the account is opened again.
+    Frozen,
+    /// Final; requires a zero balance and no open child accounts.
+    Closed,
+}
+
+impl AccountState {
+    pub fn allows_debit(&self) -> bool {
+        matches!(self, AccountState::Open | AccountState::CreditBlocked)
+    }
+
+    pub fn allows_credit(&self) -> bool {
+        matches!(self, AccountState::Open | AccountState::DebitBlocked)
+    }
+}
+
+/// Recorded change of an account's state. Changes are never modified, so together they form the
+/// audit trail of the account's lifecycle.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct AccountStateChange {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub state: AccountState,
+    /// Time from which the state applies to postings.
+    pub effective_time: DateTime<Utc>,
+    /// 34-byte multihash of the user that changed the state
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub record_user: [u8; 34],
+    pub record_time: DateTime<Utc>,
+    pub reason: Option<String>,
+}
+
+impl AccountStateChange {
+    /// State in force at `at`: that of the change with the latest effective time not after `at`,
+    /// the later recorded one winning ties.
+    pub fn state_at(changes: &[AccountStateChange], at: DateTime<Utc>) -> AccountState {
+        changes
+            .iter()
+            .filter(|c| c.effective_time <= at)
+            .max_by_key(|c| (c.effective_time, c.record_time))
+            .map(|c| c.state.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn change(state: AccountState, effective_time: DateTime<Utc>) -> AccountStateChange {
+        AccountStateChange {
+            id: Uuid::new_v4(),
+            account_id: Uuid::nil(),
+            state,
+            effective_time,
+            record_user: [0; 34],
+            record_time: Utc::now(),
+            reason: None,
+        }
+    }
+
+    #[test]
+    fn test_state_at() {
+        let t0 = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let changes = vec![
+            change(AccountState::Closed, t0 + Duration::days(10)),
+            change(AccountState::Frozen, t0),
+        ];
+
+        assert_eq!(AccountStateChange::state_at(&changes, t0 - Duration::days(1)), AccountState::Open);
+        assert_eq!(AccountStateChange::state_at(&changes, t0 + Duration::days(5)), AccountState::Frozen);
+        assert_eq!(AccountStateChange::state_at(&changes, t0 + Duration::days(10)), AccountState::Closed);
+    }
+
+    #[test]
+    fn test_allowed_sides() {
+        assert!(AccountState::DebitBlocked.allows_credit());
+        assert!(!AccountState::DebitBlocked.allows_debit());
+        assert!(AccountState::CreditBlocked.allows_debit());
+        assert!(!AccountState::CreditBlocked.allows_credit());
+        assert!(!AccountState::Frozen.allows_debit() && !AccountState::Frozen.allows_credit());
+        assert!(!AccountState::Closed.allows_debit() && !AccountState::Closed.allows_credit());
+    }
+}
@@ -5,12 +5,15 @@ use crate::domain::ledger_account::LedgerAccount;
 use crate::domain::posting_trace::PostingTrace;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct AccountStmt {
     #[serde(flatten)]
     pub financial_stmt: FinancialStmt,
     pub account: LedgerAccount,
     pub youngest_pst: Option<PostingTrace>,
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub total_debit: BigDecimal,
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub total_credit: BigDecimal,
 }
 
@@ -55,6 +58,7 @@ mod tests {
             coa: coa.clone(),
             balance_side: BalanceSide::Dr,
             category: AccountCategory::AS,
+            code: None,
         };
 
         let financial_stmt = FinancialStmt {
@@ -0,0 +1,41 @@
+use bigdecimal::BigDecimal;
+use serde::{Deserialize, Serialize};
+use crate::domain::ledger::Ledger;
+
+/// Four-eyes rule of a ledger. Without a threshold every posting needs a second user's approval,
+/// otherwise only postings whose debit total reaches the threshold do.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ApprovalPolicy {
+    pub ledger: Ledger,
+    pub amount_threshold: Option<BigDecimal>,
+}
+
+impl ApprovalPolicy {
+    pub fn requires_approval(&self, amount: &BigDecimal) -> bool {
+        match &self.amount_threshold {
+            Some(threshold) => amount >= threshold,
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::chart_of_account::ChartOfAccount;
+    use uuid::Uuid;
+
+    fn policy(amount_threshold: Option<BigDecimal>) -> ApprovalPolicy {
+        ApprovalPolicy {
+            ledger: Ledger { id: Uuid::new_v4(), coa: ChartOfAccount { id: Uuid::new_v4() } },
+            amount_threshold,
+        }
+    }
+
+    #[test]
+    fn test_requires_approval() {
+        assert!(policy(None).requires_approval(&BigDecimal::from(1)));
+        assert!(policy(Some(BigDecimal::from(1000))).requires_approval(&BigDecimal::from(1000)));
+        assert!(!policy(Some(BigDecimal::from(1000))).requires_approval(&BigDecimal::from(999)));
+    }
+}
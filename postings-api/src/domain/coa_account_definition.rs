@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use crate::domain::account_category::AccountCategory;
+use crate::domain::balance_side::BalanceSide;
+use crate::domain::chart_of_account::ChartOfAccount;
+
+/// Account of a chart of accounts. Ledgers instantiated from the chart get one ledger account per
+/// definition, placed under the account defined by `parent_code`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct CoaAccountDefinition {
+    pub id: Uuid,
+    pub coa: ChartOfAccount,
+    pub code: String,
+    pub name: String,
+    pub language: String,
+    pub category: AccountCategory,
+    pub balance_side: BalanceSide,
+    pub parent_code: Option<String>,
+}
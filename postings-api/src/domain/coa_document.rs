@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::account_category::AccountCategory;
+use crate::domain::balance_side::BalanceSide;
+
+/// A chart of accounts and one ledger on it, as kept in COA definition files. Charts and ledgers
+/// are identified by their names, accounts by their code.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoaDocument {
+    pub chart: Vec<CoaName>,
+    pub ledger: Vec<CoaName>,
+    pub accounts: Vec<CoaAccount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoaName {
+    pub language: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoaAccount {
+    pub code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_code: Option<String>,
+    pub category: AccountCategory,
+    pub balance_side: BalanceSide,
+    /// The first name is the one recorded on the account definition of the chart.
+    pub names: Vec<CoaName>,
+}
@@ -0,0 +1,80 @@
+use bigdecimal::BigDecimal;
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// How days between two dates are counted and over how many days a year the annual rate is spread.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DayCountConvention {
+    /// Actual days over a 360-day year.
+    Act360,
+    /// Actual days over a 365-day year.
+    Act365,
+    /// 30-day months over a 360-day year (ISDA bond basis).
+    Thirty360,
+}
+
+impl DayCountConvention {
+    /// Days counted from `from` (inclusive) to `to` (exclusive).
+    pub fn day_count(&self, from: NaiveDate, to: NaiveDate) -> i64 {
+        match self {
+            DayCountConvention::Act360 | DayCountConvention::Act365 => (to - from).num_days(),
+            DayCountConvention::Thirty360 => {
+                let d1 = from.day().min(30) as i64;
+                let d2 = if to.day() == 31 && d1 == 30 { 30 } else { to.day() as i64 };
+                360 * (to.year() - from.year()) as i64
+                    + 30 * (to.month() as i64 - from.month() as i64)
+                    + (d2 - d1)
+            }
+        }
+    }
+
+    pub fn days_in_year(&self) -> i64 {
+        match self {
+            DayCountConvention::Act360 | DayCountConvention::Thirty360 => 360,
+            DayCountConvention::Act365 => 365,
+        }
+    }
+
+    /// Share of a year between the two dates, to be multiplied with an annual rate.
+    pub fn year_fraction(&self, from: NaiveDate, to: NaiveDate) -> BigDecimal {
+        BigDecimal::from(self.day_count(from, to)) / BigDecimal::from(self.days_in_year())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_act_360() {
+        let convention = DayCountConvention::Act360;
+        assert_eq!(convention.day_count(date(2025, 1, 1), date(2025, 3, 1)), 59);
+        assert_eq!(convention.year_fraction(date(2025, 1, 1), date(2025, 1, 19)), BigDecimal::from_str("0.05").unwrap());
+        assert_eq!(convention.year_fraction(date(2025, 1, 1), date(2026, 1, 1)), BigDecimal::from(365) / BigDecimal::from(360));
+    }
+
+    #[test]
+    fn test_act_365() {
+        let convention = DayCountConvention::Act365;
+        assert_eq!(convention.day_count(date(2024, 2, 1), date(2024, 3, 1)), 29);
+        assert_eq!(convention.year_fraction(date(2025, 1, 1), date(2026, 1, 1)), BigDecimal::from(1));
+        assert_eq!(convention.year_fraction(date(2024, 1, 1), date(2025, 1, 1)), BigDecimal::from(366) / BigDecimal::from(365));
+    }
+
+    #[test]
+    fn test_thirty_360() {
+        let convention = DayCountConvention::Thirty360;
+        assert_eq!(convention.day_count(date(2025, 1, 1), date(2025, 2, 1)), 30);
+        assert_eq!(convention.day_count(date(2025, 2, 1), date(2025, 3, 1)), 30);
+        assert_eq!(convention.day_count(date(2025, 1, 30), date(2025, 1, 31)), 0);
+        assert_eq!(convention.day_count(date(2025, 1, 31), date(2025, 2, 1)), 1);
+        assert_eq!(convention.day_count(date(2025, 1, 15), date(2025, 1, 31)), 16);
+        assert_eq!(convention.year_fraction(date(2025, 1, 1), date(2026, 1, 1)), BigDecimal::from(1));
+        assert_eq!(convention.year_fraction(date(2025, 1, 1), date(2025, 4, 1)), BigDecimal::from_str("0.25").unwrap());
+    }
+}
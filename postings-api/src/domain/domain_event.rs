@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+
+use crate::domain::account_state::AccountState;
+use crate::domain::posting_status::PostingStatus;
+
+/// Change to a ledger that downstream systems are told about.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type")]
+pub enum DomainEventPayload {
+    LedgerCreated { ledger_id: Uuid, coa_id: Uuid },
+    LedgerAccountCreated { account_id: Uuid, parent_id: Option<Uuid>, code: Option<String> },
+    AccountStateChanged { account_id: Uuid, state: AccountState, effective_time: DateTime<Utc> },
+    PostingCreated {
+        posting_id: Uuid,
+        /// 32-byte hash of the operation the posting was recorded for
+        #[serde_as(as = "serde_with::hex::Hex")]
+        opr_id: [u8; 34],
+        pst_status: PostingStatus,
+        pst_time: DateTime<Utc>,
+    },
+    PostingStatusChanged { posting_id: Uuid, pst_status: PostingStatus },
+    /// The posting was cancelled and will not take effect.
+    PostingDiscarded { posting_id: Uuid },
+    StatementClosed { stmt_id: Uuid, account_id: Uuid, posting_id: Option<Uuid>, pst_time: DateTime<Utc> },
+}
+
+/// Event recorded in the outbox in the same transaction as the change it describes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DomainEvent {
+    pub id: i64,
+    pub ledger_id: Uuid,
+    /// Position of the event in its ledger, gapless from 1 in commit order. Consumers use it to
+    /// order events and to drop the duplicates an at-least-once delivery can produce.
+    pub sequence: u64,
+    pub occurred_at: DateTime<Utc>,
+    /// `None` until a relay has delivered the event.
+    pub dispatched_at: Option<DateTime<Utc>>,
+    pub payload: DomainEventPayload,
+}
+
+/// Outcome of one relay pass.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RelayReport {
+    /// Events delivered and marked dispatched.
+    pub delivered: usize,
+    /// Ledgers whose delivery stopped at a failing event; their remaining events wait for the
+    /// next pass.
+    pub blocked_ledgers: Vec<Uuid>,
+}
@@ -6,6 +6,7 @@ use crate::domain::posting_trace::PostingTrace;
 use crate::domain::stmt_status::StmtStatus;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct FinancialStmt {
     pub id: Uuid,
     pub posting: Option<Posting>,
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A link of a ledger's hash chain that does not hold.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(tag = "type")]
+pub enum ChainBreak {
+    /// The posting names no antecedent although it is not the first of the chain.
+    MissingAntecedent { posting_id: Uuid },
+    /// The antecedent named by the posting is not a hashed posting of the ledger.
+    UnknownAntecedent { posting_id: Uuid, antecedent_id: Uuid },
+    /// The antecedent hash recorded by the posting differs from the antecedent's own hash.
+    HashMismatch { posting_id: Uuid, antecedent_id: Uuid },
+    /// More than one posting names the same antecedent, so the chain forks.
+    Fork { antecedent_id: Uuid, posting_ids: Vec<Uuid> },
+}
+
+/// Result of walking a ledger's hash chain from its first posting to its head.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ChainVerification {
+    pub ledger_id: Uuid,
+    /// Number of hashed postings checked.
+    pub postings: u64,
+    /// Newest posting of the chain, which the next effective posting will name as antecedent.
+    pub head: Option<Uuid>,
+    pub breaks: Vec<ChainBreak>,
+}
+
+impl ChainVerification {
+    pub fn is_intact(&self) -> bool {
+        self.breaks.is_empty()
+    }
+}
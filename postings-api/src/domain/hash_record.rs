@@ -4,10 +4,13 @@ use uuid::Uuid;
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct HashRecord {
     pub antecedent_id: Option<Uuid>,
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub antecedent_hash: Option<[u8; 34]>,
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub hash: Option<[u8; 34]>,
 }
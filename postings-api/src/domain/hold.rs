@@ -0,0 +1,71 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum HoldStatus {
+    /// Reserves its amount until captured, released or expired.
+    Active,
+    Captured,
+    Released,
+    Expired,
+}
+
+/// Funds reserved on a ledger account, as for a card authorization, that are later captured into
+/// a posting or released. The amount is taken off the account's balance side: an active hold on a
+/// `Cr` account reserves credits, one on a `Dr` account debits.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Hold {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub amount: BigDecimal,
+    /// Time from which the hold no longer reserves funds unless captured before.
+    pub expiry: DateTime<Utc>,
+    /// 32-byte hash of the operation the hold was placed for. The capturing posting carries it on.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_id: [u8; 34],
+    pub status: HoldStatus,
+    pub record_time: DateTime<Utc>,
+    /// Time the hold was captured, released or expired.
+    pub closed_time: Option<DateTime<Utc>>,
+    /// Amount turned into a posting; the rest of a captured hold is freed.
+    pub captured_amount: Option<BigDecimal>,
+    pub posting_id: Option<Uuid>,
+}
+
+impl Hold {
+    /// Whether the hold reserves funds at `at`.
+    pub fn is_active_at(&self, at: DateTime<Utc>) -> bool {
+        self.status == HoldStatus::Active && self.expiry > at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_is_active_at() {
+        let now = Utc::now();
+        let mut hold = Hold {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            amount: BigDecimal::from(10),
+            expiry: now + Duration::days(1),
+            opr_id: [0; 34],
+            status: HoldStatus::Active,
+            record_time: now,
+            closed_time: None,
+            captured_amount: None,
+            posting_id: None,
+        };
+        assert!(hold.is_active_at(now));
+        assert!(!hold.is_active_at(now + Duration::days(1)));
+        hold.status = HoldStatus::Released;
+        assert!(!hold.is_active_at(now));
+    }
+}
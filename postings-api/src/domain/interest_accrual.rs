@@ -0,0 +1,35 @@
+use bigdecimal::BigDecimal;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use crate::domain::interest_rate_schedule::InterestRateSchedule;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::posting::Posting;
+
+/// Accrues interest on `account` and books it against `interest_account`, the interest income
+/// account for asset balances or the interest expense account for liability balances.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InterestAccrualRule {
+    pub account: LedgerAccount,
+    pub schedule: InterestRateSchedule,
+    pub interest_account: LedgerAccount,
+}
+
+/// Interest of one day, computed on the account's value-dated balance at the end of that day.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DailyAccrual {
+    pub date: NaiveDate,
+    pub balance: BigDecimal,
+    pub interest: BigDecimal,
+}
+
+/// Interest accrued over `[from, to)`. `amount` is the sum of the daily interest rounded to cents,
+/// signed along the account's balance side; `posting` is set once it has been booked.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InterestAccrual {
+    pub account: LedgerAccount,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub days: Vec<DailyAccrual>,
+    pub amount: BigDecimal,
+    pub posting: Option<Posting>,
+}
@@ -0,0 +1,73 @@
+use bigdecimal::{BigDecimal, Zero};
+use serde::{Deserialize, Serialize};
+use crate::domain::day_count_convention::DayCountConvention;
+
+/// Annual rate, as a decimal fraction, applied to the part of a balance above `from_balance`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InterestRateTier {
+    pub from_balance: BigDecimal,
+    pub rate: BigDecimal,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct InterestRateSchedule {
+    pub convention: DayCountConvention,
+    pub tiers: Vec<InterestRateTier>,
+}
+
+impl InterestRateSchedule {
+    /// Interest for one year on a constant balance. Each tier's rate applies to the slice of the
+    /// balance between its threshold and the next tier's; negative balances yield negative interest.
+    pub fn annual_interest(&self, balance: &BigDecimal) -> BigDecimal {
+        let amount = balance.abs();
+        let mut tiers: Vec<&InterestRateTier> = self.tiers.iter().collect();
+        tiers.sort_by(|a, b| a.from_balance.cmp(&b.from_balance));
+
+        let mut interest = BigDecimal::zero();
+        for (idx, tier) in tiers.iter().enumerate() {
+            if amount <= tier.from_balance {
+                break;
+            }
+            let upper = match tiers.get(idx + 1) {
+                Some(next) if next.from_balance < amount => next.from_balance.clone(),
+                _ => amount.clone(),
+            };
+            interest += (upper - &tier.from_balance) * &tier.rate;
+        }
+
+        if balance < &BigDecimal::zero() {
+            -interest
+        } else {
+            interest
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn dec(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    fn schedule() -> InterestRateSchedule {
+        InterestRateSchedule {
+            convention: DayCountConvention::Act365,
+            tiers: vec![
+                InterestRateTier { from_balance: dec("10000"), rate: dec("0.02") },
+                InterestRateTier { from_balance: dec("0"), rate: dec("0.01") },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_annual_interest_is_tiered() {
+        assert_eq!(schedule().annual_interest(&dec("5000")), dec("50"));
+        assert_eq!(schedule().annual_interest(&dec("10000")), dec("100"));
+        assert_eq!(schedule().annual_interest(&dec("15000")), dec("200"));
+        assert_eq!(schedule().annual_interest(&dec("-5000")), dec("-50"));
+        assert_eq!(schedule().annual_interest(&dec("0")), dec("0"));
+    }
+}
@@ -3,6 +3,7 @@ use uuid::Uuid;
 use crate::domain::chart_of_account::ChartOfAccount;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Ledger {
     pub id: Uuid,
     pub coa: ChartOfAccount,
@@ -6,11 +6,16 @@ use crate::domain::chart_of_account::ChartOfAccount;
 use crate::domain::ledger::Ledger;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct LedgerAccount {
     pub id: Uuid,
     pub ledger: Ledger,
+    #[cfg_attr(feature = "openapi", schema(no_recursion))]
     pub parent: Option<Box<LedgerAccount>>,
     pub coa: ChartOfAccount,
     pub balance_side: BalanceSide,
     pub category: AccountCategory,
+    /// Structured account code such as `1200` or `4000.10`, unique within the ledger. Without an
+    /// explicit parent, the account with the longest code that prefixes this one becomes its parent.
+    pub code: Option<String>,
 }
@@ -1,16 +1,34 @@
+pub mod account_balance;
 pub mod account_category;
+pub mod account_code_scheme;
+pub mod account_limit;
+pub mod account_movement;
+pub mod account_state;
+pub mod approval_policy;
 pub mod account_stmt;
 pub mod balance_side;
 pub mod chart_of_account;
+pub mod coa_account_definition;
+pub mod coa_document;
+pub mod day_count_convention;
+pub mod domain_event;
 pub mod financial_stmt;
+pub mod hash_chain;
 pub mod hash_record;
+pub mod hold;
+pub mod interest_accrual;
+pub mod interest_rate_schedule;
 pub mod ledger;
 pub mod ledger_account;
 pub mod ledger_stmt;
 pub mod named;
 pub mod posting;
+pub mod posting_approval;
 pub mod posting_line;
+pub mod posting_schedule;
 pub mod posting_status;
 pub mod posting_trace;
 pub mod posting_type;
+pub mod recurrence;
 pub mod stmt_status;
+pub mod time_axis;
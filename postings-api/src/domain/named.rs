@@ -6,6 +6,7 @@ use uuid::Uuid;
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Validator)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Named {
     pub id: Uuid,
     pub container: Uuid,
@@ -17,15 +18,19 @@ pub struct Named {
     pub created: DateTime<Utc>,
     /// 32-byte hash of the lowercase string of user details.
     #[serde_as(as = "serde_with::hex::Hex")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub user_details: [u8; 34],
     #[rule(Opt(MaxLength(1024)))]
     pub short_desc: Option<String>,
     #[rule(Opt(MaxLength(2048)))]
     pub long_desc: Option<String>,
     pub container_type: ContainerType,
+    /// Set once the name has been retired; retired names are kept but no longer resolve.
+    pub retired: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum ContainerType {
     ChartOfAccount,
     Ledger,
@@ -11,24 +11,30 @@ use crate::domain::posting_type::PostingType;
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Validator)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Posting {
     pub id: Uuid,
     /// 32-byte hash of name of User that recorded the posting
     #[serde_as(as = "serde_with::hex::Hex")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub record_user: [u8; 34],
     pub record_time: DateTime<Utc>,
     /// 32-byte hash of operation id
     #[serde_as(as = "serde_with::hex::Hex")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub opr_id: [u8; 34],
     pub opr_time: DateTime<Utc>,
     /// 32-byte hash of Operation Type
     #[serde_as(as = "serde_with::hex::Hex")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub opr_type: [u8; 34],
     /// 32-byte hash of Operation Details
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub opr_details: Option<[u8; 34]>,
     /// 32-byte hash Operation Source
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub opr_src: Option<[u8; 34]>,
     pub pst_time: DateTime<Utc>,
     pub pst_type: PostingType,
@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostingApproval {
+    pub id: Uuid,
+    pub posting_id: Uuid,
+    /// 32-byte hash of name of User that approved the posting
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub approver: [u8; 34],
+    pub approval_time: DateTime<Utc>,
+}
@@ -10,27 +10,37 @@ use crate::domain::posting_type::PostingType;
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Validator)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PostingLine {
     pub id: Uuid,
     pub account: LedgerAccount,
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub debit_amount: BigDecimal,
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub credit_amount: BigDecimal,
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub details: Option<[u8; 34]>,
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub src_account: Option<[u8; 34]>,
     pub base_line: Option<Uuid>,
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub sub_opr_src_id: Option<[u8; 34]>,
     pub record_time: DateTime<Utc>,
     #[serde_as(as = "serde_with::hex::Hex")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub opr_id: [u8; 34],
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub opr_src: Option<[u8; 34]>,
     pub pst_time: DateTime<Utc>,
+    pub val_time: Option<DateTime<Utc>>,
     pub pst_type: PostingType,
     pub pst_status: PostingStatus,
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub hash: Option<[u8; 34]>,
     #[rule(Opt(MaxLength(1024)))]
     pub additional_information: Option<String>,
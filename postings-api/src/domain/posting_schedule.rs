@@ -0,0 +1,72 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use uuid::Uuid;
+
+use crate::domain::ledger::Ledger;
+use crate::domain::posting_type::PostingType;
+use crate::domain::recurrence::Recurrence;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ScheduleStatus {
+    Active,
+    /// All occurrences of the rule have been posted.
+    Finished,
+    Cancelled,
+}
+
+/// How occurrences missed while no run took place, e.g. during downtime, are posted.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum CatchUp {
+    /// Every missed occurrence gets its posting.
+    #[default]
+    All,
+    /// Only the latest missed occurrence is posted; earlier ones are skipped.
+    Latest,
+}
+
+/// Line of the posting a schedule books at each occurrence.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScheduledLine {
+    pub account_id: Uuid,
+    pub debit_amount: BigDecimal,
+    pub credit_amount: BigDecimal,
+}
+
+/// Posting template booked on every occurrence of a recurrence rule, such as a standing order,
+/// a monthly fee or an amortisation instalment.
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PostingSchedule {
+    pub id: Uuid,
+    pub ledger: Ledger,
+    pub recurrence: Recurrence,
+    /// Time the rule starts from; occurrences keep its time of day.
+    pub start_time: DateTime<Utc>,
+    pub catch_up: CatchUp,
+    /// 32-byte hash of the user the postings are recorded for
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub record_user: [u8; 34],
+    /// 32-byte hash of the operation type of the postings
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub opr_type: [u8; 34],
+    pub pst_type: PostingType,
+    pub lines: Vec<ScheduledLine>,
+    pub status: ScheduleStatus,
+    /// Index of the next occurrence to post, counting from zero.
+    pub next_index: u32,
+    /// Time of the next occurrence to post, `None` once the rule is exhausted.
+    pub next_time: Option<DateTime<Utc>>,
+    pub record_time: DateTime<Utc>,
+}
+
+/// Posting booked for one occurrence of a schedule.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ScheduleRun {
+    pub schedule_id: Uuid,
+    pub occurrence_index: u32,
+    pub occurrence_time: DateTime<Utc>,
+    pub posting_id: Uuid,
+    pub run_time: DateTime<Utc>,
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub enum PostingStatus {
     Deferred,
     Posted,
@@ -11,3 +12,54 @@ pub enum PostingStatus {
     Cancelled,
     Other,
 }
+
+impl PostingStatus {
+    /// Whether postings in this status count toward balances and are linked into the ledger's hash chain.
+    pub fn is_effective(&self) -> bool {
+        matches!(self, PostingStatus::Posted | PostingStatus::Tax | PostingStatus::Other)
+    }
+
+    /// Allowed lifecycle transitions. Effective and cancelled postings are final.
+    pub fn can_transition_to(&self, next: &PostingStatus) -> bool {
+        match self {
+            PostingStatus::Proposed | PostingStatus::Deferred | PostingStatus::Unposted => {
+                matches!(next, PostingStatus::Posted | PostingStatus::Cancelled)
+            }
+            PostingStatus::Simulated => matches!(next, PostingStatus::Cancelled),
+            PostingStatus::Posted
+            | PostingStatus::Tax
+            | PostingStatus::Other
+            | PostingStatus::Cancelled => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_effective() {
+        assert!(PostingStatus::Posted.is_effective());
+        assert!(PostingStatus::Tax.is_effective());
+        assert!(PostingStatus::Other.is_effective());
+        assert!(!PostingStatus::Deferred.is_effective());
+        assert!(!PostingStatus::Proposed.is_effective());
+        assert!(!PostingStatus::Simulated.is_effective());
+        assert!(!PostingStatus::Unposted.is_effective());
+        assert!(!PostingStatus::Cancelled.is_effective());
+    }
+
+    #[test]
+    fn test_can_transition_to() {
+        assert!(PostingStatus::Proposed.can_transition_to(&PostingStatus::Posted));
+        assert!(PostingStatus::Proposed.can_transition_to(&PostingStatus::Cancelled));
+        assert!(PostingStatus::Deferred.can_transition_to(&PostingStatus::Posted));
+        assert!(PostingStatus::Unposted.can_transition_to(&PostingStatus::Cancelled));
+        assert!(PostingStatus::Simulated.can_transition_to(&PostingStatus::Cancelled));
+        assert!(!PostingStatus::Simulated.can_transition_to(&PostingStatus::Posted));
+        assert!(!PostingStatus::Proposed.can_transition_to(&PostingStatus::Deferred));
+        assert!(!PostingStatus::Posted.can_transition_to(&PostingStatus::Cancelled));
+        assert!(!PostingStatus::Cancelled.can_transition_to(&PostingStatus::Posted));
+    }
+}
@@ -7,16 +7,21 @@ use crate::domain::ledger_account::LedgerAccount;
 
 #[serde_as]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct PostingTrace {
     pub id: Uuid,
     pub tgt_pst_id: Uuid,
     pub src_pst_time: DateTime<Utc>,
     pub src_pst_id: Uuid,
     #[serde_as(as = "serde_with::hex::Hex")]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub src_opr_id: [u8; 34],
     pub account: LedgerAccount,
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub debit_amount: BigDecimal,
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub credit_amount: BigDecimal,
     #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[cfg_attr(feature = "openapi", schema(value_type = Option<String>))]
     pub src_pst_hash: Option<[u8; 34]>,
 }
@@ -0,0 +1,229 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::ServiceError;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+/// Recurrence rule in a subset of RFC 5545 RRULE: `FREQ`, `INTERVAL`, `BYMONTHDAY`, `COUNT` and
+/// `UNTIL`, e.g. `FREQ=MONTHLY;BYMONTHDAY=-1;COUNT=12` for the last day of the next twelve months.
+///
+/// Occurrences are derived from the start time rather than from each other, so a monthly rule
+/// starting on the 31st falls on the last day of shorter months and returns to the 31st after.
+/// They keep the start's time of day.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub struct Recurrence {
+    pub frequency: Frequency,
+    /// Number of days, weeks, months or years between occurrences.
+    pub interval: u32,
+    /// Day of the month for monthly and yearly rules; negative days count from the end of the
+    /// month, -1 being the last day. Defaults to the day of the start time.
+    pub by_month_day: Option<i32>,
+    /// Maximum number of occurrences.
+    pub count: Option<u32>,
+    /// Last time an occurrence may fall on.
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl Recurrence {
+    pub fn new(frequency: Frequency) -> Self {
+        Self { frequency, interval: 1, by_month_day: None, count: None, until: None }
+    }
+
+    /// Monthly on the last day of the month.
+    pub fn end_of_month() -> Self {
+        Self { by_month_day: Some(-1), ..Self::new(Frequency::Monthly) }
+    }
+
+    /// Occurrences at or after `start`, in order.
+    pub fn occurrences(&self, start: DateTime<Utc>) -> impl Iterator<Item = DateTime<Utc>> + '_ {
+        (0u32..)
+            .map_while(move |k| self.candidate(start, k))
+            .filter(move |time| *time >= start)
+            .take(self.count.map_or(usize::MAX, |c| c as usize))
+            .take_while(move |time| self.until.is_none_or(|until| *time <= until))
+    }
+
+    /// The `n`-th occurrence, counting from zero.
+    pub fn occurrence(&self, start: DateTime<Utc>, n: u32) -> Option<DateTime<Utc>> {
+        self.occurrences(start).nth(n as usize)
+    }
+
+    fn candidate(&self, start: DateTime<Utc>, k: u32) -> Option<DateTime<Utc>> {
+        let step = k.checked_mul(self.interval)?;
+        match self.frequency {
+            Frequency::Daily => start.checked_add_signed(Duration::days(step as i64)),
+            Frequency::Weekly => start.checked_add_signed(Duration::weeks(step as i64)),
+            Frequency::Monthly => self.in_month(start, step),
+            Frequency::Yearly => self.in_month(start, step.checked_mul(12)?),
+        }
+    }
+
+    /// Day of the rule in the month `months` after the start's, clamped to the month's length.
+    fn in_month(&self, start: DateTime<Utc>, months: u32) -> Option<DateTime<Utc>> {
+        let first = start.date_naive().with_day(1)?.checked_add_months(Months::new(months))?;
+        let last_day = first.checked_add_months(Months::new(1))?.pred_opt()?.day() as i32;
+        let day = match self.by_month_day {
+            Some(day) if day > 0 => day.min(last_day),
+            Some(day) => (last_day + 1 + day).max(1),
+            None => (start.day() as i32).min(last_day),
+        };
+        Some(first.with_day(day as u32)?.and_time(start.time()).and_utc())
+    }
+
+    fn parse_until(value: &str) -> Option<DateTime<Utc>> {
+        NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+            .ok()
+            .or_else(|| NaiveDate::parse_from_str(value, "%Y%m%d").ok()?.and_hms_opt(23, 59, 59))
+            .map(|until| until.and_utc())
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = ServiceError;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let rule = rule.strip_prefix("RRULE:").unwrap_or(rule);
+        let mut frequency = None;
+        let mut recurrence = Self::new(Frequency::Daily);
+        for part in rule.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part.split_once('=').ok_or(ServiceError::InvalidRecurrence)?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        "YEARLY" => Frequency::Yearly,
+                        _ => return Err(ServiceError::InvalidRecurrence),
+                    })
+                }
+                "INTERVAL" => recurrence.interval = value.parse().map_err(|_| ServiceError::InvalidRecurrence)?,
+                "BYMONTHDAY" => recurrence.by_month_day = Some(value.parse().map_err(|_| ServiceError::InvalidRecurrence)?),
+                "COUNT" => recurrence.count = Some(value.parse().map_err(|_| ServiceError::InvalidRecurrence)?),
+                "UNTIL" => recurrence.until = Some(Self::parse_until(value).ok_or(ServiceError::InvalidRecurrence)?),
+                _ => return Err(ServiceError::InvalidRecurrence),
+            }
+        }
+        recurrence.frequency = frequency.ok_or(ServiceError::InvalidRecurrence)?;
+        let month_day_valid = match recurrence.by_month_day {
+            None => true,
+            Some(day) => {
+                matches!(recurrence.frequency, Frequency::Monthly | Frequency::Yearly) && day != 0 && (-31..=31).contains(&day)
+            }
+        };
+        if recurrence.interval == 0 || !month_day_valid || (recurrence.count.is_some() && recurrence.until.is_some()) {
+            return Err(ServiceError::InvalidRecurrence);
+        }
+        Ok(recurrence)
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+            Frequency::Yearly => "YEARLY",
+        };
+        write!(f, "FREQ={frequency}")?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if let Some(day) = self.by_month_day {
+            write!(f, ";BYMONTHDAY={day}")?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={count}")?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<String> for Recurrence {
+    type Error = ServiceError;
+
+    fn try_from(rule: String) -> Result<Self, Self::Error> {
+        rule.parse()
+    }
+}
+
+impl From<Recurrence> for String {
+    fn from(recurrence: Recurrence) -> Self {
+        recurrence.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, 9, 0, 0).unwrap()
+    }
+
+    fn first(rule: &str, start: DateTime<Utc>, n: usize) -> Vec<DateTime<Utc>> {
+        rule.parse::<Recurrence>().unwrap().occurrences(start).take(n).collect()
+    }
+
+    #[test]
+    fn test_daily_and_weekly() {
+        assert_eq!(first("FREQ=DAILY;INTERVAL=2", at(2024, 2, 28), 3), vec![at(2024, 2, 28), at(2024, 3, 1), at(2024, 3, 3)]);
+        assert_eq!(first("FREQ=WEEKLY", at(2024, 12, 30), 2), vec![at(2024, 12, 30), at(2025, 1, 6)]);
+    }
+
+    #[test]
+    fn test_monthly_keeps_start_day() {
+        assert_eq!(
+            first("FREQ=MONTHLY", at(2025, 1, 31), 4),
+            vec![at(2025, 1, 31), at(2025, 2, 28), at(2025, 3, 31), at(2025, 4, 30)]
+        );
+    }
+
+    #[test]
+    fn test_end_of_month() {
+        let start = at(2024, 1, 15);
+        let occurrences: Vec<_> = Recurrence::end_of_month().occurrences(start).take(3).collect();
+        assert_eq!(occurrences, vec![at(2024, 1, 31), at(2024, 2, 29), at(2024, 3, 31)]);
+    }
+
+    #[test]
+    fn test_by_month_day_before_start_is_skipped() {
+        assert_eq!(first("FREQ=MONTHLY;BYMONTHDAY=1", at(2025, 1, 15), 2), vec![at(2025, 2, 1), at(2025, 3, 1)]);
+    }
+
+    #[test]
+    fn test_count_and_until() {
+        assert_eq!(first("FREQ=YEARLY;COUNT=2", at(2024, 2, 29), 5), vec![at(2024, 2, 29), at(2025, 2, 28)]);
+        assert_eq!(first("FREQ=MONTHLY;UNTIL=20250301", at(2025, 1, 1), 5), vec![at(2025, 1, 1), at(2025, 2, 1), at(2025, 3, 1)]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for rule in ["FREQ=DAILY", "FREQ=MONTHLY;INTERVAL=3;BYMONTHDAY=-1;COUNT=4", "FREQ=WEEKLY;UNTIL=20251231T000000Z"] {
+            assert_eq!(rule.parse::<Recurrence>().unwrap().to_string(), rule);
+        }
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        for rule in ["", "INTERVAL=2", "FREQ=HOURLY", "FREQ=DAILY;INTERVAL=0", "FREQ=DAILY;BYMONTHDAY=1", "FREQ=MONTHLY;BYMONTHDAY=0", "FREQ=DAILY;COUNT=2;UNTIL=20250101", "FREQ=DAILY;BYDAY=MO"] {
+            assert!(rule.parse::<Recurrence>().is_err(), "{rule}");
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Time axis along which posting lines are selected for balances and statements.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum TimeAxis {
+    /// Effective booking time of the posting.
+    #[default]
+    PstTime,
+    /// Value date, used for interest calculation. Lines without one fall back to their posting time.
+    ValTime,
+    /// Time the posting was recorded in the system.
+    RecordTime,
+}
@@ -1,12 +1,45 @@
 pub mod domain;
 pub mod service;
 
+use bigdecimal::BigDecimal;
 use thiserror::Error;
+use uuid::Uuid;
+
+/// What kind of database failure a [`ServiceError::Db`] stems from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DbFailure {
+    /// The database could not be reached or the connection was lost.
+    Connection,
+    /// The transaction lost a serialization conflict or deadlock against a concurrent one.
+    Conflict,
+    UniqueViolation,
+    ForeignKeyViolation,
+    /// A check or not null constraint rejected the row.
+    CheckViolation,
+    /// A row the operation relies on does not exist (any more).
+    NotFound,
+    /// Anything else, e.g. a malformed statement or a stored value that does not decode.
+    Other,
+}
+
+impl DbFailure {
+    /// Whether the same operation may succeed when tried again.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, DbFailure::Connection | DbFailure::Conflict)
+    }
+}
 
 #[derive(Error, Debug)]
 pub enum ServiceError {
-    #[error("Database error")]
-    Db,
+    /// `context` tells what was being done and with which ids, `source` is the database error.
+    #[error("Database error while {context}")]
+    Db {
+        failure: DbFailure,
+        constraint: Option<String>,
+        context: String,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
     #[error("Not enough information provided")]
     NotEnoughInfo,
     #[error("Chart of account not found")]
@@ -31,4 +64,70 @@ pub enum ServiceError {
     StatementNotFound,
     #[error("Statement is already closed")]
     StatementAlreadyClosed,
+    #[error("Posting status transition is not allowed")]
+    InvalidStatusTransition,
+    #[error("Posting cannot be approved by the user who recorded it")]
+    SelfApproval,
+    #[error("Period must end after it starts")]
+    InvalidPeriod,
+    #[error("Period overlaps an accrual already booked for the account")]
+    AccrualPeriodOverlap,
+    #[error("Name not found")]
+    NamedNotFound,
+    #[error("Name is invalid")]
+    InvalidName,
+    #[error("Name is already taken in its context")]
+    DuplicateName,
+    #[error("Account definitions of the chart of account are inconsistent")]
+    InvalidAccountDefinition,
+    #[error("Ledger account does not conform to its chart of account")]
+    NonConformingAccount,
+    #[error("Ledger account has no code")]
+    MissingAccountCode,
+    #[error("Account code does not match the account code scheme")]
+    InvalidAccountCode,
+    #[error("Account code is already taken in the ledger")]
+    DuplicateAccountCode,
+    #[error("COA definition file could not be read or written")]
+    InvalidCoaFile,
+    #[error("Ledger account is blocked for debits")]
+    AccountDebitBlocked,
+    #[error("Ledger account is blocked for credits")]
+    AccountCreditBlocked,
+    #[error("Ledger account is frozen")]
+    AccountFrozen,
+    #[error("Ledger account is closed")]
+    AccountClosed,
+    #[error("Ledger account can only be closed at zero balance")]
+    AccountBalanceNotZero,
+    #[error("Ledger account still has open child accounts")]
+    OpenChildAccounts,
+    #[error("Minimum balance of the account limit is above its maximum")]
+    InvalidAccountLimit,
+    #[error("Posting exceeds the limit of account {account_id} by {shortfall}")]
+    LimitExceeded { account_id: Uuid, shortfall: BigDecimal },
+    #[error("Accounts belong to different ledgers")]
+    LedgerMismatch,
+    #[error("Hold not found")]
+    HoldNotFound,
+    #[error("Hold was already captured, released or has expired")]
+    HoldNotActive,
+    #[error("Hold amount must be positive and captures cannot exceed the held amount")]
+    InvalidHoldAmount,
+    #[error("Capture would need approval under the ledger's approval policy")]
+    CaptureRequiresApproval,
+    #[error("Recurrence rule is invalid or unsupported")]
+    InvalidRecurrence,
+    #[error("Schedule not found")]
+    ScheduleNotFound,
+    #[error("Schedule is no longer active")]
+    ScheduleNotActive,
+}
+
+impl ServiceError {
+    /// Whether the call may succeed when made again unchanged, i.e. it failed on a lost
+    /// connection or a conflict with a concurrent transaction.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ServiceError::Db { failure, .. } if failure.is_retryable())
+    }
 }
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::account_state::{AccountState, AccountStateChange};
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait AccountLifecycleService {
+    /// Records a state change effective at `effective_time`. Closing requires a zero balance and
+    /// no child account that is still open at that time; closed accounts accept no further changes.
+    async fn change_account_state(
+        &self,
+        account_id: Uuid,
+        state: AccountState,
+        effective_time: DateTime<Utc>,
+        record_user: [u8; 34],
+        reason: Option<String>,
+    ) -> Result<AccountStateChange, ServiceError>;
+    async fn find_account_state(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<AccountState, ServiceError>;
+    /// All state changes of the account ordered by effective time.
+    async fn find_account_state_changes(&self, account_id: Uuid) -> Result<Vec<AccountStateChange>, ServiceError>;
+}
@@ -2,11 +2,18 @@ use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use crate::domain::account_stmt::AccountStmt;
 use crate::domain::ledger_account::LedgerAccount;
+use crate::domain::time_axis::TimeAxis;
 use crate::ServiceError;
 
 #[async_trait]
 pub trait AccountStmtService {
     async fn read_stmt(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<AccountStmt, ServiceError>;
+    /// Reads the statement with lines selected along the given time axis. Closed statements are
+    /// only reused on the posting time axis, which is the one they are closed on: a back-valued
+    /// line may sit before a statement on the value time axis yet after it on the posting time
+    /// axis. On the other axes the statement therefore sums the account's whole history up to
+    /// `ref_time`.
+    async fn read_stmt_by_time_axis(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>, axis: TimeAxis) -> Result<AccountStmt, ServiceError>;
     async fn create_stmt(&self, ledger_account: LedgerAccount, ref_time: DateTime<Utc>) -> Result<AccountStmt, ServiceError>;
     async fn close_stmt(&self, stmt: AccountStmt) -> Result<AccountStmt, ServiceError>;
 }
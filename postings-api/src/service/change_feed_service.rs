@@ -0,0 +1,21 @@
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use futures_core::Stream;
+use uuid::Uuid;
+use crate::domain::account_movement::{AccountMovement, FeedCursor};
+use crate::ServiceError;
+
+pub type MovementStream = Pin<Box<dyn Stream<Item = Result<AccountMovement, ServiceError>> + Send>>;
+
+#[async_trait]
+pub trait ChangeFeedService {
+    /// Streams the lines appended to the account after `from_cursor`, or from its first line, in
+    /// cursor order and without end. Only lines in the books are streamed: a proposed or deferred
+    /// line appears under a new cursor once it is posted, and not at all if cancelled. Lines are read in batches only as the stream is polled, so a
+    /// slow consumer holds back reading rather than buffering. A failed read ends the stream
+    /// after yielding the error; subscribing again with the last cursor resumes without gaps.
+    async fn subscribe(&self, account_id: Uuid, from_cursor: Option<FeedCursor>) -> Result<MovementStream, ServiceError>;
+    /// Cursor of the account's latest line, to subscribe to new lines only.
+    async fn current_cursor(&self, account_id: Uuid) -> Result<FeedCursor, ServiceError>;
+}
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use crate::domain::chart_of_account::ChartOfAccount;
+use crate::domain::coa_account_definition::CoaAccountDefinition;
 use crate::domain::named::Named;
 use crate::ServiceError;
 use uuid::Uuid;
@@ -9,4 +10,8 @@ pub trait ChartOfAccountService {
     async fn new_chart_of_account(&self, chart_of_account: ChartOfAccount, named: Vec<Named>) -> Result<(ChartOfAccount, Vec<Named>), ServiceError>;
     async fn find_chart_of_accounts_by_name(&self, name: &str) -> Result<Vec<ChartOfAccount>, ServiceError>;
     async fn find_chart_of_accounts_by_id(&self, id: Uuid) -> Result<Option<ChartOfAccount>, ServiceError>;
+    /// Adds account definitions to the chart. Codes must be unique within the chart and every
+    /// parent code must resolve to a definition of the same chart without forming a cycle.
+    async fn add_account_definitions(&self, coa_id: Uuid, definitions: Vec<CoaAccountDefinition>) -> Result<Vec<CoaAccountDefinition>, ServiceError>;
+    async fn find_account_definitions(&self, coa_id: Uuid) -> Result<Vec<CoaAccountDefinition>, ServiceError>;
 }
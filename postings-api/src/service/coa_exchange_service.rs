@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use crate::domain::coa_document::CoaDocument;
+use crate::domain::ledger::Ledger;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait CoaExchangeService {
+    /// Creates whatever part of the document does not exist yet: the chart, its account
+    /// definitions, the ledger, its accounts and missing names. Importing the same document
+    /// twice leaves the second run without effect.
+    async fn import_coa(&self, document: CoaDocument) -> Result<(Ledger, Vec<LedgerAccount>), ServiceError>;
+    /// Describes the ledger and its chart in the format read by [`CoaExchangeService::import_coa`].
+    async fn export_coa(&self, ledger_id: Uuid) -> Result<CoaDocument, ServiceError>;
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use crate::domain::account_balance::AccountBalance;
+use crate::domain::hold::Hold;
+use crate::domain::posting::Posting;
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait HoldService {
+    /// Reserves `amount` on the account until `expiry`. Fails with [`ServiceError::LimitExceeded`]
+    /// if the available balance would drop below the account's minimum balance.
+    async fn place_hold(&self, account_id: Uuid, amount: BigDecimal, expiry: DateTime<Utc>, opr_id: [u8; 34]) -> Result<Hold, ServiceError>;
+    /// Turns the hold into a posting that moves `amount`, or the whole held amount if `None`, from
+    /// the held account to the counter account. A capture closes the hold: a partial one frees the
+    /// remainder rather than keeping it for further captures. Fails with
+    /// [`ServiceError::CaptureRequiresApproval`] if the ledger's approval policy would hold the
+    /// posting back, as the funds would leave the hold before reaching the books.
+    async fn capture_hold(
+        &self,
+        hold_id: Uuid,
+        counter_account_id: Uuid,
+        amount: Option<BigDecimal>,
+        record_user: [u8; 34],
+    ) -> Result<(Hold, Posting), ServiceError>;
+    async fn release_hold(&self, hold_id: Uuid) -> Result<Hold, ServiceError>;
+    /// Marks the holds that expired at or before `ref_time` as expired and returns their number.
+    /// Holds stop reserving funds at their expiry whether or not this has run.
+    async fn expire_holds(&self, ref_time: DateTime<Utc>) -> Result<u64, ServiceError>;
+    async fn find_hold_by_id(&self, id: Uuid) -> Result<Option<Hold>, ServiceError>;
+    async fn find_active_holds(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<Vec<Hold>, ServiceError>;
+    /// Booked balance of the postings up to `at` and the balance available after the holds
+    /// active at `at`.
+    async fn find_account_balance(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<AccountBalance, ServiceError>;
+}
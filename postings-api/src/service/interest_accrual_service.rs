@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use crate::domain::interest_accrual::{InterestAccrual, InterestAccrualRule};
+use crate::domain::interest_rate_schedule::InterestRateSchedule;
+use crate::domain::ledger_account::LedgerAccount;
+use crate::ServiceError;
+
+#[async_trait]
+pub trait InterestAccrualService {
+    /// Computes the daily interest of `[from, to)` from the account's value-dated balances.
+    /// Only effective lines are taken into account, so the result can be reproduced from the journal.
+    async fn compute_accrual(&self, account: LedgerAccount, schedule: &InterestRateSchedule, from: NaiveDate, to: NaiveDate) -> Result<InterestAccrual, ServiceError>;
+    /// Computes and books one accrual posting per rule, value-dated at `to`. Accruals rounding to zero
+    /// and periods already booked for an account are returned without a posting. A period that
+    /// overlaps another one booked for the account fails with `AccrualPeriodOverlap`.
+    async fn book_accruals(&self, rules: Vec<InterestAccrualRule>, from: NaiveDate, to: NaiveDate, record_user: [u8; 34]) -> Result<Vec<InterestAccrual>, ServiceError>;
+}
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use async_trait::async_trait;
+use crate::domain::account_limit::AccountLimit;
 use crate::domain::ledger::Ledger;
 use crate::domain::ledger_account::LedgerAccount;
 use crate::domain::named::Named;
@@ -8,12 +9,28 @@ use uuid::Uuid;
 
 #[async_trait]
 pub trait LedgerService {
+    /// Creates a ledger. A nil id lets the service assign one; names get their ids and creation
+    /// time from the service.
     async fn new_ledger(&self, ledger: Ledger, named: Vec<Named>) -> Result<(Ledger, Vec<Named>), ServiceError>;
     async fn find_ledger_by_id(&self, id: Uuid) -> Result<Option<Ledger>, ServiceError>;
     async fn find_ledger_by_name(&self, name: &str, coa_id: Uuid) -> Result<Vec<Ledger>, ServiceError>;
+    /// Creates a ledger on the chart together with one account per account definition of the chart,
+    /// all in one transaction.
+    async fn instantiate_ledger_from_coa(&self, coa_id: Uuid, named: Vec<Named>) -> Result<(Ledger, Vec<LedgerAccount>), ServiceError>;
+    /// Creates a ledger account. Its code, if any, must match the account code scheme and be free
+    /// in the ledger. On charts with account definitions the account must match the definition of
+    /// its code, including its parent. A nil id lets the service assign one.
     async fn new_ledger_account(&self, ledger_account: LedgerAccount, named: Vec<Named>) -> Result<(LedgerAccount, Vec<Named>), ServiceError>;
     async fn find_ledger_account_by_id(&self, id: Uuid) -> Result<Option<LedgerAccount>, ServiceError>;
+    async fn find_ledger_account_by_code(&self, ledger: &Ledger, code: &str) -> Result<Option<LedgerAccount>, ServiceError>;
+    /// Accounts whose code lies between `from` and `to`, both inclusive, sub-accounts of `to`
+    /// included. Codes compare as text, so ranges work best with codes of equal length.
+    async fn find_ledger_accounts_by_code_range(&self, ledger: &Ledger, from: &str, to: &str) -> Result<Vec<LedgerAccount>, ServiceError>;
     async fn find_ledger_account_by_name(&self, ledger: &Ledger, name: &str) -> Result<Vec<LedgerAccount>, ServiceError>;
     async fn check_if_ledger_account_exist(&self, ledger: &Ledger, name: &str) -> Result<bool, ServiceError>;
+    /// Sets the balance limit of the account, replacing the one it has.
+    async fn set_account_limit(&self, limit: AccountLimit) -> Result<AccountLimit, ServiceError>;
+    async fn find_account_limit(&self, account_id: Uuid) -> Result<Option<AccountLimit>, ServiceError>;
+    async fn remove_account_limit(&self, account_id: Uuid) -> Result<(), ServiceError>;
     async fn find_ledger_accounts_by_ibans(&self, ibans: Vec<String>, ledger: &Ledger) -> Result<HashMap<String, Vec<LedgerAccount>>, ServiceError>;
 }
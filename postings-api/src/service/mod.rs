@@ -1,4 +1,12 @@
+pub mod account_lifecycle_service;
 pub mod account_stmt_service;
+pub mod change_feed_service;
 pub mod chart_of_account_service;
+pub mod coa_exchange_service;
+pub mod hold_service;
+pub mod interest_accrual_service;
 pub mod ledger_service;
+pub mod named_service;
+pub mod outbox_service;
 pub mod posting_service;
+pub mod schedule_service;
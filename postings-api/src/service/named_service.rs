@@ -1,10 +1,19 @@
 use async_trait::async_trait;
-use crate::domain::named::Named;
+use crate::domain::named::{ContainerType, Named};
 use crate::ServiceError;
 use uuid::Uuid;
 
 #[async_trait]
 pub trait NamedService {
+    async fn add_named(&self, named: Named) -> Result<Named, ServiceError>;
+    /// Replaces name, language and descriptions of an active name. Container and type stay as they are.
+    async fn update_named(&self, named: Named) -> Result<Named, ServiceError>;
+    async fn retire_named(&self, id: Uuid) -> Result<Named, ServiceError>;
+    /// Active names of a container, oldest first.
     async fn find_by_container_id(&self, container_id: Uuid) -> Result<Vec<Named>, ServiceError>;
-    async fn find_by_name_and_type(&self, name: String, container_type: ContainerType) -> Result<Vec<Named>, ServiceError>;
+    /// Active names with the given name and container type.
+    async fn find_by_name_and_type(&self, name: &str, container_type: ContainerType) -> Result<Vec<Named>, ServiceError>;
+    /// Resolves the name of a container in the first of `languages` it has a name in, falling back
+    /// to its oldest active name when none of them match.
+    async fn resolve_name(&self, container_id: Uuid, languages: &[String]) -> Result<Option<Named>, ServiceError>;
 }
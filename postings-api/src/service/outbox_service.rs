@@ -0,0 +1,32 @@
+use async_trait::async_trait;
+use thiserror::Error;
+use uuid::Uuid;
+use crate::domain::domain_event::{DomainEvent, RelayReport};
+use crate::ServiceError;
+
+#[derive(Error, Debug)]
+#[error("Event could not be delivered: {0}")]
+pub struct DeliveryError(pub String);
+
+/// Destination of relayed events, e.g. a message broker or webhook.
+#[async_trait]
+pub trait EventSink {
+    /// Must not return before the event is durably accepted: a successful delivery marks it
+    /// dispatched. The same event can still arrive more than once, e.g. after a crash between
+    /// delivery and marking.
+    async fn deliver(&self, event: &DomainEvent) -> Result<(), DeliveryError>;
+}
+
+#[async_trait]
+pub trait OutboxService {
+    /// Undispatched events, oldest first.
+    async fn find_pending_events(&self, limit: u32) -> Result<Vec<DomainEvent>, ServiceError>;
+    /// Events of the ledger after the given sequence number, dispatched or not.
+    async fn find_ledger_events(&self, ledger_id: Uuid, after_sequence: u64, limit: u32) -> Result<Vec<DomainEvent>, ServiceError>;
+    async fn mark_dispatched(&self, ids: &[i64]) -> Result<(), ServiceError>;
+    /// Delivers up to `limit` pending events of each ledger to the sink, in sequence order, and
+    /// marks the delivered ones dispatched. A failed delivery holds back the rest of its ledger until
+    /// a later pass; other ledgers carry on. Only one relay should run against a database at a
+    /// time, as concurrent passes would deliver the same events out of order.
+    async fn relay(&self, sink: &(dyn EventSink + Send + Sync), limit: u32) -> Result<RelayReport, ServiceError>;
+}
@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
+use crate::domain::approval_policy::ApprovalPolicy;
+use crate::domain::hash_chain::ChainVerification;
 use crate::domain::ledger_account::LedgerAccount;
 use crate::domain::posting::Posting;
+use crate::domain::posting_approval::PostingApproval;
 use crate::domain::posting_line::PostingLine;
 use crate::ServiceError;
 use uuid::Uuid;
@@ -13,8 +16,34 @@ pub struct Page<T> {
 
 #[async_trait]
 pub trait PostingService {
+    /// Records a posting under a new id and the current time. Lines with a nil id get one assigned.
     async fn new_posting(&self, posting: Posting) -> Result<Posting, ServiceError>;
+    async fn new_postings(&self, postings: Vec<Posting>) -> Result<Vec<Posting>, ServiceError>;
+    /// Moves a proposed posting to `Posted` and links it into the hash chain. The approver must
+    /// differ from the user that recorded the posting. A deferred posting that falls under the
+    /// ledger's approval policy stays deferred; the approval is recorded and lets
+    /// `post_deferred_postings` post it when due. Other deferred postings cannot be approved.
+    async fn approve_posting(&self, posting_id: Uuid, approver: [u8; 34]) -> Result<Posting, ServiceError>;
+    /// Moves a posting that has not reached the books to `Cancelled`.
+    async fn cancel_posting(&self, posting_id: Uuid) -> Result<Posting, ServiceError>;
+    /// Posts every deferred posting whose value time is at or before `ref_time`. A posting that
+    /// awaits approval under the ledger's approval policy, would breach an account limit or would
+    /// book into an account whose state forbids it stays deferred, to be tried again at the next
+    /// run, and is left out of the result.
+    async fn post_deferred_postings(&self, ref_time: DateTime<Utc>) -> Result<Vec<Posting>, ServiceError>;
+    /// Proposed postings of the ledger that are waiting for approval, oldest first.
+    async fn find_pending_postings(&self, ledger_id: Uuid) -> Result<Vec<Posting>, ServiceError>;
+    async fn find_posting_approvals(&self, posting_id: Uuid) -> Result<Vec<PostingApproval>, ServiceError>;
+    /// Sets the four-eyes rule of a ledger. Postings it applies to are recorded as `Proposed`.
+    async fn set_approval_policy(&self, policy: ApprovalPolicy) -> Result<ApprovalPolicy, ServiceError>;
+    async fn find_approval_policy(&self, ledger_id: Uuid) -> Result<Option<ApprovalPolicy>, ServiceError>;
+    async fn remove_approval_policy(&self, ledger_id: Uuid) -> Result<(), ServiceError>;
     async fn find_postings_by_operation_id(&self, opr_id: &[u8; 34]) -> Result<Vec<Posting>, ServiceError>;
+    /// Postings of the ledger with a posting time in `(date_from, date_to]`, in posting time order.
+    async fn find_postings_by_ledger(&self, ledger_id: Uuid, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<Vec<Posting>, ServiceError>;
+    /// Checks that every hashed posting of the ledger links to the one before it. The links are
+    /// checked, not the content the hashes were computed over.
+    async fn verify_hash_chain(&self, ledger_id: Uuid) -> Result<ChainVerification, ServiceError>;
     async fn find_postings_by_dates(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<Vec<PostingLine>, ServiceError>;
     async fn find_postings_by_dates_paged(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>, page: usize, size: usize) -> Result<Page<PostingLine>, ServiceError>;
     async fn find_posting_line_by_id(&self, ledger_account: LedgerAccount, transaction_id: Uuid) -> Result<PostingLine, ServiceError>;
@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::posting::Posting;
+use crate::domain::posting_schedule::{PostingSchedule, ScheduleRun};
+use crate::ServiceError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ScheduleService {
+    /// Stores the schedule. Its lines must balance and belong to accounts of the schedule's
+    /// ledger; the next occurrence is derived from the rule.
+    async fn new_schedule(&self, schedule: PostingSchedule) -> Result<PostingSchedule, ServiceError>;
+    async fn cancel_schedule(&self, id: Uuid) -> Result<PostingSchedule, ServiceError>;
+    async fn find_schedule_by_id(&self, id: Uuid) -> Result<Option<PostingSchedule>, ServiceError>;
+    async fn find_schedules_by_ledger(&self, ledger_id: Uuid) -> Result<Vec<PostingSchedule>, ServiceError>;
+    /// Postings booked by the schedule, by occurrence.
+    async fn find_schedule_runs(&self, schedule_id: Uuid) -> Result<Vec<ScheduleRun>, ServiceError>;
+    /// Posts every occurrence due at `now` of the active schedules, each with the occurrence time
+    /// as posting time. An occurrence is posted exactly once, even when runs overlap. A failing
+    /// schedule is logged and skipped, so the others still run, and the next run retries it from
+    /// the same occurrence. The result holds the postings of every schedule, including those a
+    /// failing one saved before it failed.
+    async fn run_due(&self, now: DateTime<Utc>) -> Result<Vec<Posting>, ServiceError>;
+}
@@ -0,0 +1,75 @@
+use anyhow::{bail, Context};
+use postings_logic::engine::PostingsEngine;
+use sqlx::{MySqlPool, PgPool};
+
+/// Database the tool operates on, picked from the scheme of the connection URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackendKind {
+    Postgres,
+    MariaDb,
+}
+
+impl BackendKind {
+    /// `postgres://` and `postgresql://` select Postgres, `mysql://` and `mariadb://` MariaDB.
+    pub fn from_url(url: &str) -> anyhow::Result<Self> {
+        let scheme = url.split_once("://").map(|(scheme, _)| scheme).unwrap_or_default();
+        match scheme {
+            "postgres" | "postgresql" => Ok(BackendKind::Postgres),
+            "mysql" | "mariadb" => Ok(BackendKind::MariaDb),
+            _ => bail!("unsupported database URL scheme '{scheme}', expected postgres, postgresql, mysql or mariadb"),
+        }
+    }
+}
+
+/// A connection pool to one of the supported databases.
+#[derive(Debug, Clone)]
+pub enum Backend {
+    Postgres(PgPool),
+    MariaDb(MySqlPool),
+}
+
+impl Backend {
+    pub async fn connect(url: &str) -> anyhow::Result<Self> {
+        match BackendKind::from_url(url)? {
+            BackendKind::Postgres => Ok(Backend::Postgres(PgPool::connect(url).await.context("cannot connect to Postgres")?)),
+            BackendKind::MariaDb => {
+                // sqlx only knows the mysql scheme; MariaDB speaks the same protocol.
+                let url = url.strip_prefix("mariadb://").map(|rest| format!("mysql://{rest}")).unwrap_or_else(|| url.to_string());
+                Ok(Backend::MariaDb(MySqlPool::connect(&url).await.context("cannot connect to MariaDB")?))
+            }
+        }
+    }
+
+    /// Applies the schema migrations of the backend's repository crate that are not applied yet.
+    pub async fn migrate(&self) -> anyhow::Result<()> {
+        match self {
+            Backend::Postgres(pool) => postings_db_postgres::MIGRATOR.run(pool).await?,
+            Backend::MariaDb(pool) => postings_db_mariadb::MIGRATOR.run(pool).await?,
+        }
+        Ok(())
+    }
+
+    /// The services on the backend's repositories.
+    pub fn engine(&self) -> PostingsEngine {
+        let repositories = match self {
+            Backend::Postgres(pool) => postings_db_postgres::repositories(pool.clone()),
+            Backend::MariaDb(pool) => postings_db_mariadb::repositories(pool.clone()),
+        };
+        PostingsEngine::builder(repositories).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_kind_from_url_scheme() {
+        assert_eq!(BackendKind::from_url("postgres://user@localhost/db").unwrap(), BackendKind::Postgres);
+        assert_eq!(BackendKind::from_url("postgresql://user@localhost/db").unwrap(), BackendKind::Postgres);
+        assert_eq!(BackendKind::from_url("mysql://user@localhost/db").unwrap(), BackendKind::MariaDb);
+        assert_eq!(BackendKind::from_url("mariadb://user@localhost/db").unwrap(), BackendKind::MariaDb);
+        assert!(BackendKind::from_url("sqlite://ledger.db").is_err());
+        assert!(BackendKind::from_url("localhost/db").is_err());
+    }
+}
@@ -0,0 +1,159 @@
+use std::io::Write;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use clap::ValueEnum;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::posting_type::PostingType;
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// One row per posting line, with the posting's fields repeated.
+    Csv,
+    /// The postings with their lines, as returned by the service.
+    Json,
+}
+
+/// CSV row of one posting line. Hashes are hex encoded.
+#[derive(Debug, Serialize)]
+struct LineRow<'a> {
+    posting_id: Uuid,
+    opr_id: String,
+    pst_time: DateTime<Utc>,
+    record_time: DateTime<Utc>,
+    pst_type: &'a PostingType,
+    pst_status: &'a PostingStatus,
+    line_id: Uuid,
+    account_id: Uuid,
+    account_code: Option<&'a str>,
+    debit_amount: &'a BigDecimal,
+    credit_amount: &'a BigDecimal,
+    hash: Option<String>,
+}
+
+pub fn write_postings(postings: &[Posting], format: ExportFormat, out: &mut dyn Write) -> anyhow::Result<()> {
+    match format {
+        ExportFormat::Json => {
+            serde_json::to_writer_pretty(&mut *out, postings)?;
+            writeln!(out)?;
+        }
+        ExportFormat::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for posting in postings {
+                for line in &posting.lines {
+                    writer.serialize(LineRow {
+                        posting_id: posting.id,
+                        opr_id: hex::encode(posting.opr_id),
+                        pst_time: posting.pst_time,
+                        record_time: posting.record_time,
+                        pst_type: &posting.pst_type,
+                        pst_status: &posting.pst_status,
+                        line_id: line.id,
+                        account_id: line.account.id,
+                        account_code: line.account.code.as_deref(),
+                        debit_amount: &line.debit_amount,
+                        credit_amount: &line.credit_amount,
+                        hash: posting.hash_record.hash.map(hex::encode),
+                    })?;
+                }
+            }
+            writer.flush()?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::balance_side::BalanceSide;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::domain::posting_line::PostingLine;
+
+    fn create_test_posting() -> Posting {
+        let now = Utc::now();
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        let ledger = Ledger { id: Uuid::new_v4(), coa: coa.clone() };
+        let line = |code: &str, debit: i64, credit: i64| PostingLine {
+            id: Uuid::new_v4(),
+            account: LedgerAccount {
+                id: Uuid::new_v4(),
+                ledger: ledger.clone(),
+                parent: None,
+                coa: coa.clone(),
+                balance_side: BalanceSide::Dr,
+                category: AccountCategory::AS,
+                code: Some(code.to_string()),
+            },
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: now,
+            opr_id: [1; 34],
+            opr_src: None,
+            pst_time: now,
+            val_time: None,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+        };
+        Posting {
+            id: Uuid::new_v4(),
+            record_user: [0; 34],
+            record_time: now,
+            opr_id: [1; 34],
+            opr_time: now,
+            opr_type: [0; 34],
+            opr_details: None,
+            opr_src: None,
+            pst_time: now,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            val_time: None,
+            lines: vec![line("1000", 100, 0), line("2000", 0, 100)],
+            ledger,
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        }
+    }
+
+    #[test]
+    fn test_csv_has_one_row_per_line() {
+        let posting = create_test_posting();
+        let mut out = Vec::new();
+
+        write_postings(std::slice::from_ref(&posting), ExportFormat::Csv, &mut out).unwrap();
+
+        let csv = String::from_utf8(out).unwrap();
+        let rows: Vec<&str> = csv.lines().collect();
+        assert_eq!(rows.len(), 3);
+        assert!(rows[0].starts_with("posting_id,opr_id,pst_time"));
+        assert!(rows[1].contains(",1000,100,0,"));
+        assert!(rows[2].contains(",2000,0,100,"));
+        assert!(rows[1].starts_with(&format!("{},{}", posting.id, hex::encode([1u8; 34]))));
+    }
+
+    #[test]
+    fn test_json_round_trips() {
+        let posting = create_test_posting();
+        let mut out = Vec::new();
+
+        write_postings(std::slice::from_ref(&posting), ExportFormat::Json, &mut out).unwrap();
+
+        let read: Vec<Posting> = serde_json::from_slice(&out).unwrap();
+        assert_eq!(read, vec![posting]);
+    }
+}
@@ -0,0 +1,160 @@
+use anyhow::{anyhow, bail, Context};
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::service::ledger_service::LedgerService;
+use postings_logic::engine::PostingsEngine;
+use postings_logic::hash_utils::hash_serialize;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A journal entry as read from a JSON file by `post`.
+///
+/// Operation id, operation type, recording user and line details are free text; the ledger
+/// stores their 34-byte multihash.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JournalEntry {
+    pub ledger_id: Uuid,
+    pub opr_id: String,
+    #[serde(default = "default_opr_type")]
+    pub opr_type: String,
+    pub record_user: String,
+    /// Defaults to now.
+    #[serde(default)]
+    pub pst_time: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub val_time: Option<DateTime<Utc>>,
+    #[serde(default = "default_pst_type")]
+    pub pst_type: PostingType,
+    #[serde(default = "default_pst_status")]
+    pub pst_status: PostingStatus,
+    pub lines: Vec<JournalLine>,
+}
+
+/// One side of a journal entry. The account is given either by id or by its code in the ledger.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct JournalLine {
+    #[serde(default)]
+    pub account_id: Option<Uuid>,
+    #[serde(default)]
+    pub account_code: Option<String>,
+    #[serde(default)]
+    pub debit_amount: BigDecimal,
+    #[serde(default)]
+    pub credit_amount: BigDecimal,
+    #[serde(default)]
+    pub details: Option<String>,
+}
+
+fn default_opr_type() -> String {
+    "journal".to_string()
+}
+
+fn default_pst_type() -> PostingType {
+    PostingType::BusiTx
+}
+
+fn default_pst_status() -> PostingStatus {
+    PostingStatus::Posted
+}
+
+fn hash_text(text: &str) -> anyhow::Result<[u8; 34]> {
+    hash_serialize(&text).context("cannot hash text")
+}
+
+impl JournalEntry {
+    /// Resolves ledger and accounts into a posting for the service, which assigns id, record
+    /// time and hash chain. Balancing is left to the service.
+    pub async fn into_posting(self, services: &PostingsEngine) -> anyhow::Result<Posting> {
+        let ledger = services.ledger.find_ledger_by_id(self.ledger_id).await?.ok_or_else(|| anyhow!("ledger {} not found", self.ledger_id))?;
+        let pst_time = self.pst_time.unwrap_or_else(Utc::now);
+        let opr_id = hash_text(&self.opr_id)?;
+        let mut lines = Vec::with_capacity(self.lines.len());
+        for (index, line) in self.lines.into_iter().enumerate() {
+            let account = match (line.account_id, line.account_code.as_deref()) {
+                (Some(id), None) => services.ledger.find_ledger_account_by_id(id).await?,
+                (None, Some(code)) => services.ledger.find_ledger_account_by_code(&ledger, code).await?,
+                _ => bail!("line {index} needs exactly one of account_id and account_code"),
+            }
+            .ok_or_else(|| anyhow!("account of line {index} not found"))?;
+            lines.push(PostingLine {
+                id: Uuid::nil(),
+                account,
+                debit_amount: line.debit_amount,
+                credit_amount: line.credit_amount,
+                details: line.details.as_deref().map(hash_text).transpose()?,
+                src_account: None,
+                base_line: None,
+                sub_opr_src_id: None,
+                record_time: DateTime::UNIX_EPOCH,
+                opr_id,
+                opr_src: None,
+                pst_time,
+                val_time: self.val_time,
+                pst_type: self.pst_type.clone(),
+                pst_status: self.pst_status.clone(),
+                hash: None,
+                additional_information: None,
+                discarded_time: None,
+            });
+        }
+        Ok(Posting {
+            id: Uuid::nil(),
+            record_user: hash_text(&self.record_user)?,
+            record_time: DateTime::UNIX_EPOCH,
+            opr_id,
+            opr_time: pst_time,
+            opr_type: hash_text(&self.opr_type)?,
+            opr_details: None,
+            opr_src: None,
+            pst_time,
+            pst_type: self.pst_type,
+            pst_status: self.pst_status,
+            ledger,
+            val_time: self.val_time,
+            lines,
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_journal_entry_defaults() {
+        let json = r#"{
+            "ledger_id": "7b0f6a38-53a1-4c3e-9d87-1f7f4c1b2a10",
+            "opr_id": "INV-1",
+            "record_user": "alice",
+            "lines": [
+                { "account_code": "1000", "debit_amount": "100.25" },
+                { "account_id": "0c5c4f0e-8d8e-4a57-9d6e-3c8f2b9b7a11", "credit_amount": "100.25" }
+            ]
+        }"#;
+
+        let entry: JournalEntry = serde_json::from_str(json).unwrap();
+
+        assert_eq!(entry.opr_type, "journal");
+        assert_eq!(entry.pst_type, PostingType::BusiTx);
+        assert_eq!(entry.pst_status, PostingStatus::Posted);
+        assert_eq!(entry.pst_time, None);
+        assert_eq!(entry.lines[0].debit_amount, BigDecimal::from_str("100.25").unwrap());
+        assert_eq!(entry.lines[0].credit_amount, BigDecimal::from(0));
+        assert_eq!(entry.lines[1].account_code, None);
+    }
+
+    #[test]
+    fn test_hash_text_is_stable_and_distinct() {
+        assert_eq!(hash_text("INV-1").unwrap(), hash_text("INV-1").unwrap());
+        assert_ne!(hash_text("INV-1").unwrap(), hash_text("INV-2").unwrap());
+    }
+}
@@ -0,0 +1,339 @@
+//! Administration of a postings database from the command line.
+//!
+//! Every command runs against the services of `postings-logic` on top of the Postgres or
+//! MariaDB repositories; results are printed as JSON, exports as CSV or JSON.
+
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, Utc};
+use clap::{Args, Parser, Subcommand};
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::named::{ContainerType, Named};
+use postings_api::domain::time_axis::TimeAxis;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::chart_of_account_service::ChartOfAccountService;
+use postings_api::service::hold_service::HoldService;
+use postings_api::service::ledger_service::LedgerService;
+use postings_api::service::posting_service::PostingService;
+use postings_logic::engine::PostingsEngine;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::json;
+use uuid::Uuid;
+
+pub mod backend;
+pub mod export;
+pub mod journal;
+
+use backend::Backend;
+use export::ExportFormat;
+use journal::JournalEntry;
+
+#[derive(Debug, Parser)]
+#[command(name = "postings-cli", version, about = "Inspect and operate a postings ledger")]
+pub struct Cli {
+    /// postgres://, postgresql://, mysql:// or mariadb:// URL of the database.
+    #[arg(long, env = "DATABASE_URL", global = true, hide_env_values = true)]
+    pub database_url: Option<String>,
+    #[command(subcommand)]
+    pub command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Applies pending schema migrations.
+    Migrate,
+    /// Charts of accounts.
+    #[command(subcommand)]
+    Coa(CoaCommand),
+    /// Ledgers.
+    #[command(subcommand)]
+    Ledger(LedgerCommand),
+    /// Ledger accounts.
+    #[command(subcommand)]
+    Account(AccountCommand),
+    /// Books a journal entry read from a JSON file.
+    Post {
+        /// JSON journal entry; `-` reads standard input.
+        #[arg(long)]
+        file: PathBuf,
+    },
+    /// Booked, held and available balance of an account.
+    Balance {
+        #[arg(long)]
+        account: Uuid,
+        /// Defaults to now.
+        #[arg(long)]
+        at: Option<DateTime<Utc>>,
+    },
+    /// Statement of an account.
+    Statement {
+        #[arg(long)]
+        account: Uuid,
+        /// Defaults to now.
+        #[arg(long)]
+        at: Option<DateTime<Utc>>,
+        /// PstTime, ValTime or RecordTime.
+        #[arg(long, value_parser = parse_serde::<TimeAxis>, default_value = "PstTime")]
+        axis: TimeAxis,
+    },
+    /// Closes the statement of an account; nothing can be booked before it afterwards.
+    CloseStatement {
+        #[arg(long)]
+        account: Uuid,
+        /// Defaults to now.
+        #[arg(long)]
+        at: Option<DateTime<Utc>>,
+    },
+    /// Checks the hash chain of a ledger; exits with status 2 if it is broken.
+    VerifyChain {
+        #[arg(long)]
+        ledger: Uuid,
+    },
+    /// Writes the postings of a ledger in a posting time period.
+    Export {
+        #[arg(long)]
+        ledger: Uuid,
+        /// Exclusive; defaults to the Unix epoch.
+        #[arg(long)]
+        from: Option<DateTime<Utc>>,
+        /// Inclusive; defaults to now.
+        #[arg(long)]
+        to: Option<DateTime<Utc>>,
+        #[arg(long, value_enum, default_value_t = ExportFormat::Csv)]
+        format: ExportFormat,
+        /// Defaults to standard output.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum CoaCommand {
+    Create {
+        #[command(flatten)]
+        name: NameArgs,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum LedgerCommand {
+    Create {
+        #[arg(long)]
+        coa: Uuid,
+        #[command(flatten)]
+        name: NameArgs,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AccountCommand {
+    Create {
+        #[arg(long)]
+        ledger: Uuid,
+        /// RE, EX, AS, LI, EQ, NOOP, NORE or NOEX.
+        #[arg(long, value_parser = parse_serde::<AccountCategory>)]
+        category: AccountCategory,
+        /// Dr, Cr or DrCr; defaults to the usual side of the category.
+        #[arg(long, value_parser = parse_serde::<BalanceSide>)]
+        balance_side: Option<BalanceSide>,
+        #[arg(long)]
+        code: Option<String>,
+        #[arg(long)]
+        parent: Option<Uuid>,
+        #[command(flatten)]
+        name: NameArgs,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct NameArgs {
+    #[arg(long)]
+    pub name: Option<String>,
+    /// Two-letter language code of the name.
+    #[arg(long, default_value = "en")]
+    pub language: String,
+}
+
+impl NameArgs {
+    /// The service fills in id, creation time, container and context.
+    fn into_named(self, container_type: ContainerType) -> Vec<Named> {
+        self.name
+            .map(|name| Named {
+                id: Uuid::nil(),
+                container: Uuid::nil(),
+                context: Uuid::nil(),
+                name,
+                language: self.language,
+                created: DateTime::UNIX_EPOCH,
+                user_details: [0; 34],
+                short_desc: None,
+                long_desc: None,
+                container_type,
+                retired: None,
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Parses a domain enum by its serde name, e.g. `AS` or `DrCr`.
+fn parse_serde<T: DeserializeOwned>(value: &str) -> Result<T, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_string())).map_err(|e| e.to_string())
+}
+
+/// How a command ended when it did not fail.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Done,
+    /// `verify-chain` found breaks in the chain.
+    ChainBroken,
+}
+
+fn print_json<T: Serialize + ?Sized>(out: &mut dyn Write, value: &T) -> anyhow::Result<()> {
+    serde_json::to_writer_pretty(&mut *out, value)?;
+    writeln!(out)?;
+    Ok(())
+}
+
+async fn load_account(services: &PostingsEngine, id: Uuid) -> anyhow::Result<LedgerAccount> {
+    services.ledger.find_ledger_account_by_id(id).await?.ok_or_else(|| anyhow!("account {id} not found"))
+}
+
+async fn load_ledger(services: &PostingsEngine, id: Uuid) -> anyhow::Result<Ledger> {
+    services.ledger.find_ledger_by_id(id).await?.ok_or_else(|| anyhow!("ledger {id} not found"))
+}
+
+fn read_journal_entry(file: &Path) -> anyhow::Result<JournalEntry> {
+    let mut json = String::new();
+    if file.as_os_str() == "-" {
+        std::io::stdin().read_to_string(&mut json)?;
+    } else {
+        json = std::fs::read_to_string(file).with_context(|| format!("cannot read {}", file.display()))?;
+    }
+    serde_json::from_str(&json).with_context(|| format!("{} is not a valid journal entry", file.display()))
+}
+
+/// Runs `command` against `backend`, writing its result to `out`.
+pub async fn execute(backend: &Backend, command: Command, out: &mut dyn Write) -> anyhow::Result<Outcome> {
+    let services = backend.engine();
+    match command {
+        Command::Migrate => {
+            backend.migrate().await?;
+            print_json(out, &json!({ "migrated": true }))?;
+        }
+        Command::Coa(CoaCommand::Create { name }) => {
+            let (chart, names) = services.chart_of_account.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, name.into_named(ContainerType::ChartOfAccount)).await?;
+            print_json(out, &json!({ "chart": chart, "names": names }))?;
+        }
+        Command::Ledger(LedgerCommand::Create { coa, name }) => {
+            let ledger = Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: coa } };
+            let (ledger, names) = services.ledger.new_ledger(ledger, name.into_named(ContainerType::Ledger)).await?;
+            print_json(out, &json!({ "ledger": ledger, "names": names }))?;
+        }
+        Command::Account(AccountCommand::Create { ledger, category, balance_side, code, parent, name }) => {
+            let ledger = load_ledger(&services, ledger).await?;
+            let parent = match parent {
+                Some(parent) => Some(Box::new(load_account(&services, parent).await?)),
+                None => None,
+            };
+            let account = LedgerAccount {
+                id: Uuid::nil(),
+                coa: ledger.coa.clone(),
+                ledger,
+                parent,
+                balance_side: balance_side.unwrap_or_else(|| category.default_bs()),
+                category,
+                code,
+            };
+            let (account, names) = services.ledger.new_ledger_account(account, name.into_named(ContainerType::LedgerAccount)).await?;
+            print_json(out, &json!({ "account": account, "names": names }))?;
+        }
+        Command::Post { file } => {
+            let posting = read_journal_entry(&file)?.into_posting(&services).await?;
+            print_json(out, &services.posting.new_posting(posting).await?)?;
+        }
+        Command::Balance { account, at } => {
+            print_json(out, &services.hold.find_account_balance(account, at.unwrap_or_else(Utc::now)).await?)?;
+        }
+        Command::Statement { account, at, axis } => {
+            let account = load_account(&services, account).await?;
+            print_json(out, &services.stmt.read_stmt_by_time_axis(account, at.unwrap_or_else(Utc::now), axis).await?)?;
+        }
+        Command::CloseStatement { account, at } => {
+            let account = load_account(&services, account).await?;
+            let stmt = services.stmt.create_stmt(account, at.unwrap_or_else(Utc::now)).await?;
+            print_json(out, &services.stmt.close_stmt(stmt).await?)?;
+        }
+        Command::VerifyChain { ledger } => {
+            let verification = services.posting.verify_hash_chain(ledger).await?;
+            print_json(out, &verification)?;
+            if !verification.is_intact() {
+                return Ok(Outcome::ChainBroken);
+            }
+        }
+        Command::Export { ledger, from, to, format, output } => {
+            let postings = services.posting.find_postings_by_ledger(ledger, from.unwrap_or(DateTime::UNIX_EPOCH), to.unwrap_or_else(Utc::now)).await?;
+            match output {
+                Some(path) => {
+                    let mut file = std::fs::File::create(&path).with_context(|| format!("cannot create {}", path.display()))?;
+                    export::write_postings(&postings, format, &mut file)?;
+                }
+                None => export::write_postings(&postings, format, out)?,
+            }
+        }
+    }
+    Ok(Outcome::Done)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_account_create() {
+        let cli = Cli::try_parse_from([
+            "postings-cli", "--database-url", "postgres://localhost/db",
+            "account", "create", "--ledger", "7b0f6a38-53a1-4c3e-9d87-1f7f4c1b2a10", "--category", "LI", "--code", "2000",
+        ])
+        .unwrap();
+
+        assert_eq!(cli.database_url.as_deref(), Some("postgres://localhost/db"));
+        match cli.command {
+            Command::Account(AccountCommand::Create { category, balance_side, code, name, .. }) => {
+                assert_eq!(category, AccountCategory::LI);
+                assert_eq!(balance_side, None);
+                assert_eq!(code.as_deref(), Some("2000"));
+                assert_eq!(name.language, "en");
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_category() {
+        let result = Cli::try_parse_from(["postings-cli", "account", "create", "--ledger", "7b0f6a38-53a1-4c3e-9d87-1f7f4c1b2a10", "--category", "XX"]);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_export_defaults() {
+        let cli = Cli::try_parse_from(["postings-cli", "export", "--ledger", "7b0f6a38-53a1-4c3e-9d87-1f7f4c1b2a10"]).unwrap();
+
+        match cli.command {
+            Command::Export { format, from, output, .. } => {
+                assert_eq!(format, ExportFormat::Csv);
+                assert_eq!(from, None);
+                assert_eq!(output, None);
+            }
+            other => panic!("unexpected command {other:?}"),
+        }
+    }
+}
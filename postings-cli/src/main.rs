@@ -0,0 +1,24 @@
+use std::process::ExitCode;
+
+use clap::Parser;
+use postings_cli::backend::Backend;
+use postings_cli::{execute, Cli, Outcome};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = async {
+        let url = cli.database_url.ok_or_else(|| anyhow::anyhow!("no database URL, pass --database-url or set DATABASE_URL"))?;
+        let backend = Backend::connect(&url).await?;
+        execute(&backend, cli.command, &mut std::io::stdout().lock()).await
+    }
+    .await;
+    match result {
+        Ok(Outcome::Done) => ExitCode::SUCCESS,
+        Ok(Outcome::ChainBroken) => ExitCode::from(2),
+        Err(e) => {
+            eprintln!("error: {e:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
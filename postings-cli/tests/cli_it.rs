@@ -0,0 +1,134 @@
+#![cfg(test)]
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::str::FromStr;
+
+    use bigdecimal::BigDecimal;
+    use clap::Parser;
+    use postings_cli::backend::Backend;
+    use postings_cli::{execute, Cli, Outcome};
+    use serde_json::{json, Value};
+    use sqlx::PgPool;
+
+    /// Parses `args` like the binary does and runs the command; returns outcome and output.
+    async fn run(backend: &Backend, args: &[&str]) -> anyhow::Result<(Outcome, String)> {
+        let cli = Cli::try_parse_from(std::iter::once("postings-cli").chain(args.iter().copied()))?;
+        let mut out = Vec::new();
+        let outcome = execute(backend, cli.command, &mut out).await?;
+        Ok((outcome, String::from_utf8(out)?))
+    }
+
+    async fn run_json(backend: &Backend, args: &[&str]) -> anyhow::Result<Value> {
+        let (_, out) = run(backend, args).await?;
+        Ok(serde_json::from_str(&out)?)
+    }
+
+    /// Creates a chart, a ledger and a cash and a loan account; returns ledger id and account ids.
+    async fn setup_accounts(backend: &Backend) -> anyhow::Result<(String, String, String)> {
+        let chart = run_json(backend, &["coa", "create", "--name", "cli chart"]).await?;
+        let coa_id = chart["chart"]["id"].as_str().unwrap().to_string();
+        let ledger = run_json(backend, &["ledger", "create", "--coa", &coa_id, "--name", "cli ledger"]).await?;
+        let ledger_id = ledger["ledger"]["id"].as_str().unwrap().to_string();
+        let cash = run_json(backend, &["account", "create", "--ledger", &ledger_id, "--category", "AS", "--code", "1000"]).await?;
+        let loan = run_json(backend, &["account", "create", "--ledger", &ledger_id, "--category", "LI", "--code", "2000"]).await?;
+        Ok((ledger_id, cash["account"]["id"].as_str().unwrap().to_string(), loan["account"]["id"].as_str().unwrap().to_string()))
+    }
+
+    fn amount(value: &Value) -> BigDecimal {
+        BigDecimal::from_str(value.as_str().unwrap()).unwrap()
+    }
+
+    fn write_entry(ledger_id: &str, opr_id: &str, amount: &str) -> anyhow::Result<std::path::PathBuf> {
+        let entry = json!({
+            "ledger_id": ledger_id,
+            "opr_id": opr_id,
+            "record_user": "operator",
+            "lines": [
+                { "account_code": "1000", "debit_amount": amount },
+                { "account_code": "2000", "credit_amount": amount }
+            ]
+        });
+        let path = std::env::temp_dir().join(format!("postings-cli-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, serde_json::to_vec(&entry)?)?;
+        Ok(path)
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_post_balance_export_and_verify(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let backend = Backend::Postgres(pool);
+        let migrated = run_json(&backend, &["migrate"]).await?;
+        let (ledger_id, cash_id, loan_id) = setup_accounts(&backend).await?;
+        let first = write_entry(&ledger_id, "LOAN-1", "100.00")?;
+        let second = write_entry(&ledger_id, "LOAN-2", "50.00")?;
+
+        // Act
+        let posted = run_json(&backend, &["post", "--file", first.to_str().unwrap()]).await?;
+        run_json(&backend, &["post", "--file", second.to_str().unwrap()]).await?;
+        let balance = run_json(&backend, &["balance", "--account", &cash_id]).await?;
+        let statement = run_json(&backend, &["statement", "--account", &loan_id]).await?;
+        let (verified, verification) = run(&backend, &["verify-chain", "--ledger", &ledger_id]).await?;
+        let (_, csv) = run(&backend, &["export", "--ledger", &ledger_id]).await?;
+        let exported = run_json(&backend, &["export", "--ledger", &ledger_id, "--format", "json"]).await?;
+        let closed = run_json(&backend, &["close-statement", "--account", &cash_id]).await?;
+
+        // Assert
+        assert_eq!(migrated["migrated"], true);
+        assert_eq!(posted["pst_status"], "Posted");
+        assert!(posted["hash"].is_string());
+        assert_eq!(amount(&balance["booked"]), BigDecimal::from(150));
+        assert_eq!(amount(&statement["total_credit"]), BigDecimal::from(150));
+        assert_eq!(verified, Outcome::Done);
+        let verification: Value = serde_json::from_str(&verification)?;
+        assert_eq!(verification["postings"], 2);
+        assert!(verification["breaks"].as_array().unwrap().is_empty());
+        assert_eq!(csv.lines().count(), 5);
+        assert_eq!(csv.lines().filter(|row| row.contains(",1000,")).count(), 2);
+        assert_eq!(exported.as_array().unwrap().len(), 2);
+        assert_eq!(exported[0]["id"], posted["id"]);
+        assert_eq!(closed["stmt_status"], "CLOSED");
+        std::fs::remove_file(first)?;
+        std::fs::remove_file(second)?;
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_verify_chain_reports_broken_chain(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let backend = Backend::Postgres(pool.clone());
+        let (ledger_id, _, _) = setup_accounts(&backend).await?;
+        let first = write_entry(&ledger_id, "LOAN-1", "100.00")?;
+        let second = write_entry(&ledger_id, "LOAN-2", "50.00")?;
+        run_json(&backend, &["post", "--file", first.to_str().unwrap()]).await?;
+        let tampered = run_json(&backend, &["post", "--file", second.to_str().unwrap()]).await?;
+        sqlx::query("UPDATE posting SET antecedent_hash = $1 WHERE id = $2::uuid")
+            .bind(vec![7u8; 34])
+            .bind(tampered["id"].as_str().unwrap())
+            .execute(&pool)
+            .await?;
+
+        // Act
+        let (outcome, out) = run(&backend, &["verify-chain", "--ledger", &ledger_id]).await?;
+        let unbalanced = write_entry(&ledger_id, "LOAN-3", "10.00")?;
+        let mut entry: Value = serde_json::from_slice(&std::fs::read(&unbalanced)?)?;
+        entry["lines"][1]["credit_amount"] = json!("9.00");
+        std::fs::write(&unbalanced, serde_json::to_vec(&entry)?)?;
+        let rejected = run(&backend, &["post", "--file", unbalanced.to_str().unwrap()]).await;
+
+        // Assert
+        assert_eq!(outcome, Outcome::ChainBroken);
+        let verification: Value = serde_json::from_str(&out)?;
+        assert_eq!(verification["breaks"][0]["type"], "HashMismatch");
+        assert_eq!(verification["breaks"][0]["posting_id"], tampered["id"]);
+        assert!(rejected.is_err());
+        for path in [first, second, unbalanced] {
+            std::fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
+}
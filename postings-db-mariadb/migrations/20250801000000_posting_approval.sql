@@ -0,0 +1,21 @@
+-- =============================================================================
+-- FOUR-EYES APPROVAL
+-- =============================================================================
+
+-- Approval policy per ledger. A NULL threshold requires approval for every posting.
+CREATE TABLE approval_policy (
+    ledger_id CHAR(36) PRIMARY KEY,
+    amount_threshold DECIMAL(19, 2),
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+-- Approvals given to postings
+CREATE TABLE posting_approval (
+    id CHAR(36) PRIMARY KEY,
+    posting_id CHAR(36) NOT NULL,
+    approver BLOB NOT NULL,           -- Binary hash
+    approval_time TIMESTAMP NOT NULL,
+    FOREIGN KEY (posting_id) REFERENCES posting(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_posting_approval_posting_id ON posting_approval(posting_id);
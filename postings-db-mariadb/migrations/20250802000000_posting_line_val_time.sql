@@ -0,0 +1,14 @@
+-- =============================================================================
+-- VALUE DATE ON POSTING LINES
+-- =============================================================================
+
+ALTER TABLE posting_line ADD COLUMN val_time TIMESTAMP NULL;
+
+-- Lines take the value time of the posting they belong to
+UPDATE posting_line pl
+JOIN posting p ON p.opr_id = pl.opr_id AND p.pst_status = pl.pst_status
+SET pl.val_time = p.val_time
+WHERE p.discarding_id IS NULL
+  AND p.val_time IS NOT NULL;
+
+CREATE INDEX idx_posting_line_account_id_val_time ON posting_line(account_id, val_time);
@@ -0,0 +1,19 @@
+-- =============================================================================
+-- UNIQUE NAMES PER CONTEXT
+-- =============================================================================
+
+-- Retire duplicates so only the oldest active name of each collision survives
+UPDATE named n
+JOIN named o ON o.name = n.name
+    AND o.language = n.language
+    AND o.container_type = n.container_type
+    AND o.context = n.context
+    AND o.retired IS NULL
+    AND (o.created < n.created OR (o.created = n.created AND o.id < n.id))
+SET n.retired = NOW()
+WHERE n.retired IS NULL;
+
+-- MariaDB has no partial indexes; NULL for retired names keeps them out of the unique key
+ALTER TABLE named ADD COLUMN active TINYINT AS (IF(retired IS NULL, 1, NULL)) PERSISTENT;
+
+CREATE UNIQUE INDEX uq_named_name_language_type_context ON named(name, language, container_type, context, active);
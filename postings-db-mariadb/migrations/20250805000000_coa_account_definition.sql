@@ -0,0 +1,20 @@
+-- =============================================================================
+-- CHART OF ACCOUNT TEMPLATES
+-- =============================================================================
+
+-- Account definitions of a chart; ledgers instantiated from the chart get one account per definition
+CREATE TABLE coa_account_definition (
+    id CHAR(36) PRIMARY KEY,
+    coa_id CHAR(36) NOT NULL,
+    code VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    language CHAR(2) NOT NULL,
+    category ENUM('RE', 'EX', 'AS', 'LI', 'EQ', 'NOOP', 'NORE', 'NOEX') NOT NULL,
+    balance_side ENUM('Dr', 'Cr', 'DrCr') NOT NULL,
+    parent_code VARCHAR(64),
+    FOREIGN KEY (coa_id) REFERENCES chart_of_account(id),
+    UNIQUE KEY unique_coa_account_definition (coa_id, code)
+) ENGINE=InnoDB;
+
+-- Code of the definition a ledger account was created from
+ALTER TABLE ledger_account ADD COLUMN code VARCHAR(64);
@@ -0,0 +1,6 @@
+-- =============================================================================
+-- ACCOUNT CODES
+-- =============================================================================
+
+-- Codes identify accounts within their ledger; accounts without a code are not affected
+CREATE UNIQUE INDEX unique_ledger_account_code ON ledger_account (ledger_id, code);
@@ -0,0 +1,17 @@
+-- =============================================================================
+-- ACCOUNT LIFECYCLE
+-- =============================================================================
+
+-- State changes of ledger accounts. Rows are only ever inserted and serve as audit trail.
+CREATE TABLE ledger_account_state (
+    id CHAR(36) PRIMARY KEY,
+    account_id CHAR(36) NOT NULL,
+    state ENUM('OPEN', 'DEBIT_BLOCKED', 'CREDIT_BLOCKED', 'FROZEN', 'CLOSED') NOT NULL,
+    effective_time TIMESTAMP NOT NULL,
+    record_user BLOB NOT NULL,        -- Binary hash
+    record_time TIMESTAMP NOT NULL,
+    reason TEXT,
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_ledger_account_state_account_id ON ledger_account_state(account_id);
@@ -0,0 +1,13 @@
+-- =============================================================================
+-- ACCOUNT LIMITS
+-- =============================================================================
+
+-- Balance bounds of ledger accounts, on the account's balance side. Postings lock the row of
+-- each limited account they touch, which serializes concurrent postings to the same account.
+CREATE TABLE account_limit (
+    account_id CHAR(36) PRIMARY KEY,
+    min_balance DECIMAL(19, 2),
+    max_balance DECIMAL(19, 2),
+    CHECK (min_balance IS NULL OR max_balance IS NULL OR min_balance <= max_balance),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
@@ -0,0 +1,23 @@
+-- =============================================================================
+-- FUNDS RESERVATIONS
+-- =============================================================================
+
+-- Funds reserved on ledger accounts. Active holds that have not expired reduce the available
+-- balance of their account.
+CREATE TABLE account_hold (
+    id CHAR(36) PRIMARY KEY,
+    account_id CHAR(36) NOT NULL,
+    amount DECIMAL(19, 2) NOT NULL CHECK (amount > 0),
+    expiry TIMESTAMP NOT NULL,
+    opr_id BLOB NOT NULL,              -- Binary hash
+    status ENUM('ACTIVE', 'CAPTURED', 'RELEASED', 'EXPIRED') NOT NULL,
+    record_time TIMESTAMP NOT NULL,
+    closed_time TIMESTAMP NULL,
+    captured_amount DECIMAL(19, 2),
+    posting_id CHAR(36),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id),
+    FOREIGN KEY (posting_id) REFERENCES posting(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_account_hold_account_id_status ON account_hold(account_id, status);
+CREATE INDEX idx_account_hold_status_expiry ON account_hold(status, expiry);
@@ -0,0 +1,47 @@
+-- =============================================================================
+-- SCHEDULED POSTINGS
+-- =============================================================================
+
+-- Posting templates booked on every occurrence of a recurrence rule
+CREATE TABLE posting_schedule (
+    id CHAR(36) PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    rrule TEXT NOT NULL,
+    start_time TIMESTAMP NOT NULL,
+    catch_up ENUM('ALL', 'LATEST') NOT NULL,
+    record_user BLOB NOT NULL,         -- Binary hash
+    opr_type BLOB NOT NULL,            -- Binary hash
+    pst_type ENUM('BUSI_TX', 'ADJ_TX', 'BAL_STMT', 'PNL_STMT', 'BS_STMT', 'LDG_CLSNG') NOT NULL,
+    status ENUM('ACTIVE', 'FINISHED', 'CANCELLED') NOT NULL,
+    next_index INT NOT NULL,
+    next_time TIMESTAMP NULL,
+    record_time TIMESTAMP NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_posting_schedule_ledger_id ON posting_schedule(ledger_id);
+CREATE INDEX idx_posting_schedule_status_next_time ON posting_schedule(status, next_time);
+
+CREATE TABLE posting_schedule_line (
+    id CHAR(36) PRIMARY KEY,
+    schedule_id CHAR(36) NOT NULL,
+    account_id CHAR(36) NOT NULL,
+    debit_amount DECIMAL(19, 2) NOT NULL,
+    credit_amount DECIMAL(19, 2) NOT NULL,
+    FOREIGN KEY (schedule_id) REFERENCES posting_schedule(id),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_posting_schedule_line_schedule_id ON posting_schedule_line(schedule_id);
+
+-- One row per posted occurrence; the primary key keeps an occurrence from being posted twice.
+CREATE TABLE posting_schedule_run (
+    schedule_id CHAR(36) NOT NULL,
+    occurrence_index INT NOT NULL,
+    occurrence_time TIMESTAMP NOT NULL,
+    posting_id CHAR(36) NOT NULL,
+    run_time TIMESTAMP NOT NULL,
+    PRIMARY KEY (schedule_id, occurrence_index),
+    FOREIGN KEY (schedule_id) REFERENCES posting_schedule(id),
+    FOREIGN KEY (posting_id) REFERENCES posting(id)
+) ENGINE=InnoDB;
@@ -0,0 +1,26 @@
+-- =============================================================================
+-- TRANSACTIONAL OUTBOX
+-- =============================================================================
+
+-- Last sequence number handed out per ledger. Its row stays locked until the writing
+-- transaction ends, so sequence order is commit order within a ledger.
+CREATE TABLE outbox_ledger_seq (
+    ledger_id CHAR(36) PRIMARY KEY,
+    last_seq BIGINT NOT NULL,
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+-- Domain events written in the transaction of the change they describe
+CREATE TABLE outbox_event (
+    id BIGINT AUTO_INCREMENT PRIMARY KEY,
+    ledger_id CHAR(36) NOT NULL,
+    ledger_seq BIGINT NOT NULL,
+    event_type VARCHAR(64) NOT NULL,
+    payload TEXT NOT NULL,             -- JSON
+    occurred_at TIMESTAMP NOT NULL,
+    dispatched_at TIMESTAMP NULL,
+    UNIQUE (ledger_id, ledger_seq),
+    FOREIGN KEY (ledger_id) REFERENCES ledger(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_outbox_event_dispatched_at ON outbox_event(dispatched_at, id);
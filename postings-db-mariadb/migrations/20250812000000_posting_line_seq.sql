@@ -0,0 +1,9 @@
+-- =============================================================================
+-- CHANGE FEED
+-- =============================================================================
+
+-- Position of a line in the change feed of its account. Lines are inserted while the ledger's
+-- outbox sequence row is locked, so within an account the numbers follow commit order.
+ALTER TABLE posting_line ADD COLUMN line_seq BIGINT NOT NULL AUTO_INCREMENT UNIQUE;
+
+CREATE UNIQUE INDEX idx_posting_line_account_seq ON posting_line(account_id, line_seq);
@@ -0,0 +1,8 @@
+-- =============================================================================
+-- MICROSECOND RECORD TIMES
+-- =============================================================================
+
+-- The postings of a batch are a microsecond apart, and the chain head is the posting with the
+-- latest record time, so whole seconds would leave the head of a batch undetermined.
+ALTER TABLE posting MODIFY COLUMN record_time TIMESTAMP(6) NOT NULL;
+ALTER TABLE posting_line MODIFY COLUMN record_time TIMESTAMP(6) NOT NULL;
@@ -0,0 +1,10 @@
+-- =============================================================================
+-- VALUE TIME AXIS INDEX
+-- =============================================================================
+
+-- Lines without a value time fall back to their posting time on the value time axis. Storing
+-- that time lets the index serve the range scans of value time statements.
+ALTER TABLE posting_line ADD COLUMN eff_val_time TIMESTAMP AS (COALESCE(val_time, pst_time)) PERSISTENT;
+
+DROP INDEX idx_posting_line_account_id_val_time ON posting_line;
+CREATE INDEX idx_posting_line_account_id_eff_val_time ON posting_line(account_id, eff_val_time);
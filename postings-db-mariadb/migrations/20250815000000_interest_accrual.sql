@@ -0,0 +1,20 @@
+-- =============================================================================
+-- BOOKED INTEREST ACCRUALS
+-- =============================================================================
+
+-- Periods [period_from, period_to) booked per account. The operation id is derived from account
+-- and period, so a period cannot be booked twice; overlapping periods are rejected while the
+-- account row is locked.
+CREATE TABLE interest_accrual (
+    opr_id VARBINARY(34) NOT NULL,     -- Binary hash
+    account_id CHAR(36) NOT NULL,
+    period_from DATE NOT NULL,
+    period_to DATE NOT NULL,
+    posting_id CHAR(36) NOT NULL,
+    UNIQUE KEY unique_interest_accrual_opr_id (opr_id),
+    CHECK (period_from < period_to),
+    FOREIGN KEY (account_id) REFERENCES ledger_account(id),
+    FOREIGN KEY (posting_id) REFERENCES posting(id)
+) ENGINE=InnoDB;
+
+CREATE INDEX idx_interest_accrual_account_id_period ON interest_accrual(account_id, period_from);
@@ -0,0 +1,12 @@
+-- =============================================================================
+-- CHANGE FEED SEQUENCE
+-- =============================================================================
+
+-- Auto-increment numbers are only handed out on insert. Lines whose status changes take a new
+-- number in place, so the numbers come from a sequence that both inserts and updates draw from.
+SET @create_seq = CONCAT('CREATE SEQUENCE posting_line_seq START WITH ', (SELECT COALESCE(MAX(line_seq), 0) + 1 FROM posting_line));
+PREPARE create_seq FROM @create_seq;
+EXECUTE create_seq;
+DEALLOCATE PREPARE create_seq;
+
+ALTER TABLE posting_line MODIFY line_seq BIGINT NOT NULL DEFAULT (NEXT VALUE FOR posting_line_seq);
@@ -1,2 +1,25 @@
 pub mod repositories;
-pub mod models;
\ No newline at end of file
+pub mod models;
+
+/// Migrations of the MariaDB schema, embedded at build time.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+/// All repositories on the pool.
+pub fn repositories(pool: sqlx::MySqlPool) -> postings_db::repositories::Repositories {
+    use std::sync::Arc;
+    use repositories::*;
+    postings_db::repositories::Repositories {
+        coa: Arc::new(chart_of_account_repository::MariaDbChartOfAccountRepository::new(pool.clone())),
+        ledger: Arc::new(ledger_repository::MariaDbLedgerRepository::new(pool.clone())),
+        ledger_account: Arc::new(ledger_account_repository::MariaDbLedgerAccountRepository::new(pool.clone())),
+        named: Arc::new(named_repository::MariaDbNamedRepository::new(pool.clone())),
+        posting: Arc::new(posting_repository::MariaDbPostingRepository::new(pool.clone())),
+        stmt: Arc::new(account_stmt_repository::MariaDbAccountStmtRepository::new(pool.clone())),
+        line: Arc::new(posting_line_repository::MariaDbPostingLineRepository::new(pool.clone())),
+        trace: Arc::new(posting_trace_repository::MariaDbPostingTraceRepository::new(pool.clone())),
+        approval: Arc::new(posting_approval_repository::MariaDbPostingApprovalRepository::new(pool.clone())),
+        hold: Arc::new(hold_repository::MariaDbHoldRepository::new(pool.clone())),
+        schedule: Arc::new(schedule_repository::MariaDbScheduleRepository::new(pool.clone())),
+        outbox: Arc::new(outbox_repository::MariaDbOutboxRepository::new(pool)),
+    }
+}
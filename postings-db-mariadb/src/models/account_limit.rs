@@ -0,0 +1,21 @@
+use bigdecimal::BigDecimal;
+use sqlx::FromRow;
+use uuid::Uuid;
+use postings_db::models::account_limit::AccountLimit;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountLimitDb {
+    pub account_id: String,
+    pub min_balance: Option<BigDecimal>,
+    pub max_balance: Option<BigDecimal>,
+}
+
+impl From<AccountLimitDb> for AccountLimit {
+    fn from(l: AccountLimitDb) -> Self {
+        Self {
+            account_id: Uuid::parse_str(&l.account_id).unwrap_or_default(),
+            min_balance: l.min_balance,
+            max_balance: l.max_balance,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use sqlx::FromRow;
+use uuid::Uuid;
+use postings_db::models::account_state::{AccountState, AccountStateChange};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountStateChangeDb {
+    pub id: String,
+    pub account_id: String,
+    pub state: String,
+    pub effective_time: chrono::DateTime<chrono::Utc>,
+    pub record_user: Vec<u8>,
+    pub record_time: chrono::DateTime<chrono::Utc>,
+    pub reason: Option<String>,
+}
+
+impl From<AccountStateChangeDb> for AccountStateChange {
+    fn from(c: AccountStateChangeDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&c.id).unwrap_or_default(),
+            account_id: Uuid::parse_str(&c.account_id).unwrap_or_default(),
+            state: match c.state.as_str() {
+                "DEBIT_BLOCKED" => AccountState::DebitBlocked,
+                "CREDIT_BLOCKED" => AccountState::CreditBlocked,
+                "FROZEN" => AccountState::Frozen,
+                "CLOSED" => AccountState::Closed,
+                _ => AccountState::Open,
+            },
+            effective_time: c.effective_time,
+            record_user: c.record_user.try_into().unwrap_or([0u8; 34]),
+            record_time: c.record_time,
+            reason: c.reason,
+        }
+    }
+}
+
+pub fn state_to_db(state: &AccountState) -> &'static str {
+    match state {
+        AccountState::Open => "OPEN",
+        AccountState::DebitBlocked => "DEBIT_BLOCKED",
+        AccountState::CreditBlocked => "CREDIT_BLOCKED",
+        AccountState::Frozen => "FROZEN",
+        AccountState::Closed => "CLOSED",
+    }
+}
@@ -0,0 +1,50 @@
+use sqlx::FromRow;
+use uuid::Uuid;
+use postings_db::models::account_category::AccountCategory;
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::coa_account_definition::CoaAccountDefinition;
+use postings_db::DbError;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct CoaAccountDefinitionDb {
+    pub id: String,
+    pub coa_id: String,
+    pub code: String,
+    pub name: String,
+    pub language: String,
+    pub category: String,
+    pub balance_side: String,
+    pub parent_code: Option<String>,
+}
+
+impl TryFrom<CoaAccountDefinitionDb> for CoaAccountDefinition {
+    type Error = DbError;
+
+    fn try_from(d: CoaAccountDefinitionDb) -> Result<Self, DbError> {
+        Ok(Self {
+            id: Uuid::parse_str(&d.id).unwrap_or_default(),
+            coa_id: Uuid::parse_str(&d.coa_id).unwrap_or_default(),
+            code: d.code,
+            name: d.name,
+            language: d.language,
+            category: match d.category.as_str() {
+                "RE" => AccountCategory::RE,
+                "EX" => AccountCategory::EX,
+                "AS" => AccountCategory::AS,
+                "LI" => AccountCategory::LI,
+                "EQ" => AccountCategory::EQ,
+                "NOOP" => AccountCategory::NOOP,
+                "NORE" => AccountCategory::NORE,
+                "NOEX" => AccountCategory::NOEX,
+                other => return Err(DbError::Decode { what: format!("account category {other}") }),
+            },
+            balance_side: match d.balance_side.as_str() {
+                "Dr" => BalanceSide::Dr,
+                "Cr" => BalanceSide::Cr,
+                "DrCr" => BalanceSide::DrCr,
+                other => return Err(DbError::Decode { what: format!("balance side {other}") }),
+            },
+            parent_code: d.parent_code,
+        })
+    }
+}
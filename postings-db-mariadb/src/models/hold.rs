@@ -0,0 +1,49 @@
+use bigdecimal::BigDecimal;
+use sqlx::FromRow;
+use uuid::Uuid;
+use postings_db::models::hold::{Hold, HoldStatus};
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct HoldDb {
+    pub id: String,
+    pub account_id: String,
+    pub amount: BigDecimal,
+    pub expiry: chrono::DateTime<chrono::Utc>,
+    pub opr_id: Vec<u8>,
+    pub status: String,
+    pub record_time: chrono::DateTime<chrono::Utc>,
+    pub closed_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub captured_amount: Option<BigDecimal>,
+    pub posting_id: Option<String>,
+}
+
+impl From<HoldDb> for Hold {
+    fn from(h: HoldDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&h.id).unwrap_or_default(),
+            account_id: Uuid::parse_str(&h.account_id).unwrap_or_default(),
+            amount: h.amount,
+            expiry: h.expiry,
+            opr_id: h.opr_id.try_into().unwrap_or([0u8; 34]),
+            status: match h.status.as_str() {
+                "CAPTURED" => HoldStatus::Captured,
+                "RELEASED" => HoldStatus::Released,
+                "EXPIRED" => HoldStatus::Expired,
+                _ => HoldStatus::Active,
+            },
+            record_time: h.record_time,
+            closed_time: h.closed_time,
+            captured_amount: h.captured_amount,
+            posting_id: h.posting_id.map(|s| Uuid::parse_str(&s).unwrap_or_default()),
+        }
+    }
+}
+
+pub fn status_to_db(status: &HoldStatus) -> &'static str {
+    match status {
+        HoldStatus::Active => "ACTIVE",
+        HoldStatus::Captured => "CAPTURED",
+        HoldStatus::Released => "RELEASED",
+        HoldStatus::Expired => "EXPIRED",
+    }
+}
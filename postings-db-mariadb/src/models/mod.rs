@@ -1,6 +1,13 @@
+pub mod account_limit;
+pub mod account_state;
+pub mod hold;
 pub mod posting;
+pub mod posting_approval;
 pub mod posting_line;
 pub mod posting_trace;
+pub mod posting_schedule;
 pub mod named;
+pub mod outbox_event;
 pub mod chart_of_account;
+pub mod coa_account_definition;
 pub mod ledger;
@@ -13,6 +13,7 @@ pub struct Named {
     pub short_desc: Option<String>,
     pub long_desc: Option<String>,
     pub container_type: String,  // Will convert to/from ContainerType enum
+    pub retired: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
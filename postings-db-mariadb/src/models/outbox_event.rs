@@ -0,0 +1,29 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+use postings_db::models::outbox_event::OutboxEvent;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct OutboxEventDb {
+    pub id: i64,
+    pub ledger_id: String,
+    pub ledger_seq: i64,
+    pub event_type: String,
+    pub payload: String,
+    pub occurred_at: DateTime<Utc>,
+    pub dispatched_at: Option<DateTime<Utc>>,
+}
+
+impl From<OutboxEventDb> for OutboxEvent {
+    fn from(e: OutboxEventDb) -> Self {
+        Self {
+            id: e.id,
+            ledger_id: Uuid::parse_str(&e.ledger_id).unwrap_or_default(),
+            ledger_seq: e.ledger_seq,
+            event_type: e.event_type,
+            payload: e.payload,
+            occurred_at: e.occurred_at,
+            dispatched_at: e.dispatched_at,
+        }
+    }
+}
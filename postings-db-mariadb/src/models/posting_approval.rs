@@ -0,0 +1,39 @@
+use bigdecimal::BigDecimal;
+use sqlx::FromRow;
+use uuid::Uuid;
+use postings_db::models::approval_policy::ApprovalPolicy;
+use postings_db::models::posting_approval::PostingApproval;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ApprovalPolicyDb {
+    pub ledger_id: String,
+    pub amount_threshold: Option<BigDecimal>,
+}
+
+impl From<ApprovalPolicyDb> for ApprovalPolicy {
+    fn from(p: ApprovalPolicyDb) -> Self {
+        Self {
+            ledger_id: Uuid::parse_str(&p.ledger_id).unwrap_or_default(),
+            amount_threshold: p.amount_threshold,
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct PostingApprovalDb {
+    pub id: String,
+    pub posting_id: String,
+    pub approver: Vec<u8>,
+    pub approval_time: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<PostingApprovalDb> for PostingApproval {
+    fn from(p: PostingApprovalDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&p.id).unwrap_or_default(),
+            posting_id: Uuid::parse_str(&p.posting_id).unwrap_or_default(),
+            approver: p.approver.try_into().unwrap_or([0u8; 34]),
+            approval_time: p.approval_time,
+        }
+    }
+}
@@ -3,11 +3,12 @@ use sqlx::FromRow;
 use bigdecimal::BigDecimal;
 use postings_db::models::posting_type::PostingType;
 use postings_db::models::posting_status::PostingStatus;
-use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_line::{PostingLine, SequencedPostingLine};
 
 #[derive(Debug, Clone, FromRow, PartialEq)]
 pub struct PostingLineDb {
     pub id: String,
+    pub posting_id: Option<String>,
     pub account_id: String,
     pub debit_amount: BigDecimal,
     pub credit_amount: BigDecimal,
@@ -19,16 +20,31 @@ pub struct PostingLineDb {
     pub opr_id: Vec<u8>,
     pub opr_src: Option<Vec<u8>>,
     pub pst_time: chrono::DateTime<chrono::Utc>,
+    pub val_time: Option<chrono::DateTime<chrono::Utc>>,
     pub pst_type: String,
     pub pst_status: String,
     pub hash: Option<Vec<u8>>,
     pub discarded_time: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct SequencedPostingLineDb {
+    pub line_seq: i64,
+    #[sqlx(flatten)]
+    pub line: PostingLineDb,
+}
+
+impl From<SequencedPostingLineDb> for SequencedPostingLine {
+    fn from(p: SequencedPostingLineDb) -> Self {
+        Self { line_seq: p.line_seq, line: p.line.into() }
+    }
+}
+
 impl From<PostingLineDb> for PostingLine {
     fn from(p: PostingLineDb) -> Self {
         Self {
             id: Uuid::parse_str(&p.id).unwrap(),
+            posting_id: p.posting_id.map(|s| Uuid::parse_str(&s).unwrap()),
             account_id: Uuid::parse_str(&p.account_id).unwrap(),
             debit_amount: p.debit_amount,
             credit_amount: p.credit_amount,
@@ -40,6 +56,7 @@ impl From<PostingLineDb> for PostingLine {
             opr_id: p.opr_id.try_into().unwrap_or([0u8; 34]),
             opr_src: p.opr_src.map(|v| v.try_into().unwrap_or([0u8; 34])),
             pst_time: p.pst_time,
+            val_time: p.val_time,
             pst_type: match p.pst_type.as_str() {
                 "BUSI_TX" => PostingType::BusiTx,
                 "ADJ_TX" => PostingType::AdjTx,
@@ -69,6 +86,7 @@ impl From<PostingLine> for PostingLineDb {
     fn from(p: PostingLine) -> Self {
         Self {
             id: p.id.to_string(),
+            posting_id: p.posting_id.map(|uuid| uuid.to_string()),
             account_id: p.account_id.to_string(),
             debit_amount: p.debit_amount,
             credit_amount: p.credit_amount,
@@ -80,6 +98,7 @@ impl From<PostingLine> for PostingLineDb {
             opr_id: p.opr_id.to_vec(),
             opr_src: p.opr_src.map(|v| v.to_vec()),
             pst_time: p.pst_time,
+            val_time: p.val_time,
             pst_type: match p.pst_type {
                 PostingType::BusiTx => "BUSI_TX".to_string(),
                 PostingType::AdjTx => "ADJ_TX".to_string(),
@@ -0,0 +1,112 @@
+use bigdecimal::BigDecimal;
+use sqlx::FromRow;
+use uuid::Uuid;
+use postings_db::models::posting_schedule::{CatchUp, PostingSchedule, ScheduleRun, ScheduleStatus, ScheduledLine};
+use postings_db::models::posting_type::PostingType;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct PostingScheduleDb {
+    pub id: String,
+    pub ledger_id: String,
+    pub rrule: String,
+    pub start_time: chrono::DateTime<chrono::Utc>,
+    pub catch_up: String,
+    pub record_user: Vec<u8>,
+    pub opr_type: Vec<u8>,
+    pub pst_type: String,
+    pub status: String,
+    pub next_index: i32,
+    pub next_time: Option<chrono::DateTime<chrono::Utc>>,
+    pub record_time: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<PostingScheduleDb> for PostingSchedule {
+    fn from(s: PostingScheduleDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&s.id).unwrap_or_default(),
+            ledger_id: Uuid::parse_str(&s.ledger_id).unwrap_or_default(),
+            rrule: s.rrule,
+            start_time: s.start_time,
+            catch_up: match s.catch_up.as_str() {
+                "LATEST" => CatchUp::Latest,
+                _ => CatchUp::All,
+            },
+            record_user: s.record_user.try_into().unwrap_or([0u8; 34]),
+            opr_type: s.opr_type.try_into().unwrap_or([0u8; 34]),
+            pst_type: match s.pst_type.as_str() {
+                "BUSI_TX" => PostingType::BusiTx,
+                "ADJ_TX" => PostingType::AdjTx,
+                "BAL_STMT" => PostingType::BalStmt,
+                "PNL_STMT" => PostingType::PnlStmt,
+                "BS_STMT" => PostingType::BsStmt,
+                "LDG_CLSNG" => PostingType::LdgClsng,
+                _ => PostingType::Unknown,
+            },
+            status: match s.status.as_str() {
+                "FINISHED" => ScheduleStatus::Finished,
+                "CANCELLED" => ScheduleStatus::Cancelled,
+                _ => ScheduleStatus::Active,
+            },
+            next_index: s.next_index,
+            next_time: s.next_time,
+            record_time: s.record_time,
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ScheduledLineDb {
+    pub id: String,
+    pub schedule_id: String,
+    pub account_id: String,
+    pub debit_amount: BigDecimal,
+    pub credit_amount: BigDecimal,
+}
+
+impl From<ScheduledLineDb> for ScheduledLine {
+    fn from(l: ScheduledLineDb) -> Self {
+        Self {
+            id: Uuid::parse_str(&l.id).unwrap_or_default(),
+            schedule_id: Uuid::parse_str(&l.schedule_id).unwrap_or_default(),
+            account_id: Uuid::parse_str(&l.account_id).unwrap_or_default(),
+            debit_amount: l.debit_amount,
+            credit_amount: l.credit_amount,
+        }
+    }
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ScheduleRunDb {
+    pub schedule_id: String,
+    pub occurrence_index: i32,
+    pub occurrence_time: chrono::DateTime<chrono::Utc>,
+    pub posting_id: String,
+    pub run_time: chrono::DateTime<chrono::Utc>,
+}
+
+impl From<ScheduleRunDb> for ScheduleRun {
+    fn from(r: ScheduleRunDb) -> Self {
+        Self {
+            schedule_id: Uuid::parse_str(&r.schedule_id).unwrap_or_default(),
+            occurrence_index: r.occurrence_index,
+            occurrence_time: r.occurrence_time,
+            posting_id: Uuid::parse_str(&r.posting_id).unwrap_or_default(),
+            run_time: r.run_time,
+        }
+    }
+}
+
+pub fn status_to_db(status: &ScheduleStatus) -> &'static str {
+    match status {
+        ScheduleStatus::Active => "ACTIVE",
+        ScheduleStatus::Finished => "FINISHED",
+        ScheduleStatus::Cancelled => "CANCELLED",
+    }
+}
+
+pub fn catch_up_to_db(catch_up: &CatchUp) -> &'static str {
+    match catch_up {
+        CatchUp::All => "ALL",
+        CatchUp::Latest => "LATEST",
+    }
+}
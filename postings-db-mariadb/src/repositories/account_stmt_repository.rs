@@ -1,11 +1,15 @@
 use async_trait::async_trait;
-use sqlx::MySqlPool;
+use sqlx::{MySqlConnection, MySqlPool};
 use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
 use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::outbox_event::NewOutboxEvent;
+use postings_db::models::posting::Posting;
 use postings_db::models::stmt_status::StmtStatus;
 use postings_db::DbError;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use crate::repositories::outbox_repository::insert_events;
+use crate::repositories::posting_repository::insert_postings;
 
 pub struct MariaDbAccountStmtRepository {
     pool: MySqlPool,
@@ -17,6 +21,42 @@ impl MariaDbAccountStmtRepository {
     }
 }
 
+/// Inserts or replaces the statement on an open connection, writing a `StatementClosed` event if
+/// this closes it.
+async fn upsert_stmt(conn: &mut MySqlConnection, stmt: AccountStmt, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError> {
+    let previous_status: Option<StmtStatus> = sqlx::query_scalar("SELECT stmt_status FROM account_stmt WHERE id = ? FOR UPDATE")
+        .bind(stmt.id.to_string())
+        .fetch_optional(&mut *conn)
+        .await?;
+    sqlx::query(
+        "INSERT INTO account_stmt (id, account_id, youngest_pst_id, total_debit, total_credit, posting_id, pst_time, stmt_status, latest_pst_id, stmt_seq_nbr) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?) \
+         ON DUPLICATE KEY UPDATE account_id = VALUES(account_id), youngest_pst_id = VALUES(youngest_pst_id), total_debit = VALUES(total_debit), \
+            total_credit = VALUES(total_credit), posting_id = VALUES(posting_id), pst_time = VALUES(pst_time), stmt_status = VALUES(stmt_status), \
+            latest_pst_id = VALUES(latest_pst_id), stmt_seq_nbr = VALUES(stmt_seq_nbr)",
+    )
+        .bind(stmt.id.to_string())
+        .bind(stmt.account_id.to_string())
+        .bind(stmt.youngest_pst_id.map(|u| u.to_string()))
+        .bind(&stmt.total_debit)
+        .bind(&stmt.total_credit)
+        .bind(stmt.posting_id.map(|u| u.to_string()))
+        .bind(stmt.pst_time)
+        .bind(&stmt.stmt_status)
+        .bind(stmt.latest_pst_id.map(|u| u.to_string()))
+        .bind(stmt.stmt_seq_nbr)
+        .execute(&mut *conn)
+        .await?;
+    if stmt.stmt_status == StmtStatus::Closed && previous_status != Some(StmtStatus::Closed) {
+        let ledger_id: String = sqlx::query_scalar("SELECT ledger_id FROM ledger_account WHERE id = ?")
+            .bind(stmt.account_id.to_string())
+            .fetch_one(&mut *conn)
+            .await?;
+        let ledger_id = Uuid::parse_str(&ledger_id).map_err(|_| DbError::Query)?;
+        insert_events(conn, &[NewOutboxEvent::statement_closed(ledger_id, &stmt, record_time)]).await?;
+    }
+    Ok(stmt)
+}
+
 #[async_trait]
 impl AccountStmtRepository for MariaDbAccountStmtRepository {
     async fn find_first_by_account_and_status_and_pst_time_less_than_ordered(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
@@ -39,21 +79,21 @@ impl AccountStmtRepository for MariaDbAccountStmtRepository {
             .map_err(DbError::from)
     }
 
-    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
-        sqlx::query("INSERT INTO account_stmt (id, account_id, youngest_pst_id, total_debit, total_credit, posting_id, pst_time, stmt_status, latest_pst_id, stmt_seq_nbr) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
-            .bind(stmt.id.to_string())
-            .bind(stmt.account_id.to_string())
-            .bind(stmt.youngest_pst_id.map(|u| u.to_string()))
-            .bind(&stmt.total_debit)
-            .bind(&stmt.total_credit)
-            .bind(stmt.posting_id.map(|u| u.to_string()))
-            .bind(stmt.pst_time)
-            .bind(&stmt.stmt_status)
-            .bind(stmt.latest_pst_id.map(|u| u.to_string()))
-            .bind(stmt.stmt_seq_nbr)
-            .execute(&self.pool)
-            .await?;
-        Ok(stmt)
+    /// Closing a statement, by saving it as closed when it was not before, also writes a
+    /// `StatementClosed` event.
+    async fn save(&self, stmt: AccountStmt, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError> {
+        let mut tx = self.pool.begin().await?;
+        let saved = upsert_stmt(&mut tx, stmt, record_time).await?;
+        tx.commit().await?;
+        Ok(saved)
+    }
+
+    async fn close(&self, stmt: AccountStmt, posting: &Posting, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_postings(&mut tx, std::slice::from_ref(posting), &[]).await?;
+        let saved = upsert_stmt(&mut tx, stmt, record_time).await?;
+        tx.commit().await?;
+        Ok(saved)
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError> {
@@ -4,7 +4,11 @@ use sqlx::MySqlPool;
 use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
 use postings_db::models::chart_of_account::ChartOfAccount as DomainChartOfAccount;
 use crate::models::chart_of_account::ChartOfAccount as MariaDbChartOfAccount;
+use postings_db::models::coa_account_definition::CoaAccountDefinition;
+use postings_db::models::named::Named;
+use crate::models::coa_account_definition::CoaAccountDefinitionDb;
 use postings_db::DbError;
+use crate::repositories::named_repository::insert_named;
 
 pub struct MariaDbChartOfAccountRepository {
     pool: MySqlPool,
@@ -48,4 +52,47 @@ impl ChartOfAccountRepository for MariaDbChartOfAccountRepository {
             .await?;
         Ok(())
     }
+
+    async fn save_with_named(&self, coa: &DomainChartOfAccount, named: &[Named]) -> Result<(), DbError> {
+        let mariadb_coa = Self::from_domain(coa);
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("INSERT INTO chart_of_account (id) VALUES (?)")
+            .bind(mariadb_coa.id)
+            .execute(&mut *tx)
+            .await?;
+        for n in named {
+            insert_named(&mut tx, n).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_account_definitions(&self, definitions: &[CoaAccountDefinition]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        for definition in definitions {
+            sqlx::query("INSERT INTO coa_account_definition (id, coa_id, code, name, language, category, balance_side, parent_code) VALUES (?, ?, ?, ?, ?, ?, ?, ?)")
+                .bind(definition.id.to_string())
+                .bind(definition.coa_id.to_string())
+                .bind(&definition.code)
+                .bind(&definition.name)
+                .bind(&definition.language)
+                .bind(&definition.category)
+                .bind(&definition.balance_side)
+                .bind(&definition.parent_code)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_account_definitions_by_coa_id(&self, coa_id: Uuid) -> Result<Vec<CoaAccountDefinition>, DbError> {
+        let results: Vec<CoaAccountDefinitionDb> = sqlx::query_as("SELECT * FROM coa_account_definition WHERE coa_id = ? ORDER BY code")
+            .bind(coa_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+
+        results.into_iter().map(CoaAccountDefinition::try_from).collect()
+    }
 }
@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+use postings_db::repositories::hold_repository::HoldRepository;
+use postings_db::models::account_limit::BalanceCheck;
+use postings_db::models::hold::{Hold, HoldStatus};
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::hold::{status_to_db, HoldDb};
+use crate::repositories::posting_repository::{check_limits, insert_postings};
+
+pub struct MariaDbHoldRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbHoldRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HoldRepository for MariaDbHoldRepository {
+    async fn save(&self, hold: &Hold, check: &BalanceCheck) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        check_limits(&mut tx, std::slice::from_ref(check)).await?;
+        sqlx::query("INSERT INTO account_hold (id, account_id, amount, expiry, opr_id, status, record_time, closed_time, captured_amount, posting_id) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+            .bind(hold.id.to_string())
+            .bind(hold.account_id.to_string())
+            .bind(&hold.amount)
+            .bind(hold.expiry)
+            .bind(hold.opr_id.as_ref())
+            .bind(status_to_db(&hold.status))
+            .bind(hold.record_time)
+            .bind(hold.closed_time)
+            .bind(&hold.captured_amount)
+            .bind(hold.posting_id.map(|id| id.to_string()))
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Hold>, DbError> {
+        let hold_db = sqlx::query_as::<_, HoldDb>("SELECT * FROM account_hold WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(hold_db.map(Into::into))
+    }
+
+    async fn find_active_by_account_id(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<Vec<Hold>, DbError> {
+        let holds_db = sqlx::query_as::<_, HoldDb>("SELECT * FROM account_hold WHERE account_id = ? AND status = 'ACTIVE' AND expiry > ? ORDER BY record_time")
+            .bind(account_id.to_string())
+            .bind(at)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(holds_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn capture(&self, id: Uuid, amount: &BigDecimal, time: DateTime<Utc>, posting: &Posting, lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        // Closing the hold first keeps its amount out of the funds held while the posting is checked.
+        let result = sqlx::query("UPDATE account_hold SET status = ?, closed_time = ?, captured_amount = ? WHERE id = ? AND status = 'ACTIVE' AND expiry > ?")
+            .bind(status_to_db(&HoldStatus::Captured))
+            .bind(time)
+            .bind(amount)
+            .bind(id.to_string())
+            .bind(time)
+            .execute(&mut *tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        check_limits(&mut tx, checks).await?;
+        insert_postings(&mut tx, std::slice::from_ref(posting), lines).await?;
+        sqlx::query("UPDATE account_hold SET posting_id = ? WHERE id = ?")
+            .bind(posting.id.to_string())
+            .bind(id.to_string())
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn release(&self, id: Uuid, time: DateTime<Utc>) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE account_hold SET status = ?, closed_time = ? WHERE id = ? AND status = 'ACTIVE' AND expiry > ?")
+            .bind(status_to_db(&HoldStatus::Released))
+            .bind(time)
+            .bind(id.to_string())
+            .bind(time)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn expire(&self, at: DateTime<Utc>) -> Result<u64, DbError> {
+        let result = sqlx::query("UPDATE account_hold SET status = 'EXPIRED', closed_time = expiry WHERE status = 'ACTIVE' AND expiry <= ?")
+            .bind(at)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
@@ -1,8 +1,19 @@
 use async_trait::async_trait;
-use sqlx::MySqlPool;
+use chrono::{DateTime, Utc};
+use bigdecimal::{BigDecimal, Zero};
+use sqlx::{MySql, MySqlConnection, MySqlPool, QueryBuilder};
 use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::models::account_limit::AccountLimit;
+use crate::models::account_limit::AccountLimitDb;
+use postings_db::models::account_state::{AccountState, AccountStateChange};
+use crate::models::account_state::{state_to_db, AccountStateChangeDb};
 use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::models::outbox_event::NewOutboxEvent;
+use postings_db::models::posting_status::PostingStatus;
 use postings_db::DbError;
+use crate::repositories::named_repository::insert_named;
+use crate::repositories::outbox_repository::insert_events;
 
 pub struct MariaDbLedgerAccountRepository {
     pool: MySqlPool,
@@ -14,6 +25,22 @@ impl MariaDbLedgerAccountRepository {
     }
 }
 
+/// Inserts a ledger account and its `LedgerAccountCreated` event on an open connection so they
+/// can join a surrounding transaction.
+pub(crate) async fn insert_ledger_account(conn: &mut MySqlConnection, ledger_account: &LedgerAccount, record_time: DateTime<Utc>) -> Result<(), DbError> {
+    sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category, code) VALUES (?, ?, ?, ?, ?, ?, ?)")
+        .bind(ledger_account.id)
+        .bind(ledger_account.ledger_id)
+        .bind(ledger_account.parent_id)
+        .bind(ledger_account.coa_id)
+        .bind(&ledger_account.balance_side)
+        .bind(&ledger_account.category)
+        .bind(&ledger_account.code)
+        .execute(&mut *conn)
+        .await?;
+    insert_events(conn, &[NewOutboxEvent::ledger_account_created(ledger_account, record_time)]).await
+}
+
 use uuid::Uuid;
 
 #[async_trait]
@@ -26,16 +53,146 @@ impl LedgerAccountRepository for MariaDbLedgerAccountRepository {
             .map_err(DbError::from)
     }
 
-    async fn save(&self, ledger_account: &LedgerAccount) -> Result<(), DbError> {
-        sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category) VALUES (?, ?, ?, ?, ?, ?)")
-            .bind(ledger_account.id)
-            .bind(ledger_account.ledger_id)
-            .bind(ledger_account.parent_id)
-            .bind(ledger_account.coa_id)
-            .bind(&ledger_account.balance_side)
-            .bind(&ledger_account.category)
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account WHERE ledger_id = ? ORDER BY code")
+            .bind(ledger_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_and_code(&self, ledger_id: Uuid, code: &str) -> Result<Option<LedgerAccount>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account WHERE ledger_id = ? AND code = ?")
+            .bind(ledger_id.to_string())
+            .bind(code)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_and_code_in(&self, ledger_id: Uuid, codes: &[String]) -> Result<Vec<LedgerAccount>, DbError> {
+        if codes.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut builder = QueryBuilder::<MySql>::new("SELECT * FROM ledger_account WHERE ledger_id = ");
+        builder.push_bind(ledger_id.to_string());
+        builder.push(" AND code IN (");
+        let mut separated = builder.separated(", ");
+        for code in codes {
+            separated.push_bind(code);
+        }
+        separated.push_unseparated(")");
+        builder
+            .build_query_as()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_and_code_between(&self, ledger_id: Uuid, from: &str, to: &str) -> Result<Vec<LedgerAccount>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account WHERE ledger_id = ? AND CHAR_LENGTH(code) >= CHAR_LENGTH(?) AND BINARY LEFT(code, CHAR_LENGTH(?)) >= ? AND CHAR_LENGTH(code) >= CHAR_LENGTH(?) AND BINARY LEFT(code, CHAR_LENGTH(?)) <= ? ORDER BY BINARY code")
+            .bind(ledger_id.to_string())
+            .bind(from)
+            .bind(from)
+            .bind(from)
+            .bind(to)
+            .bind(to)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_parent_id(&self, parent_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account WHERE parent_id = ?")
+            .bind(parent_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn save(&self, ledger_account: &LedgerAccount, record_time: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_ledger_account(&mut tx, ledger_account, record_time).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_with_named(&self, ledger_account: &LedgerAccount, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_ledger_account(&mut tx, ledger_account, record_time).await?;
+        for n in named {
+            insert_named(&mut tx, n).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_state_change(&self, change: &AccountStateChange) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        let ledger_id: String = sqlx::query_scalar("SELECT ledger_id FROM ledger_account WHERE id = ?")
+            .bind(change.account_id.to_string())
+            .fetch_one(&mut *tx)
+            .await?;
+        let ledger_id = Uuid::parse_str(&ledger_id).map_err(|_| DbError::Query)?;
+        // The event locks the ledger's outbox sequence row, which saving postings takes too.
+        insert_events(&mut tx, &[NewOutboxEvent::account_state_changed(ledger_id, change)]).await?;
+        if change.state == AccountState::Closed {
+            // A locking read, so lines committed after the transaction's snapshot count too.
+            let sql = format!("SELECT COALESCE(SUM(debit_amount - credit_amount), 0) FROM posting_line WHERE account_id = ? AND pst_status IN {} LOCK IN SHARE MODE", PostingStatus::EFFECTIVE_SQL);
+            let balance: BigDecimal = sqlx::query_scalar(&sql)
+                .bind(change.account_id.to_string())
+                .fetch_one(&mut *tx)
+                .await?;
+            if !balance.is_zero() {
+                return Err(DbError::BalanceNotZero { account_id: change.account_id });
+            }
+        }
+        sqlx::query("INSERT INTO ledger_account_state (id, account_id, state, effective_time, record_user, record_time, reason) VALUES (?, ?, ?, ?, ?, ?, ?)")
+            .bind(change.id.to_string())
+            .bind(change.account_id.to_string())
+            .bind(state_to_db(&change.state))
+            .bind(change.effective_time)
+            .bind(change.record_user.as_ref())
+            .bind(change.record_time)
+            .bind(&change.reason)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_state_changes_by_account_id(&self, account_id: Uuid) -> Result<Vec<AccountStateChange>, DbError> {
+        let changes_db = sqlx::query_as::<_, AccountStateChangeDb>("SELECT * FROM ledger_account_state WHERE account_id = ? ORDER BY effective_time, record_time")
+            .bind(account_id.to_string())
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(changes_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn save_limit(&self, limit: &AccountLimit) -> Result<(), DbError> {
+        sqlx::query("INSERT INTO account_limit (account_id, min_balance, max_balance) VALUES (?, ?, ?) ON DUPLICATE KEY UPDATE min_balance = VALUES(min_balance), max_balance = VALUES(max_balance)")
+            .bind(limit.account_id.to_string())
+            .bind(&limit.min_balance)
+            .bind(&limit.max_balance)
             .execute(&self.pool)
             .await?;
         Ok(())
     }
-}
\ No newline at end of file
+
+    async fn find_limit_by_account_id(&self, account_id: Uuid) -> Result<Option<AccountLimit>, DbError> {
+        let limit_db = sqlx::query_as::<_, AccountLimitDb>("SELECT * FROM account_limit WHERE account_id = ?")
+            .bind(account_id.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(limit_db.map(Into::into))
+    }
+
+    async fn delete_limit(&self, account_id: Uuid) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM account_limit WHERE account_id = ?")
+            .bind(account_id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
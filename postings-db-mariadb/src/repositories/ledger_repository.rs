@@ -1,9 +1,16 @@
 use async_trait::async_trait;
-use sqlx::MySqlPool;
+use chrono::{DateTime, Utc};
+use sqlx::{MySqlConnection, MySqlPool};
 use postings_db::repositories::ledger_repository::LedgerRepository;
 use postings_db::models::ledger::Ledger as DbLedger;
 use crate::models::ledger::Ledger as MariaDbLedger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::models::outbox_event::NewOutboxEvent;
+use crate::repositories::ledger_account_repository::insert_ledger_account;
 use postings_db::DbError;
+use crate::repositories::named_repository::insert_named;
+use crate::repositories::outbox_repository::insert_events;
 
 pub struct MariaDbLedgerRepository {
     pool: MySqlPool,
@@ -31,6 +38,17 @@ impl MariaDbLedgerRepository {
 
 use uuid::Uuid;
 
+/// Inserts the ledger and its `LedgerCreated` event on an open connection.
+async fn insert_ledger(conn: &mut MySqlConnection, ledger: &DbLedger, record_time: DateTime<Utc>) -> Result<(), DbError> {
+    let mariadb_ledger = MariaDbLedgerRepository::from_domain(ledger);
+    sqlx::query("INSERT INTO ledger (id, coa_id) VALUES (?, ?)")
+        .bind(mariadb_ledger.id)
+        .bind(mariadb_ledger.coa_id)
+        .execute(&mut *conn)
+        .await?;
+    insert_events(conn, &[NewOutboxEvent::ledger_created(ledger, record_time)]).await
+}
+
 #[async_trait]
 impl LedgerRepository for MariaDbLedgerRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<DbLedger>, DbError> {
@@ -43,13 +61,33 @@ impl LedgerRepository for MariaDbLedgerRepository {
         Ok(result.map(Self::to_domain))
     }
 
-    async fn save(&self, ledger: &DbLedger) -> Result<(), DbError> {
-        let mariadb_ledger = Self::from_domain(ledger);
-        sqlx::query("INSERT INTO ledger (id, coa_id) VALUES (?, ?)")
-            .bind(mariadb_ledger.id)
-            .bind(mariadb_ledger.coa_id)
-            .execute(&self.pool)
-            .await?;
+    async fn save(&self, ledger: &DbLedger, record_time: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_ledger(&mut tx, ledger, record_time).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_with_named(&self, ledger: &DbLedger, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_ledger(&mut tx, ledger, record_time).await?;
+        for n in named {
+            insert_named(&mut tx, n).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_with_accounts(&self, ledger: &DbLedger, accounts: &[LedgerAccount], named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_ledger(&mut tx, ledger, record_time).await?;
+        for account in accounts {
+            insert_ledger_account(&mut tx, account, record_time).await?;
+        }
+        for n in named {
+            insert_named(&mut tx, n).await?;
+        }
+        tx.commit().await?;
         Ok(())
     }
 }
@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use sqlx::{MySqlPool, query_as};
+use sqlx::{MySqlConnection, MySqlPool, query_as};
 use uuid::Uuid;
 use postings_db::models::named::{Named as DomainNamed, ContainerType as DomainContainerType};
 use postings_db::repositories::named_repository::NamedRepository;
@@ -31,11 +31,12 @@ impl MariaDbNamedRepository {
                 "Ledger" => DomainContainerType::Ledger,
                 "LedgerAccount" => DomainContainerType::LedgerAccount,
                 _ => panic!("Unknown container type: {}", maria_named.container_type),
-            }
+            },
+            retired: maria_named.retired,
         }
     }
 
-    fn from_domain(domain_named: DomainNamed) -> MariaDbNamed {
+    pub(crate) fn from_domain(domain_named: DomainNamed) -> MariaDbNamed {
         MariaDbNamed {
             id: domain_named.id.to_string(),
             container: domain_named.container.to_string(),
@@ -50,7 +51,8 @@ impl MariaDbNamedRepository {
                 DomainContainerType::ChartOfAccount => "ChartOfAccount".to_string(),
                 DomainContainerType::Ledger => "Ledger".to_string(),
                 DomainContainerType::LedgerAccount => "LedgerAccount".to_string(),
-            }
+            },
+            retired: domain_named.retired,
         }
     }
 
@@ -63,8 +65,38 @@ impl MariaDbNamedRepository {
     }
 }
 
+/// Inserts a name on an open connection so it can join the transaction of the entity it names.
+pub(crate) async fn insert_named(conn: &mut MySqlConnection, named: &DomainNamed) -> Result<(), DbError> {
+    let maria_named = MariaDbNamedRepository::from_domain(named.clone());
+    sqlx::query("INSERT INTO named (id, container, context, name, language, created, user_details, short_desc, long_desc, container_type, retired) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+        .bind(maria_named.id)
+        .bind(maria_named.container)
+        .bind(maria_named.context)
+        .bind(maria_named.name)
+        .bind(maria_named.language)
+        .bind(maria_named.created)
+        .bind(maria_named.user_details)
+        .bind(maria_named.short_desc)
+        .bind(maria_named.long_desc)
+        .bind(maria_named.container_type)
+        .bind(maria_named.retired)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
 #[async_trait]
 impl NamedRepository for MariaDbNamedRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<DomainNamed>, DbError> {
+        let result: Option<MariaDbNamed> = query_as::<_, MariaDbNamed>("SELECT * FROM named WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+
+        Ok(result.map(Self::to_domain))
+    }
+
     async fn find_by_container(&self, container_id: Uuid) -> Result<Vec<DomainNamed>, DbError> {
         let results: Vec<MariaDbNamed> = query_as::<_, MariaDbNamed>("SELECT * FROM named WHERE container = ?")
             .bind(container_id.to_string())
@@ -77,7 +109,7 @@ impl NamedRepository for MariaDbNamedRepository {
 
     async fn find_by_name_and_type(&self, name: &str, container_type: DomainContainerType) -> Result<Vec<DomainNamed>, DbError> {
         let mariadb_type = Self::convert_container_type(container_type);
-        let results: Vec<MariaDbNamed> = query_as::<_, MariaDbNamed>("SELECT * FROM named WHERE name = ? AND container_type = ?")
+        let results: Vec<MariaDbNamed> = query_as::<_, MariaDbNamed>("SELECT * FROM named WHERE name = ? AND container_type = ? AND retired IS NULL")
             .bind(name)
             .bind(mariadb_type)
             .fetch_all(&self.pool)
@@ -89,7 +121,7 @@ impl NamedRepository for MariaDbNamedRepository {
 
     async fn find_by_name_and_type_and_context(&self, name: &str, container_type: DomainContainerType, context: Uuid) -> Result<Vec<DomainNamed>, DbError> {
         let mariadb_type = Self::convert_container_type(container_type);
-        let results: Vec<MariaDbNamed> = query_as::<_, MariaDbNamed>("SELECT * FROM named WHERE name = ? AND container_type = ? AND context = ?")
+        let results: Vec<MariaDbNamed> = query_as::<_, MariaDbNamed>("SELECT * FROM named WHERE name = ? AND container_type = ? AND context = ? AND retired IS NULL")
             .bind(name)
             .bind(mariadb_type)
             .bind(context.to_string())
@@ -101,35 +133,38 @@ impl NamedRepository for MariaDbNamedRepository {
     }
 
     async fn save(&self, named: DomainNamed) -> Result<DomainNamed, DbError> {
-        let maria_named = Self::from_domain(named);
-        
-        sqlx::query(
-            "INSERT INTO named (id, container, context, name, language, created, user_details, short_desc, long_desc, container_type)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-             ON DUPLICATE KEY UPDATE
-                container = VALUES(container),
-                context = VALUES(context),
-                name = VALUES(name),
-                language = VALUES(language),
-                created = VALUES(created),
-                user_details = VALUES(user_details),
-                short_desc = VALUES(short_desc),
-                long_desc = VALUES(long_desc),
-                container_type = VALUES(container_type)")
+        let maria_named = Self::from_domain(named.clone());
+        let mut tx = self.pool.begin().await?;
+
+        // ON DUPLICATE KEY UPDATE would also fire on the unique name key and overwrite the
+        // colliding name, so updates are told apart from inserts by id.
+        let exists = sqlx::query("SELECT id FROM named WHERE id = ? FOR UPDATE")
             .bind(&maria_named.id)
-            .bind(&maria_named.container)
-            .bind(&maria_named.context)
-            .bind(&maria_named.name)
-            .bind(&maria_named.language)
-            .bind(maria_named.created)
-            .bind(&maria_named.user_details)
-            .bind(&maria_named.short_desc)
-            .bind(&maria_named.long_desc)
-            .bind(maria_named.container_type)
-            .execute(&self.pool)
-            .await
-            .map_err(DbError::from)?;
-            
+            .fetch_optional(&mut *tx)
+            .await?
+            .is_some();
+        if exists {
+            sqlx::query(
+                "UPDATE named SET container = ?, context = ?, name = ?, language = ?, created = ?, user_details = ?, short_desc = ?, long_desc = ?, container_type = ?, retired = ?
+                 WHERE id = ?")
+                .bind(&maria_named.container)
+                .bind(&maria_named.context)
+                .bind(&maria_named.name)
+                .bind(&maria_named.language)
+                .bind(maria_named.created)
+                .bind(&maria_named.user_details)
+                .bind(&maria_named.short_desc)
+                .bind(&maria_named.long_desc)
+                .bind(&maria_named.container_type)
+                .bind(maria_named.retired)
+                .bind(&maria_named.id)
+                .execute(&mut *tx)
+                .await?;
+        } else {
+            insert_named(&mut tx, &named).await?;
+        }
+        tx.commit().await?;
+
         // MariaDB does not support RETURNING, so we have to fetch it again
         let result: MariaDbNamed = query_as::<_, MariaDbNamed>("SELECT * FROM named WHERE id = ?")
             .bind(&maria_named.id)
@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{MySql, MySqlConnection, MySqlPool, QueryBuilder};
+use postings_db::models::outbox_event::{NewOutboxEvent, OutboxEvent};
+use postings_db::repositories::outbox_repository::OutboxRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::outbox_event::OutboxEventDb;
+
+/// Rows per multi-row INSERT, keeping the placeholder count well below the 65535 limit.
+const BATCH_CHUNK_SIZE: usize = 1000;
+
+pub struct MariaDbOutboxRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbOutboxRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Inserts the events on an open connection so they commit or roll back with the change they
+/// describe. Each ledger's sequence row is locked, in ledger order so that concurrent writers
+/// cannot deadlock, until the transaction ends.
+pub(crate) async fn insert_events(conn: &mut MySqlConnection, events: &[NewOutboxEvent]) -> Result<(), DbError> {
+    let mut counts: BTreeMap<Uuid, i64> = BTreeMap::new();
+    for event in events {
+        *counts.entry(event.ledger_id).or_default() += 1;
+    }
+    let mut next_seq: BTreeMap<Uuid, i64> = BTreeMap::new();
+    for (ledger_id, count) in counts {
+        sqlx::query("INSERT INTO outbox_ledger_seq (ledger_id, last_seq) VALUES (?, ?) ON DUPLICATE KEY UPDATE last_seq = last_seq + VALUES(last_seq)")
+            .bind(ledger_id.to_string())
+            .bind(count)
+            .execute(&mut *conn)
+            .await?;
+        let last_seq: i64 = sqlx::query_scalar("SELECT last_seq FROM outbox_ledger_seq WHERE ledger_id = ?")
+            .bind(ledger_id.to_string())
+            .fetch_one(&mut *conn)
+            .await?;
+        next_seq.insert(ledger_id, last_seq - count + 1);
+    }
+
+    let mut rows = Vec::with_capacity(events.len());
+    for event in events {
+        let seq = next_seq.get_mut(&event.ledger_id).ok_or_else(|| DbError::Decode { what: format!("outbox sequence of ledger {}", event.ledger_id) })?;
+        rows.push((event, *seq, event.payload_json()?));
+        *seq += 1;
+    }
+
+    for chunk in rows.chunks(BATCH_CHUNK_SIZE) {
+        let mut builder = QueryBuilder::<MySql>::new("INSERT INTO outbox_event (ledger_id, ledger_seq, event_type, payload, occurred_at) ");
+        builder.push_values(chunk, |mut row, (event, seq, payload)| {
+            row.push_bind(event.ledger_id.to_string())
+                .push_bind(*seq)
+                .push_bind(event.payload.event_type())
+                .push_bind(payload)
+                .push_bind(event.occurred_at);
+        });
+        builder.build().execute(&mut *conn).await?;
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl OutboxRepository for MariaDbOutboxRepository {
+    async fn find_pending(&self, limit: i64) -> Result<Vec<OutboxEvent>, DbError> {
+        let events = sqlx::query_as::<_, OutboxEventDb>("SELECT * FROM outbox_event WHERE dispatched_at IS NULL ORDER BY id LIMIT ?")
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(events.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_pending_per_ledger(&self, limit: i64) -> Result<Vec<OutboxEvent>, DbError> {
+        let events = sqlx::query_as::<_, OutboxEventDb>(
+            "SELECT id, ledger_id, ledger_seq, event_type, payload, occurred_at, dispatched_at FROM ( \
+                SELECT *, ROW_NUMBER() OVER (PARTITION BY ledger_id ORDER BY ledger_seq) AS pending_rank \
+                FROM outbox_event WHERE dispatched_at IS NULL \
+             ) pending WHERE pending_rank <= ? ORDER BY id",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(events.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid, after_seq: i64, limit: i64) -> Result<Vec<OutboxEvent>, DbError> {
+        let events = sqlx::query_as::<_, OutboxEventDb>("SELECT * FROM outbox_event WHERE ledger_id = ? AND ledger_seq > ? ORDER BY ledger_seq LIMIT ?")
+            .bind(ledger_id.to_string())
+            .bind(after_seq)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(events.into_iter().map(Into::into).collect())
+    }
+
+    async fn mark_dispatched(&self, ids: &[i64], time: DateTime<Utc>) -> Result<(), DbError> {
+        if ids.is_empty() {
+            return Ok(());
+        }
+        let mut builder = QueryBuilder::<MySql>::new("UPDATE outbox_event SET dispatched_at = ");
+        builder.push_bind(time);
+        builder.push(" WHERE dispatched_at IS NULL AND id IN (");
+        let mut separated = builder.separated(", ");
+        for id in ids {
+            separated.push_bind(*id);
+        }
+        separated.push_unseparated(")");
+        builder.build().execute(&self.pool).await?;
+        Ok(())
+    }
+}
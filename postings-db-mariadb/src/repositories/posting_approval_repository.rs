@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use sqlx::MySqlPool;
+use postings_db::repositories::posting_approval_repository::PostingApprovalRepository;
+use postings_db::models::approval_policy::ApprovalPolicy;
+use postings_db::models::posting_approval::PostingApproval;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::posting_approval::{ApprovalPolicyDb, PostingApprovalDb};
+
+pub struct MariaDbPostingApprovalRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbPostingApprovalRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PostingApprovalRepository for MariaDbPostingApprovalRepository {
+    async fn save_policy(&self, policy: &ApprovalPolicy) -> Result<(), DbError> {
+        sqlx::query("INSERT INTO approval_policy (ledger_id, amount_threshold) VALUES (?, ?) ON DUPLICATE KEY UPDATE amount_threshold = VALUES(amount_threshold)")
+            .bind(policy.ledger_id.to_string())
+            .bind(&policy.amount_threshold)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_policy_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<ApprovalPolicy>, DbError> {
+        let policy_db = sqlx::query_as::<_, ApprovalPolicyDb>("SELECT * FROM approval_policy WHERE ledger_id = ?")
+            .bind(ledger_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(policy_db.map(Into::into))
+    }
+
+    async fn delete_policy_by_ledger_id(&self, ledger_id: Uuid) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM approval_policy WHERE ledger_id = ?")
+            .bind(ledger_id.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn save(&self, approval: &PostingApproval) -> Result<(), DbError> {
+        sqlx::query("INSERT INTO posting_approval (id, posting_id, approver, approval_time) VALUES (?, ?, ?, ?)")
+            .bind(approval.id.to_string())
+            .bind(approval.posting_id.to_string())
+            .bind(approval.approver.as_ref())
+            .bind(approval.approval_time)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_by_posting_id(&self, posting_id: Uuid) -> Result<Vec<PostingApproval>, DbError> {
+        let approvals_db = sqlx::query_as::<_, PostingApprovalDb>("SELECT * FROM posting_approval WHERE posting_id = ? ORDER BY approval_time ASC")
+            .bind(posting_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(approvals_db.into_iter().map(Into::into).collect())
+    }
+}
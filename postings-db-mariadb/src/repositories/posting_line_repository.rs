@@ -1,11 +1,12 @@
 use async_trait::async_trait;
 use sqlx::MySqlPool;
 use postings_db::repositories::posting_line_repository::PostingLineRepository;
-use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_line::{PostingLine, SequencedPostingLine};
+use postings_db::models::time_axis::TimeAxis;
 use postings_db::DbError;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
-use crate::models::posting_line::PostingLineDb;
+use crate::models::posting_line::{PostingLineDb, SequencedPostingLineDb};
 
 pub struct MariaDbPostingLineRepository {
     pool: MySqlPool,
@@ -22,8 +23,9 @@ impl PostingLineRepository for MariaDbPostingLineRepository {
     async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError> {
         let db_model = PostingLineDb::from(posting_line.clone());
         
-        sqlx::query("INSERT INTO posting_line (id, account_id, debit_amount, credit_amount, details, src_account, base_line, sub_opr_src_id, record_time, opr_id, opr_src, pst_time, pst_type, pst_status, hash, discarded_time) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+        sqlx::query("INSERT INTO posting_line (id, posting_id, account_id, debit_amount, credit_amount, details, src_account, base_line, sub_opr_src_id, record_time, opr_id, opr_src, pst_time, pst_type, pst_status, hash, discarded_time, val_time) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
             .bind(&db_model.id)
+            .bind(&db_model.posting_id)
             .bind(&db_model.account_id)
             .bind(&db_model.debit_amount)
             .bind(&db_model.credit_amount)
@@ -39,6 +41,7 @@ impl PostingLineRepository for MariaDbPostingLineRepository {
             .bind(&db_model.pst_status)
             .bind(&db_model.hash)
             .bind(db_model.discarded_time)
+            .bind(db_model.val_time)
             .execute(&self.pool)
             .await
             .map_err(DbError::from)?;
@@ -56,6 +59,24 @@ impl PostingLineRepository for MariaDbPostingLineRepository {
         Ok(posting_line_db.map(Into::into))
     }
 
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError> {
+        let posting_lines_db = sqlx::query_as::<_, PostingLineDb>("SELECT * FROM posting_line WHERE opr_id = ?")
+            .bind(opr_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(posting_lines_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_posting_id(&self, posting_id: Uuid) -> Result<Vec<PostingLine>, DbError> {
+        let posting_lines_db = sqlx::query_as::<_, PostingLineDb>("SELECT * FROM posting_line WHERE posting_id = ?")
+            .bind(posting_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(posting_lines_db.into_iter().map(Into::into).collect())
+    }
+
     async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
         let posting_lines_db = sqlx::query_as::<_, PostingLineDb>("SELECT * FROM posting_line WHERE account_id = ? AND pst_time > ? AND pst_time <= ? AND discarded_time IS NULL ORDER BY pst_time DESC")
             .bind(account_id.to_string())
@@ -96,4 +117,46 @@ impl PostingLineRepository for MariaDbPostingLineRepository {
             .map_err(DbError::from)?;
         Ok(posting_lines_db.into_iter().map(Into::into).collect())
     }
+
+    async fn find_by_account_and_time_between(&self, account_id: Uuid, axis: TimeAxis, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let sql = format!("SELECT * FROM posting_line WHERE account_id = ? AND {0} > ? AND {0} <= ? AND discarded_time IS NULL ORDER BY {0} DESC", axis.column());
+        let posting_lines_db = sqlx::query_as::<_, PostingLineDb>(&sql)
+            .bind(account_id.to_string())
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(posting_lines_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_account_and_time_less_than_equal(&self, account_id: Uuid, axis: TimeAxis, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let sql = format!("SELECT * FROM posting_line WHERE account_id = ? AND {0} <= ? AND discarded_time IS NULL ORDER BY {0} DESC", axis.column());
+        let posting_lines_db = sqlx::query_as::<_, PostingLineDb>(&sql)
+            .bind(account_id.to_string())
+            .bind(ref_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(posting_lines_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_account_after_seq(&self, account_id: Uuid, after_seq: i64, limit: i64) -> Result<Vec<SequencedPostingLine>, DbError> {
+        let posting_lines_db = sqlx::query_as::<_, SequencedPostingLineDb>("SELECT * FROM posting_line WHERE account_id = ? AND line_seq > ? ORDER BY line_seq LIMIT ?")
+            .bind(account_id.to_string())
+            .bind(after_seq)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(posting_lines_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_last_seq_by_account(&self, account_id: Uuid) -> Result<i64, DbError> {
+        sqlx::query_scalar("SELECT COALESCE(MAX(line_seq), 0) FROM posting_line WHERE account_id = ?")
+            .bind(account_id.to_string())
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
 }
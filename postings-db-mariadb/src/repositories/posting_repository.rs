@@ -1,9 +1,24 @@
 use async_trait::async_trait;
-use sqlx::MySqlPool;
+use bigdecimal::BigDecimal;
+use sqlx::{MySql, MySqlConnection, MySqlPool, QueryBuilder};
 use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db::models::account_limit::{AccountLimit, BalanceCheck};
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::interest_accrual::AccrualPeriod;
+use postings_db::models::outbox_event::NewOutboxEvent;
 use postings_db::models::posting::Posting;
+use postings_db::models::posting_approval::PostingApproval;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_status::PostingStatus;
 use postings_db::DbError;
+use std::collections::BTreeSet;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use crate::models::account_limit::AccountLimitDb;
+use crate::repositories::outbox_repository::insert_events;
+
+/// Rows per multi-row INSERT, keeping the placeholder count well below the 65535 limit.
+const BATCH_CHUNK_SIZE: usize = 1000;
 
 pub struct MariaDbPostingRepository {
     pool: MySqlPool,
@@ -16,6 +31,143 @@ impl MariaDbPostingRepository {
 }
 
 use crate::models::posting::PostingDb;
+use crate::models::posting_line::PostingLineDb;
+
+
+/// Locks the limits of the checked accounts, in account order so that concurrent batches cannot
+/// deadlock, and fails if a check takes an account's current balance, less its active holds,
+/// past its limit as of the check's time.
+pub(crate) async fn check_limits(conn: &mut MySqlConnection, checks: &[BalanceCheck]) -> Result<(), DbError> {
+    let mut checks: Vec<&BalanceCheck> = checks.iter().collect();
+    checks.sort_by_key(|c| c.account_id);
+    for check in checks {
+        let limit_db: Option<AccountLimitDb> = sqlx::query_as("SELECT * FROM account_limit WHERE account_id = ? FOR UPDATE")
+            .bind(check.account_id.to_string())
+            .fetch_optional(&mut *conn)
+            .await?;
+        let Some(limit) = limit_db.map(AccountLimit::from) else {
+            continue;
+        };
+        let balance_side: String = sqlx::query_scalar("SELECT balance_side FROM ledger_account WHERE id = ?")
+            .bind(check.account_id.to_string())
+            .fetch_one(&mut *conn)
+            .await?;
+        let balance_side = match balance_side.as_str() {
+            "Cr" => BalanceSide::Cr,
+            "DrCr" => BalanceSide::DrCr,
+            _ => BalanceSide::Dr,
+        };
+        // Locking reads, so the sums see the lines and holds committed by concurrent checks of the
+        // account rather than the transaction's snapshot.
+        let sql = format!("SELECT COALESCE(SUM(debit_amount - credit_amount), 0) FROM posting_line WHERE account_id = ? AND pst_status IN {} LOCK IN SHARE MODE", PostingStatus::EFFECTIVE_SQL);
+        let balance: BigDecimal = sqlx::query_scalar(&sql)
+            .bind(check.account_id.to_string())
+            .fetch_one(&mut *conn)
+            .await?;
+        let held: BigDecimal = sqlx::query_scalar("SELECT COALESCE(SUM(amount), 0) FROM account_hold WHERE account_id = ? AND status = 'ACTIVE' AND expiry > ? LOCK IN SHARE MODE")
+            .bind(check.account_id.to_string())
+            .bind(check.at)
+            .fetch_one(&mut *conn)
+            .await?;
+        if let Some(shortfall) = limit.shortfall(&balance_side, &balance, &held, &check.delta) {
+            return Err(DbError::LimitExceeded { account_id: check.account_id, shortfall });
+        }
+    }
+    Ok(())
+}
+
+
+/// Fails if a line books into an account closed by the line's record time. Runs once the ledgers'
+/// outbox sequence rows are locked, which a closing locks too, and reads with a locking read so
+/// it sees every earlier closing rather than the transaction's snapshot.
+pub(crate) async fn check_open(conn: &mut MySqlConnection, lines: &[PostingLine]) -> Result<(), DbError> {
+    let account_ids: BTreeSet<Uuid> = lines.iter().map(|l| l.account_id).collect();
+    if account_ids.is_empty() {
+        return Ok(());
+    }
+    let mut builder = QueryBuilder::<MySql>::new("SELECT account_id, effective_time FROM ledger_account_state WHERE state = 'CLOSED' AND account_id IN (");
+    let mut separated = builder.separated(", ");
+    for account_id in &account_ids {
+        separated.push_bind(account_id.to_string());
+    }
+    separated.push_unseparated(") LOCK IN SHARE MODE");
+    let closings: Vec<(String, DateTime<Utc>)> = builder.build_query_as().fetch_all(&mut *conn).await?;
+    for (account_id, closed) in closings {
+        let account_id = Uuid::parse_str(&account_id).map_err(|_| DbError::Query)?;
+        if lines.iter().any(|l| l.account_id == account_id && l.record_time >= closed) {
+            return Err(DbError::AccountClosed { account_id });
+        }
+    }
+    Ok(())
+}
+
+/// Inserts the postings and their lines with multi-row inserts on an open connection so they can
+/// join a surrounding transaction, along with a `PostingCreated` event per posting.
+pub(crate) async fn insert_postings(conn: &mut MySqlConnection, postings: &[Posting], lines: &[PostingLine]) -> Result<(), DbError> {
+    // The events lock the ledgers' outbox sequence rows, which keeps the lines' sequence numbers,
+    // assigned below, in commit order within each account.
+    let events: Vec<_> = postings.iter().map(NewOutboxEvent::posting_created).collect();
+    insert_events(conn, &events).await?;
+    check_open(conn, lines).await?;
+
+    for chunk in postings.chunks(BATCH_CHUNK_SIZE) {
+        let mut builder = QueryBuilder::<MySql>::new("INSERT INTO posting (id, record_user, record_time, opr_id, opr_time, opr_type, opr_details, opr_src, pst_time, pst_type, pst_status, ledger_id, val_time, discarded_id, discarded_time, discarding_id, antecedent_id, antecedent_hash, hash) ");
+        builder.push_values(chunk, |mut row, posting| {
+            row.push_bind(posting.id.to_string())
+                .push_bind(posting.record_user.as_ref())
+                .push_bind(posting.record_time)
+                .push_bind(posting.opr_id.as_ref())
+                .push_bind(posting.opr_time)
+                .push_bind(posting.opr_type.as_ref())
+                .push_bind(posting.opr_details.as_ref().map(|v| v.as_ref()))
+                .push_bind(posting.opr_src.as_ref().map(|v| v.as_ref()))
+                .push_bind(posting.pst_time)
+                .push_bind(&posting.pst_type)
+                .push_bind(&posting.pst_status)
+                .push_bind(posting.ledger_id.to_string())
+                .push_bind(posting.val_time)
+                .push_bind(posting.discarded_id.map(|u| u.to_string()))
+                .push_bind(posting.discarded_time)
+                .push_bind(posting.discarding_id.map(|u| u.to_string()))
+                .push_bind(posting.antecedent_id.map(|u| u.to_string()))
+                .push_bind(posting.antecedent_hash.as_ref().map(|v| v.as_ref()))
+                .push_bind(posting.hash.as_ref().map(|v| v.as_ref()));
+        });
+        builder.build().execute(&mut *conn).await?;
+    }
+
+    insert_lines(conn, lines).await
+}
+
+/// Inserts the lines with multi-row inserts, each getting the next `line_seq`.
+async fn insert_lines(conn: &mut MySqlConnection, lines: &[PostingLine]) -> Result<(), DbError> {
+    for chunk in lines.chunks(BATCH_CHUNK_SIZE) {
+        let mut builder = QueryBuilder::<MySql>::new("INSERT INTO posting_line (id, posting_id, account_id, debit_amount, credit_amount, details, src_account, base_line, sub_opr_src_id, record_time, opr_id, opr_src, pst_time, pst_type, pst_status, hash, discarded_time, val_time) ");
+        builder.push_values(chunk.iter().cloned().map(PostingLineDb::from), |mut row, line| {
+            row.push_bind(line.id)
+                .push_bind(line.posting_id)
+                .push_bind(line.account_id)
+                .push_bind(line.debit_amount)
+                .push_bind(line.credit_amount)
+                .push_bind(line.details)
+                .push_bind(line.src_account)
+                .push_bind(line.base_line)
+                .push_bind(line.sub_opr_src_id)
+                .push_bind(line.record_time)
+                .push_bind(line.opr_id)
+                .push_bind(line.opr_src)
+                .push_bind(line.pst_time)
+                .push_bind(line.pst_type)
+                .push_bind(line.pst_status)
+                .push_bind(line.hash)
+                .push_bind(line.discarded_time)
+                .push_bind(line.val_time);
+        });
+        builder.build().execute(&mut *conn).await?;
+    }
+
+    Ok(())
+}
 
 #[async_trait]
 impl PostingRepository for MariaDbPostingRepository {
@@ -46,29 +198,101 @@ impl PostingRepository for MariaDbPostingRepository {
         Ok(posting_db.map(Into::into))
     }
 
+    async fn find_first_by_ledger_and_hash_is_not_null_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError> {
+        let posting_db = sqlx::query_as::<_, PostingDb>("SELECT * FROM posting WHERE ledger_id = ? AND hash IS NOT NULL ORDER BY record_time DESC LIMIT 1")
+            .bind(ledger_id.to_string())
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(posting_db.map(Into::into))
+    }
+
+    async fn find_by_ledger_and_pst_status(&self, ledger_id: Uuid, pst_status: PostingStatus) -> Result<Vec<Posting>, DbError> {
+        let postings_db = sqlx::query_as::<_, PostingDb>("SELECT * FROM posting WHERE ledger_id = ? AND pst_status = ? ORDER BY record_time ASC")
+            .bind(ledger_id.to_string())
+            .bind(pst_status)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(postings_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_pst_status_and_val_time_less_than_equal(&self, pst_status: PostingStatus, ref_time: DateTime<Utc>) -> Result<Vec<Posting>, DbError> {
+        let postings_db = sqlx::query_as::<_, PostingDb>("SELECT * FROM posting WHERE pst_status = ? AND val_time <= ? ORDER BY val_time ASC")
+            .bind(pst_status)
+            .bind(ref_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(postings_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_ledger_and_pst_time_between(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<Posting>, DbError> {
+        let postings_db = sqlx::query_as::<_, PostingDb>("SELECT * FROM posting WHERE ledger_id = ? AND pst_time > ? AND pst_time <= ? ORDER BY pst_time ASC, record_time ASC")
+            .bind(ledger_id.to_string())
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(postings_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_by_ledger_and_hash_is_not_null_order_by_record_time(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError> {
+        let postings_db = sqlx::query_as::<_, PostingDb>("SELECT * FROM posting WHERE ledger_id = ? AND hash IS NOT NULL ORDER BY record_time ASC")
+            .bind(ledger_id.to_string())
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)?;
+        Ok(postings_db.into_iter().map(Into::into).collect())
+    }
+
     async fn save(&self, posting: &Posting) -> Result<(), DbError> {
-        sqlx::query("INSERT INTO posting (id, record_user, record_time, opr_id, opr_time, opr_type, opr_details, opr_src, pst_time, pst_type, pst_status, ledger_id, val_time, discarded_id, discarded_time, discarding_id, antecedent_id, antecedent_hash, hash) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
-            .bind(posting.id.to_string())
-            .bind(posting.record_user.as_ref())
-            .bind(posting.record_time)
-            .bind(posting.opr_id.as_ref())
-            .bind(posting.opr_time)
-            .bind(posting.opr_type.as_ref())
-            .bind(posting.opr_details.as_ref().map(|v| v.as_ref()))
-            .bind(posting.opr_src.as_ref().map(|v| v.as_ref()))
-            .bind(posting.pst_time)
-            .bind(&posting.pst_type)
-            .bind(&posting.pst_status)
-            .bind(posting.ledger_id.to_string())
-            .bind(posting.val_time)
-            .bind(posting.discarded_id.map(|u| u.to_string()))
-            .bind(posting.discarded_time)
-            .bind(posting.discarding_id.map(|u| u.to_string()))
-            .bind(posting.antecedent_id.map(|u| u.to_string()))
-            .bind(posting.antecedent_hash.as_ref().map(|v| v.as_ref()))
-            .bind(posting.hash.as_ref().map(|v| v.as_ref()))
-            .execute(&self.pool)
+        let mut tx = self.pool.begin().await?;
+        insert_postings(&mut tx, std::slice::from_ref(posting), &[]).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_batch(&self, postings: &[Posting], lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        check_limits(&mut tx, checks).await?;
+        insert_postings(&mut tx, postings, lines).await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_accrual(&self, period: &AccrualPeriod, posting: &Posting, lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        // The account row lock serializes the accruals of the account.
+        sqlx::query("SELECT id FROM ledger_account WHERE id = ? FOR UPDATE")
+            .bind(period.account_id.to_string())
+            .fetch_one(&mut *tx)
+            .await?;
+        let overlapping: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM interest_accrual WHERE account_id = ? AND period_from < ? AND period_to > ? AND opr_id <> ?")
+            .bind(period.account_id.to_string())
+            .bind(period.period_to)
+            .bind(period.period_from)
+            .bind(period.opr_id.as_ref())
+            .fetch_one(&mut *tx)
+            .await?;
+        if overlapping > 0 {
+            return Err(DbError::PeriodOverlap { account_id: period.account_id });
+        }
+
+        check_limits(&mut tx, checks).await?;
+        insert_postings(&mut tx, std::slice::from_ref(posting), lines).await?;
+        sqlx::query("INSERT INTO interest_accrual (opr_id, account_id, period_from, period_to, posting_id) VALUES (?, ?, ?, ?, ?)")
+            .bind(period.opr_id.as_ref())
+            .bind(period.account_id.to_string())
+            .bind(period.period_from)
+            .bind(period.period_to)
+            .bind(period.posting_id.to_string())
+            .execute(&mut *tx)
             .await?;
+
+        tx.commit().await?;
         Ok(())
     }
 
@@ -80,4 +304,55 @@ impl PostingRepository for MariaDbPostingRepository {
             .map_err(DbError::from)?;
         Ok(posting_db.map(Into::into))
     }
-}
\ No newline at end of file
+
+    async fn update_status(&self, posting: &Posting, from: PostingStatus, lines: &[PostingLine], approval: Option<&PostingApproval>, checks: &[BalanceCheck]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        // Limits first, then the outbox sequence, in the order saving postings locks them.
+        if posting.pst_status.is_effective() {
+            check_limits(&mut tx, checks).await?;
+        }
+        insert_events(&mut tx, &[NewOutboxEvent::posting_status_changed(posting)]).await?;
+        if posting.pst_status.is_effective() {
+            check_open(&mut tx, lines).await?;
+        }
+
+        let result = sqlx::query("UPDATE posting SET pst_status = ?, record_time = ?, antecedent_id = ?, antecedent_hash = ?, hash = ? WHERE id = ? AND pst_status = ?")
+            .bind(&posting.pst_status)
+            .bind(posting.record_time)
+            .bind(posting.antecedent_id.map(|u| u.to_string()))
+            .bind(posting.antecedent_hash.as_ref().map(|v| v.as_ref()))
+            .bind(posting.hash.as_ref().map(|v| v.as_ref()))
+            .bind(posting.id.to_string())
+            .bind(from)
+            .execute(&mut *tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::StatusChanged { id: posting.id });
+        }
+
+        // A new sequence number, taken under the ledger's outbox lock like on insert, lets change
+        // feeds read the lines again with their new status.
+        for line in lines {
+            sqlx::query("UPDATE posting_line SET pst_status = ?, record_time = ?, hash = ?, line_seq = NEXT VALUE FOR posting_line_seq WHERE id = ?")
+                .bind(&line.pst_status)
+                .bind(line.record_time)
+                .bind(line.hash.as_ref().map(|v| v.as_ref()))
+                .bind(line.id.to_string())
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        if let Some(approval) = approval {
+            sqlx::query("INSERT INTO posting_approval (id, posting_id, approver, approval_time) VALUES (?, ?, ?, ?)")
+                .bind(approval.id.to_string())
+                .bind(approval.posting_id.to_string())
+                .bind(approval.approver.as_ref())
+                .bind(approval.approval_time)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+}
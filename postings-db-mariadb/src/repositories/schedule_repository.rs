@@ -0,0 +1,143 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::MySqlPool;
+use postings_db::repositories::schedule_repository::ScheduleRepository;
+use postings_db::models::account_limit::BalanceCheck;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_schedule::{PostingSchedule, ScheduleRun, ScheduleStatus, ScheduledLine};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::models::posting_schedule::{catch_up_to_db, status_to_db, PostingScheduleDb, ScheduleRunDb, ScheduledLineDb};
+use crate::repositories::posting_repository::{check_limits, insert_postings};
+
+pub struct MariaDbScheduleRepository {
+    pool: MySqlPool,
+}
+
+impl MariaDbScheduleRepository {
+    pub fn new(pool: MySqlPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ScheduleRepository for MariaDbScheduleRepository {
+    async fn save(&self, schedule: &PostingSchedule, lines: &[ScheduledLine]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("INSERT INTO posting_schedule (id, ledger_id, rrule, start_time, catch_up, record_user, opr_type, pst_type, status, next_index, next_time, record_time) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)")
+            .bind(schedule.id.to_string())
+            .bind(schedule.ledger_id.to_string())
+            .bind(&schedule.rrule)
+            .bind(schedule.start_time)
+            .bind(catch_up_to_db(&schedule.catch_up))
+            .bind(schedule.record_user.as_ref())
+            .bind(schedule.opr_type.as_ref())
+            .bind(&schedule.pst_type)
+            .bind(status_to_db(&schedule.status))
+            .bind(schedule.next_index)
+            .bind(schedule.next_time)
+            .bind(schedule.record_time)
+            .execute(&mut *tx)
+            .await?;
+        for line in lines {
+            sqlx::query("INSERT INTO posting_schedule_line (id, schedule_id, account_id, debit_amount, credit_amount) VALUES (?, ?, ?, ?, ?)")
+                .bind(line.id.to_string())
+                .bind(line.schedule_id.to_string())
+                .bind(line.account_id.to_string())
+                .bind(&line.debit_amount)
+                .bind(&line.credit_amount)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingSchedule>, DbError> {
+        let schedule_db = sqlx::query_as::<_, PostingScheduleDb>("SELECT * FROM posting_schedule WHERE id = ?")
+            .bind(id.to_string())
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(schedule_db.map(Into::into))
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<PostingSchedule>, DbError> {
+        let schedules_db = sqlx::query_as::<_, PostingScheduleDb>("SELECT * FROM posting_schedule WHERE ledger_id = ? ORDER BY record_time")
+            .bind(ledger_id.to_string())
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(schedules_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_lines_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<ScheduledLine>, DbError> {
+        let lines_db = sqlx::query_as::<_, ScheduledLineDb>("SELECT * FROM posting_schedule_line WHERE schedule_id = ?")
+            .bind(schedule_id.to_string())
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(lines_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_due(&self, now: DateTime<Utc>) -> Result<Vec<PostingSchedule>, DbError> {
+        let schedules_db = sqlx::query_as::<_, PostingScheduleDb>("SELECT * FROM posting_schedule WHERE status = 'ACTIVE' AND next_time <= ? ORDER BY next_time")
+            .bind(now)
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(schedules_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn find_runs_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<ScheduleRun>, DbError> {
+        let runs_db = sqlx::query_as::<_, ScheduleRunDb>("SELECT * FROM posting_schedule_run WHERE schedule_id = ? ORDER BY occurrence_index")
+            .bind(schedule_id.to_string())
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(runs_db.into_iter().map(Into::into).collect())
+    }
+
+    async fn cancel(&self, id: Uuid) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE posting_schedule SET status = ? WHERE id = ? AND status = 'ACTIVE'")
+            .bind(status_to_db(&ScheduleStatus::Cancelled))
+            .bind(id.to_string())
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn save_run(
+        &self,
+        schedule: &PostingSchedule,
+        expected_index: i32,
+        run: &ScheduleRun,
+        posting: &Posting,
+        lines: &[PostingLine],
+        checks: &[BalanceCheck],
+    ) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        let result = sqlx::query("UPDATE posting_schedule SET status = ?, next_index = ?, next_time = ? WHERE id = ? AND status = 'ACTIVE' AND next_index = ?")
+            .bind(status_to_db(&schedule.status))
+            .bind(schedule.next_index)
+            .bind(schedule.next_time)
+            .bind(schedule.id.to_string())
+            .bind(expected_index)
+            .execute(&mut *tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        check_limits(&mut tx, checks).await?;
+        insert_postings(&mut tx, std::slice::from_ref(posting), lines).await?;
+        sqlx::query("INSERT INTO posting_schedule_run (schedule_id, occurrence_index, occurrence_time, posting_id, run_time) VALUES (?, ?, ?, ?, ?)")
+            .bind(run.schedule_id.to_string())
+            .bind(run.occurrence_index)
+            .bind(run.occurrence_time)
+            .bind(run.posting_id.to_string())
+            .bind(run.run_time)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+//! Repositories that keep everything in process memory, for tests, demos and embedding without
+//! a database server.
+//!
+//! They follow the Postgres repositories: the same unique constraints fail with
+//! [`DbError::Duplicate`](postings_db::DbError::Duplicate) naming the Postgres constraint,
+//! limits are checked on save, line sequence numbers and outbox events are written along with
+//! the change. Foreign keys are not enforced beyond what a method needs to look up, and nothing
+//! survives the process.
+
+use std::sync::Arc;
+
+use postings_db::repositories::Repositories;
+
+pub mod repositories;
+mod store;
+
+pub use store::MemoryStore;
+
+use repositories::*;
+
+impl MemoryStore {
+    /// All repositories on this store.
+    pub fn repositories(&self) -> Repositories {
+        Repositories {
+            coa: Arc::new(chart_of_account_repository::MemoryChartOfAccountRepository::new(self.clone())),
+            ledger: Arc::new(ledger_repository::MemoryLedgerRepository::new(self.clone())),
+            ledger_account: Arc::new(ledger_account_repository::MemoryLedgerAccountRepository::new(self.clone())),
+            named: Arc::new(named_repository::MemoryNamedRepository::new(self.clone())),
+            posting: Arc::new(posting_repository::MemoryPostingRepository::new(self.clone())),
+            stmt: Arc::new(account_stmt_repository::MemoryAccountStmtRepository::new(self.clone())),
+            line: Arc::new(posting_line_repository::MemoryPostingLineRepository::new(self.clone())),
+            trace: Arc::new(posting_trace_repository::MemoryPostingTraceRepository::new(self.clone())),
+            approval: Arc::new(posting_approval_repository::MemoryPostingApprovalRepository::new(self.clone())),
+            hold: Arc::new(hold_repository::MemoryHoldRepository::new(self.clone())),
+            schedule: Arc::new(schedule_repository::MemoryScheduleRepository::new(self.clone())),
+            outbox: Arc::new(outbox_repository::MemoryOutboxRepository::new(self.clone())),
+        }
+    }
+}
+
+/// All repositories on a new, empty store.
+pub fn repositories() -> Repositories {
+    MemoryStore::new().repositories()
+}
@@ -0,0 +1,77 @@
+use std::cmp::Reverse;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::outbox_event::NewOutboxEvent;
+use postings_db::models::posting::Posting;
+use postings_db::models::stmt_status::StmtStatus;
+use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::{MemoryStore, Tables};
+
+pub struct MemoryAccountStmtRepository {
+    store: MemoryStore,
+}
+
+impl MemoryAccountStmtRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+/// Inserts or replaces the statement, writing a `StatementClosed` event if this closes it. The
+/// update in place comes after the only lookup that can fail.
+fn upsert_stmt(t: &mut Tables, stmt: AccountStmt, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError> {
+    let ledger_id = t.account(stmt.account_id)?.ledger_id;
+    let previous = t.stmts.iter_mut().find(|s| s.id == stmt.id);
+    let was_closed = previous.as_ref().is_some_and(|s| s.stmt_status == StmtStatus::Closed);
+    match previous {
+        Some(existing) => *existing = stmt.clone(),
+        None => t.stmts.push(stmt.clone()),
+    }
+    if stmt.stmt_status == StmtStatus::Closed && !was_closed {
+        t.insert_events(&[NewOutboxEvent::statement_closed(ledger_id, &stmt, record_time)])?;
+    }
+    Ok(stmt)
+}
+
+#[async_trait]
+impl AccountStmtRepository for MemoryAccountStmtRepository {
+    async fn find_first_by_account_and_status_and_pst_time_less_than_ordered(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        self.store.read(|t| {
+            t.stmts
+                .iter()
+                .filter(|s| s.account_id == account_id && s.stmt_status == status && s.pst_time < ref_time)
+                .min_by_key(|s| (Reverse(s.pst_time), Reverse(s.stmt_seq_nbr)))
+                .cloned()
+        })
+    }
+
+    async fn find_first_by_account_and_status_and_pst_time_greater_than_equal(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
+        self.store.read(|t| {
+            t.stmts
+                .iter()
+                .find(|s| s.account_id == account_id && s.stmt_status == status && s.pst_time >= ref_time)
+                .cloned()
+        })
+    }
+
+    /// Replaces the statement with the same id, if there is one. Closing it, by saving it as
+    /// closed when it was not before, also writes a `StatementClosed` event.
+    async fn save(&self, stmt: AccountStmt, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError> {
+        self.store.write(|t| upsert_stmt(t, stmt, record_time))
+    }
+
+    async fn close(&self, stmt: AccountStmt, posting: &Posting, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError> {
+        self.store.write(|t| {
+            t.insert_postings(std::slice::from_ref(posting), &[])?;
+            upsert_stmt(t, stmt, record_time)
+        })
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError> {
+        self.store.read(|t| t.stmts.iter().find(|s| s.id == id).cloned())
+    }
+}
@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::coa_account_definition::CoaAccountDefinition;
+use postings_db::models::named::Named;
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::MemoryStore;
+
+pub struct MemoryChartOfAccountRepository {
+    store: MemoryStore,
+}
+
+impl MemoryChartOfAccountRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ChartOfAccountRepository for MemoryChartOfAccountRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ChartOfAccount>, DbError> {
+        self.store.read(|t| t.coas.iter().find(|c| c.id == id).cloned())
+    }
+
+    async fn save(&self, coa: &ChartOfAccount) -> Result<(), DbError> {
+        self.store.write(|t| t.insert_coa(coa))
+    }
+
+    async fn save_with_named(&self, coa: &ChartOfAccount, named: &[Named]) -> Result<(), DbError> {
+        self.store.write(|t| {
+            t.insert_coa(coa)?;
+            named.iter().try_for_each(|n| t.insert_named(n))
+        })
+    }
+
+    /// Codes are unique within a chart.
+    async fn save_account_definitions(&self, definitions: &[CoaAccountDefinition]) -> Result<(), DbError> {
+        self.store.write(|t| {
+            for definition in definitions {
+                if t.coa_definitions.iter().any(|d| d.id == definition.id) {
+                    return Err(DbError::duplicate("coa_account_definition_pkey"));
+                }
+                if t.coa_definitions.iter().any(|d| d.coa_id == definition.coa_id && d.code == definition.code) {
+                    return Err(DbError::duplicate("coa_account_definition_coa_id_code_key"));
+                }
+                t.coa_definitions.push(definition.clone());
+            }
+            Ok(())
+        })
+    }
+
+    async fn find_account_definitions_by_coa_id(&self, coa_id: Uuid) -> Result<Vec<CoaAccountDefinition>, DbError> {
+        self.store.read(|t| {
+            let mut definitions: Vec<_> = t.coa_definitions.iter().filter(|d| d.coa_id == coa_id).cloned().collect();
+            definitions.sort_by(|a, b| a.code.cmp(&b.code));
+            definitions
+        })
+    }
+}
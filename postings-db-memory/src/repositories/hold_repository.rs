@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_db::models::account_limit::BalanceCheck;
+use postings_db::models::hold::{Hold, HoldStatus};
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::repositories::hold_repository::HoldRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::{MemoryStore, Tables};
+
+pub struct MemoryHoldRepository {
+    store: MemoryStore,
+}
+
+impl MemoryHoldRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+/// Index of the hold if it is still active at `time`.
+fn active_hold(t: &Tables, id: Uuid, time: DateTime<Utc>) -> Result<usize, DbError> {
+    t.holds
+        .iter()
+        .position(|h| h.id == id && h.status == HoldStatus::Active && h.expiry > time)
+        .ok_or(DbError::NotFound)
+}
+
+#[async_trait]
+impl HoldRepository for MemoryHoldRepository {
+    async fn save(&self, hold: &Hold, check: &BalanceCheck) -> Result<(), DbError> {
+        self.store.write(|t| {
+            t.check_limits(std::slice::from_ref(check), None)?;
+            if t.holds.iter().any(|h| h.id == hold.id) {
+                return Err(DbError::duplicate("account_hold_pkey"));
+            }
+            t.holds.push(hold.clone());
+            Ok(())
+        })
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Hold>, DbError> {
+        self.store.read(|t| t.holds.iter().find(|h| h.id == id).cloned())
+    }
+
+    async fn find_active_by_account_id(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<Vec<Hold>, DbError> {
+        self.store.read(|t| {
+            let mut holds: Vec<_> = t
+                .holds
+                .iter()
+                .filter(|h| h.account_id == account_id && h.status == HoldStatus::Active && h.expiry > at)
+                .cloned()
+                .collect();
+            holds.sort_by_key(|h| h.record_time);
+            holds
+        })
+    }
+
+    async fn capture(&self, id: Uuid, amount: &BigDecimal, time: DateTime<Utc>, posting: &Posting, lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError> {
+        self.store.write(|t| {
+            let index = active_hold(t, id, time)?;
+            t.check_limits(checks, Some(id))?;
+            t.insert_postings(std::slice::from_ref(posting), lines)?;
+            let hold = &mut t.holds[index];
+            hold.status = HoldStatus::Captured;
+            hold.closed_time = Some(time);
+            hold.captured_amount = Some(amount.clone());
+            hold.posting_id = Some(posting.id);
+            Ok(())
+        })
+    }
+
+    async fn release(&self, id: Uuid, time: DateTime<Utc>) -> Result<(), DbError> {
+        self.store.write(|t| {
+            let index = active_hold(t, id, time)?;
+            let hold = &mut t.holds[index];
+            hold.status = HoldStatus::Released;
+            hold.closed_time = Some(time);
+            Ok(())
+        })
+    }
+
+    async fn expire(&self, at: DateTime<Utc>) -> Result<u64, DbError> {
+        self.store.write(|t| {
+            let mut expired = 0;
+            for hold in t.holds.iter_mut().filter(|h| h.status == HoldStatus::Active && h.expiry <= at) {
+                hold.status = HoldStatus::Expired;
+                hold.closed_time = Some(hold.expiry);
+                expired += 1;
+            }
+            Ok(expired)
+        })
+    }
+}
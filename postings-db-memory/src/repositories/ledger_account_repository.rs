@@ -0,0 +1,132 @@
+use std::cmp::Ordering;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use bigdecimal::Zero;
+use postings_db::models::account_limit::AccountLimit;
+use postings_db::models::account_state::{AccountState, AccountStateChange};
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::models::outbox_event::NewOutboxEvent;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::MemoryStore;
+
+pub struct MemoryLedgerAccountRepository {
+    store: MemoryStore,
+}
+
+impl MemoryLedgerAccountRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+/// Byte order of the codes, accounts without a code last as in SQL.
+fn by_code(a: &LedgerAccount, b: &LedgerAccount) -> Ordering {
+    match (&a.code, &b.code) {
+        (Some(a), Some(b)) => a.cmp(b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+#[async_trait]
+impl LedgerAccountRepository for MemoryLedgerAccountRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccount>, DbError> {
+        self.store.read(|t| t.accounts.iter().find(|a| a.id == id).cloned())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        self.store.read(|t| {
+            let mut accounts: Vec<_> = t.accounts.iter().filter(|a| a.ledger_id == ledger_id).cloned().collect();
+            accounts.sort_by(by_code);
+            accounts
+        })
+    }
+
+    async fn find_by_ledger_id_and_code(&self, ledger_id: Uuid, code: &str) -> Result<Option<LedgerAccount>, DbError> {
+        self.store.read(|t| t.accounts.iter().find(|a| a.ledger_id == ledger_id && a.code.as_deref() == Some(code)).cloned())
+    }
+
+    async fn find_by_ledger_id_and_code_in(&self, ledger_id: Uuid, codes: &[String]) -> Result<Vec<LedgerAccount>, DbError> {
+        self.store.read(|t| t.accounts.iter().filter(|a| a.ledger_id == ledger_id && a.code.as_ref().is_some_and(|c| codes.contains(c))).cloned().collect())
+    }
+
+    async fn find_by_ledger_id_and_code_between(&self, ledger_id: Uuid, from: &str, to: &str) -> Result<Vec<LedgerAccount>, DbError> {
+        self.store.read(|t| {
+            let cut = |code: &str, len: usize| code.get(..len).map(str::to_string);
+            let in_range = |code: &str| cut(code, from.len()).is_some_and(|c| c.as_str() >= from) && cut(code, to.len()).is_some_and(|c| c.as_str() <= to);
+            let mut accounts: Vec<_> = t
+                .accounts
+                .iter()
+                .filter(|a| a.ledger_id == ledger_id && a.code.as_deref().is_some_and(in_range))
+                .cloned()
+                .collect();
+            accounts.sort_by(by_code);
+            accounts
+        })
+    }
+
+    async fn find_by_parent_id(&self, parent_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        self.store.read(|t| t.accounts.iter().filter(|a| a.parent_id == Some(parent_id)).cloned().collect())
+    }
+
+    async fn save(&self, ledger_account: &LedgerAccount, record_time: DateTime<Utc>) -> Result<(), DbError> {
+        self.store.write(|t| t.insert_ledger_account(ledger_account, record_time))
+    }
+
+    async fn save_with_named(&self, ledger_account: &LedgerAccount, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        self.store.write(|t| {
+            t.insert_ledger_account(ledger_account, record_time)?;
+            named.iter().try_for_each(|n| t.insert_named(n))
+        })
+    }
+
+    async fn save_state_change(&self, change: &AccountStateChange) -> Result<(), DbError> {
+        self.store.write(|t| {
+            let ledger_id = t.account(change.account_id)?.ledger_id;
+            if t.state_changes.iter().any(|c| c.id == change.id) {
+                return Err(DbError::duplicate("ledger_account_state_pkey"));
+            }
+            if change.state == AccountState::Closed && !t.balance(change.account_id).is_zero() {
+                return Err(DbError::BalanceNotZero { account_id: change.account_id });
+            }
+            t.state_changes.push(change.clone());
+            t.insert_events(&[NewOutboxEvent::account_state_changed(ledger_id, change)])?;
+            Ok(())
+        })
+    }
+
+    async fn find_state_changes_by_account_id(&self, account_id: Uuid) -> Result<Vec<AccountStateChange>, DbError> {
+        self.store.read(|t| {
+            let mut changes: Vec<_> = t.state_changes.iter().filter(|c| c.account_id == account_id).cloned().collect();
+            changes.sort_by_key(|c| (c.effective_time, c.record_time));
+            changes
+        })
+    }
+
+    async fn save_limit(&self, limit: &AccountLimit) -> Result<(), DbError> {
+        self.store.write(|t| {
+            t.account(limit.account_id)?;
+            match t.limits.iter_mut().find(|l| l.account_id == limit.account_id) {
+                Some(existing) => *existing = limit.clone(),
+                None => t.limits.push(limit.clone()),
+            }
+            Ok(())
+        })
+    }
+
+    async fn find_limit_by_account_id(&self, account_id: Uuid) -> Result<Option<AccountLimit>, DbError> {
+        self.store.read(|t| t.limits.iter().find(|l| l.account_id == account_id).cloned())
+    }
+
+    async fn delete_limit(&self, account_id: Uuid) -> Result<(), DbError> {
+        self.store.write(|t| {
+            t.limits.retain(|l| l.account_id != account_id);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,45 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::MemoryStore;
+
+pub struct MemoryLedgerRepository {
+    store: MemoryStore,
+}
+
+impl MemoryLedgerRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl LedgerRepository for MemoryLedgerRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Ledger>, DbError> {
+        self.store.read(|t| t.ledgers.iter().find(|l| l.id == id).cloned())
+    }
+
+    async fn save(&self, ledger: &Ledger, record_time: DateTime<Utc>) -> Result<(), DbError> {
+        self.store.write(|t| t.insert_ledger(ledger, record_time))
+    }
+
+    async fn save_with_named(&self, ledger: &Ledger, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        self.store.write(|t| {
+            t.insert_ledger(ledger, record_time)?;
+            named.iter().try_for_each(|n| t.insert_named(n))
+        })
+    }
+
+    async fn save_with_accounts(&self, ledger: &Ledger, accounts: &[LedgerAccount], named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        self.store.write(|t| {
+            t.insert_ledger(ledger, record_time)?;
+            accounts.iter().try_for_each(|a| t.insert_ledger_account(a, record_time))?;
+            named.iter().try_for_each(|n| t.insert_named(n))
+        })
+    }
+}
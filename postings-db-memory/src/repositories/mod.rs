@@ -0,0 +1,12 @@
+pub mod account_stmt_repository;
+pub mod chart_of_account_repository;
+pub mod hold_repository;
+pub mod ledger_account_repository;
+pub mod ledger_repository;
+pub mod named_repository;
+pub mod outbox_repository;
+pub mod posting_approval_repository;
+pub mod posting_line_repository;
+pub mod posting_repository;
+pub mod posting_trace_repository;
+pub mod schedule_repository;
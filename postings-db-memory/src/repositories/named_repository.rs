@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use postings_db::models::named::{ContainerType, Named};
+use postings_db::repositories::named_repository::NamedRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::MemoryStore;
+
+pub struct MemoryNamedRepository {
+    store: MemoryStore,
+}
+
+impl MemoryNamedRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl NamedRepository for MemoryNamedRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Named>, DbError> {
+        self.store.read(|t| t.named.iter().find(|n| n.id == id).cloned())
+    }
+
+    async fn find_by_container(&self, container_id: Uuid) -> Result<Vec<Named>, DbError> {
+        self.store.read(|t| t.named.iter().filter(|n| n.container == container_id).cloned().collect())
+    }
+
+    async fn find_by_name_and_type(&self, name: &str, container_type: ContainerType) -> Result<Vec<Named>, DbError> {
+        self.store.read(|t| {
+            t.named
+                .iter()
+                .filter(|n| n.name == name && n.container_type == container_type && n.retired.is_none())
+                .cloned()
+                .collect()
+        })
+    }
+
+    async fn find_by_name_and_type_and_context(&self, name: &str, container_type: ContainerType, context: Uuid) -> Result<Vec<Named>, DbError> {
+        self.store.read(|t| {
+            t.named
+                .iter()
+                .filter(|n| n.name == name && n.container_type == container_type && n.context == context && n.retired.is_none())
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Replaces the name with the same id, if there is one.
+    async fn save(&self, named: Named) -> Result<Named, DbError> {
+        self.store.write(|t| {
+            t.check_named(&named)?;
+            match t.named.iter_mut().find(|n| n.id == named.id) {
+                Some(existing) => *existing = named.clone(),
+                None => t.named.push(named.clone()),
+            }
+            Ok(named)
+        })
+    }
+}
@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::outbox_event::OutboxEvent;
+use postings_db::repositories::outbox_repository::OutboxRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::MemoryStore;
+
+pub struct MemoryOutboxRepository {
+    store: MemoryStore,
+}
+
+impl MemoryOutboxRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+fn take(limit: i64) -> usize {
+    usize::try_from(limit).unwrap_or(0)
+}
+
+#[async_trait]
+impl OutboxRepository for MemoryOutboxRepository {
+    async fn find_pending(&self, limit: i64) -> Result<Vec<OutboxEvent>, DbError> {
+        self.store.read(|t| t.outbox.iter().filter(|e| e.dispatched_at.is_none()).take(take(limit)).cloned().collect())
+    }
+
+    async fn find_pending_per_ledger(&self, limit: i64) -> Result<Vec<OutboxEvent>, DbError> {
+        self.store.read(|t| {
+            let mut taken: HashMap<Uuid, usize> = HashMap::new();
+            t.outbox
+                .iter()
+                .filter(|e| e.dispatched_at.is_none())
+                .filter(|e| {
+                    let count = taken.entry(e.ledger_id).or_default();
+                    *count += 1;
+                    *count <= take(limit)
+                })
+                .cloned()
+                .collect()
+        })
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid, after_seq: i64, limit: i64) -> Result<Vec<OutboxEvent>, DbError> {
+        self.store.read(|t| {
+            t.outbox
+                .iter()
+                .filter(|e| e.ledger_id == ledger_id && e.ledger_seq > after_seq)
+                .take(take(limit))
+                .cloned()
+                .collect()
+        })
+    }
+
+    async fn mark_dispatched(&self, ids: &[i64], time: DateTime<Utc>) -> Result<(), DbError> {
+        self.store.write(|t| {
+            for event in t.outbox.iter_mut().filter(|e| ids.contains(&e.id) && e.dispatched_at.is_none()) {
+                event.dispatched_at = Some(time);
+            }
+            Ok(())
+        })
+    }
+}
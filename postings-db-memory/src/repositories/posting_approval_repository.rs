@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use postings_db::models::approval_policy::ApprovalPolicy;
+use postings_db::models::posting_approval::PostingApproval;
+use postings_db::repositories::posting_approval_repository::PostingApprovalRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::MemoryStore;
+
+pub struct MemoryPostingApprovalRepository {
+    store: MemoryStore,
+}
+
+impl MemoryPostingApprovalRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl PostingApprovalRepository for MemoryPostingApprovalRepository {
+    async fn save_policy(&self, policy: &ApprovalPolicy) -> Result<(), DbError> {
+        self.store.write(|t| {
+            match t.policies.iter_mut().find(|p| p.ledger_id == policy.ledger_id) {
+                Some(existing) => *existing = policy.clone(),
+                None => t.policies.push(policy.clone()),
+            }
+            Ok(())
+        })
+    }
+
+    async fn find_policy_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<ApprovalPolicy>, DbError> {
+        self.store.read(|t| t.policies.iter().find(|p| p.ledger_id == ledger_id).cloned())
+    }
+
+    async fn delete_policy_by_ledger_id(&self, ledger_id: Uuid) -> Result<(), DbError> {
+        self.store.write(|t| {
+            t.policies.retain(|p| p.ledger_id != ledger_id);
+            Ok(())
+        })
+    }
+
+    async fn save(&self, approval: &PostingApproval) -> Result<(), DbError> {
+        self.store.write(|t| {
+            if t.approvals.iter().any(|a| a.id == approval.id) {
+                return Err(DbError::duplicate("posting_approval_pkey"));
+            }
+            t.approvals.push(approval.clone());
+            Ok(())
+        })
+    }
+
+    async fn find_by_posting_id(&self, posting_id: Uuid) -> Result<Vec<PostingApproval>, DbError> {
+        self.store.read(|t| {
+            let mut approvals: Vec<_> = t.approvals.iter().filter(|a| a.posting_id == posting_id).cloned().collect();
+            approvals.sort_by_key(|a| a.approval_time);
+            approvals
+        })
+    }
+}
@@ -0,0 +1,94 @@
+use std::cmp::Reverse;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::posting_line::{PostingLine, SequencedPostingLine};
+use postings_db::models::time_axis::TimeAxis;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::{line_time, MemoryStore};
+
+pub struct MemoryPostingLineRepository {
+    store: MemoryStore,
+}
+
+impl MemoryPostingLineRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    fn find(&self, filter: impl Fn(&PostingLine) -> bool) -> Result<Vec<PostingLine>, DbError> {
+        self.store.read(|t| t.lines.iter().map(|l| &l.line).filter(|l| filter(l)).cloned().collect())
+    }
+}
+
+#[async_trait]
+impl PostingLineRepository for MemoryPostingLineRepository {
+    async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError> {
+        self.store.write(|t| t.insert_line(&posting_line))?;
+        Ok(posting_line)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        Ok(self.find(|l| l.id == id)?.into_iter().next())
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError> {
+        self.find(|l| l.opr_id == opr_id)
+    }
+
+    async fn find_by_posting_id(&self, posting_id: Uuid) -> Result<Vec<PostingLine>, DbError> {
+        self.find(|l| l.posting_id == Some(posting_id))
+    }
+
+    async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        self.find_by_account_and_time_between(account_id, TimeAxis::PstTime, from, to).await
+    }
+
+    async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError> {
+        Ok(self.find(|l| l.id == id && l.account_id == account_id)?.into_iter().next())
+    }
+
+    async fn find_by_base_line_and_pst_time_less_than_equal(&self, base_line: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = self.find(|l| l.base_line == Some(base_line) && l.pst_time <= ref_time && l.discarded_time.is_none())?;
+        lines.sort_by_key(|l| Reverse(l.record_time));
+        Ok(lines)
+    }
+
+    async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = self.find(|l| l.account_id == account_id && l.pst_time <= ref_time && l.discarded_time.is_none())?;
+        lines.sort_by_key(|l| Reverse(l.record_time));
+        Ok(lines)
+    }
+
+    async fn find_by_account_and_time_between(&self, account_id: Uuid, axis: TimeAxis, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = self.find(|l| {
+            let time = line_time(l, axis);
+            l.account_id == account_id && time > from && time <= to && l.discarded_time.is_none()
+        })?;
+        lines.sort_by_key(|l| Reverse(line_time(l, axis)));
+        Ok(lines)
+    }
+
+    async fn find_by_account_and_time_less_than_equal(&self, account_id: Uuid, axis: TimeAxis, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let mut lines = self.find(|l| l.account_id == account_id && line_time(l, axis) <= ref_time && l.discarded_time.is_none())?;
+        lines.sort_by_key(|l| Reverse(line_time(l, axis)));
+        Ok(lines)
+    }
+
+    async fn find_by_account_after_seq(&self, account_id: Uuid, after_seq: i64, limit: i64) -> Result<Vec<SequencedPostingLine>, DbError> {
+        self.store.read(|t| {
+            t.lines
+                .iter()
+                .filter(|l| l.line.account_id == account_id && l.line_seq > after_seq)
+                .take(usize::try_from(limit).unwrap_or(0))
+                .cloned()
+                .collect()
+        })
+    }
+
+    async fn find_last_seq_by_account(&self, account_id: Uuid) -> Result<i64, DbError> {
+        self.store.read(|t| t.lines.iter().rev().find(|l| l.line.account_id == account_id).map_or(0, |l| l.line_seq))
+    }
+}
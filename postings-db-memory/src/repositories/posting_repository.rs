@@ -0,0 +1,134 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::account_limit::BalanceCheck;
+use postings_db::models::interest_accrual::AccrualPeriod;
+use postings_db::models::outbox_event::NewOutboxEvent;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_approval::PostingApproval;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_status::PostingStatus;
+use postings_db::repositories::posting_repository::{PostingRepository, UNIQUE_ACCRUAL_OPR_ID};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::MemoryStore;
+
+pub struct MemoryPostingRepository {
+    store: MemoryStore,
+}
+
+impl MemoryPostingRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+
+    fn find(&self, filter: impl Fn(&Posting) -> bool) -> Result<Vec<Posting>, DbError> {
+        self.store.read(|t| t.postings.iter().filter(|p| filter(p)).cloned().collect())
+    }
+}
+
+#[async_trait]
+impl PostingRepository for MemoryPostingRepository {
+    async fn find_by_opr_id_and_discarding_id_is_null(&self, opr_id: &[u8]) -> Result<Option<Posting>, DbError> {
+        Ok(self.find(|p| p.opr_id == opr_id && p.discarding_id.is_none())?.into_iter().next())
+    }
+
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<Posting>, DbError> {
+        self.find(|p| p.opr_id == opr_id)
+    }
+
+    async fn find_first_by_ledger_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError> {
+        Ok(self.find(|p| p.ledger_id == ledger_id)?.into_iter().max_by_key(|p| p.record_time))
+    }
+
+    async fn find_first_by_ledger_and_hash_is_not_null_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError> {
+        Ok(self.find(|p| p.ledger_id == ledger_id && p.hash.is_some())?.into_iter().max_by_key(|p| p.record_time))
+    }
+
+    async fn find_by_ledger_and_pst_status(&self, ledger_id: Uuid, pst_status: PostingStatus) -> Result<Vec<Posting>, DbError> {
+        let mut postings = self.find(|p| p.ledger_id == ledger_id && p.pst_status == pst_status)?;
+        postings.sort_by_key(|p| p.record_time);
+        Ok(postings)
+    }
+
+    async fn find_by_pst_status_and_val_time_less_than_equal(&self, pst_status: PostingStatus, ref_time: DateTime<Utc>) -> Result<Vec<Posting>, DbError> {
+        let mut postings = self.find(|p| p.pst_status == pst_status && p.val_time.is_some_and(|val_time| val_time <= ref_time))?;
+        postings.sort_by_key(|p| p.val_time);
+        Ok(postings)
+    }
+
+    async fn find_by_ledger_and_pst_time_between(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<Posting>, DbError> {
+        let mut postings = self.find(|p| p.ledger_id == ledger_id && p.pst_time > from && p.pst_time <= to)?;
+        postings.sort_by_key(|p| (p.pst_time, p.record_time));
+        Ok(postings)
+    }
+
+    async fn find_by_ledger_and_hash_is_not_null_order_by_record_time(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError> {
+        let mut postings = self.find(|p| p.ledger_id == ledger_id && p.hash.is_some())?;
+        postings.sort_by_key(|p| p.record_time);
+        Ok(postings)
+    }
+
+    async fn save(&self, posting: &Posting) -> Result<(), DbError> {
+        self.store.write(|t| t.insert_postings(std::slice::from_ref(posting), &[]))
+    }
+
+    async fn save_batch(&self, postings: &[Posting], lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError> {
+        self.store.write(|t| {
+            t.check_limits(checks, None)?;
+            t.insert_postings(postings, lines)
+        })
+    }
+
+    async fn save_accrual(&self, period: &AccrualPeriod, posting: &Posting, lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError> {
+        self.store.write(|t| {
+            if t.accruals.iter().any(|a| a.account_id == period.account_id && a.opr_id != period.opr_id && a.overlaps(period.period_from, period.period_to)) {
+                return Err(DbError::PeriodOverlap { account_id: period.account_id });
+            }
+            if t.accruals.iter().any(|a| a.opr_id == period.opr_id) {
+                return Err(DbError::duplicate(UNIQUE_ACCRUAL_OPR_ID));
+            }
+            t.check_limits(checks, None)?;
+            t.insert_postings(std::slice::from_ref(posting), lines)?;
+            t.accruals.push(period.clone());
+            Ok(())
+        })
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Posting>, DbError> {
+        Ok(self.find(|p| p.id == id)?.into_iter().next())
+    }
+
+    async fn update_status(&self, posting: &Posting, from: PostingStatus, lines: &[PostingLine], approval: Option<&PostingApproval>, checks: &[BalanceCheck]) -> Result<(), DbError> {
+        self.store.write(|t| {
+            let index = t.postings.iter().position(|p| p.id == posting.id && p.pst_status == from).ok_or(DbError::StatusChanged { id: posting.id })?;
+            if posting.pst_status.is_effective() {
+                t.check_limits(checks, None)?;
+                t.check_open(lines)?;
+            }
+            if let Some(approval) = approval {
+                if t.approvals.iter().any(|a| a.id == approval.id) {
+                    return Err(DbError::duplicate("posting_approval_pkey"));
+                }
+                t.approvals.push(approval.clone());
+            }
+            let stored = &mut t.postings[index];
+            stored.pst_status = posting.pst_status.clone();
+            stored.record_time = posting.record_time;
+            stored.antecedent_id = posting.antecedent_id;
+            stored.antecedent_hash = posting.antecedent_hash;
+            stored.hash = posting.hash;
+            // The lines move to the end with a new sequence number, as the database stores do.
+            for line in lines {
+                if let Some(index) = t.lines.iter().position(|l| l.line.id == line.id) {
+                    let mut stored = t.lines.remove(index).line;
+                    stored.pst_status = line.pst_status.clone();
+                    stored.record_time = line.record_time;
+                    stored.hash = line.hash;
+                    t.append_line(stored);
+                }
+            }
+            t.insert_events(&[NewOutboxEvent::posting_status_changed(posting)])?;
+            Ok(())
+        })
+    }
+}
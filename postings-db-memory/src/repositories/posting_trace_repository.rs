@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use postings_db::models::posting_trace::PostingTrace;
+use postings_db::repositories::posting_trace_repository::PostingTraceRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::MemoryStore;
+
+pub struct MemoryPostingTraceRepository {
+    store: MemoryStore,
+}
+
+impl MemoryPostingTraceRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl PostingTraceRepository for MemoryPostingTraceRepository {
+    /// A source posting is traced at most once per target.
+    async fn save(&self, trace: PostingTrace) -> Result<PostingTrace, DbError> {
+        self.store.write(|t| {
+            if t.traces.iter().any(|x| x.id == trace.id) {
+                return Err(DbError::duplicate("posting_trace_pkey"));
+            }
+            if t.traces.iter().any(|x| x.tgt_pst_id == trace.tgt_pst_id && x.src_pst_id == trace.src_pst_id) {
+                return Err(DbError::duplicate("posting_trace_tgt_pst_id_src_pst_id_key"));
+            }
+            t.traces.push(trace.clone());
+            Ok(trace)
+        })
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingTrace>, DbError> {
+        self.store.read(|t| t.traces.iter().find(|x| x.id == id).cloned())
+    }
+}
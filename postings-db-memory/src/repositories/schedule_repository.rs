@@ -0,0 +1,117 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::account_limit::BalanceCheck;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_schedule::{PostingSchedule, ScheduleRun, ScheduleStatus, ScheduledLine};
+use postings_db::repositories::schedule_repository::ScheduleRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::store::MemoryStore;
+
+pub struct MemoryScheduleRepository {
+    store: MemoryStore,
+}
+
+impl MemoryScheduleRepository {
+    pub fn new(store: MemoryStore) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait]
+impl ScheduleRepository for MemoryScheduleRepository {
+    async fn save(&self, schedule: &PostingSchedule, lines: &[ScheduledLine]) -> Result<(), DbError> {
+        self.store.write(|t| {
+            if t.schedules.iter().any(|s| s.id == schedule.id) {
+                return Err(DbError::duplicate("posting_schedule_pkey"));
+            }
+            t.schedules.push(schedule.clone());
+            for line in lines {
+                if t.schedule_lines.iter().any(|l| l.id == line.id) {
+                    return Err(DbError::duplicate("posting_schedule_line_pkey"));
+                }
+                t.schedule_lines.push(line.clone());
+            }
+            Ok(())
+        })
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingSchedule>, DbError> {
+        self.store.read(|t| t.schedules.iter().find(|s| s.id == id).cloned())
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<PostingSchedule>, DbError> {
+        self.store.read(|t| {
+            let mut schedules: Vec<_> = t.schedules.iter().filter(|s| s.ledger_id == ledger_id).cloned().collect();
+            schedules.sort_by_key(|s| s.record_time);
+            schedules
+        })
+    }
+
+    async fn find_lines_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<ScheduledLine>, DbError> {
+        self.store.read(|t| t.schedule_lines.iter().filter(|l| l.schedule_id == schedule_id).cloned().collect())
+    }
+
+    async fn find_due(&self, now: DateTime<Utc>) -> Result<Vec<PostingSchedule>, DbError> {
+        self.store.read(|t| {
+            let mut schedules: Vec<_> = t
+                .schedules
+                .iter()
+                .filter(|s| s.status == ScheduleStatus::Active && s.next_time.is_some_and(|next_time| next_time <= now))
+                .cloned()
+                .collect();
+            schedules.sort_by_key(|s| s.next_time);
+            schedules
+        })
+    }
+
+    async fn find_runs_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<ScheduleRun>, DbError> {
+        self.store.read(|t| {
+            let mut runs: Vec<_> = t.schedule_runs.iter().filter(|r| r.schedule_id == schedule_id).cloned().collect();
+            runs.sort_by_key(|r| r.occurrence_index);
+            runs
+        })
+    }
+
+    async fn cancel(&self, id: Uuid) -> Result<(), DbError> {
+        self.store.write(|t| {
+            let schedule = t
+                .schedules
+                .iter_mut()
+                .find(|s| s.id == id && s.status == ScheduleStatus::Active)
+                .ok_or(DbError::NotFound)?;
+            schedule.status = ScheduleStatus::Cancelled;
+            Ok(())
+        })
+    }
+
+    async fn save_run(
+        &self,
+        schedule: &PostingSchedule,
+        expected_index: i32,
+        run: &ScheduleRun,
+        posting: &Posting,
+        lines: &[PostingLine],
+        checks: &[BalanceCheck],
+    ) -> Result<(), DbError> {
+        self.store.write(|t| {
+            let index = t
+                .schedules
+                .iter()
+                .position(|s| s.id == schedule.id && s.status == ScheduleStatus::Active && s.next_index == expected_index)
+                .ok_or(DbError::NotFound)?;
+            t.check_limits(checks, None)?;
+            t.insert_postings(std::slice::from_ref(posting), lines)?;
+            if t.schedule_runs.iter().any(|r| r.schedule_id == run.schedule_id && r.occurrence_index == run.occurrence_index) {
+                return Err(DbError::duplicate("posting_schedule_run_pkey"));
+            }
+            t.schedule_runs.push(run.clone());
+            let stored = &mut t.schedules[index];
+            stored.status = schedule.status.clone();
+            stored.next_index = schedule.next_index;
+            stored.next_time = schedule.next_time;
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,314 @@
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{DateTime, Utc};
+use postings_db::models::account_limit::{AccountLimit, BalanceCheck};
+use postings_db::models::account_state::{AccountState, AccountStateChange};
+use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::approval_policy::ApprovalPolicy;
+use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::coa_account_definition::CoaAccountDefinition;
+use postings_db::models::hold::{Hold, HoldStatus};
+use postings_db::models::interest_accrual::AccrualPeriod;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::models::outbox_event::{NewOutboxEvent, OutboxEvent};
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_approval::PostingApproval;
+use postings_db::models::posting_line::{PostingLine, SequencedPostingLine};
+use postings_db::models::posting_schedule::{PostingSchedule, ScheduleRun, ScheduledLine};
+use postings_db::models::posting_trace::PostingTrace;
+use postings_db::models::time_axis::TimeAxis;
+use postings_db::repositories::named_repository::UNIQUE_NAME_INDEX;
+use postings_db::DbError;
+use uuid::Uuid;
+
+/// The rows of all tables, in insertion order. Lookups scan, which is plenty for tests and
+/// demos but not meant for production volumes.
+#[derive(Default)]
+pub(crate) struct Tables {
+    pub coas: Vec<ChartOfAccount>,
+    pub coa_definitions: Vec<CoaAccountDefinition>,
+    pub ledgers: Vec<Ledger>,
+    pub accounts: Vec<LedgerAccount>,
+    pub state_changes: Vec<AccountStateChange>,
+    pub limits: Vec<AccountLimit>,
+    pub named: Vec<Named>,
+    pub postings: Vec<Posting>,
+    pub lines: Vec<SequencedPostingLine>,
+    /// The last `line_seq` handed out. Like a database sequence it never goes back, also when
+    /// lines are moved or rolled back.
+    pub line_seq: i64,
+    pub stmts: Vec<AccountStmt>,
+    pub traces: Vec<PostingTrace>,
+    pub policies: Vec<ApprovalPolicy>,
+    pub approvals: Vec<PostingApproval>,
+    pub holds: Vec<Hold>,
+    pub schedules: Vec<PostingSchedule>,
+    pub schedule_lines: Vec<ScheduledLine>,
+    pub schedule_runs: Vec<ScheduleRun>,
+    pub outbox: Vec<OutboxEvent>,
+    pub accruals: Vec<AccrualPeriod>,
+}
+
+/// Table lengths before a write. Inserts only ever append, so truncating to these undoes them.
+struct Savepoint {
+    coas: usize,
+    coa_definitions: usize,
+    ledgers: usize,
+    accounts: usize,
+    state_changes: usize,
+    named: usize,
+    postings: usize,
+    lines: usize,
+    traces: usize,
+    approvals: usize,
+    holds: usize,
+    schedules: usize,
+    schedule_lines: usize,
+    schedule_runs: usize,
+    outbox: usize,
+    accruals: usize,
+}
+
+impl Tables {
+    fn savepoint(&self) -> Savepoint {
+        Savepoint {
+            coas: self.coas.len(),
+            coa_definitions: self.coa_definitions.len(),
+            ledgers: self.ledgers.len(),
+            accounts: self.accounts.len(),
+            state_changes: self.state_changes.len(),
+            named: self.named.len(),
+            postings: self.postings.len(),
+            lines: self.lines.len(),
+            traces: self.traces.len(),
+            approvals: self.approvals.len(),
+            holds: self.holds.len(),
+            schedules: self.schedules.len(),
+            schedule_lines: self.schedule_lines.len(),
+            schedule_runs: self.schedule_runs.len(),
+            outbox: self.outbox.len(),
+            accruals: self.accruals.len(),
+        }
+    }
+
+    fn rollback(&mut self, savepoint: Savepoint) {
+        self.coas.truncate(savepoint.coas);
+        self.coa_definitions.truncate(savepoint.coa_definitions);
+        self.ledgers.truncate(savepoint.ledgers);
+        self.accounts.truncate(savepoint.accounts);
+        self.state_changes.truncate(savepoint.state_changes);
+        self.named.truncate(savepoint.named);
+        self.postings.truncate(savepoint.postings);
+        self.lines.truncate(savepoint.lines);
+        self.traces.truncate(savepoint.traces);
+        self.approvals.truncate(savepoint.approvals);
+        self.holds.truncate(savepoint.holds);
+        self.schedules.truncate(savepoint.schedules);
+        self.schedule_lines.truncate(savepoint.schedule_lines);
+        self.schedule_runs.truncate(savepoint.schedule_runs);
+        self.outbox.truncate(savepoint.outbox);
+        self.accruals.truncate(savepoint.accruals);
+    }
+
+    pub fn account(&self, id: Uuid) -> Result<&LedgerAccount, DbError> {
+        self.accounts.iter().find(|a| a.id == id).ok_or(DbError::NotFound)
+    }
+
+    /// Appends the events, numbering them per ledger like the outbox sequence table does.
+    pub fn insert_events(&mut self, events: &[NewOutboxEvent]) -> Result<(), DbError> {
+        for event in events {
+            let ledger_seq = self.outbox.iter().filter(|e| e.ledger_id == event.ledger_id).count() as i64 + 1;
+            self.outbox.push(OutboxEvent {
+                id: self.outbox.len() as i64 + 1,
+                ledger_id: event.ledger_id,
+                ledger_seq,
+                event_type: event.payload.event_type().to_string(),
+                payload: event.payload_json()?,
+                occurred_at: event.occurred_at,
+                dispatched_at: None,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn insert_coa(&mut self, coa: &ChartOfAccount) -> Result<(), DbError> {
+        if self.coas.iter().any(|c| c.id == coa.id) {
+            return Err(DbError::duplicate("chart_of_account_pkey"));
+        }
+        self.coas.push(coa.clone());
+        Ok(())
+    }
+
+    pub fn insert_ledger(&mut self, ledger: &Ledger, record_time: DateTime<Utc>) -> Result<(), DbError> {
+        if self.ledgers.iter().any(|l| l.id == ledger.id) {
+            return Err(DbError::duplicate("ledger_pkey"));
+        }
+        self.ledgers.push(ledger.clone());
+        self.insert_events(&[NewOutboxEvent::ledger_created(ledger, record_time)])?;
+        Ok(())
+    }
+
+    /// Codes are unique within a ledger; accounts without one do not collide.
+    pub fn insert_ledger_account(&mut self, account: &LedgerAccount, record_time: DateTime<Utc>) -> Result<(), DbError> {
+        if self.accounts.iter().any(|a| a.id == account.id) {
+            return Err(DbError::duplicate("ledger_account_pkey"));
+        }
+        if self.accounts.iter().any(|a| a.ledger_id == account.ledger_id && a.code.is_some() && a.code == account.code) {
+            return Err(DbError::duplicate("unique_ledger_account_code"));
+        }
+        self.accounts.push(account.clone());
+        self.insert_events(&[NewOutboxEvent::ledger_account_created(account, record_time)])?;
+        Ok(())
+    }
+
+    /// A name that is not retired must be the only one with its name and language for its type
+    /// in its context.
+    pub fn check_named(&self, named: &Named) -> Result<(), DbError> {
+        let collides = |n: &Named| {
+            n.id != named.id
+                && n.retired.is_none()
+                && named.retired.is_none()
+                && n.name == named.name
+                && n.language == named.language
+                && n.container_type == named.container_type
+                && n.context == named.context
+        };
+        if self.named.iter().any(collides) {
+            return Err(DbError::duplicate(UNIQUE_NAME_INDEX));
+        }
+        Ok(())
+    }
+
+    pub fn insert_named(&mut self, named: &Named) -> Result<(), DbError> {
+        if self.named.iter().any(|n| n.id == named.id) {
+            return Err(DbError::duplicate("named_pkey"));
+        }
+        self.check_named(named)?;
+        self.named.push(named.clone());
+        Ok(())
+    }
+
+    /// Fails if a check takes an account's effective balance, less its active holds, past its
+    /// limit. A hold being captured by the same write no longer counts as held.
+    pub fn check_limits(&self, checks: &[BalanceCheck], capturing: Option<Uuid>) -> Result<(), DbError> {
+        for check in checks {
+            let Some(limit) = self.limits.iter().find(|l| l.account_id == check.account_id) else {
+                continue;
+            };
+            let balance_side = &self.account(check.account_id)?.balance_side;
+            let balance = self.balance(check.account_id);
+            let held = self
+                .holds
+                .iter()
+                .filter(|h| h.account_id == check.account_id && h.status == HoldStatus::Active && h.expiry > check.at && Some(h.id) != capturing)
+                .fold(BigDecimal::zero(), |sum, h| sum + &h.amount);
+            if let Some(shortfall) = limit.shortfall(balance_side, &balance, &held, &check.delta) {
+                return Err(DbError::LimitExceeded { account_id: check.account_id, shortfall });
+            }
+        }
+        Ok(())
+    }
+
+    /// Sum of debits less credits over the account's effective lines.
+    pub fn balance(&self, account_id: Uuid) -> BigDecimal {
+        self.lines
+            .iter()
+            .map(|l| &l.line)
+            .filter(|l| l.account_id == account_id && is_effective(l))
+            .fold(BigDecimal::zero(), |sum, l| sum + &l.debit_amount - &l.credit_amount)
+    }
+
+    /// Fails if a line books into an account closed by the line's record time.
+    pub fn check_open(&self, lines: &[PostingLine]) -> Result<(), DbError> {
+        for line in lines {
+            let closed = self.state_changes.iter().any(|c| c.account_id == line.account_id && c.state == AccountState::Closed && c.effective_time <= line.record_time);
+            if closed {
+                return Err(DbError::AccountClosed { account_id: line.account_id });
+            }
+        }
+        Ok(())
+    }
+
+    pub fn insert_line(&mut self, line: &PostingLine) -> Result<(), DbError> {
+        if self.lines.iter().any(|l| l.line.id == line.id) {
+            return Err(DbError::duplicate("posting_line_pkey"));
+        }
+        self.append_line(line.clone());
+        Ok(())
+    }
+
+    /// Appends the line with the next sequence number.
+    pub fn append_line(&mut self, line: PostingLine) {
+        self.line_seq += 1;
+        self.lines.push(SequencedPostingLine { line_seq: self.line_seq, line });
+    }
+
+    /// Inserts the postings and their lines along with a `PostingCreated` event per posting.
+    pub fn insert_postings(&mut self, postings: &[Posting], lines: &[PostingLine]) -> Result<(), DbError> {
+        self.insert_events(&postings.iter().map(NewOutboxEvent::posting_created).collect::<Vec<_>>())?;
+        self.check_open(lines)?;
+        for posting in postings {
+            // Postings that discard nothing share the NULL discarding id, which never collides.
+            if self.postings.iter().any(|p| p.id == posting.id) {
+                return Err(DbError::duplicate("posting_pkey"));
+            }
+            if self.postings.iter().any(|p| p.discarding_id.is_some() && p.discarding_id == posting.discarding_id && p.opr_id == posting.opr_id) {
+                return Err(DbError::duplicate("posting_opr_id_discarding_id_key"));
+            }
+            self.postings.push(posting.clone());
+        }
+        for line in lines {
+            self.insert_line(line)?;
+        }
+        Ok(())
+    }
+}
+
+fn is_effective(line: &PostingLine) -> bool {
+    line.pst_status.is_effective()
+}
+
+/// Time of the line on the axis. Lines without a value time fall back to their posting time.
+pub(crate) fn line_time(line: &PostingLine, axis: TimeAxis) -> DateTime<Utc> {
+    match axis {
+        TimeAxis::PstTime => line.pst_time,
+        TimeAxis::ValTime => line.val_time.unwrap_or(line.pst_time),
+        TimeAxis::RecordTime => line.record_time,
+    }
+}
+
+/// An in-process database. Clones share the same tables; every repository call holds the lock
+/// for its whole duration, so each one is a serializable transaction of its own.
+#[derive(Clone, Default)]
+pub struct MemoryStore {
+    tables: Arc<Mutex<Tables>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn lock(&self) -> Result<MutexGuard<'_, Tables>, DbError> {
+        self.tables.lock().map_err(|_| DbError::Connection { source: None })
+    }
+
+    pub(crate) fn read<T>(&self, f: impl FnOnce(&Tables) -> T) -> Result<T, DbError> {
+        Ok(f(&*self.lock()?))
+    }
+
+    /// Runs `f` as one transaction: the rows it inserted are removed again if it fails. Updates
+    /// in place are not undone, so `f` makes them only once nothing can fail any more.
+    pub(crate) fn write<T>(&self, f: impl FnOnce(&mut Tables) -> Result<T, DbError>) -> Result<T, DbError> {
+        let mut tables = self.lock()?;
+        let savepoint = tables.savepoint();
+        let result = f(&mut tables);
+        if result.is_err() {
+            tables.rollback(savepoint);
+        }
+        result
+    }
+}
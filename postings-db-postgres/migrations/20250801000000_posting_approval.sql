@@ -0,0 +1,19 @@
+-- =============================================================================
+-- FOUR-EYES APPROVAL
+-- =============================================================================
+
+-- Approval policy per ledger. A NULL threshold requires approval for every posting.
+CREATE TABLE approval_policy (
+    ledger_id UUID PRIMARY KEY REFERENCES ledger(id),
+    amount_threshold NUMERIC(19, 2)
+);
+
+-- Approvals given to postings
+CREATE TABLE posting_approval (
+    id UUID PRIMARY KEY,
+    posting_id UUID NOT NULL REFERENCES posting(id),
+    approver BYTEA NOT NULL,           -- 34-byte hash
+    approval_time TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_posting_approval_posting_id ON posting_approval(posting_id);
@@ -0,0 +1,6 @@
+-- =============================================================================
+-- RETIRED NAMES
+-- =============================================================================
+
+-- Retired names are kept for history but no longer resolve
+ALTER TABLE named ADD COLUMN retired TIMESTAMPTZ;
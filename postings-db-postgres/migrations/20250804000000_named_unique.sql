@@ -0,0 +1,19 @@
+-- =============================================================================
+-- UNIQUE NAMES PER CONTEXT
+-- =============================================================================
+
+-- Retire duplicates so only the oldest active name of each collision survives
+UPDATE named n
+SET retired = NOW()
+WHERE n.retired IS NULL
+  AND EXISTS (
+    SELECT 1 FROM named o
+    WHERE o.name = n.name
+      AND o.language = n.language
+      AND o.container_type = n.container_type
+      AND o.context = n.context
+      AND o.retired IS NULL
+      AND (o.created, o.id) < (n.created, n.id)
+  );
+
+CREATE UNIQUE INDEX uq_named_name_language_type_context ON named(name, language, container_type, context) WHERE retired IS NULL;
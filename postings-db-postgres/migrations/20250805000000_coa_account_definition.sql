@@ -0,0 +1,19 @@
+-- =============================================================================
+-- CHART OF ACCOUNT TEMPLATES
+-- =============================================================================
+
+-- Account definitions of a chart; ledgers instantiated from the chart get one account per definition
+CREATE TABLE coa_account_definition (
+    id UUID PRIMARY KEY,
+    coa_id UUID NOT NULL REFERENCES chart_of_account(id),
+    code VARCHAR(64) NOT NULL,
+    name VARCHAR(255) NOT NULL,
+    language CHAR(2) NOT NULL,
+    category account_category NOT NULL,
+    balance_side balance_side NOT NULL,
+    parent_code VARCHAR(64),
+    UNIQUE(coa_id, code)
+);
+
+-- Code of the definition a ledger account was created from
+ALTER TABLE ledger_account ADD COLUMN code VARCHAR(64);
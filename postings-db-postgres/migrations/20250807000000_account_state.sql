@@ -0,0 +1,18 @@
+-- =============================================================================
+-- ACCOUNT LIFECYCLE
+-- =============================================================================
+
+CREATE TYPE account_state AS ENUM ('OPEN', 'DEBIT_BLOCKED', 'CREDIT_BLOCKED', 'FROZEN', 'CLOSED');
+
+-- State changes of ledger accounts. Rows are only ever inserted and serve as audit trail.
+CREATE TABLE ledger_account_state (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    state account_state NOT NULL,
+    effective_time TIMESTAMPTZ NOT NULL,
+    record_user BYTEA NOT NULL,        -- 34-byte hash
+    record_time TIMESTAMPTZ NOT NULL,
+    reason TEXT
+);
+
+CREATE INDEX idx_ledger_account_state_account_id ON ledger_account_state(account_id);
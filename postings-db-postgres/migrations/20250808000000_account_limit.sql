@@ -0,0 +1,12 @@
+-- =============================================================================
+-- ACCOUNT LIMITS
+-- =============================================================================
+
+-- Balance bounds of ledger accounts, on the account's balance side. Postings lock the row of
+-- each limited account they touch, which serializes concurrent postings to the same account.
+CREATE TABLE account_limit (
+    account_id UUID PRIMARY KEY REFERENCES ledger_account(id),
+    min_balance NUMERIC(19, 2),
+    max_balance NUMERIC(19, 2),
+    CHECK (min_balance IS NULL OR max_balance IS NULL OR min_balance <= max_balance)
+);
@@ -0,0 +1,23 @@
+-- =============================================================================
+-- FUNDS RESERVATIONS
+-- =============================================================================
+
+CREATE TYPE hold_status AS ENUM ('ACTIVE', 'CAPTURED', 'RELEASED', 'EXPIRED');
+
+-- Funds reserved on ledger accounts. Active holds that have not expired reduce the available
+-- balance of their account.
+CREATE TABLE account_hold (
+    id UUID PRIMARY KEY,
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    amount NUMERIC(19, 2) NOT NULL CHECK (amount > 0),
+    expiry TIMESTAMPTZ NOT NULL,
+    opr_id BYTEA NOT NULL,             -- 34-byte hash
+    status hold_status NOT NULL,
+    record_time TIMESTAMPTZ NOT NULL,
+    closed_time TIMESTAMPTZ,
+    captured_amount NUMERIC(19, 2),
+    posting_id UUID REFERENCES posting(id)
+);
+
+CREATE INDEX idx_account_hold_account_id_status ON account_hold(account_id, status);
+CREATE INDEX idx_account_hold_status_expiry ON account_hold(status, expiry);
@@ -0,0 +1,45 @@
+-- =============================================================================
+-- SCHEDULED POSTINGS
+-- =============================================================================
+
+CREATE TYPE schedule_status AS ENUM ('ACTIVE', 'FINISHED', 'CANCELLED');
+CREATE TYPE schedule_catch_up AS ENUM ('ALL', 'LATEST');
+
+-- Posting templates booked on every occurrence of a recurrence rule
+CREATE TABLE posting_schedule (
+    id UUID PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    rrule TEXT NOT NULL,
+    start_time TIMESTAMPTZ NOT NULL,
+    catch_up schedule_catch_up NOT NULL,
+    record_user BYTEA NOT NULL,        -- 34-byte hash
+    opr_type BYTEA NOT NULL,           -- 34-byte hash
+    pst_type posting_type NOT NULL,
+    status schedule_status NOT NULL,
+    next_index INTEGER NOT NULL,
+    next_time TIMESTAMPTZ,
+    record_time TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_posting_schedule_ledger_id ON posting_schedule(ledger_id);
+CREATE INDEX idx_posting_schedule_status_next_time ON posting_schedule(status, next_time);
+
+CREATE TABLE posting_schedule_line (
+    id UUID PRIMARY KEY,
+    schedule_id UUID NOT NULL REFERENCES posting_schedule(id),
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    debit_amount NUMERIC(19, 2) NOT NULL,
+    credit_amount NUMERIC(19, 2) NOT NULL
+);
+
+CREATE INDEX idx_posting_schedule_line_schedule_id ON posting_schedule_line(schedule_id);
+
+-- One row per posted occurrence; the primary key keeps an occurrence from being posted twice.
+CREATE TABLE posting_schedule_run (
+    schedule_id UUID NOT NULL REFERENCES posting_schedule(id),
+    occurrence_index INTEGER NOT NULL,
+    occurrence_time TIMESTAMPTZ NOT NULL,
+    posting_id UUID NOT NULL REFERENCES posting(id),
+    run_time TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (schedule_id, occurrence_index)
+);
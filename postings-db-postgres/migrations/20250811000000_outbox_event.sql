@@ -0,0 +1,24 @@
+-- =============================================================================
+-- TRANSACTIONAL OUTBOX
+-- =============================================================================
+
+-- Last sequence number handed out per ledger. Its row stays locked until the writing
+-- transaction ends, so sequence order is commit order within a ledger.
+CREATE TABLE outbox_ledger_seq (
+    ledger_id UUID PRIMARY KEY REFERENCES ledger(id),
+    last_seq BIGINT NOT NULL
+);
+
+-- Domain events written in the transaction of the change they describe
+CREATE TABLE outbox_event (
+    id BIGSERIAL PRIMARY KEY,
+    ledger_id UUID NOT NULL REFERENCES ledger(id),
+    ledger_seq BIGINT NOT NULL,
+    event_type VARCHAR(64) NOT NULL,
+    payload TEXT NOT NULL,             -- JSON
+    occurred_at TIMESTAMPTZ NOT NULL,
+    dispatched_at TIMESTAMPTZ,
+    UNIQUE (ledger_id, ledger_seq)
+);
+
+CREATE INDEX idx_outbox_event_pending ON outbox_event(id) WHERE dispatched_at IS NULL;
@@ -0,0 +1,18 @@
+-- =============================================================================
+-- BOOKED INTEREST ACCRUALS
+-- =============================================================================
+
+-- Periods [period_from, period_to) booked per account. The operation id is derived from account
+-- and period, so a period cannot be booked twice; overlapping periods are rejected while the
+-- account row is locked.
+CREATE TABLE interest_accrual (
+    opr_id BYTEA NOT NULL,             -- 34-byte hash
+    account_id UUID NOT NULL REFERENCES ledger_account(id),
+    period_from DATE NOT NULL,
+    period_to DATE NOT NULL,
+    posting_id UUID NOT NULL REFERENCES posting(id),
+    CONSTRAINT unique_interest_accrual_opr_id UNIQUE (opr_id),
+    CHECK (period_from < period_to)
+);
+
+CREATE INDEX idx_interest_accrual_account_id_period ON interest_accrual(account_id, period_from);
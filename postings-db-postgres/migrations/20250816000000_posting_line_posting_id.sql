@@ -0,0 +1,21 @@
+-- =============================================================================
+-- POSTING OF A LINE
+-- =============================================================================
+
+-- Operation ids are shared by the postings of an operation, e.g. a posting and its predecessor
+-- or the captures of the holds placed under one operation, so lines name their posting.
+ALTER TABLE posting_line ADD COLUMN posting_id UUID REFERENCES posting(id);
+
+-- Existing lines take the posting of their operation they were found through: the one with their
+-- status, the current one first.
+UPDATE posting_line pl
+SET posting_id = (
+    SELECT p.id
+    FROM posting p
+    WHERE p.opr_id = pl.opr_id
+      AND p.pst_status = pl.pst_status
+    ORDER BY p.discarding_id IS NOT NULL, p.record_time DESC
+    LIMIT 1
+);
+
+CREATE INDEX idx_posting_line_posting_id ON posting_line(posting_id);
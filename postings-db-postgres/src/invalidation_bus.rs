@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgListener;
+use sqlx::PgPool;
+use postings_db::invalidation_bus::{InvalidationBus, InvalidationEvent, InvalidationSubscription};
+use postings_db::DbError;
+
+/// The channel events are sent on, shared by all instances on a database.
+pub const INVALIDATION_CHANNEL: &str = "postings_cache_invalidation";
+
+/// Sends events with `NOTIFY` and receives them with `LISTEN`, so every instance connected to the
+/// same database gets them without further infrastructure.
+pub struct PostgresInvalidationBus {
+    pool: PgPool,
+}
+
+impl PostgresInvalidationBus {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl InvalidationBus for PostgresInvalidationBus {
+    async fn publish(&self, event: &InvalidationEvent) -> Result<(), DbError> {
+        sqlx::query("SELECT pg_notify($1, $2)")
+            .bind(INVALIDATION_CHANNEL)
+            .bind(event.to_string())
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    /// Holds one connection of the pool for as long as the subscription lives.
+    async fn subscribe(&self) -> Result<Box<dyn InvalidationSubscription + Send>, DbError> {
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(INVALIDATION_CHANNEL).await?;
+        Ok(Box::new(PostgresInvalidationSubscription { listener }))
+    }
+}
+
+pub struct PostgresInvalidationSubscription {
+    listener: PgListener,
+}
+
+#[async_trait]
+impl InvalidationSubscription for PostgresInvalidationSubscription {
+    /// Notifications sent while the connection was lost are gone, which is reported as
+    /// [`InvalidationEvent::All`]; the listener reconnects on the next call. Ends when it cannot.
+    async fn next(&mut self) -> Option<InvalidationEvent> {
+        match self.listener.try_recv().await {
+            Ok(Some(notification)) => Some(notification.payload().parse().unwrap_or(InvalidationEvent::All)),
+            Ok(None) => Some(InvalidationEvent::All),
+            Err(_) => None,
+        }
+    }
+}
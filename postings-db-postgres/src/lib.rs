@@ -1 +1,26 @@
+pub mod invalidation_bus;
+pub mod line_feed;
 pub mod repositories;
+
+/// Migrations of the Postgres schema, embedded at build time.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+/// All repositories on the pool.
+pub fn repositories(pool: sqlx::PgPool) -> postings_db::repositories::Repositories {
+    use std::sync::Arc;
+    use repositories::*;
+    postings_db::repositories::Repositories {
+        coa: Arc::new(chart_of_account_repository::PostgresChartOfAccountRepository::new(pool.clone())),
+        ledger: Arc::new(ledger_repository::PostgresLedgerRepository::new(pool.clone())),
+        ledger_account: Arc::new(ledger_account_repository::PostgresLedgerAccountRepository::new(pool.clone())),
+        named: Arc::new(named_repository::PostgresNamedRepository::new(pool.clone())),
+        posting: Arc::new(posting_repository::PostgresPostingRepository::new(pool.clone())),
+        stmt: Arc::new(account_stmt_repository::PostgresAccountStmtRepository::new(pool.clone())),
+        line: Arc::new(posting_line_repository::PostgresPostingLineRepository::new(pool.clone())),
+        trace: Arc::new(posting_trace_repository::PostgresPostingTraceRepository::new(pool.clone())),
+        approval: Arc::new(posting_approval_repository::PostgresPostingApprovalRepository::new(pool.clone())),
+        hold: Arc::new(hold_repository::PostgresHoldRepository::new(pool.clone())),
+        schedule: Arc::new(schedule_repository::PostgresScheduleRepository::new(pool.clone())),
+        outbox: Arc::new(outbox_repository::PostgresOutboxRepository::new(pool)),
+    }
+}
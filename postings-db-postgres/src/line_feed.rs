@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use sqlx::postgres::PgListener;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use postings_db::line_feed::{LineAppended, LineNotifications, LineNotifier};
+use postings_db::models::posting_line::PostingLine;
+use postings_db::DbError;
+
+/// The channel that carries the id of every account lines were appended to.
+pub const LINE_APPENDED_CHANNEL: &str = "postings_line_appended";
+
+/// Queues a notification per account of the lines. Postgres delivers them when the surrounding
+/// transaction commits, and drops them when it rolls back.
+pub(crate) async fn notify_lines_appended(conn: &mut PgConnection, lines: &[PostingLine]) -> Result<(), DbError> {
+    let mut account_ids: Vec<Uuid> = lines.iter().map(|line| line.account_id).collect();
+    account_ids.sort();
+    account_ids.dedup();
+    if account_ids.is_empty() {
+        return Ok(());
+    }
+    sqlx::query("SELECT pg_notify($1, account_id::text) FROM UNNEST($2::uuid[]) AS account_id")
+        .bind(LINE_APPENDED_CHANNEL)
+        .bind(account_ids)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
+/// Receives the notifications sent on commit of new lines with `LISTEN`.
+pub struct PostgresLineNotifier {
+    pool: PgPool,
+}
+
+impl PostgresLineNotifier {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl LineNotifier for PostgresLineNotifier {
+    /// Holds one connection of the pool for as long as the notifications are listened to.
+    async fn listen(&self) -> Result<Box<dyn LineNotifications + Send>, DbError> {
+        let mut listener = PgListener::connect_with(&self.pool).await?;
+        listener.listen(LINE_APPENDED_CHANNEL).await?;
+        Ok(Box::new(PostgresLineNotifications { listener }))
+    }
+}
+
+pub struct PostgresLineNotifications {
+    listener: PgListener,
+}
+
+#[async_trait]
+impl LineNotifications for PostgresLineNotifications {
+    /// A lost connection is reported as [`LineAppended::Unknown`] and re-established on the next
+    /// call. Ends when it cannot be.
+    async fn next(&mut self) -> Option<LineAppended> {
+        match self.listener.try_recv().await {
+            Ok(Some(notification)) => Some(Uuid::parse_str(notification.payload()).map_or(LineAppended::Unknown, LineAppended::Account)),
+            Ok(None) => Some(LineAppended::Unknown),
+            Err(_) => None,
+        }
+    }
+}
@@ -1,11 +1,15 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use sqlx::{PgConnection, PgPool};
 use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
 use postings_db::models::account_stmt::AccountStmt;
+use postings_db::models::outbox_event::NewOutboxEvent;
+use postings_db::models::posting::Posting;
 use postings_db::models::stmt_status::StmtStatus;
 use postings_db::DbError;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use crate::repositories::outbox_repository::insert_events;
+use crate::repositories::posting_repository::insert_postings;
 
 pub struct PostgresAccountStmtRepository {
     pool: PgPool,
@@ -17,6 +21,50 @@ impl PostgresAccountStmtRepository {
     }
 }
 
+/// Inserts or replaces the statement on an open connection, writing a `StatementClosed` event if
+/// this closes it.
+async fn upsert_stmt(conn: &mut PgConnection, stmt: AccountStmt, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError> {
+    let previous_status: Option<StmtStatus> = sqlx::query_scalar("SELECT stmt_status FROM account_stmt WHERE id = $1 FOR UPDATE")
+        .bind(stmt.id)
+        .fetch_optional(&mut *conn)
+        .await?;
+    let saved: AccountStmt = sqlx::query_as(
+        "INSERT INTO account_stmt (id, account_id, youngest_pst_id, total_debit, total_credit, posting_id, pst_time, stmt_status, latest_pst_id, stmt_seq_nbr) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) \
+         ON CONFLICT (id) DO UPDATE SET \
+            account_id = EXCLUDED.account_id, \
+            youngest_pst_id = EXCLUDED.youngest_pst_id, \
+            total_debit = EXCLUDED.total_debit, \
+            total_credit = EXCLUDED.total_credit, \
+            posting_id = EXCLUDED.posting_id, \
+            pst_time = EXCLUDED.pst_time, \
+            stmt_status = EXCLUDED.stmt_status, \
+            latest_pst_id = EXCLUDED.latest_pst_id, \
+            stmt_seq_nbr = EXCLUDED.stmt_seq_nbr \
+         RETURNING *"
+    )
+        .bind(stmt.id)
+        .bind(stmt.account_id)
+        .bind(stmt.youngest_pst_id)
+        .bind(stmt.total_debit)
+        .bind(stmt.total_credit)
+        .bind(stmt.posting_id)
+        .bind(stmt.pst_time)
+        .bind(stmt.stmt_status)
+        .bind(stmt.latest_pst_id)
+        .bind(stmt.stmt_seq_nbr)
+        .fetch_one(&mut *conn)
+        .await?;
+    if saved.stmt_status == StmtStatus::Closed && previous_status != Some(StmtStatus::Closed) {
+        let ledger_id: Uuid = sqlx::query_scalar("SELECT ledger_id FROM ledger_account WHERE id = $1")
+            .bind(saved.account_id)
+            .fetch_one(&mut *conn)
+            .await?;
+        insert_events(conn, &[NewOutboxEvent::statement_closed(ledger_id, &saved, record_time)]).await?;
+    }
+    Ok(saved)
+}
+
 #[async_trait]
 impl AccountStmtRepository for PostgresAccountStmtRepository {
     async fn find_first_by_account_and_status_and_pst_time_less_than_ordered(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError> {
@@ -39,35 +87,21 @@ impl AccountStmtRepository for PostgresAccountStmtRepository {
             .map_err(DbError::from)
     }
 
-    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError> {
-        sqlx::query_as(
-            "INSERT INTO account_stmt (id, account_id, youngest_pst_id, total_debit, total_credit, posting_id, pst_time, stmt_status, latest_pst_id, stmt_seq_nbr) \
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) \
-             ON CONFLICT (id) DO UPDATE SET \
-                account_id = EXCLUDED.account_id, \
-                youngest_pst_id = EXCLUDED.youngest_pst_id, \
-                total_debit = EXCLUDED.total_debit, \
-                total_credit = EXCLUDED.total_credit, \
-                posting_id = EXCLUDED.posting_id, \
-                pst_time = EXCLUDED.pst_time, \
-                stmt_status = EXCLUDED.stmt_status, \
-                latest_pst_id = EXCLUDED.latest_pst_id, \
-                stmt_seq_nbr = EXCLUDED.stmt_seq_nbr \
-             RETURNING *"
-        )
-            .bind(stmt.id)
-            .bind(stmt.account_id)
-            .bind(stmt.youngest_pst_id)
-            .bind(stmt.total_debit)
-            .bind(stmt.total_credit)
-            .bind(stmt.posting_id)
-            .bind(stmt.pst_time)
-            .bind(stmt.stmt_status)
-            .bind(stmt.latest_pst_id)
-            .bind(stmt.stmt_seq_nbr)
-            .fetch_one(&self.pool)
-            .await
-            .map_err(DbError::from)
+    /// Closing a statement, by saving it as closed when it was not before, also writes a
+    /// `StatementClosed` event.
+    async fn save(&self, stmt: AccountStmt, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError> {
+        let mut tx = self.pool.begin().await?;
+        let saved = upsert_stmt(&mut tx, stmt, record_time).await?;
+        tx.commit().await?;
+        Ok(saved)
+    }
+
+    async fn close(&self, stmt: AccountStmt, posting: &Posting, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_postings(&mut tx, std::slice::from_ref(posting), &[]).await?;
+        let saved = upsert_stmt(&mut tx, stmt, record_time).await?;
+        tx.commit().await?;
+        Ok(saved)
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError> {
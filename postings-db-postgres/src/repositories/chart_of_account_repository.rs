@@ -3,6 +3,9 @@ use async_trait::async_trait;
 use sqlx::PgPool;
 use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
 use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::coa_account_definition::CoaAccountDefinition;
+use postings_db::models::named::Named;
+use crate::repositories::named_repository::insert_named;
 use postings_db::DbError;
 
 pub struct PostgresChartOfAccountRepository {
@@ -32,4 +35,44 @@ impl ChartOfAccountRepository for PostgresChartOfAccountRepository {
             .await?;
         Ok(())
     }
+
+    async fn save_with_named(&self, coa: &ChartOfAccount, named: &[Named]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("INSERT INTO chart_of_account (id) VALUES ($1)")
+            .bind(coa.id)
+            .execute(&mut *tx)
+            .await?;
+        for n in named {
+            insert_named(&mut tx, n).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_account_definitions(&self, definitions: &[CoaAccountDefinition]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        for definition in definitions {
+            sqlx::query("INSERT INTO coa_account_definition (id, coa_id, code, name, language, category, balance_side, parent_code) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)")
+                .bind(definition.id)
+                .bind(definition.coa_id)
+                .bind(&definition.code)
+                .bind(&definition.name)
+                .bind(&definition.language)
+                .bind(&definition.category)
+                .bind(&definition.balance_side)
+                .bind(&definition.parent_code)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_account_definitions_by_coa_id(&self, coa_id: Uuid) -> Result<Vec<CoaAccountDefinition>, DbError> {
+        sqlx::query_as("SELECT * FROM coa_account_definition WHERE coa_id = $1 ORDER BY code")
+            .bind(coa_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
 }
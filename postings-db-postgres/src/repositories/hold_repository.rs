@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use postings_db::repositories::hold_repository::HoldRepository;
+use postings_db::models::account_limit::BalanceCheck;
+use postings_db::models::hold::{Hold, HoldStatus};
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::repositories::posting_repository::{check_limits, insert_postings};
+
+pub struct PostgresHoldRepository {
+    pool: PgPool,
+}
+
+impl PostgresHoldRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl HoldRepository for PostgresHoldRepository {
+    async fn save(&self, hold: &Hold, check: &BalanceCheck) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        check_limits(&mut tx, std::slice::from_ref(check)).await?;
+        sqlx::query("INSERT INTO account_hold (id, account_id, amount, expiry, opr_id, status, record_time, closed_time, captured_amount, posting_id) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)")
+            .bind(hold.id)
+            .bind(hold.account_id)
+            .bind(&hold.amount)
+            .bind(hold.expiry)
+            .bind(hold.opr_id)
+            .bind(&hold.status)
+            .bind(hold.record_time)
+            .bind(hold.closed_time)
+            .bind(&hold.captured_amount)
+            .bind(hold.posting_id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Hold>, DbError> {
+        sqlx::query_as("SELECT * FROM account_hold WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_active_by_account_id(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<Vec<Hold>, DbError> {
+        sqlx::query_as("SELECT * FROM account_hold WHERE account_id = $1 AND status = $2 AND expiry > $3 ORDER BY record_time")
+            .bind(account_id)
+            .bind(HoldStatus::Active)
+            .bind(at)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn capture(&self, id: Uuid, amount: &BigDecimal, time: DateTime<Utc>, posting: &Posting, lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        // Closing the hold first keeps its amount out of the funds held while the posting is checked.
+        let result = sqlx::query("UPDATE account_hold SET status = $2, closed_time = $3, captured_amount = $4 WHERE id = $1 AND status = $5 AND expiry > $3")
+            .bind(id)
+            .bind(HoldStatus::Captured)
+            .bind(time)
+            .bind(amount)
+            .bind(HoldStatus::Active)
+            .execute(&mut *tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        check_limits(&mut tx, checks).await?;
+        insert_postings(&mut tx, std::slice::from_ref(posting), lines).await?;
+        sqlx::query("UPDATE account_hold SET posting_id = $2 WHERE id = $1")
+            .bind(id)
+            .bind(posting.id)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn release(&self, id: Uuid, time: DateTime<Utc>) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE account_hold SET status = $2, closed_time = $3 WHERE id = $1 AND status = $4 AND expiry > $3")
+            .bind(id)
+            .bind(HoldStatus::Released)
+            .bind(time)
+            .bind(HoldStatus::Active)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn expire(&self, at: DateTime<Utc>) -> Result<u64, DbError> {
+        let result = sqlx::query("UPDATE account_hold SET status = $1, closed_time = expiry WHERE status = $2 AND expiry <= $3")
+            .bind(HoldStatus::Expired)
+            .bind(HoldStatus::Active)
+            .bind(at)
+            .execute(&self.pool)
+            .await?;
+        Ok(result.rows_affected())
+    }
+}
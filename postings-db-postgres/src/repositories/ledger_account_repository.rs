@@ -1,8 +1,17 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use bigdecimal::{BigDecimal, Zero};
+use sqlx::{PgConnection, PgPool};
 use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::models::account_limit::AccountLimit;
+use postings_db::models::account_state::{AccountState, AccountStateChange};
 use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::models::outbox_event::NewOutboxEvent;
+use postings_db::models::posting_status::PostingStatus;
 use postings_db::DbError;
+use crate::repositories::named_repository::insert_named;
+use crate::repositories::outbox_repository::insert_events;
 
 pub struct PostgresLedgerAccountRepository {
     pool: PgPool,
@@ -14,6 +23,22 @@ impl PostgresLedgerAccountRepository {
     }
 }
 
+/// Inserts a ledger account and its `LedgerAccountCreated` event on an open connection so they
+/// can join a surrounding transaction.
+pub(crate) async fn insert_ledger_account(conn: &mut PgConnection, ledger_account: &LedgerAccount, record_time: DateTime<Utc>) -> Result<(), DbError> {
+    sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category, code) VALUES ($1, $2, $3, $4, $5, $6, $7)")
+        .bind(ledger_account.id)
+        .bind(ledger_account.ledger_id)
+        .bind(ledger_account.parent_id)
+        .bind(ledger_account.coa_id)
+        .bind(&ledger_account.balance_side)
+        .bind(&ledger_account.category)
+        .bind(&ledger_account.code)
+        .execute(&mut *conn)
+        .await?;
+    insert_events(conn, &[NewOutboxEvent::ledger_account_created(ledger_account, record_time)]).await
+}
+
 use uuid::Uuid;
 
 #[async_trait]
@@ -26,14 +51,128 @@ impl LedgerAccountRepository for PostgresLedgerAccountRepository {
             .map_err(DbError::from)
     }
 
-    async fn save(&self, ledger_account: &LedgerAccount) -> Result<(), DbError> {
-        sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category) VALUES ($1, $2, $3, $4, $5, $6)")
-            .bind(ledger_account.id)
-            .bind(ledger_account.ledger_id)
-            .bind(ledger_account.parent_id)
-            .bind(ledger_account.coa_id)
-            .bind(&ledger_account.balance_side)
-            .bind(&ledger_account.category)
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account WHERE ledger_id = $1 ORDER BY code")
+            .bind(ledger_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_and_code(&self, ledger_id: Uuid, code: &str) -> Result<Option<LedgerAccount>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account WHERE ledger_id = $1 AND code = $2")
+            .bind(ledger_id)
+            .bind(code)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_and_code_in(&self, ledger_id: Uuid, codes: &[String]) -> Result<Vec<LedgerAccount>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account WHERE ledger_id = $1 AND code = ANY($2)")
+            .bind(ledger_id)
+            .bind(codes)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id_and_code_between(&self, ledger_id: Uuid, from: &str, to: &str) -> Result<Vec<LedgerAccount>, DbError> {
+        sqlx::query_as(r#"SELECT * FROM ledger_account WHERE ledger_id = $1 AND LENGTH(code) >= LENGTH($2) AND LEFT(code, LENGTH($2)) COLLATE "C" >= $2 AND LENGTH(code) >= LENGTH($3) AND LEFT(code, LENGTH($3)) COLLATE "C" <= $3 ORDER BY code COLLATE "C""#)
+            .bind(ledger_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_parent_id(&self, parent_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account WHERE parent_id = $1")
+            .bind(parent_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn save(&self, ledger_account: &LedgerAccount, record_time: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_ledger_account(&mut tx, ledger_account, record_time).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_with_named(&self, ledger_account: &LedgerAccount, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_ledger_account(&mut tx, ledger_account, record_time).await?;
+        for n in named {
+            insert_named(&mut tx, n).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_state_change(&self, change: &AccountStateChange) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        let ledger_id: Uuid = sqlx::query_scalar("SELECT ledger_id FROM ledger_account WHERE id = $1")
+            .bind(change.account_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        // The event locks the ledger's outbox sequence row, which saving postings takes too.
+        insert_events(&mut tx, &[NewOutboxEvent::account_state_changed(ledger_id, change)]).await?;
+        if change.state == AccountState::Closed {
+            let sql = format!("SELECT COALESCE(SUM(debit_amount - credit_amount), 0) FROM posting_line WHERE account_id = $1 AND pst_status IN {}", PostingStatus::EFFECTIVE_SQL);
+            let balance: BigDecimal = sqlx::query_scalar(&sql)
+                .bind(change.account_id)
+                .fetch_one(&mut *tx)
+                .await?;
+            if !balance.is_zero() {
+                return Err(DbError::BalanceNotZero { account_id: change.account_id });
+            }
+        }
+        sqlx::query("INSERT INTO ledger_account_state (id, account_id, state, effective_time, record_user, record_time, reason) VALUES ($1, $2, $3, $4, $5, $6, $7)")
+            .bind(change.id)
+            .bind(change.account_id)
+            .bind(&change.state)
+            .bind(change.effective_time)
+            .bind(change.record_user)
+            .bind(change.record_time)
+            .bind(&change.reason)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_state_changes_by_account_id(&self, account_id: Uuid) -> Result<Vec<AccountStateChange>, DbError> {
+        sqlx::query_as("SELECT * FROM ledger_account_state WHERE account_id = $1 ORDER BY effective_time, record_time")
+            .bind(account_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn save_limit(&self, limit: &AccountLimit) -> Result<(), DbError> {
+        sqlx::query("INSERT INTO account_limit (account_id, min_balance, max_balance) VALUES ($1, $2, $3) ON CONFLICT (account_id) DO UPDATE SET min_balance = EXCLUDED.min_balance, max_balance = EXCLUDED.max_balance")
+            .bind(limit.account_id)
+            .bind(&limit.min_balance)
+            .bind(&limit.max_balance)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_limit_by_account_id(&self, account_id: Uuid) -> Result<Option<AccountLimit>, DbError> {
+        sqlx::query_as("SELECT * FROM account_limit WHERE account_id = $1")
+            .bind(account_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn delete_limit(&self, account_id: Uuid) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM account_limit WHERE account_id = $1")
+            .bind(account_id)
             .execute(&self.pool)
             .await?;
         Ok(())
@@ -1,8 +1,16 @@
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use postings_db::repositories::ledger_repository::LedgerRepository;
 use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::models::outbox_event::NewOutboxEvent;
+use crate::repositories::ledger_account_repository::insert_ledger_account;
+use crate::repositories::named_repository::insert_named;
+use crate::repositories::outbox_repository::insert_events;
 use postings_db::DbError;
+use sqlx::PgConnection;
 
 pub struct PostgresLedgerRepository {
     pool: PgPool,
@@ -16,6 +24,16 @@ impl PostgresLedgerRepository {
 
 use uuid::Uuid;
 
+/// Inserts the ledger and its `LedgerCreated` event on an open connection.
+async fn insert_ledger(conn: &mut PgConnection, ledger: &Ledger, record_time: DateTime<Utc>) -> Result<(), DbError> {
+    sqlx::query("INSERT INTO ledger (id, coa_id) VALUES ($1, $2)")
+        .bind(ledger.id)
+        .bind(ledger.coa_id)
+        .execute(&mut *conn)
+        .await?;
+    insert_events(conn, &[NewOutboxEvent::ledger_created(ledger, record_time)]).await
+}
+
 #[async_trait]
 impl LedgerRepository for PostgresLedgerRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Ledger>, DbError> {
@@ -26,12 +44,33 @@ impl LedgerRepository for PostgresLedgerRepository {
             .map_err(DbError::from)
     }
 
-    async fn save(&self, ledger: &Ledger) -> Result<(), DbError> {
-        sqlx::query("INSERT INTO ledger (id, coa_id) VALUES ($1, $2)")
-            .bind(ledger.id)
-            .bind(ledger.coa_id)
-            .execute(&self.pool)
-            .await?;
+    async fn save(&self, ledger: &Ledger, record_time: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_ledger(&mut tx, ledger, record_time).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_with_named(&self, ledger: &Ledger, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_ledger(&mut tx, ledger, record_time).await?;
+        for n in named {
+            insert_named(&mut tx, n).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_with_accounts(&self, ledger: &Ledger, accounts: &[LedgerAccount], named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        insert_ledger(&mut tx, ledger, record_time).await?;
+        for account in accounts {
+            insert_ledger_account(&mut tx, account, record_time).await?;
+        }
+        for n in named {
+            insert_named(&mut tx, n).await?;
+        }
+        tx.commit().await?;
         Ok(())
     }
 }
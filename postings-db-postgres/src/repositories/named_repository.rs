@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use sqlx::{PgPool, query_as};
+use sqlx::{PgConnection, PgPool, query_as};
 use uuid::Uuid;
 use postings_db::models::named::{Named, ContainerType};
 use postings_db::repositories::named_repository::NamedRepository;
@@ -15,8 +15,35 @@ impl PostgresNamedRepository {
     }
 }
 
+/// Inserts a name on an open connection so it can join the transaction of the entity it names.
+pub(crate) async fn insert_named(conn: &mut PgConnection, named: &Named) -> Result<(), DbError> {
+    sqlx::query("INSERT INTO named (id, container, context, name, language, created, user_details, short_desc, long_desc, container_type, retired) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)")
+        .bind(named.id)
+        .bind(named.container)
+        .bind(named.context)
+        .bind(&named.name)
+        .bind(&named.language)
+        .bind(named.created)
+        .bind(named.user_details)
+        .bind(&named.short_desc)
+        .bind(&named.long_desc)
+        .bind(&named.container_type)
+        .bind(named.retired)
+        .execute(conn)
+        .await?;
+    Ok(())
+}
+
 #[async_trait]
 impl NamedRepository for PostgresNamedRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Named>, DbError> {
+        query_as::<_, Named>("SELECT * FROM named WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| e.into())
+    }
+
     async fn find_by_container(&self, container_id: Uuid) -> Result<Vec<Named>, DbError> {
         query_as::<_, Named>("SELECT * FROM named WHERE container = $1")
             .bind(container_id)
@@ -26,7 +53,7 @@ impl NamedRepository for PostgresNamedRepository {
     }
 
     async fn find_by_name_and_type(&self, name: &str, container_type: ContainerType) -> Result<Vec<Named>, DbError> {
-        query_as::<_, Named>("SELECT * FROM named WHERE name = $1 AND container_type = $2")
+        query_as::<_, Named>("SELECT * FROM named WHERE name = $1 AND container_type = $2 AND retired IS NULL")
             .bind(name)
             .bind(container_type)
             .fetch_all(&self.pool)
@@ -35,7 +62,7 @@ impl NamedRepository for PostgresNamedRepository {
     }
 
     async fn find_by_name_and_type_and_context(&self, name: &str, container_type: ContainerType, context: Uuid) -> Result<Vec<Named>, DbError> {
-        query_as::<_, Named>("SELECT * FROM named WHERE name = $1 AND container_type = $2 AND context = $3")
+        query_as::<_, Named>("SELECT * FROM named WHERE name = $1 AND container_type = $2 AND context = $3 AND retired IS NULL")
             .bind(name)
             .bind(container_type)
             .bind(context)
@@ -46,10 +73,10 @@ impl NamedRepository for PostgresNamedRepository {
 
     async fn save(&self, named: Named) -> Result<Named, DbError> {
         query_as::<_, Named>(
-            "INSERT INTO named (id, container, context, name, language, created, user_details, short_desc, long_desc, container_type)
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "INSERT INTO named (id, container, context, name, language, created, user_details, short_desc, long_desc, container_type, retired)
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
              ON CONFLICT (id) DO UPDATE SET
-                container = $2, context = $3, name = $4, language = $5, created = $6, user_details = $7, short_desc = $8, long_desc = $9, container_type = $10
+                container = $2, context = $3, name = $4, language = $5, created = $6, user_details = $7, short_desc = $8, long_desc = $9, container_type = $10, retired = $11
              RETURNING *")
             .bind(named.id)
             .bind(named.container)
@@ -61,6 +88,7 @@ impl NamedRepository for PostgresNamedRepository {
             .bind(named.short_desc)
             .bind(named.long_desc)
             .bind(named.container_type)
+            .bind(named.retired)
             .fetch_one(&self.pool)
             .await
             .map_err(|e| e.into())
@@ -0,0 +1,107 @@
+use std::collections::BTreeMap;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
+use postings_db::models::outbox_event::{NewOutboxEvent, OutboxEvent};
+use postings_db::repositories::outbox_repository::OutboxRepository;
+use postings_db::DbError;
+use uuid::Uuid;
+
+/// Rows per multi-row INSERT, keeping the bind count well below the 65535 parameter limit.
+const BATCH_CHUNK_SIZE: usize = 1000;
+
+pub struct PostgresOutboxRepository {
+    pool: PgPool,
+}
+
+impl PostgresOutboxRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Inserts the events on an open connection so they commit or roll back with the change they
+/// describe. Each ledger's sequence row is locked, in ledger order so that concurrent writers
+/// cannot deadlock, until the transaction ends.
+pub(crate) async fn insert_events(conn: &mut PgConnection, events: &[NewOutboxEvent]) -> Result<(), DbError> {
+    let mut counts: BTreeMap<Uuid, i64> = BTreeMap::new();
+    for event in events {
+        *counts.entry(event.ledger_id).or_default() += 1;
+    }
+    let mut next_seq: BTreeMap<Uuid, i64> = BTreeMap::new();
+    for (ledger_id, count) in counts {
+        let last_seq: i64 = sqlx::query_scalar(
+            "INSERT INTO outbox_ledger_seq (ledger_id, last_seq) VALUES ($1, $2) \
+             ON CONFLICT (ledger_id) DO UPDATE SET last_seq = outbox_ledger_seq.last_seq + EXCLUDED.last_seq \
+             RETURNING last_seq",
+        )
+        .bind(ledger_id)
+        .bind(count)
+        .fetch_one(&mut *conn)
+        .await?;
+        next_seq.insert(ledger_id, last_seq - count + 1);
+    }
+
+    let mut rows = Vec::with_capacity(events.len());
+    for event in events {
+        let seq = next_seq.get_mut(&event.ledger_id).ok_or_else(|| DbError::Decode { what: format!("outbox sequence of ledger {}", event.ledger_id) })?;
+        rows.push((event, *seq, event.payload_json()?));
+        *seq += 1;
+    }
+
+    for chunk in rows.chunks(BATCH_CHUNK_SIZE) {
+        let mut builder = QueryBuilder::<Postgres>::new("INSERT INTO outbox_event (ledger_id, ledger_seq, event_type, payload, occurred_at) ");
+        builder.push_values(chunk, |mut row, (event, seq, payload)| {
+            row.push_bind(event.ledger_id)
+                .push_bind(*seq)
+                .push_bind(event.payload.event_type())
+                .push_bind(payload)
+                .push_bind(event.occurred_at);
+        });
+        builder.build().execute(&mut *conn).await?;
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl OutboxRepository for PostgresOutboxRepository {
+    async fn find_pending(&self, limit: i64) -> Result<Vec<OutboxEvent>, DbError> {
+        sqlx::query_as("SELECT * FROM outbox_event WHERE dispatched_at IS NULL ORDER BY id LIMIT $1")
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_pending_per_ledger(&self, limit: i64) -> Result<Vec<OutboxEvent>, DbError> {
+        sqlx::query_as(
+            "SELECT id, ledger_id, ledger_seq, event_type, payload, occurred_at, dispatched_at FROM ( \
+                SELECT *, ROW_NUMBER() OVER (PARTITION BY ledger_id ORDER BY ledger_seq) AS pending_rank \
+                FROM outbox_event WHERE dispatched_at IS NULL \
+             ) pending WHERE pending_rank <= $1 ORDER BY id",
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid, after_seq: i64, limit: i64) -> Result<Vec<OutboxEvent>, DbError> {
+        sqlx::query_as("SELECT * FROM outbox_event WHERE ledger_id = $1 AND ledger_seq > $2 ORDER BY ledger_seq LIMIT $3")
+            .bind(ledger_id)
+            .bind(after_seq)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn mark_dispatched(&self, ids: &[i64], time: DateTime<Utc>) -> Result<(), DbError> {
+        sqlx::query("UPDATE outbox_event SET dispatched_at = $2 WHERE id = ANY($1) AND dispatched_at IS NULL")
+            .bind(ids)
+            .bind(time)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
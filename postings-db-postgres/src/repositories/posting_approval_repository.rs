@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use postings_db::repositories::posting_approval_repository::PostingApprovalRepository;
+use postings_db::models::approval_policy::ApprovalPolicy;
+use postings_db::models::posting_approval::PostingApproval;
+use postings_db::DbError;
+use uuid::Uuid;
+
+pub struct PostgresPostingApprovalRepository {
+    pool: PgPool,
+}
+
+impl PostgresPostingApprovalRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl PostingApprovalRepository for PostgresPostingApprovalRepository {
+    async fn save_policy(&self, policy: &ApprovalPolicy) -> Result<(), DbError> {
+        sqlx::query("INSERT INTO approval_policy (ledger_id, amount_threshold) VALUES ($1, $2) ON CONFLICT (ledger_id) DO UPDATE SET amount_threshold = EXCLUDED.amount_threshold")
+            .bind(policy.ledger_id)
+            .bind(&policy.amount_threshold)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_policy_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<ApprovalPolicy>, DbError> {
+        sqlx::query_as("SELECT * FROM approval_policy WHERE ledger_id = $1")
+            .bind(ledger_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn delete_policy_by_ledger_id(&self, ledger_id: Uuid) -> Result<(), DbError> {
+        sqlx::query("DELETE FROM approval_policy WHERE ledger_id = $1")
+            .bind(ledger_id)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn save(&self, approval: &PostingApproval) -> Result<(), DbError> {
+        sqlx::query("INSERT INTO posting_approval (id, posting_id, approver, approval_time) VALUES ($1, $2, $3, $4)")
+            .bind(approval.id)
+            .bind(approval.posting_id)
+            .bind(approval.approver)
+            .bind(approval.approval_time)
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+
+    async fn find_by_posting_id(&self, posting_id: Uuid) -> Result<Vec<PostingApproval>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_approval WHERE posting_id = $1 ORDER BY approval_time ASC")
+            .bind(posting_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+}
@@ -1,7 +1,8 @@
 use async_trait::async_trait;
 use sqlx::PgPool;
 use postings_db::repositories::posting_line_repository::PostingLineRepository;
-use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_line::{PostingLine, SequencedPostingLine};
+use postings_db::models::time_axis::TimeAxis;
 use postings_db::DbError;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
@@ -19,8 +20,9 @@ impl PostgresPostingLineRepository {
 #[async_trait]
 impl PostingLineRepository for PostgresPostingLineRepository {
     async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError> {
-        sqlx::query_as("INSERT INTO posting_line (id, account_id, debit_amount, credit_amount, details, src_account, base_line, sub_opr_src_id, record_time, opr_id, opr_src, pst_time, pst_type, pst_status, hash, discarded_time) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16) RETURNING *")
+        sqlx::query_as("INSERT INTO posting_line (id, posting_id, account_id, debit_amount, credit_amount, details, src_account, base_line, sub_opr_src_id, record_time, opr_id, opr_src, pst_time, pst_type, pst_status, hash, discarded_time, val_time) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18) RETURNING *")
             .bind(posting_line.id)
+            .bind(posting_line.posting_id)
             .bind(posting_line.account_id)
             .bind(posting_line.debit_amount)
             .bind(posting_line.credit_amount)
@@ -36,6 +38,7 @@ impl PostingLineRepository for PostgresPostingLineRepository {
             .bind(posting_line.pst_status)
             .bind(posting_line.hash)
             .bind(posting_line.discarded_time)
+            .bind(posting_line.val_time)
             .fetch_one(&self.pool)
             .await
             .map_err(DbError::from)
@@ -49,6 +52,22 @@ impl PostingLineRepository for PostgresPostingLineRepository {
             .map_err(DbError::from)
     }
 
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_line WHERE opr_id = $1")
+            .bind(opr_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_posting_id(&self, posting_id: Uuid) -> Result<Vec<PostingLine>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_line WHERE posting_id = $1")
+            .bind(posting_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
     async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
         sqlx::query_as("SELECT * FROM posting_line WHERE account_id = $1 AND pst_time > $2 AND pst_time <= $3 AND discarded_time IS NULL ORDER BY pst_time DESC")
             .bind(account_id)
@@ -85,4 +104,43 @@ impl PostingLineRepository for PostgresPostingLineRepository {
             .await
             .map_err(DbError::from)
     }
+
+    async fn find_by_account_and_time_between(&self, account_id: Uuid, axis: TimeAxis, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let sql = format!("SELECT * FROM posting_line WHERE account_id = $1 AND {0} > $2 AND {0} <= $3 AND discarded_time IS NULL ORDER BY {0} DESC", axis.column());
+        sqlx::query_as(&sql)
+            .bind(account_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_account_and_time_less_than_equal(&self, account_id: Uuid, axis: TimeAxis, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError> {
+        let sql = format!("SELECT * FROM posting_line WHERE account_id = $1 AND {0} <= $2 AND discarded_time IS NULL ORDER BY {0} DESC", axis.column());
+        sqlx::query_as(&sql)
+            .bind(account_id)
+            .bind(ref_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_account_after_seq(&self, account_id: Uuid, after_seq: i64, limit: i64) -> Result<Vec<SequencedPostingLine>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_line WHERE account_id = $1 AND line_seq > $2 ORDER BY line_seq LIMIT $3")
+            .bind(account_id)
+            .bind(after_seq)
+            .bind(limit)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_last_seq_by_account(&self, account_id: Uuid) -> Result<i64, DbError> {
+        sqlx::query_scalar("SELECT COALESCE(MAX(line_seq), 0) FROM posting_line WHERE account_id = $1")
+            .bind(account_id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
 }
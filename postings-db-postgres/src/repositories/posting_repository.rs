@@ -1,9 +1,24 @@
 use async_trait::async_trait;
-use sqlx::PgPool;
+use bigdecimal::BigDecimal;
+use sqlx::{PgConnection, PgPool, Postgres, QueryBuilder};
 use postings_db::repositories::posting_repository::PostingRepository;
+use postings_db::models::account_limit::{AccountLimit, BalanceCheck};
+use postings_db::models::balance_side::BalanceSide;
+use postings_db::models::interest_accrual::AccrualPeriod;
+use postings_db::models::outbox_event::NewOutboxEvent;
 use postings_db::models::posting::Posting;
+use postings_db::models::posting_approval::PostingApproval;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_status::PostingStatus;
 use postings_db::DbError;
+use std::collections::BTreeSet;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
+use crate::line_feed::notify_lines_appended;
+use crate::repositories::outbox_repository::insert_events;
+
+/// Rows per multi-row INSERT, keeping the bind count well below the 65535 parameter limit.
+const BATCH_CHUNK_SIZE: usize = 1000;
 
 pub struct PostgresPostingRepository {
     pool: PgPool,
@@ -15,6 +30,124 @@ impl PostgresPostingRepository {
     }
 }
 
+
+/// Locks the limits of the checked accounts, in account order so that concurrent batches cannot
+/// deadlock, and fails if a check takes an account's current balance, less its active holds,
+/// past its limit as of the check's time.
+pub(crate) async fn check_limits(conn: &mut PgConnection, checks: &[BalanceCheck]) -> Result<(), DbError> {
+    let mut checks: Vec<&BalanceCheck> = checks.iter().collect();
+    checks.sort_by_key(|c| c.account_id);
+    for check in checks {
+        let limit: Option<AccountLimit> = sqlx::query_as("SELECT * FROM account_limit WHERE account_id = $1 FOR UPDATE")
+            .bind(check.account_id)
+            .fetch_optional(&mut *conn)
+            .await?;
+        let Some(limit) = limit else {
+            continue;
+        };
+        let balance_side: BalanceSide = sqlx::query_scalar("SELECT balance_side FROM ledger_account WHERE id = $1")
+            .bind(check.account_id)
+            .fetch_one(&mut *conn)
+            .await?;
+        let sql = format!("SELECT COALESCE(SUM(debit_amount - credit_amount), 0) FROM posting_line WHERE account_id = $1 AND pst_status IN {}", PostingStatus::EFFECTIVE_SQL);
+        let balance: BigDecimal = sqlx::query_scalar(&sql)
+            .bind(check.account_id)
+            .fetch_one(&mut *conn)
+            .await?;
+        let held: BigDecimal = sqlx::query_scalar("SELECT COALESCE(SUM(amount), 0) FROM account_hold WHERE account_id = $1 AND status = 'ACTIVE' AND expiry > $2")
+            .bind(check.account_id)
+            .bind(check.at)
+            .fetch_one(&mut *conn)
+            .await?;
+        if let Some(shortfall) = limit.shortfall(&balance_side, &balance, &held, &check.delta) {
+            return Err(DbError::LimitExceeded { account_id: check.account_id, shortfall });
+        }
+    }
+    Ok(())
+}
+
+/// Fails if a line books into an account closed by the line's record time. Runs once the ledgers'
+/// outbox sequence rows are locked, which a closing locks too, so it sees every earlier closing.
+pub(crate) async fn check_open(conn: &mut PgConnection, lines: &[PostingLine]) -> Result<(), DbError> {
+    let account_ids: Vec<Uuid> = lines.iter().map(|l| l.account_id).collect::<BTreeSet<_>>().into_iter().collect();
+    if account_ids.is_empty() {
+        return Ok(());
+    }
+    let closings: Vec<(Uuid, DateTime<Utc>)> = sqlx::query_as("SELECT account_id, MIN(effective_time) FROM ledger_account_state WHERE state = 'CLOSED' AND account_id = ANY($1) GROUP BY account_id")
+        .bind(&account_ids)
+        .fetch_all(&mut *conn)
+        .await?;
+    for (account_id, closed) in closings {
+        if lines.iter().any(|l| l.account_id == account_id && l.record_time >= closed) {
+            return Err(DbError::AccountClosed { account_id });
+        }
+    }
+    Ok(())
+}
+
+/// Inserts the postings and their lines with multi-row inserts on an open connection so they can
+/// join a surrounding transaction, along with a `PostingCreated` event per posting.
+pub(crate) async fn insert_postings(conn: &mut PgConnection, postings: &[Posting], lines: &[PostingLine]) -> Result<(), DbError> {
+    // The events lock the ledgers' outbox sequence rows, which keeps the lines' sequence numbers,
+    // assigned below, in commit order within each account.
+    let events: Vec<_> = postings.iter().map(NewOutboxEvent::posting_created).collect();
+    insert_events(conn, &events).await?;
+    check_open(conn, lines).await?;
+
+    for chunk in postings.chunks(BATCH_CHUNK_SIZE) {
+        let mut builder = QueryBuilder::<Postgres>::new("INSERT INTO posting (id, record_user, record_time, opr_id, opr_time, opr_type, opr_details, opr_src, pst_time, pst_type, pst_status, ledger_id, val_time, discarded_id, discarded_time, discarding_id, antecedent_id, antecedent_hash, hash) ");
+        builder.push_values(chunk, |mut row, posting| {
+            row.push_bind(posting.id)
+                .push_bind(posting.record_user)
+                .push_bind(posting.record_time)
+                .push_bind(posting.opr_id)
+                .push_bind(posting.opr_time)
+                .push_bind(posting.opr_type)
+                .push_bind(posting.opr_details)
+                .push_bind(posting.opr_src)
+                .push_bind(posting.pst_time)
+                .push_bind(&posting.pst_type)
+                .push_bind(&posting.pst_status)
+                .push_bind(posting.ledger_id)
+                .push_bind(posting.val_time)
+                .push_bind(posting.discarded_id)
+                .push_bind(posting.discarded_time)
+                .push_bind(posting.discarding_id)
+                .push_bind(posting.antecedent_id)
+                .push_bind(posting.antecedent_hash)
+                .push_bind(posting.hash);
+        });
+        builder.build().execute(&mut *conn).await?;
+    }
+
+    for chunk in lines.chunks(BATCH_CHUNK_SIZE) {
+        let mut builder = QueryBuilder::<Postgres>::new("INSERT INTO posting_line (id, posting_id, account_id, debit_amount, credit_amount, details, src_account, base_line, sub_opr_src_id, record_time, opr_id, opr_src, pst_time, pst_type, pst_status, hash, discarded_time, val_time) ");
+        builder.push_values(chunk, |mut row, line| {
+            row.push_bind(line.id)
+                .push_bind(line.posting_id)
+                .push_bind(line.account_id)
+                .push_bind(&line.debit_amount)
+                .push_bind(&line.credit_amount)
+                .push_bind(line.details)
+                .push_bind(line.src_account)
+                .push_bind(line.base_line)
+                .push_bind(line.sub_opr_src_id)
+                .push_bind(line.record_time)
+                .push_bind(line.opr_id)
+                .push_bind(line.opr_src)
+                .push_bind(line.pst_time)
+                .push_bind(&line.pst_type)
+                .push_bind(&line.pst_status)
+                .push_bind(line.hash)
+                .push_bind(line.discarded_time)
+                .push_bind(line.val_time);
+        });
+        builder.build().execute(&mut *conn).await?;
+    }
+
+    notify_lines_appended(conn, lines).await
+}
+
 #[async_trait]
 impl PostingRepository for PostgresPostingRepository {
     async fn find_by_opr_id_and_discarding_id_is_null(&self, opr_id: &[u8]) -> Result<Option<Posting>, DbError> {
@@ -41,29 +174,97 @@ impl PostingRepository for PostgresPostingRepository {
             .map_err(DbError::from)
     }
 
+    async fn find_first_by_ledger_and_hash_is_not_null_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError> {
+        sqlx::query_as("SELECT * FROM posting WHERE ledger_id = $1 AND hash IS NOT NULL ORDER BY record_time DESC LIMIT 1")
+            .bind(ledger_id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_and_pst_status(&self, ledger_id: Uuid, pst_status: PostingStatus) -> Result<Vec<Posting>, DbError> {
+        sqlx::query_as("SELECT * FROM posting WHERE ledger_id = $1 AND pst_status = $2 ORDER BY record_time ASC")
+            .bind(ledger_id)
+            .bind(pst_status)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_pst_status_and_val_time_less_than_equal(&self, pst_status: PostingStatus, ref_time: DateTime<Utc>) -> Result<Vec<Posting>, DbError> {
+        sqlx::query_as("SELECT * FROM posting WHERE pst_status = $1 AND val_time <= $2 ORDER BY val_time ASC")
+            .bind(pst_status)
+            .bind(ref_time)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_and_pst_time_between(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<Posting>, DbError> {
+        sqlx::query_as("SELECT * FROM posting WHERE ledger_id = $1 AND pst_time > $2 AND pst_time <= $3 ORDER BY pst_time ASC, record_time ASC")
+            .bind(ledger_id)
+            .bind(from)
+            .bind(to)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_and_hash_is_not_null_order_by_record_time(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError> {
+        sqlx::query_as("SELECT * FROM posting WHERE ledger_id = $1 AND hash IS NOT NULL ORDER BY record_time ASC")
+            .bind(ledger_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
     async fn save(&self, posting: &Posting) -> Result<(), DbError> {
-        sqlx::query("INSERT INTO posting (id, record_user, record_time, opr_id, opr_time, opr_type, opr_details, opr_src, pst_time, pst_type, pst_status, ledger_id, val_time, discarded_id, discarded_time, discarding_id, antecedent_id, antecedent_hash, hash) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)")
-            .bind(posting.id)
-            .bind(posting.record_user)
-            .bind(posting.record_time)
-            .bind(posting.opr_id)
-            .bind(posting.opr_time)
-            .bind(posting.opr_type)
-            .bind(posting.opr_details)
-            .bind(posting.opr_src)
-            .bind(posting.pst_time)
-            .bind(&posting.pst_type)
-            .bind(&posting.pst_status)
-            .bind(posting.ledger_id)
-            .bind(posting.val_time)
-            .bind(posting.discarded_id)
-            .bind(posting.discarded_time)
-            .bind(posting.discarding_id)
-            .bind(posting.antecedent_id)
-            .bind(posting.antecedent_hash)
-            .bind(posting.hash)
-            .execute(&self.pool)
+        let mut tx = self.pool.begin().await?;
+        insert_postings(&mut tx, std::slice::from_ref(posting), &[]).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_batch(&self, postings: &[Posting], lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        check_limits(&mut tx, checks).await?;
+        insert_postings(&mut tx, postings, lines).await?;
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn save_accrual(&self, period: &AccrualPeriod, posting: &Posting, lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        // The account row lock serializes the accruals of the account. NO KEY leaves postings to
+        // the account, whose foreign keys only share-lock the key, free to go on.
+        sqlx::query("SELECT id FROM ledger_account WHERE id = $1 FOR NO KEY UPDATE")
+            .bind(period.account_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        let overlaps: bool = sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM interest_accrual WHERE account_id = $1 AND period_from < $3 AND period_to > $2 AND opr_id <> $4)")
+            .bind(period.account_id)
+            .bind(period.period_from)
+            .bind(period.period_to)
+            .bind(period.opr_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        if overlaps {
+            return Err(DbError::PeriodOverlap { account_id: period.account_id });
+        }
+
+        check_limits(&mut tx, checks).await?;
+        insert_postings(&mut tx, std::slice::from_ref(posting), lines).await?;
+        sqlx::query("INSERT INTO interest_accrual (opr_id, account_id, period_from, period_to, posting_id) VALUES ($1, $2, $3, $4, $5)")
+            .bind(period.opr_id)
+            .bind(period.account_id)
+            .bind(period.period_from)
+            .bind(period.period_to)
+            .bind(period.posting_id)
+            .execute(&mut *tx)
             .await?;
+
+        tx.commit().await?;
         Ok(())
     }
 
@@ -74,4 +275,56 @@ impl PostingRepository for PostgresPostingRepository {
             .await
             .map_err(DbError::from)
     }
+
+    async fn update_status(&self, posting: &Posting, from: PostingStatus, lines: &[PostingLine], approval: Option<&PostingApproval>, checks: &[BalanceCheck]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        // Limits first, then the outbox sequence, in the order saving postings locks them.
+        if posting.pst_status.is_effective() {
+            check_limits(&mut tx, checks).await?;
+        }
+        insert_events(&mut tx, &[NewOutboxEvent::posting_status_changed(posting)]).await?;
+        if posting.pst_status.is_effective() {
+            check_open(&mut tx, lines).await?;
+        }
+
+        let result = sqlx::query("UPDATE posting SET pst_status = $2, record_time = $3, antecedent_id = $4, antecedent_hash = $5, hash = $6 WHERE id = $1 AND pst_status = $7")
+            .bind(posting.id)
+            .bind(&posting.pst_status)
+            .bind(posting.record_time)
+            .bind(posting.antecedent_id)
+            .bind(posting.antecedent_hash)
+            .bind(posting.hash)
+            .bind(from)
+            .execute(&mut *tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::StatusChanged { id: posting.id });
+        }
+
+        // A new sequence number, taken under the ledger's outbox lock like on insert, lets change
+        // feeds read the lines again with their new status.
+        for line in lines {
+            sqlx::query("UPDATE posting_line SET pst_status = $2, record_time = $3, hash = $4, line_seq = nextval(pg_get_serial_sequence('posting_line', 'line_seq')) WHERE id = $1")
+                .bind(line.id)
+                .bind(&line.pst_status)
+                .bind(line.record_time)
+                .bind(line.hash)
+                .execute(&mut *tx)
+                .await?;
+        }
+        notify_lines_appended(&mut tx, lines).await?;
+
+        if let Some(approval) = approval {
+            sqlx::query("INSERT INTO posting_approval (id, posting_id, approver, approval_time) VALUES ($1, $2, $3, $4)")
+                .bind(approval.id)
+                .bind(approval.posting_id)
+                .bind(approval.approver)
+                .bind(approval.approval_time)
+                .execute(&mut *tx)
+                .await?;
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
 }
@@ -0,0 +1,145 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use postings_db::repositories::schedule_repository::ScheduleRepository;
+use postings_db::models::account_limit::BalanceCheck;
+use postings_db::models::posting::Posting;
+use postings_db::models::posting_line::PostingLine;
+use postings_db::models::posting_schedule::{PostingSchedule, ScheduleRun, ScheduleStatus, ScheduledLine};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::repositories::posting_repository::{check_limits, insert_postings};
+
+pub struct PostgresScheduleRepository {
+    pool: PgPool,
+}
+
+impl PostgresScheduleRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ScheduleRepository for PostgresScheduleRepository {
+    async fn save(&self, schedule: &PostingSchedule, lines: &[ScheduledLine]) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("INSERT INTO posting_schedule (id, ledger_id, rrule, start_time, catch_up, record_user, opr_type, pst_type, status, next_index, next_time, record_time) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)")
+            .bind(schedule.id)
+            .bind(schedule.ledger_id)
+            .bind(&schedule.rrule)
+            .bind(schedule.start_time)
+            .bind(&schedule.catch_up)
+            .bind(schedule.record_user)
+            .bind(schedule.opr_type)
+            .bind(&schedule.pst_type)
+            .bind(&schedule.status)
+            .bind(schedule.next_index)
+            .bind(schedule.next_time)
+            .bind(schedule.record_time)
+            .execute(&mut *tx)
+            .await?;
+        for line in lines {
+            sqlx::query("INSERT INTO posting_schedule_line (id, schedule_id, account_id, debit_amount, credit_amount) VALUES ($1, $2, $3, $4, $5)")
+                .bind(line.id)
+                .bind(line.schedule_id)
+                .bind(line.account_id)
+                .bind(&line.debit_amount)
+                .bind(&line.credit_amount)
+                .execute(&mut *tx)
+                .await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingSchedule>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_schedule WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<PostingSchedule>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_schedule WHERE ledger_id = $1 ORDER BY record_time")
+            .bind(ledger_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_lines_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<ScheduledLine>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_schedule_line WHERE schedule_id = $1")
+            .bind(schedule_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_due(&self, now: DateTime<Utc>) -> Result<Vec<PostingSchedule>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_schedule WHERE status = $1 AND next_time <= $2 ORDER BY next_time")
+            .bind(ScheduleStatus::Active)
+            .bind(now)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn find_runs_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<ScheduleRun>, DbError> {
+        sqlx::query_as("SELECT * FROM posting_schedule_run WHERE schedule_id = $1 ORDER BY occurrence_index")
+            .bind(schedule_id)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(DbError::from)
+    }
+
+    async fn cancel(&self, id: Uuid) -> Result<(), DbError> {
+        let result = sqlx::query("UPDATE posting_schedule SET status = $2 WHERE id = $1 AND status = $3")
+            .bind(id)
+            .bind(ScheduleStatus::Cancelled)
+            .bind(ScheduleStatus::Active)
+            .execute(&self.pool)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        Ok(())
+    }
+
+    async fn save_run(
+        &self,
+        schedule: &PostingSchedule,
+        expected_index: i32,
+        run: &ScheduleRun,
+        posting: &Posting,
+        lines: &[PostingLine],
+        checks: &[BalanceCheck],
+    ) -> Result<(), DbError> {
+        let mut tx = self.pool.begin().await?;
+        let result = sqlx::query("UPDATE posting_schedule SET status = $2, next_index = $3, next_time = $4 WHERE id = $1 AND status = $5 AND next_index = $6")
+            .bind(schedule.id)
+            .bind(&schedule.status)
+            .bind(schedule.next_index)
+            .bind(schedule.next_time)
+            .bind(ScheduleStatus::Active)
+            .bind(expected_index)
+            .execute(&mut *tx)
+            .await?;
+        if result.rows_affected() == 0 {
+            return Err(DbError::NotFound);
+        }
+        check_limits(&mut tx, checks).await?;
+        insert_postings(&mut tx, std::slice::from_ref(posting), lines).await?;
+        sqlx::query("INSERT INTO posting_schedule_run (schedule_id, occurrence_index, occurrence_time, posting_id, run_time) VALUES ($1, $2, $3, $4, $5)")
+            .bind(run.schedule_id)
+            .bind(run.occurrence_index)
+            .bind(run.occurrence_time)
+            .bind(run.posting_id)
+            .bind(run.run_time)
+            .execute(&mut *tx)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+use std::fmt;
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::DbError;
+
+/// A change to master data that caches of other instances must drop. Events are sent after the
+/// change is committed and name the changed entity only, never its new state.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum InvalidationEvent {
+    ChartOfAccount(Uuid),
+    Ledger(Uuid),
+    LedgerAccount(Uuid),
+    /// The state changes of the account with this id.
+    AccountState(Uuid),
+    Named { id: Uuid, container: Uuid },
+    /// Events may have been missed, so everything cached is suspect.
+    All,
+}
+
+#[derive(thiserror::Error, Debug, PartialEq, Eq)]
+#[error("Invalid invalidation event: {0}")]
+pub struct InvalidationEventParseError(String);
+
+/// The wire form, e.g. `ledger:<uuid>` or `named:<id>:<container>`.
+impl fmt::Display for InvalidationEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvalidationEvent::ChartOfAccount(id) => write!(f, "coa:{}", id),
+            InvalidationEvent::Ledger(id) => write!(f, "ledger:{}", id),
+            InvalidationEvent::LedgerAccount(id) => write!(f, "ledger_account:{}", id),
+            InvalidationEvent::AccountState(id) => write!(f, "account_state:{}", id),
+            InvalidationEvent::Named { id, container } => write!(f, "named:{}:{}", id, container),
+            InvalidationEvent::All => write!(f, "all"),
+        }
+    }
+}
+
+impl FromStr for InvalidationEvent {
+    type Err = InvalidationEventParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || InvalidationEventParseError(s.to_string());
+        let mut parts = s.split(':');
+        let kind = parts.next().ok_or_else(error)?;
+        let mut id = || -> Result<Uuid, Self::Err> { parts.next().and_then(|p| Uuid::parse_str(p).ok()).ok_or_else(error) };
+        let event = match kind {
+            "coa" => InvalidationEvent::ChartOfAccount(id()?),
+            "ledger" => InvalidationEvent::Ledger(id()?),
+            "ledger_account" => InvalidationEvent::LedgerAccount(id()?),
+            "account_state" => InvalidationEvent::AccountState(id()?),
+            "named" => InvalidationEvent::Named { id: id()?, container: id()? },
+            "all" => InvalidationEvent::All,
+            _ => return Err(error()),
+        };
+        match parts.next() {
+            Some(_) => Err(error()),
+            None => Ok(event),
+        }
+    }
+}
+
+/// Carries invalidation events between the instances sharing a database. Every subscriber gets
+/// every event published after it subscribed, including its own instance's.
+#[async_trait]
+pub trait InvalidationBus {
+    async fn publish(&self, event: &InvalidationEvent) -> Result<(), DbError>;
+    async fn subscribe(&self) -> Result<Box<dyn InvalidationSubscription + Send>, DbError>;
+}
+
+#[async_trait]
+pub trait InvalidationSubscription {
+    /// Waits for the next event. Yields [`InvalidationEvent::All`] when events may have been lost,
+    /// and `None` once no further events can arrive.
+    async fn next(&mut self) -> Option<InvalidationEvent>;
+}
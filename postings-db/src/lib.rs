@@ -1,14 +1,315 @@
 pub mod repositories;
 pub mod models;
+pub mod invalidation_bus;
+pub mod line_feed;
+
+use bigdecimal::BigDecimal;
+use postings_api::{DbFailure, ServiceError};
+use sqlx::error::{DatabaseError, ErrorKind};
+use uuid::Uuid;
 
 #[derive(thiserror::Error, Debug)]
 pub enum DbError {
+    /// The database could not be reached, the pool is exhausted or the connection was lost.
     #[error("Connection error")]
-    Connection,
+    Connection { source: Option<sqlx::Error> },
+    /// The transaction was rolled back as the loser of a serialization conflict or deadlock.
+    #[error("Transaction conflicted with a concurrent one")]
+    Conflict { source: sqlx::Error },
     #[error("Query error")]
     Query,
     #[error("Not found")]
     NotFound,
+    #[error("Duplicate key violates {}", .constraint.as_deref().unwrap_or("a unique constraint"))]
+    Duplicate { constraint: Option<String> },
+    #[error("Missing reference violates {}", .constraint.as_deref().unwrap_or("a foreign key constraint"))]
+    ForeignKey { constraint: Option<String> },
+    /// A check or not null constraint rejected the row.
+    #[error("Row violates {}", .constraint.as_deref().unwrap_or("a check constraint"))]
+    Check { constraint: Option<String> },
+    #[error("Limit of account {account_id} exceeded by {shortfall}")]
+    LimitExceeded { account_id: Uuid, shortfall: BigDecimal },
+    /// The accrual period overlaps one already booked for the account.
+    #[error("Accrual period overlaps one booked for account {account_id}")]
+    PeriodOverlap { account_id: Uuid },
+    /// A line books into an account that was closed by the line's record time.
+    #[error("Account {account_id} is closed")]
+    AccountClosed { account_id: Uuid },
+    /// Closing the account was refused as its effective lines do not sum to zero.
+    #[error("Balance of account {account_id} is not zero")]
+    BalanceNotZero { account_id: Uuid },
+    /// The row no longer had the status the update expected, as a concurrent one moved it on.
+    #[error("Status of {id} changed concurrently")]
+    StatusChanged { id: Uuid },
+    /// A value could not be converted between its row and model form, e.g. an unknown name.
+    #[error("Could not decode {what}")]
+    Decode { what: String },
     #[error(transparent)]
-    Sqlx(#[from] sqlx::Error),
+    Sqlx(sqlx::Error),
+}
+
+impl DbError {
+    pub fn duplicate(constraint: &str) -> Self {
+        DbError::Duplicate { constraint: Some(constraint.to_string()) }
+    }
+
+    pub fn failure(&self) -> DbFailure {
+        match self {
+            DbError::Connection { .. } => DbFailure::Connection,
+            DbError::Conflict { .. } | DbError::StatusChanged { .. } => DbFailure::Conflict,
+            DbError::NotFound => DbFailure::NotFound,
+            DbError::Duplicate { .. } => DbFailure::UniqueViolation,
+            DbError::ForeignKey { .. } => DbFailure::ForeignKeyViolation,
+            DbError::Check { .. }
+            | DbError::LimitExceeded { .. }
+            | DbError::PeriodOverlap { .. }
+            | DbError::AccountClosed { .. }
+            | DbError::BalanceNotZero { .. } => DbFailure::CheckViolation,
+            DbError::Query | DbError::Decode { .. } | DbError::Sqlx(_) => DbFailure::Other,
+        }
+    }
+
+    /// Name of the violated constraint or unique index, if the database reported it.
+    pub fn constraint(&self) -> Option<&str> {
+        match self {
+            DbError::Duplicate { constraint } | DbError::ForeignKey { constraint } | DbError::Check { constraint } => constraint.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Whether the operation may succeed when run again, in a new transaction.
+    pub fn is_retryable(&self) -> bool {
+        self.failure().is_retryable()
+    }
+
+    /// Turns the error into a [`ServiceError::Db`]. `context` says what was being done, naming
+    /// the ids involved, e.g. `format!("loading ledger {id}")`.
+    pub fn into_service_error(self, context: impl Into<String>) -> ServiceError {
+        ServiceError::Db {
+            failure: self.failure(),
+            constraint: self.constraint().map(str::to_string),
+            context: context.into(),
+            source: Box::new(self),
+        }
+    }
+}
+
+/// Constraint named in a MySQL or MariaDB error message, which, unlike Postgres, does not
+/// report it separately. Unique keys may be prefixed with their table.
+fn constraint_from_message(message: &str) -> Option<String> {
+    let quoted = |start: &str, end: char| {
+        let from = message.find(start)? + start.len();
+        let len = message[from..].find(end)?;
+        Some(message[from..from + len].to_string())
+    };
+    if let Some(key) = quoted("for key '", '\'') {
+        return Some(key.rsplit('.').next().unwrap_or(&key).to_string());
+    }
+    quoted("CONSTRAINT `", '`').or_else(|| quoted("constraint '", '\''))
+}
+
+/// Serialization failures and deadlocks (SQLSTATE class 40) and MySQL's lock wait timeout.
+fn is_conflict(db: &dyn DatabaseError) -> bool {
+    db.code().is_some_and(|code| code.starts_with("40")) || db.message().starts_with("Lock wait timeout exceeded")
+}
+
+/// Connection exceptions (class 08) and Postgres shutting the session down (57P01 to 57P03).
+fn is_connection_loss(db: &dyn DatabaseError) -> bool {
+    db.code().is_some_and(|code| code.starts_with("08") || code.starts_with("57P0"))
+}
+
+impl From<sqlx::Error> for DbError {
+    fn from(e: sqlx::Error) -> Self {
+        match &e {
+            sqlx::Error::Database(db) => {
+                let constraint = || db.constraint().map(str::to_string).or_else(|| constraint_from_message(db.message()));
+                match db.kind() {
+                    ErrorKind::UniqueViolation => DbError::Duplicate { constraint: constraint() },
+                    ErrorKind::ForeignKeyViolation => DbError::ForeignKey { constraint: constraint() },
+                    ErrorKind::CheckViolation | ErrorKind::NotNullViolation => DbError::Check { constraint: constraint() },
+                    _ if is_conflict(db.as_ref()) => DbError::Conflict { source: e },
+                    _ if is_connection_loss(db.as_ref()) => DbError::Connection { source: Some(e) },
+                    _ => DbError::Sqlx(e),
+                }
+            }
+            sqlx::Error::Io(_)
+            | sqlx::Error::Tls(_)
+            | sqlx::Error::PoolTimedOut
+            | sqlx::Error::PoolClosed
+            | sqlx::Error::WorkerCrashed => DbError::Connection { source: Some(e) },
+            sqlx::Error::RowNotFound => DbError::NotFound,
+            _ => DbError::Sqlx(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::borrow::Cow;
+    use std::error::Error;
+    use std::fmt;
+
+    /// A database error as a driver reports it.
+    #[derive(Debug)]
+    struct FakeDatabaseError {
+        code: &'static str,
+        message: &'static str,
+        kind: ErrorKind,
+        constraint: Option<&'static str>,
+    }
+
+    impl fmt::Display for FakeDatabaseError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.message)
+        }
+    }
+
+    impl Error for FakeDatabaseError {}
+
+    impl DatabaseError for FakeDatabaseError {
+        fn message(&self) -> &str {
+            self.message
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.code))
+        }
+
+        fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn constraint(&self) -> Option<&str> {
+            self.constraint
+        }
+
+        fn kind(&self) -> ErrorKind {
+            match self.kind {
+                ErrorKind::UniqueViolation => ErrorKind::UniqueViolation,
+                ErrorKind::ForeignKeyViolation => ErrorKind::ForeignKeyViolation,
+                ErrorKind::NotNullViolation => ErrorKind::NotNullViolation,
+                ErrorKind::CheckViolation => ErrorKind::CheckViolation,
+                _ => ErrorKind::Other,
+            }
+        }
+    }
+
+    fn database_error(code: &'static str, message: &'static str, kind: ErrorKind, constraint: Option<&'static str>) -> DbError {
+        sqlx::Error::Database(Box::new(FakeDatabaseError { code, message, kind, constraint })).into()
+    }
+
+    #[test]
+    fn postgres_errors_are_classified() {
+        // Arrange
+        let cases = [
+            (database_error("23505", "duplicate key value", ErrorKind::UniqueViolation, Some("unique_ledger_account_code")), DbFailure::UniqueViolation, Some("unique_ledger_account_code")),
+            (database_error("23503", "violates foreign key", ErrorKind::ForeignKeyViolation, Some("ledger_coa_id_fkey")), DbFailure::ForeignKeyViolation, Some("ledger_coa_id_fkey")),
+            (database_error("23514", "violates check", ErrorKind::CheckViolation, Some("account_hold_amount_check")), DbFailure::CheckViolation, Some("account_hold_amount_check")),
+            (database_error("40001", "could not serialize access", ErrorKind::Other, None), DbFailure::Conflict, None),
+            (database_error("40P01", "deadlock detected", ErrorKind::Other, None), DbFailure::Conflict, None),
+            (database_error("57P01", "terminating connection", ErrorKind::Other, None), DbFailure::Connection, None),
+            (database_error("42P01", "relation does not exist", ErrorKind::Other, None), DbFailure::Other, None),
+        ];
+
+        for (error, failure, constraint) in cases {
+            // Assert
+            assert_eq!(error.failure(), failure, "{error:?}");
+            assert_eq!(error.constraint(), constraint, "{error:?}");
+            assert_eq!(error.is_retryable(), matches!(failure, DbFailure::Conflict | DbFailure::Connection));
+        }
+    }
+
+    #[test]
+    fn mysql_constraints_are_read_from_the_message() {
+        // Arrange
+        let cases = [
+            (database_error("23000", "Duplicate entry 'a-1000' for key 'unique_ledger_account_code'", ErrorKind::UniqueViolation, None), "unique_ledger_account_code"),
+            (database_error("23000", "Duplicate entry 'a-1000' for key 'ledger_account.unique_ledger_account_code'", ErrorKind::UniqueViolation, None), "unique_ledger_account_code"),
+            (
+                database_error(
+                    "23000",
+                    "Cannot add or update a child row: a foreign key constraint fails (`db`.`ledger`, CONSTRAINT `ledger_ibfk_1` FOREIGN KEY (`coa_id`) REFERENCES `chart_of_account` (`id`))",
+                    ErrorKind::ForeignKeyViolation,
+                    None,
+                ),
+                "ledger_ibfk_1",
+            ),
+            (database_error("23000", "CONSTRAINT `amount_positive` failed for `db`.`account_hold`", ErrorKind::CheckViolation, None), "amount_positive"),
+            (database_error("HY000", "Check constraint 'amount_positive' is violated.", ErrorKind::CheckViolation, None), "amount_positive"),
+        ];
+
+        for (error, constraint) in cases {
+            // Assert
+            assert_eq!(error.constraint(), Some(constraint), "{error:?}");
+        }
+    }
+
+    #[test]
+    fn mysql_deadlocks_and_lock_timeouts_are_retryable() {
+        // Act
+        let deadlock = database_error("40001", "Deadlock found when trying to get lock", ErrorKind::Other, None);
+        let timeout = database_error("HY000", "Lock wait timeout exceeded; try restarting transaction", ErrorKind::Other, None);
+
+        // Assert
+        assert!(matches!(deadlock, DbError::Conflict { .. }));
+        assert!(matches!(timeout, DbError::Conflict { .. }));
+    }
+
+    #[test]
+    fn driver_errors_are_classified() {
+        // Act
+        let pool = DbError::from(sqlx::Error::PoolTimedOut);
+        let io = DbError::from(sqlx::Error::Io(std::io::Error::from(std::io::ErrorKind::ConnectionReset)));
+        let missing = DbError::from(sqlx::Error::RowNotFound);
+        let decode = DbError::from(sqlx::Error::ColumnNotFound("id".to_string()));
+
+        // Assert
+        assert!(pool.is_retryable());
+        assert!(matches!(io, DbError::Connection { source: Some(_) }));
+        assert_eq!(missing.failure(), DbFailure::NotFound);
+        assert_eq!(decode.failure(), DbFailure::Other);
+        assert!(!decode.is_retryable());
+    }
+
+    #[test]
+    fn values_that_do_not_decode_are_not_retryable() {
+        // Act
+        let error = DbError::Decode { what: "balance side Xx".to_string() };
+
+        // Assert
+        assert_eq!(error.failure(), DbFailure::Other);
+        assert!(!error.is_retryable());
+        assert_eq!(error.to_string(), "Could not decode balance side Xx");
+    }
+
+    #[test]
+    fn service_error_keeps_failure_constraint_context_and_source() {
+        // Arrange
+        let ledger_id = Uuid::new_v4();
+
+        // Act
+        let error = DbError::duplicate("ledger_pkey").into_service_error(format!("saving ledger {ledger_id}"));
+
+        // Assert
+        match &error {
+            ServiceError::Db { failure, constraint, context, .. } => {
+                assert_eq!(*failure, DbFailure::UniqueViolation);
+                assert_eq!(constraint.as_deref(), Some("ledger_pkey"));
+                assert_eq!(context, &format!("saving ledger {ledger_id}"));
+            }
+            other => panic!("expected Db, got {other:?}"),
+        }
+        assert_eq!(error.to_string(), format!("Database error while saving ledger {ledger_id}"));
+        assert_eq!(error.source().map(ToString::to_string).as_deref(), Some("Duplicate key violates ledger_pkey"));
+        assert!(!error.is_retryable());
+    }
 }
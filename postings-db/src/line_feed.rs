@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::DbError;
+
+/// Signal that committed lines were appended to an account. It carries no lines: readers fetch
+/// them by sequence number, so a signal may be late, duplicated or merged with others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineAppended {
+    Account(Uuid),
+    /// Signals may have been missed, so any account may have new lines.
+    Unknown,
+}
+
+/// Source of [`LineAppended`] signals, for backends that can push them. Without one, change
+/// feeds find new lines by polling.
+#[async_trait]
+pub trait LineNotifier {
+    async fn listen(&self) -> Result<Box<dyn LineNotifications + Send>, DbError>;
+}
+
+#[async_trait]
+pub trait LineNotifications {
+    /// Waits for the next signal; `None` once no further signals can arrive.
+    async fn next(&mut self) -> Option<LineAppended>;
+}
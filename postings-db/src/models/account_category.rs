@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use sqlx::Type;
 
-#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[derive(Debug, Clone, Type, PartialEq, Eq, Serialize, Deserialize)]
 #[sqlx(type_name = "account_category", rename_all = "UPPERCASE")]
 pub enum AccountCategory {
     RE,
@@ -0,0 +1,47 @@
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+use crate::models::balance_side::BalanceSide;
+
+/// Balance bounds of a ledger account, expressed on its balance side.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct AccountLimit {
+    pub account_id: Uuid,
+    pub min_balance: Option<BigDecimal>,
+    pub max_balance: Option<BigDecimal>,
+}
+
+/// Net amount, debits minus credits, a batch of postings moves an account by.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceCheck {
+    pub account_id: Uuid,
+    pub delta: BigDecimal,
+    /// Service time of the write; holds expiring by then no longer count against the account.
+    pub at: DateTime<Utc>,
+}
+
+impl AccountLimit {
+    /// Amount by which moving `balance` by `delta`, both debits minus credits, ends up outside the
+    /// limit. Funds `held` on the account count against the minimum only. Moves toward the allowed
+    /// range pass even if they do not reach it.
+    pub fn shortfall(&self, balance_side: &BalanceSide, balance: &BigDecimal, held: &BigDecimal, delta: &BigDecimal) -> Option<BigDecimal> {
+        let (balance, delta) = match balance_side {
+            BalanceSide::Cr => (-balance, -delta),
+            BalanceSide::Dr | BalanceSide::DrCr => (balance.clone(), delta.clone()),
+        };
+        let new_balance = &balance + &delta;
+        if delta < BigDecimal::zero() {
+            let available = &new_balance - held;
+            if let Some(min) = self.min_balance.as_ref().filter(|min| available < **min) {
+                return Some(min - available);
+            }
+        }
+        if delta > BigDecimal::zero() {
+            if let Some(max) = self.max_balance.as_ref().filter(|max| new_balance > **max) {
+                return Some(new_balance - max);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Type, PartialEq, Eq, Serialize, Deserialize)]
+#[sqlx(type_name = "account_state", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountState {
+    Open,
+    DebitBlocked,
+    CreditBlocked,
+    Frozen,
+    Closed,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, FromRow, PartialEq, Serialize, Deserialize)]
+pub struct AccountStateChange {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub state: AccountState,
+    pub effective_time: DateTime<Utc>,
+    /// User that changed the state. It is a 34-byte multihash: a 32-byte digest behind a 2-byte prefix.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    pub record_user: [u8; 34],
+    pub record_time: DateTime<Utc>,
+    pub reason: Option<String>,
+}
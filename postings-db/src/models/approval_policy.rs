@@ -0,0 +1,9 @@
+use bigdecimal::BigDecimal;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ApprovalPolicy {
+    pub ledger_id: Uuid,
+    pub amount_threshold: Option<BigDecimal>,
+}
@@ -0,0 +1,16 @@
+use sqlx::FromRow;
+use uuid::Uuid;
+use crate::models::account_category::AccountCategory;
+use crate::models::balance_side::BalanceSide;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct CoaAccountDefinition {
+    pub id: Uuid,
+    pub coa_id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub language: String,
+    pub category: AccountCategory,
+    pub balance_side: BalanceSide,
+    pub parent_code: Option<String>,
+}
@@ -0,0 +1,29 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "hold_status", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HoldStatus {
+    Active,
+    Captured,
+    Released,
+    Expired,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct Hold {
+    pub id: Uuid,
+    pub account_id: Uuid,
+    pub amount: BigDecimal,
+    pub expiry: DateTime<Utc>,
+    /// Operation the hold was placed for. It is a 32-byte hash.
+    pub opr_id: [u8; 34],
+    pub status: HoldStatus,
+    pub record_time: DateTime<Utc>,
+    /// Time the hold was captured, released or expired.
+    pub closed_time: Option<DateTime<Utc>>,
+    pub captured_amount: Option<BigDecimal>,
+    pub posting_id: Option<Uuid>,
+}
@@ -0,0 +1,20 @@
+use chrono::NaiveDate;
+use uuid::Uuid;
+
+/// Period `[period_from, period_to)` an accrual posting covers for an account, recorded under
+/// the accrual's operation id.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AccrualPeriod {
+    pub opr_id: [u8; 34],
+    pub account_id: Uuid,
+    pub period_from: NaiveDate,
+    pub period_to: NaiveDate,
+    pub posting_id: Uuid,
+}
+
+impl AccrualPeriod {
+    /// Whether the period shares a day with `[from, to)`.
+    pub fn overlaps(&self, from: NaiveDate, to: NaiveDate) -> bool {
+        self.period_from < to && from < self.period_to
+    }
+}
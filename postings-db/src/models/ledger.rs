@@ -1,7 +1,8 @@
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use sqlx::FromRow;
 
-#[derive(Debug, Clone, FromRow, PartialEq)]
+#[derive(Debug, Clone, FromRow, PartialEq, Serialize, Deserialize)]
 pub struct Ledger {
     pub id: Uuid,
     pub coa_id: Uuid,
@@ -1,9 +1,10 @@
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use sqlx::FromRow;
 use crate::models::balance_side::BalanceSide;
 use crate::models::account_category::AccountCategory;
 
-#[derive(Debug, Clone, FromRow, PartialEq)]
+#[derive(Debug, Clone, FromRow, PartialEq, Serialize, Deserialize)]
 pub struct LedgerAccount {
     pub id: Uuid,
     pub ledger_id: Uuid,
@@ -11,4 +12,6 @@ pub struct LedgerAccount {
     pub coa_id: Uuid,
     pub balance_side: BalanceSide,
     pub category: AccountCategory,
+    /// Account code, unique within the ledger.
+    pub code: Option<String>,
 }
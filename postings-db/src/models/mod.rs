@@ -1,13 +1,23 @@
 pub mod account_category;
+pub mod account_limit;
+pub mod approval_policy;
+pub mod account_state;
 pub mod account_stmt;
 pub mod balance_side;
 pub mod chart_of_account;
+pub mod coa_account_definition;
+pub mod hold;
+pub mod interest_accrual;
 pub mod ledger;
 pub mod ledger_account;
 pub mod named;
+pub mod outbox_event;
 pub mod posting;
+pub mod posting_approval;
 pub mod posting_line;
+pub mod posting_schedule;
 pub mod posting_status;
 pub mod posting_trace;
 pub mod posting_type;
 pub mod stmt_status;
+pub mod time_axis;
@@ -1,9 +1,12 @@
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
 use chrono::{DateTime, Utc};
 use sqlx::FromRow;
 use uuid::Uuid;
 use sqlx::Type;
 
-#[derive(Debug, Clone, FromRow, PartialEq)]
+#[serde_as]
+#[derive(Debug, Clone, FromRow, PartialEq, Serialize, Deserialize)]
 pub struct Named {
     pub id: Uuid,
     pub container: Uuid,
@@ -11,13 +14,15 @@ pub struct Named {
     pub name: String,
     pub language: String,
     pub created: DateTime<Utc>,
+    #[serde_as(as = "serde_with::hex::Hex")]
     pub user_details: [u8; 34],
     pub short_desc: Option<String>,
     pub long_desc: Option<String>,
     pub container_type: ContainerType,
+    pub retired: Option<DateTime<Utc>>,
 }
 
-#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[derive(Debug, Clone, Type, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[sqlx(type_name = "container_type")]
 pub enum ContainerType {
     ChartOfAccount,
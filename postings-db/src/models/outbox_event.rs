@@ -0,0 +1,138 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use sqlx::FromRow;
+use uuid::Uuid;
+use crate::models::account_state::{AccountState, AccountStateChange};
+use crate::models::account_stmt::AccountStmt;
+use crate::models::ledger::Ledger;
+use crate::models::ledger_account::LedgerAccount;
+use crate::models::posting::Posting;
+use crate::models::posting_status::PostingStatus;
+use crate::DbError;
+
+/// What happened, stored as JSON in the outbox. The variant name is also kept in the
+/// `event_type` column so consumers and queries can filter without parsing the payload.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum EventPayload {
+    LedgerCreated { ledger_id: Uuid, coa_id: Uuid },
+    LedgerAccountCreated { account_id: Uuid, parent_id: Option<Uuid>, code: Option<String> },
+    AccountStateChanged { account_id: Uuid, state: AccountState, effective_time: DateTime<Utc> },
+    PostingCreated {
+        posting_id: Uuid,
+        #[serde_as(as = "serde_with::hex::Hex")]
+        opr_id: [u8; 34],
+        pst_status: PostingStatus,
+        pst_time: DateTime<Utc>,
+    },
+    PostingStatusChanged { posting_id: Uuid, pst_status: PostingStatus },
+    /// The posting was cancelled and will not take effect.
+    PostingDiscarded { posting_id: Uuid },
+    StatementClosed { stmt_id: Uuid, account_id: Uuid, posting_id: Option<Uuid>, pst_time: DateTime<Utc> },
+}
+
+impl EventPayload {
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            EventPayload::LedgerCreated { .. } => "LedgerCreated",
+            EventPayload::LedgerAccountCreated { .. } => "LedgerAccountCreated",
+            EventPayload::AccountStateChanged { .. } => "AccountStateChanged",
+            EventPayload::PostingCreated { .. } => "PostingCreated",
+            EventPayload::PostingStatusChanged { .. } => "PostingStatusChanged",
+            EventPayload::PostingDiscarded { .. } => "PostingDiscarded",
+            EventPayload::StatementClosed { .. } => "StatementClosed",
+        }
+    }
+}
+
+/// An event the repositories write along with the change it describes. Id and ledger sequence
+/// are assigned on insert.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewOutboxEvent {
+    pub ledger_id: Uuid,
+    /// Record time of the change, as the service's clock read it.
+    pub occurred_at: DateTime<Utc>,
+    pub payload: EventPayload,
+}
+
+impl NewOutboxEvent {
+    pub fn new(ledger_id: Uuid, occurred_at: DateTime<Utc>, payload: EventPayload) -> Self {
+        Self { ledger_id, occurred_at, payload }
+    }
+
+    pub fn ledger_created(ledger: &Ledger, record_time: DateTime<Utc>) -> Self {
+        Self::new(ledger.id, record_time, EventPayload::LedgerCreated { ledger_id: ledger.id, coa_id: ledger.coa_id })
+    }
+
+    pub fn ledger_account_created(account: &LedgerAccount, record_time: DateTime<Utc>) -> Self {
+        let payload = EventPayload::LedgerAccountCreated {
+            account_id: account.id,
+            parent_id: account.parent_id,
+            code: account.code.clone(),
+        };
+        Self::new(account.ledger_id, record_time, payload)
+    }
+
+    pub fn account_state_changed(ledger_id: Uuid, change: &AccountStateChange) -> Self {
+        let payload = EventPayload::AccountStateChanged {
+            account_id: change.account_id,
+            state: change.state.clone(),
+            effective_time: change.effective_time,
+        };
+        Self::new(ledger_id, change.record_time, payload)
+    }
+
+    pub fn posting_created(posting: &Posting) -> Self {
+        let payload = EventPayload::PostingCreated {
+            posting_id: posting.id,
+            opr_id: posting.opr_id,
+            pst_status: posting.pst_status.clone(),
+            pst_time: posting.pst_time,
+        };
+        Self::new(posting.ledger_id, posting.record_time, payload)
+    }
+
+    /// A cancelled posting is reported as discarded, any other new status as a status change.
+    pub fn posting_status_changed(posting: &Posting) -> Self {
+        let payload = match posting.pst_status {
+            PostingStatus::Cancelled => EventPayload::PostingDiscarded { posting_id: posting.id },
+            _ => EventPayload::PostingStatusChanged { posting_id: posting.id, pst_status: posting.pst_status.clone() },
+        };
+        Self::new(posting.ledger_id, posting.record_time, payload)
+    }
+
+    pub fn statement_closed(ledger_id: Uuid, stmt: &AccountStmt, record_time: DateTime<Utc>) -> Self {
+        let payload = EventPayload::StatementClosed {
+            stmt_id: stmt.id,
+            account_id: stmt.account_id,
+            posting_id: stmt.posting_id,
+            pst_time: stmt.pst_time,
+        };
+        Self::new(ledger_id, record_time, payload)
+    }
+
+    pub fn payload_json(&self) -> Result<String, DbError> {
+        serde_json::to_string(&self.payload).map_err(|e| DbError::Decode { what: format!("{} payload: {e}", self.payload.event_type()) })
+    }
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct OutboxEvent {
+    /// Increases in insertion order across all ledgers.
+    pub id: i64,
+    pub ledger_id: Uuid,
+    /// Gapless per ledger, starting at 1, in the order the changes were committed.
+    pub ledger_seq: i64,
+    pub event_type: String,
+    pub payload: String,
+    pub occurred_at: DateTime<Utc>,
+    pub dispatched_at: Option<DateTime<Utc>>,
+}
+
+impl OutboxEvent {
+    pub fn decode_payload(&self) -> Result<EventPayload, serde_json::Error> {
+        serde_json::from_str(&self.payload)
+    }
+}
@@ -0,0 +1,12 @@
+use chrono::{DateTime, Utc};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct PostingApproval {
+    pub id: Uuid,
+    pub posting_id: Uuid,
+    /// User that approved the posting. It is a 32-byte hash.
+    pub approver: [u8; 34],
+    pub approval_time: DateTime<Utc>,
+}
@@ -7,6 +7,8 @@ use crate::models::posting_status::PostingStatus;
 #[derive(Debug, Clone, FromRow, PartialEq)]
 pub struct PostingLine {
     pub id: Uuid,
+    /// Posting the line belongs to, `None` for lines saved on their own.
+    pub posting_id: Option<Uuid>,
     pub account_id: Uuid,
     pub debit_amount: BigDecimal,
     pub credit_amount: BigDecimal,
@@ -18,16 +20,28 @@ pub struct PostingLine {
     pub opr_id: [u8; 34],
     pub opr_src: Option<[u8; 34]>,
     pub pst_time: chrono::DateTime<chrono::Utc>,
+    /// Value time of the posting the line belongs to.
+    pub val_time: Option<chrono::DateTime<chrono::Utc>>,
     pub pst_type: PostingType,
     pub pst_status: PostingStatus,
     pub hash: Option<[u8; 34]>,
     pub discarded_time: Option<chrono::DateTime<chrono::Utc>>,
 }
 
+/// A line with its position in the change feed of its account.
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct SequencedPostingLine {
+    /// Increases with every line inserted; within an account, in the order the lines were committed.
+    pub line_seq: i64,
+    #[sqlx(flatten)]
+    pub line: PostingLine,
+}
+
 impl Default for PostingLine {
     fn default() -> Self {
         Self {
             id: Uuid::nil(),
+            posting_id: None,
             account_id: Uuid::nil(),
             debit_amount: BigDecimal::from(0),
             credit_amount: BigDecimal::from(0),
@@ -39,6 +53,7 @@ impl Default for PostingLine {
             opr_id: [0; 34],
             opr_src: None,
             pst_time: chrono::Utc::now(),
+            val_time: None,
             pst_type: Default::default(),
             pst_status: Default::default(),
             hash: None,
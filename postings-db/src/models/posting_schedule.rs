@@ -0,0 +1,56 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use sqlx::{FromRow, Type};
+use uuid::Uuid;
+use crate::models::posting_type::PostingType;
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "schedule_status", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScheduleStatus {
+    Active,
+    Finished,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Type, PartialEq, Eq)]
+#[sqlx(type_name = "schedule_catch_up", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CatchUp {
+    All,
+    Latest,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct PostingSchedule {
+    pub id: Uuid,
+    pub ledger_id: Uuid,
+    /// Recurrence rule in RRULE notation.
+    pub rrule: String,
+    pub start_time: DateTime<Utc>,
+    pub catch_up: CatchUp,
+    /// User the postings are recorded for. It is a 32-byte hash.
+    pub record_user: [u8; 34],
+    pub opr_type: [u8; 34],
+    pub pst_type: PostingType,
+    pub status: ScheduleStatus,
+    pub next_index: i32,
+    pub next_time: Option<DateTime<Utc>>,
+    pub record_time: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ScheduledLine {
+    pub id: Uuid,
+    pub schedule_id: Uuid,
+    pub account_id: Uuid,
+    pub debit_amount: BigDecimal,
+    pub credit_amount: BigDecimal,
+}
+
+#[derive(Debug, Clone, FromRow, PartialEq)]
+pub struct ScheduleRun {
+    pub schedule_id: Uuid,
+    pub occurrence_index: i32,
+    pub occurrence_time: DateTime<Utc>,
+    pub posting_id: Uuid,
+    pub run_time: DateTime<Utc>,
+}
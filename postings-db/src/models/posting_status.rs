@@ -1,6 +1,7 @@
+use serde::{Deserialize, Serialize};
 use sqlx::Type;
 
-#[derive(Debug, Clone, Type, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Type, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[sqlx(type_name = "posting_status", rename_all = "UPPERCASE")]
 pub enum PostingStatus {
     Deferred,
@@ -13,3 +14,37 @@ pub enum PostingStatus {
     #[default]
     Other,
 }
+
+impl PostingStatus {
+    /// The statuses `is_effective` accepts as a SQL list, for `pst_status IN` conditions of both
+    /// database backends.
+    pub const EFFECTIVE_SQL: &'static str = "('POSTED', 'TAX', 'OTHER')";
+
+    /// Statuses whose lines count toward balances, as in the API's `PostingStatus::is_effective`.
+    pub fn is_effective(&self) -> bool {
+        matches!(self, PostingStatus::Posted | PostingStatus::Tax | PostingStatus::Other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_effective_sql_lists_effective_statuses() {
+        let all = [
+            PostingStatus::Deferred,
+            PostingStatus::Posted,
+            PostingStatus::Proposed,
+            PostingStatus::Simulated,
+            PostingStatus::Tax,
+            PostingStatus::Unposted,
+            PostingStatus::Cancelled,
+            PostingStatus::Other,
+        ];
+        for status in all {
+            let quoted = format!("'{}'", format!("{status:?}").to_uppercase());
+            assert_eq!(PostingStatus::EFFECTIVE_SQL.contains(&quoted), status.is_effective(), "{status:?}");
+        }
+    }
+}
@@ -0,0 +1,19 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeAxis {
+    #[default]
+    PstTime,
+    ValTime,
+    RecordTime,
+}
+
+impl TimeAxis {
+    /// Column of the axis on `posting_line`. Lines without a value time fall back to their posting
+    /// time, which the generated `eff_val_time` column holds so that it can be indexed.
+    pub fn column(&self) -> &'static str {
+        match self {
+            TimeAxis::PstTime => "pst_time",
+            TimeAxis::ValTime => "eff_val_time",
+            TimeAxis::RecordTime => "record_time",
+        }
+    }
+}
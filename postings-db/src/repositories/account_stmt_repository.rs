@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use crate::models::account_stmt::AccountStmt;
+use crate::models::posting::Posting;
 use crate::models::stmt_status::StmtStatus;
 use crate::DbError;
 use chrono::{DateTime, Utc};
@@ -9,6 +10,11 @@ use uuid::Uuid;
 pub trait AccountStmtRepository {
     async fn find_first_by_account_and_status_and_pst_time_less_than_ordered(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError>;
     async fn find_first_by_account_and_status_and_pst_time_greater_than_equal(&self, account_id: Uuid, status: StmtStatus, ref_time: DateTime<Utc>) -> Result<Option<AccountStmt>, DbError>;
-    async fn save(&self, stmt: AccountStmt) -> Result<AccountStmt, DbError>;
+    /// Inserts or replaces the statement. Closing it writes a `StatementClosed` event dated
+    /// `record_time`.
+    async fn save(&self, stmt: AccountStmt, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError>;
+    /// Inserts the closing posting and saves the statement, closed and referring to it, in one
+    /// transaction, writing the `PostingCreated` and `StatementClosed` events.
+    async fn close(&self, stmt: AccountStmt, posting: &Posting, record_time: DateTime<Utc>) -> Result<AccountStmt, DbError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<AccountStmt>, DbError>;
 }
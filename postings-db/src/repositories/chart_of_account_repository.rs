@@ -1,5 +1,7 @@
 use async_trait::async_trait;
 use crate::models::chart_of_account::ChartOfAccount;
+use crate::models::coa_account_definition::CoaAccountDefinition;
+use crate::models::named::Named;
 use crate::DbError;
 use uuid::Uuid;
 
@@ -7,4 +9,9 @@ use uuid::Uuid;
 pub trait ChartOfAccountRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<ChartOfAccount>, DbError>;
     async fn save(&self, coa: &ChartOfAccount) -> Result<(), DbError>;
+    /// Saves the chart of account and its names in one transaction.
+    async fn save_with_named(&self, coa: &ChartOfAccount, named: &[Named]) -> Result<(), DbError>;
+    /// Inserts all definitions in one transaction.
+    async fn save_account_definitions(&self, definitions: &[CoaAccountDefinition]) -> Result<(), DbError>;
+    async fn find_account_definitions_by_coa_id(&self, coa_id: Uuid) -> Result<Vec<CoaAccountDefinition>, DbError>;
 }
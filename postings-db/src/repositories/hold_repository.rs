@@ -0,0 +1,29 @@
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use crate::models::account_limit::BalanceCheck;
+use crate::models::hold::Hold;
+use crate::models::posting::Posting;
+use crate::models::posting_line::PostingLine;
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait HoldRepository {
+    /// Inserts the hold after checking it, like a posting moving the account by `check`, against
+    /// the account limit.
+    async fn save(&self, hold: &Hold, check: &BalanceCheck) -> Result<(), DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Hold>, DbError>;
+    /// Active holds of the account that have not expired at `at`, ordered by record time.
+    async fn find_active_by_account_id(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<Vec<Hold>, DbError>;
+    /// Marks the hold captured and saves the posting that captures it in one transaction, checking
+    /// the posting against the account limits like
+    /// [`PostingRepository::save_batch`](crate::repositories::posting_repository::PostingRepository::save_batch).
+    /// Fails with [`DbError::NotFound`] if the hold is no longer active at `time`.
+    #[allow(clippy::too_many_arguments)]
+    async fn capture(&self, id: Uuid, amount: &BigDecimal, time: DateTime<Utc>, posting: &Posting, lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError>;
+    /// Fails with [`DbError::NotFound`] if the hold is no longer active at `time`.
+    async fn release(&self, id: Uuid, time: DateTime<Utc>) -> Result<(), DbError>;
+    /// Marks the active holds that expired at or before `at` as expired and returns their number.
+    async fn expire(&self, at: DateTime<Utc>) -> Result<u64, DbError>;
+}
@@ -1,10 +1,38 @@
 use async_trait::async_trait;
+use crate::models::account_limit::AccountLimit;
+use crate::models::account_state::AccountStateChange;
 use crate::models::ledger_account::LedgerAccount;
+use crate::models::named::Named;
 use crate::DbError;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+/// Saves take the time the service records the change at, which dates its outbox events.
 #[async_trait]
 pub trait LedgerAccountRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccount>, DbError>;
-    async fn save(&self, ledger_account: &LedgerAccount) -> Result<(), DbError>;
+    /// All accounts of a ledger, ordered by code.
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError>;
+    async fn find_by_ledger_id_and_code(&self, ledger_id: Uuid, code: &str) -> Result<Option<LedgerAccount>, DbError>;
+    /// Accounts of the ledger with one of the given codes.
+    async fn find_by_ledger_id_and_code_in(&self, ledger_id: Uuid, codes: &[String]) -> Result<Vec<LedgerAccount>, DbError>;
+    /// Accounts whose code, cut to the length of each bound, lies within `from..=to` in byte
+    /// order, ordered by code. Codes shorter than a bound are left out, so `45` is not within
+    /// `4000`–`4999` while sub-accounts such as `4999.10` are.
+    async fn find_by_ledger_id_and_code_between(&self, ledger_id: Uuid, from: &str, to: &str) -> Result<Vec<LedgerAccount>, DbError>;
+    async fn find_by_parent_id(&self, parent_id: Uuid) -> Result<Vec<LedgerAccount>, DbError>;
+    async fn save(&self, ledger_account: &LedgerAccount, record_time: DateTime<Utc>) -> Result<(), DbError>;
+    /// Saves the ledger account together with its names in one transaction. Fails with
+    /// [`DbError::Duplicate`] if one of the names is already taken in its context.
+    async fn save_with_named(&self, ledger_account: &LedgerAccount, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError>;
+    /// Saves the state change. A closing takes the lock that saving postings into the ledger
+    /// takes, then fails with [`DbError::BalanceNotZero`] unless the account's effective lines
+    /// sum to zero, so no posting can slip in between the check and the closing.
+    async fn save_state_change(&self, change: &AccountStateChange) -> Result<(), DbError>;
+    /// State changes of the account ordered by effective time, then record time.
+    async fn find_state_changes_by_account_id(&self, account_id: Uuid) -> Result<Vec<AccountStateChange>, DbError>;
+    /// Inserts the limit or replaces the one the account already has.
+    async fn save_limit(&self, limit: &AccountLimit) -> Result<(), DbError>;
+    async fn find_limit_by_account_id(&self, account_id: Uuid) -> Result<Option<AccountLimit>, DbError>;
+    async fn delete_limit(&self, account_id: Uuid) -> Result<(), DbError>;
 }
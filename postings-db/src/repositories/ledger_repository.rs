@@ -1,10 +1,18 @@
 use async_trait::async_trait;
 use crate::models::ledger::Ledger;
+use crate::models::ledger_account::LedgerAccount;
+use crate::models::named::Named;
 use crate::DbError;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+/// Saves take the time the service records the change at, which dates its outbox events.
 #[async_trait]
 pub trait LedgerRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Ledger>, DbError>;
-    async fn save(&self, ledger: &Ledger) -> Result<(), DbError>;
+    async fn save(&self, ledger: &Ledger, record_time: DateTime<Utc>) -> Result<(), DbError>;
+    /// Saves the ledger and its names in one transaction.
+    async fn save_with_named(&self, ledger: &Ledger, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError>;
+    /// Saves the ledger with its accounts, ordered parents first, and the names of both in one transaction.
+    async fn save_with_accounts(&self, ledger: &Ledger, accounts: &[LedgerAccount], named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError>;
 }
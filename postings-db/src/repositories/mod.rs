@@ -1,8 +1,45 @@
 pub mod chart_of_account_repository;
+pub mod hold_repository;
 pub mod ledger_account_repository;
 pub mod ledger_repository;
 pub mod named_repository;
+pub mod outbox_repository;
 pub mod posting_repository;
+pub mod posting_approval_repository;
 pub mod account_stmt_repository;
 pub mod posting_line_repository;
 pub mod posting_trace_repository;
+pub mod schedule_repository;
+
+use std::sync::Arc;
+
+use account_stmt_repository::AccountStmtRepository;
+use chart_of_account_repository::ChartOfAccountRepository;
+use hold_repository::HoldRepository;
+use ledger_account_repository::LedgerAccountRepository;
+use ledger_repository::LedgerRepository;
+use named_repository::NamedRepository;
+use outbox_repository::OutboxRepository;
+use posting_approval_repository::PostingApprovalRepository;
+use posting_line_repository::PostingLineRepository;
+use posting_repository::PostingRepository;
+use posting_trace_repository::PostingTraceRepository;
+use schedule_repository::ScheduleRepository;
+
+/// One implementation of every repository, all on the same database. Backends hand out a
+/// complete set so the services cannot end up reading from one store and writing to another.
+#[derive(Clone)]
+pub struct Repositories {
+    pub coa: Arc<dyn ChartOfAccountRepository + Send + Sync>,
+    pub ledger: Arc<dyn LedgerRepository + Send + Sync>,
+    pub ledger_account: Arc<dyn LedgerAccountRepository + Send + Sync>,
+    pub named: Arc<dyn NamedRepository + Send + Sync>,
+    pub posting: Arc<dyn PostingRepository + Send + Sync>,
+    pub stmt: Arc<dyn AccountStmtRepository + Send + Sync>,
+    pub line: Arc<dyn PostingLineRepository + Send + Sync>,
+    pub trace: Arc<dyn PostingTraceRepository + Send + Sync>,
+    pub approval: Arc<dyn PostingApprovalRepository + Send + Sync>,
+    pub hold: Arc<dyn HoldRepository + Send + Sync>,
+    pub schedule: Arc<dyn ScheduleRepository + Send + Sync>,
+    pub outbox: Arc<dyn OutboxRepository + Send + Sync>,
+}
@@ -4,9 +4,16 @@ use crate::models::named::{Named, ContainerType};
 use crate::DbError;
 use uuid::Uuid;
 
+/// Unique index that keeps names that are not retired apart within their context, named the same
+/// in every backend.
+pub const UNIQUE_NAME_INDEX: &str = "uq_named_name_language_type_context";
+
 #[async_trait]
 pub trait NamedRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Named>, DbError>;
+    /// All names of a container, retired ones included.
     async fn find_by_container(&self, container_id: Uuid) -> Result<Vec<Named>, DbError>;
+    /// Lookups by name only consider names that have not been retired.
     async fn find_by_name_and_type(&self, name: &str, container_type: ContainerType) -> Result<Vec<Named>, DbError>;
     async fn find_by_name_and_type_and_context(&self, name: &str, container_type: ContainerType, context: Uuid) -> Result<Vec<Named>, DbError>;
     async fn save(&self, named: Named) -> Result<Named, DbError>;
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::models::outbox_event::OutboxEvent;
+use crate::DbError;
+use uuid::Uuid;
+
+/// Reads the outbox the other repositories write to. Events are inserted by the repository
+/// methods that make the change, in the same transaction.
+#[async_trait]
+pub trait OutboxRepository {
+    /// Undispatched events in insertion order, which within a ledger is sequence order.
+    async fn find_pending(&self, limit: i64) -> Result<Vec<OutboxEvent>, DbError>;
+    /// The oldest undispatched events of every ledger, at most `limit` each, in insertion order,
+    /// so a ledger with a long backlog cannot crowd out the others.
+    async fn find_pending_per_ledger(&self, limit: i64) -> Result<Vec<OutboxEvent>, DbError>;
+    /// Events of the ledger with a sequence number above `after_seq`, dispatched or not.
+    async fn find_by_ledger_id(&self, ledger_id: Uuid, after_seq: i64, limit: i64) -> Result<Vec<OutboxEvent>, DbError>;
+    async fn mark_dispatched(&self, ids: &[i64], time: DateTime<Utc>) -> Result<(), DbError>;
+}
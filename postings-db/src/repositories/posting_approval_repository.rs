@@ -0,0 +1,18 @@
+use async_trait::async_trait;
+use crate::models::approval_policy::ApprovalPolicy;
+use crate::models::posting_approval::PostingApproval;
+use crate::DbError;
+use uuid::Uuid;
+
+/// Approval policies per ledger and the approvals recorded for postings. Approvals that post a
+/// posting are written by `PostingRepository::update_status` together with the status change.
+#[async_trait]
+pub trait PostingApprovalRepository {
+    async fn save_policy(&self, policy: &ApprovalPolicy) -> Result<(), DbError>;
+    async fn find_policy_by_ledger_id(&self, ledger_id: Uuid) -> Result<Option<ApprovalPolicy>, DbError>;
+    async fn delete_policy_by_ledger_id(&self, ledger_id: Uuid) -> Result<(), DbError>;
+    /// Records an approval that leaves the status of its posting as it is, e.g. of a deferred
+    /// posting that is posted once due.
+    async fn save(&self, approval: &PostingApproval) -> Result<(), DbError>;
+    async fn find_by_posting_id(&self, posting_id: Uuid) -> Result<Vec<PostingApproval>, DbError>;
+}
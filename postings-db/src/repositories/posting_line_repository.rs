@@ -1,5 +1,6 @@
 use async_trait::async_trait;
-use crate::models::posting_line::PostingLine;
+use crate::models::posting_line::{PostingLine, SequencedPostingLine};
+use crate::models::time_axis::TimeAxis;
 use crate::DbError;
 use chrono::{DateTime, Utc};
 use uuid::Uuid;
@@ -8,8 +9,19 @@ use uuid::Uuid;
 pub trait PostingLineRepository {
     async fn save(&self, posting_line: PostingLine) -> Result<PostingLine, DbError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingLine>, DbError>;
+    async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<PostingLine>, DbError>;
+    /// Lines of the posting, whatever their status.
+    async fn find_by_posting_id(&self, posting_id: Uuid) -> Result<Vec<PostingLine>, DbError>;
     async fn find_by_account_and_pst_time_between(&self, account_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
     async fn find_by_id_and_account_id(&self, id: Uuid, account_id: Uuid) -> Result<Option<PostingLine>, DbError>;
     async fn find_by_base_line_and_pst_time_less_than_equal(&self, base_line: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
     async fn find_by_account_and_pst_time_less_than_equal(&self, account_id: Uuid, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+    /// Lines of the account whose time on the given axis lies in `(from, to]`.
+    async fn find_by_account_and_time_between(&self, account_id: Uuid, axis: TimeAxis, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+    /// Lines of the account whose time on the given axis is at or before `ref_time`.
+    async fn find_by_account_and_time_less_than_equal(&self, account_id: Uuid, axis: TimeAxis, ref_time: DateTime<Utc>) -> Result<Vec<PostingLine>, DbError>;
+    /// Up to `limit` lines of the account with a sequence number above `after_seq`, in sequence order.
+    async fn find_by_account_after_seq(&self, account_id: Uuid, after_seq: i64, limit: i64) -> Result<Vec<SequencedPostingLine>, DbError>;
+    /// Sequence number of the account's latest line, 0 if it has none.
+    async fn find_last_seq_by_account(&self, account_id: Uuid) -> Result<i64, DbError>;
 }
@@ -1,13 +1,50 @@
 use async_trait::async_trait;
+use crate::models::account_limit::BalanceCheck;
+use crate::models::interest_accrual::AccrualPeriod;
 use crate::models::posting::Posting;
+use crate::models::posting_approval::PostingApproval;
+use crate::models::posting_line::PostingLine;
+use crate::models::posting_status::PostingStatus;
 use crate::DbError;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
+/// Unique constraint on the operation ids of booked accrual periods, named the same in every
+/// backend.
+pub const UNIQUE_ACCRUAL_OPR_ID: &str = "unique_interest_accrual_opr_id";
+
 #[async_trait]
 pub trait PostingRepository {
     async fn find_by_opr_id_and_discarding_id_is_null(&self, opr_id: &[u8]) -> Result<Option<Posting>, DbError>;
     async fn find_by_opr_id(&self, opr_id: &[u8]) -> Result<Vec<Posting>, DbError>;
     async fn find_first_by_ledger_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError>;
+    async fn find_first_by_ledger_and_hash_is_not_null_order_by_record_time_desc(&self, ledger_id: Uuid) -> Result<Option<Posting>, DbError>;
+    async fn find_by_ledger_and_pst_status(&self, ledger_id: Uuid, pst_status: PostingStatus) -> Result<Vec<Posting>, DbError>;
+    async fn find_by_pst_status_and_val_time_less_than_equal(&self, pst_status: PostingStatus, ref_time: DateTime<Utc>) -> Result<Vec<Posting>, DbError>;
+    /// Postings of the ledger with a posting time in `(from, to]`, in posting time order.
+    async fn find_by_ledger_and_pst_time_between(&self, ledger_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<Posting>, DbError>;
+    /// All postings of the ledger's hash chain, oldest first.
+    async fn find_by_ledger_and_hash_is_not_null_order_by_record_time(&self, ledger_id: Uuid) -> Result<Vec<Posting>, DbError>;
     async fn save(&self, posting: &Posting) -> Result<(), DbError>;
+    /// Persists the postings and their lines with multi-row inserts inside a single transaction.
+    /// The limits of the checked accounts are locked first, and the transaction is rolled back
+    /// with [`DbError::LimitExceeded`] if a move would take an account's current balance past
+    /// its limit, or with [`DbError::AccountClosed`] if a line books into an account closed by
+    /// the line's record time.
+    async fn save_batch(&self, postings: &[Posting], lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError>;
+    /// Saves an interest accrual posting like [`save_batch`](Self::save_batch) and records the
+    /// period it covers in the same transaction. Accruals of an account are booked one at a
+    /// time, so this fails with [`DbError::PeriodOverlap`] if the period overlaps another one
+    /// booked for the account, and with [`DbError::Duplicate`] on [`UNIQUE_ACCRUAL_OPR_ID`] if
+    /// the same period was booked already.
+    async fn save_accrual(&self, period: &AccrualPeriod, posting: &Posting, lines: &[PostingLine], checks: &[BalanceCheck]) -> Result<(), DbError>;
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Posting>, DbError>;
+    /// Updates the status, record time and hash chain fields of the posting, writes the status,
+    /// record time and hash of the given lines and records the approval, if any, inside a single
+    /// transaction. Fails with [`DbError::StatusChanged`] unless the posting still has status
+    /// `from`, so of two concurrent transitions only one goes through. When the new status is
+    /// effective, the limits of the checked accounts are locked and checked as in
+    /// [`save_batch`](Self::save_batch), and the update fails with [`DbError::LimitExceeded`] or,
+    /// if a line books into a closed account, with [`DbError::AccountClosed`].
+    async fn update_status(&self, posting: &Posting, from: PostingStatus, lines: &[PostingLine], approval: Option<&PostingApproval>, checks: &[BalanceCheck]) -> Result<(), DbError>;
 }
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::models::account_limit::BalanceCheck;
+use crate::models::posting::Posting;
+use crate::models::posting_line::PostingLine;
+use crate::models::posting_schedule::{PostingSchedule, ScheduleRun, ScheduledLine};
+use crate::DbError;
+use uuid::Uuid;
+
+#[async_trait]
+pub trait ScheduleRepository {
+    /// Saves the schedule together with its lines in one transaction.
+    async fn save(&self, schedule: &PostingSchedule, lines: &[ScheduledLine]) -> Result<(), DbError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PostingSchedule>, DbError>;
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<PostingSchedule>, DbError>;
+    async fn find_lines_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<ScheduledLine>, DbError>;
+    /// Active schedules whose next occurrence is at or before `now`, the earliest first.
+    async fn find_due(&self, now: DateTime<Utc>) -> Result<Vec<PostingSchedule>, DbError>;
+    async fn find_runs_by_schedule_id(&self, schedule_id: Uuid) -> Result<Vec<ScheduleRun>, DbError>;
+    /// Fails with [`DbError::NotFound`] unless the schedule is active.
+    async fn cancel(&self, id: Uuid) -> Result<(), DbError>;
+    /// Records the run and saves its posting in one transaction, moving the schedule on to the
+    /// status and next occurrence it carries. The schedule must still be active and at
+    /// `expected_index`, otherwise another run got there first and the call fails with
+    /// [`DbError::NotFound`]. The posting is checked against the account limits like
+    /// [`PostingRepository::save_batch`](crate::repositories::posting_repository::PostingRepository::save_batch).
+    #[allow(clippy::too_many_arguments)]
+    async fn save_run(
+        &self,
+        schedule: &PostingSchedule,
+        expected_index: i32,
+        run: &ScheduleRun,
+        posting: &Posting,
+        lines: &[PostingLine],
+        checks: &[BalanceCheck],
+    ) -> Result<(), DbError>;
+}
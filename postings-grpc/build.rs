@@ -0,0 +1,8 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Use the vendored compiler so building does not depend on a system protoc.
+    if std::env::var_os("PROTOC").is_none() {
+        std::env::set_var("PROTOC", protoc_bin_vendored::protoc_bin_path()?);
+    }
+    tonic_prost_build::configure().compile_protos(&["proto/postings/v1/postings.proto"], &["proto"])?;
+    Ok(())
+}
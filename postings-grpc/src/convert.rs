@@ -0,0 +1,630 @@
+//! Conversions between the protobuf messages and the domain types of `postings-api`.
+//!
+//! Domain values always convert into messages. Messages convert back with `TryFrom`, which fails
+//! on malformed ids, amounts or hashes and on missing required fields.
+
+use std::fmt;
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::account_stmt::AccountStmt;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::financial_stmt::FinancialStmt;
+use postings_api::domain::hash_record::HashRecord;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::posting_trace::PostingTrace;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::domain::stmt_status::StmtStatus;
+use postings_api::domain::time_axis::TimeAxis;
+use prost_types::Timestamp;
+use tonic::Status;
+use uuid::Uuid;
+
+use crate::pb;
+
+/// A field of a request message that has no domain value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionError {
+    pub field: &'static str,
+    pub reason: String,
+}
+
+impl ConversionError {
+    fn new(field: &'static str, reason: impl Into<String>) -> Self {
+        Self { field, reason: reason.into() }
+    }
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl From<ConversionError> for Status {
+    fn from(e: ConversionError) -> Self {
+        Status::invalid_argument(e.to_string())
+    }
+}
+
+pub(crate) fn parse_uuid(field: &'static str, value: &str) -> Result<Uuid, ConversionError> {
+    Uuid::parse_str(value).map_err(|e| ConversionError::new(field, e.to_string()))
+}
+
+fn parse_opt_uuid(field: &'static str, value: Option<String>) -> Result<Option<Uuid>, ConversionError> {
+    value.map(|v| parse_uuid(field, &v)).transpose()
+}
+
+pub(crate) fn parse_hash(field: &'static str, value: Vec<u8>) -> Result<[u8; 34], ConversionError> {
+    let len = value.len();
+    value.try_into().map_err(|_| ConversionError::new(field, format!("expected 34 bytes, got {len}")))
+}
+
+fn parse_opt_hash(field: &'static str, value: Option<Vec<u8>>) -> Result<Option<[u8; 34]>, ConversionError> {
+    value.map(|v| parse_hash(field, v)).transpose()
+}
+
+fn parse_decimal(field: &'static str, value: &str) -> Result<BigDecimal, ConversionError> {
+    BigDecimal::from_str(value).map_err(|e| ConversionError::new(field, e.to_string()))
+}
+
+fn required<T>(field: &'static str, value: Option<T>) -> Result<T, ConversionError> {
+    value.ok_or_else(|| ConversionError::new(field, "missing"))
+}
+
+/// Protobuf timestamp of a point in time, for building requests.
+pub fn timestamp(time: DateTime<Utc>) -> Timestamp {
+    Timestamp { seconds: time.timestamp(), nanos: time.timestamp_subsec_nanos() as i32 }
+}
+
+pub(crate) fn parse_opt_time(field: &'static str, value: Option<Timestamp>) -> Result<Option<DateTime<Utc>>, ConversionError> {
+    value
+        .map(|ts| {
+            u32::try_from(ts.nanos)
+                .ok()
+                .and_then(|nanos| DateTime::from_timestamp(ts.seconds, nanos))
+                .ok_or_else(|| ConversionError::new(field, "timestamp out of range"))
+        })
+        .transpose()
+}
+
+fn parse_time(field: &'static str, value: Option<Timestamp>) -> Result<DateTime<Utc>, ConversionError> {
+    required(field, parse_opt_time(field, value)?)
+}
+
+impl From<BalanceSide> for pb::BalanceSide {
+    fn from(side: BalanceSide) -> Self {
+        match side {
+            BalanceSide::Dr => pb::BalanceSide::Dr,
+            BalanceSide::Cr => pb::BalanceSide::Cr,
+            BalanceSide::DrCr => pb::BalanceSide::DrCr,
+        }
+    }
+}
+
+fn parse_balance_side(value: i32) -> Result<BalanceSide, ConversionError> {
+    match pb::BalanceSide::try_from(value) {
+        Ok(pb::BalanceSide::Dr) => Ok(BalanceSide::Dr),
+        Ok(pb::BalanceSide::Cr) => Ok(BalanceSide::Cr),
+        Ok(pb::BalanceSide::DrCr) => Ok(BalanceSide::DrCr),
+        _ => Err(ConversionError::new("balance_side", format!("unsupported value {value}"))),
+    }
+}
+
+impl From<AccountCategory> for pb::AccountCategory {
+    fn from(category: AccountCategory) -> Self {
+        match category {
+            AccountCategory::RE => pb::AccountCategory::Re,
+            AccountCategory::EX => pb::AccountCategory::Ex,
+            AccountCategory::AS => pb::AccountCategory::As,
+            AccountCategory::LI => pb::AccountCategory::Li,
+            AccountCategory::EQ => pb::AccountCategory::Eq,
+            AccountCategory::NOOP => pb::AccountCategory::Noop,
+            AccountCategory::NORE => pb::AccountCategory::Nore,
+            AccountCategory::NOEX => pb::AccountCategory::Noex,
+        }
+    }
+}
+
+fn parse_category(value: i32) -> Result<AccountCategory, ConversionError> {
+    match pb::AccountCategory::try_from(value) {
+        Ok(pb::AccountCategory::Re) => Ok(AccountCategory::RE),
+        Ok(pb::AccountCategory::Ex) => Ok(AccountCategory::EX),
+        Ok(pb::AccountCategory::As) => Ok(AccountCategory::AS),
+        Ok(pb::AccountCategory::Li) => Ok(AccountCategory::LI),
+        Ok(pb::AccountCategory::Eq) => Ok(AccountCategory::EQ),
+        Ok(pb::AccountCategory::Noop) => Ok(AccountCategory::NOOP),
+        Ok(pb::AccountCategory::Nore) => Ok(AccountCategory::NORE),
+        Ok(pb::AccountCategory::Noex) => Ok(AccountCategory::NOEX),
+        _ => Err(ConversionError::new("category", format!("unsupported value {value}"))),
+    }
+}
+
+impl From<PostingType> for pb::PostingType {
+    fn from(pst_type: PostingType) -> Self {
+        match pst_type {
+            PostingType::BusiTx => pb::PostingType::BusiTx,
+            PostingType::AdjTx => pb::PostingType::AdjTx,
+            PostingType::BalStmt => pb::PostingType::BalStmt,
+            PostingType::PnLStmt => pb::PostingType::PnlStmt,
+            PostingType::BsStmt => pb::PostingType::BsStmt,
+            PostingType::LdgClsng => pb::PostingType::LdgClsng,
+            PostingType::Unknown => pb::PostingType::Unknown,
+        }
+    }
+}
+
+fn parse_posting_type(value: i32) -> Result<PostingType, ConversionError> {
+    match pb::PostingType::try_from(value) {
+        Ok(pb::PostingType::BusiTx) => Ok(PostingType::BusiTx),
+        Ok(pb::PostingType::AdjTx) => Ok(PostingType::AdjTx),
+        Ok(pb::PostingType::BalStmt) => Ok(PostingType::BalStmt),
+        Ok(pb::PostingType::PnlStmt) => Ok(PostingType::PnLStmt),
+        Ok(pb::PostingType::BsStmt) => Ok(PostingType::BsStmt),
+        Ok(pb::PostingType::LdgClsng) => Ok(PostingType::LdgClsng),
+        Ok(pb::PostingType::Unknown) => Ok(PostingType::Unknown),
+        _ => Err(ConversionError::new("pst_type", format!("unsupported value {value}"))),
+    }
+}
+
+impl From<PostingStatus> for pb::PostingStatus {
+    fn from(status: PostingStatus) -> Self {
+        match status {
+            PostingStatus::Deferred => pb::PostingStatus::Deferred,
+            PostingStatus::Posted => pb::PostingStatus::Posted,
+            PostingStatus::Proposed => pb::PostingStatus::Proposed,
+            PostingStatus::Simulated => pb::PostingStatus::Simulated,
+            PostingStatus::Tax => pb::PostingStatus::Tax,
+            PostingStatus::Unposted => pb::PostingStatus::Unposted,
+            PostingStatus::Cancelled => pb::PostingStatus::Cancelled,
+            PostingStatus::Other => pb::PostingStatus::Other,
+        }
+    }
+}
+
+fn parse_posting_status(value: i32) -> Result<PostingStatus, ConversionError> {
+    match pb::PostingStatus::try_from(value) {
+        Ok(pb::PostingStatus::Deferred) => Ok(PostingStatus::Deferred),
+        Ok(pb::PostingStatus::Posted) => Ok(PostingStatus::Posted),
+        Ok(pb::PostingStatus::Proposed) => Ok(PostingStatus::Proposed),
+        Ok(pb::PostingStatus::Simulated) => Ok(PostingStatus::Simulated),
+        Ok(pb::PostingStatus::Tax) => Ok(PostingStatus::Tax),
+        Ok(pb::PostingStatus::Unposted) => Ok(PostingStatus::Unposted),
+        Ok(pb::PostingStatus::Cancelled) => Ok(PostingStatus::Cancelled),
+        Ok(pb::PostingStatus::Other) => Ok(PostingStatus::Other),
+        _ => Err(ConversionError::new("pst_status", format!("unsupported value {value}"))),
+    }
+}
+
+impl From<StmtStatus> for pb::StmtStatus {
+    fn from(status: StmtStatus) -> Self {
+        match status {
+            StmtStatus::SIMULATED => pb::StmtStatus::Simulated,
+            StmtStatus::CLOSED => pb::StmtStatus::Closed,
+        }
+    }
+}
+
+fn parse_stmt_status(value: i32) -> Result<StmtStatus, ConversionError> {
+    match pb::StmtStatus::try_from(value) {
+        Ok(pb::StmtStatus::Simulated) => Ok(StmtStatus::SIMULATED),
+        Ok(pb::StmtStatus::Closed) => Ok(StmtStatus::CLOSED),
+        _ => Err(ConversionError::new("stmt_status", format!("unsupported value {value}"))),
+    }
+}
+
+pub(crate) fn parse_time_axis(value: i32) -> Result<TimeAxis, ConversionError> {
+    match pb::TimeAxis::try_from(value) {
+        Ok(pb::TimeAxis::Unspecified) | Ok(pb::TimeAxis::PstTime) => Ok(TimeAxis::PstTime),
+        Ok(pb::TimeAxis::ValTime) => Ok(TimeAxis::ValTime),
+        Ok(pb::TimeAxis::RecordTime) => Ok(TimeAxis::RecordTime),
+        Err(_) => Err(ConversionError::new("axis", format!("unsupported value {value}"))),
+    }
+}
+
+impl From<ChartOfAccount> for pb::ChartOfAccount {
+    fn from(coa: ChartOfAccount) -> Self {
+        Self { id: coa.id.to_string() }
+    }
+}
+
+impl TryFrom<pb::ChartOfAccount> for ChartOfAccount {
+    type Error = ConversionError;
+
+    fn try_from(coa: pb::ChartOfAccount) -> Result<Self, Self::Error> {
+        Ok(Self { id: parse_uuid("coa.id", &coa.id)? })
+    }
+}
+
+impl From<Ledger> for pb::Ledger {
+    fn from(ledger: Ledger) -> Self {
+        Self { id: ledger.id.to_string(), coa: Some(ledger.coa.into()) }
+    }
+}
+
+impl TryFrom<pb::Ledger> for Ledger {
+    type Error = ConversionError;
+
+    fn try_from(ledger: pb::Ledger) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uuid("ledger.id", &ledger.id)?,
+            coa: required("ledger.coa", ledger.coa)?.try_into()?,
+        })
+    }
+}
+
+impl From<LedgerAccount> for pb::LedgerAccount {
+    fn from(account: LedgerAccount) -> Self {
+        Self {
+            id: account.id.to_string(),
+            ledger: Some(account.ledger.into()),
+            parent: account.parent.map(|parent| Box::new((*parent).into())),
+            coa: Some(account.coa.into()),
+            balance_side: pb::BalanceSide::from(account.balance_side) as i32,
+            category: pb::AccountCategory::from(account.category) as i32,
+            code: account.code,
+        }
+    }
+}
+
+impl TryFrom<pb::LedgerAccount> for LedgerAccount {
+    type Error = ConversionError;
+
+    fn try_from(account: pb::LedgerAccount) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uuid("account.id", &account.id)?,
+            ledger: required("account.ledger", account.ledger)?.try_into()?,
+            parent: account.parent.map(|parent| (*parent).try_into().map(Box::new)).transpose()?,
+            coa: required("account.coa", account.coa)?.try_into()?,
+            balance_side: parse_balance_side(account.balance_side)?,
+            category: parse_category(account.category)?,
+            code: account.code,
+        })
+    }
+}
+
+impl From<HashRecord> for pb::HashRecord {
+    fn from(record: HashRecord) -> Self {
+        Self {
+            antecedent_id: record.antecedent_id.map(|id| id.to_string()),
+            antecedent_hash: record.antecedent_hash.map(Vec::from),
+            hash: record.hash.map(Vec::from),
+        }
+    }
+}
+
+impl TryFrom<pb::HashRecord> for HashRecord {
+    type Error = ConversionError;
+
+    fn try_from(record: pb::HashRecord) -> Result<Self, Self::Error> {
+        Ok(Self {
+            antecedent_id: parse_opt_uuid("hash_record.antecedent_id", record.antecedent_id)?,
+            antecedent_hash: parse_opt_hash("hash_record.antecedent_hash", record.antecedent_hash)?,
+            hash: parse_opt_hash("hash_record.hash", record.hash)?,
+        })
+    }
+}
+
+impl From<PostingLine> for pb::PostingLine {
+    fn from(line: PostingLine) -> Self {
+        Self {
+            id: line.id.to_string(),
+            account: Some(line.account.into()),
+            debit_amount: line.debit_amount.to_string(),
+            credit_amount: line.credit_amount.to_string(),
+            details: line.details.map(Vec::from),
+            src_account: line.src_account.map(Vec::from),
+            base_line: line.base_line.map(|id| id.to_string()),
+            sub_opr_src_id: line.sub_opr_src_id.map(Vec::from),
+            record_time: Some(timestamp(line.record_time)),
+            opr_id: line.opr_id.to_vec(),
+            opr_src: line.opr_src.map(Vec::from),
+            pst_time: Some(timestamp(line.pst_time)),
+            val_time: line.val_time.map(timestamp),
+            pst_type: pb::PostingType::from(line.pst_type) as i32,
+            pst_status: pb::PostingStatus::from(line.pst_status) as i32,
+            hash: line.hash.map(Vec::from),
+            additional_information: line.additional_information,
+            discarded_time: line.discarded_time.map(timestamp),
+        }
+    }
+}
+
+impl TryFrom<pb::PostingLine> for PostingLine {
+    type Error = ConversionError;
+
+    fn try_from(line: pb::PostingLine) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uuid("line.id", &line.id)?,
+            account: required("line.account", line.account)?.try_into()?,
+            debit_amount: parse_decimal("line.debit_amount", &line.debit_amount)?,
+            credit_amount: parse_decimal("line.credit_amount", &line.credit_amount)?,
+            details: parse_opt_hash("line.details", line.details)?,
+            src_account: parse_opt_hash("line.src_account", line.src_account)?,
+            base_line: parse_opt_uuid("line.base_line", line.base_line)?,
+            sub_opr_src_id: parse_opt_hash("line.sub_opr_src_id", line.sub_opr_src_id)?,
+            record_time: parse_time("line.record_time", line.record_time)?,
+            opr_id: parse_hash("line.opr_id", line.opr_id)?,
+            opr_src: parse_opt_hash("line.opr_src", line.opr_src)?,
+            pst_time: parse_time("line.pst_time", line.pst_time)?,
+            val_time: parse_opt_time("line.val_time", line.val_time)?,
+            pst_type: parse_posting_type(line.pst_type)?,
+            pst_status: parse_posting_status(line.pst_status)?,
+            hash: parse_opt_hash("line.hash", line.hash)?,
+            additional_information: line.additional_information,
+            discarded_time: parse_opt_time("line.discarded_time", line.discarded_time)?,
+        })
+    }
+}
+
+impl From<Posting> for pb::Posting {
+    fn from(posting: Posting) -> Self {
+        Self {
+            id: posting.id.to_string(),
+            record_user: posting.record_user.to_vec(),
+            record_time: Some(timestamp(posting.record_time)),
+            opr_id: posting.opr_id.to_vec(),
+            opr_time: Some(timestamp(posting.opr_time)),
+            opr_type: posting.opr_type.to_vec(),
+            opr_details: posting.opr_details.map(Vec::from),
+            opr_src: posting.opr_src.map(Vec::from),
+            pst_time: Some(timestamp(posting.pst_time)),
+            pst_type: pb::PostingType::from(posting.pst_type) as i32,
+            pst_status: pb::PostingStatus::from(posting.pst_status) as i32,
+            ledger: Some(posting.ledger.into()),
+            val_time: posting.val_time.map(timestamp),
+            lines: posting.lines.into_iter().map(Into::into).collect(),
+            discarded_id: posting.discarded_id.map(|id| id.to_string()),
+            discarded_time: posting.discarded_time.map(timestamp),
+            discarding_id: posting.discarding_id.map(|id| id.to_string()),
+            hash_record: Some(posting.hash_record.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::Posting> for Posting {
+    type Error = ConversionError;
+
+    /// An empty id and missing record time are accepted, since the server assigns both.
+    fn try_from(posting: pb::Posting) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: if posting.id.is_empty() { Uuid::nil() } else { parse_uuid("posting.id", &posting.id)? },
+            record_user: parse_hash("posting.record_user", posting.record_user)?,
+            record_time: parse_opt_time("posting.record_time", posting.record_time)?.unwrap_or_default(),
+            opr_id: parse_hash("posting.opr_id", posting.opr_id)?,
+            opr_time: parse_time("posting.opr_time", posting.opr_time)?,
+            opr_type: parse_hash("posting.opr_type", posting.opr_type)?,
+            opr_details: parse_opt_hash("posting.opr_details", posting.opr_details)?,
+            opr_src: parse_opt_hash("posting.opr_src", posting.opr_src)?,
+            pst_time: parse_time("posting.pst_time", posting.pst_time)?,
+            pst_type: parse_posting_type(posting.pst_type)?,
+            pst_status: parse_posting_status(posting.pst_status)?,
+            ledger: required("posting.ledger", posting.ledger)?.try_into()?,
+            val_time: parse_opt_time("posting.val_time", posting.val_time)?,
+            lines: posting.lines.into_iter().map(TryInto::try_into).collect::<Result<_, _>>()?,
+            discarded_id: parse_opt_uuid("posting.discarded_id", posting.discarded_id)?,
+            discarded_time: parse_opt_time("posting.discarded_time", posting.discarded_time)?,
+            discarding_id: parse_opt_uuid("posting.discarding_id", posting.discarding_id)?,
+            hash_record: posting.hash_record.map(TryInto::try_into).transpose()?.unwrap_or_default(),
+        })
+    }
+}
+
+impl From<PostingTrace> for pb::PostingTrace {
+    fn from(trace: PostingTrace) -> Self {
+        Self {
+            id: trace.id.to_string(),
+            tgt_pst_id: trace.tgt_pst_id.to_string(),
+            src_pst_time: Some(timestamp(trace.src_pst_time)),
+            src_pst_id: trace.src_pst_id.to_string(),
+            src_opr_id: trace.src_opr_id.to_vec(),
+            account: Some(trace.account.into()),
+            debit_amount: trace.debit_amount.to_string(),
+            credit_amount: trace.credit_amount.to_string(),
+            src_pst_hash: trace.src_pst_hash.map(Vec::from),
+        }
+    }
+}
+
+impl TryFrom<pb::PostingTrace> for PostingTrace {
+    type Error = ConversionError;
+
+    fn try_from(trace: pb::PostingTrace) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: parse_uuid("trace.id", &trace.id)?,
+            tgt_pst_id: parse_uuid("trace.tgt_pst_id", &trace.tgt_pst_id)?,
+            src_pst_time: parse_time("trace.src_pst_time", trace.src_pst_time)?,
+            src_pst_id: parse_uuid("trace.src_pst_id", &trace.src_pst_id)?,
+            src_opr_id: parse_hash("trace.src_opr_id", trace.src_opr_id)?,
+            account: required("trace.account", trace.account)?.try_into()?,
+            debit_amount: parse_decimal("trace.debit_amount", &trace.debit_amount)?,
+            credit_amount: parse_decimal("trace.credit_amount", &trace.credit_amount)?,
+            src_pst_hash: parse_opt_hash("trace.src_pst_hash", trace.src_pst_hash)?,
+        })
+    }
+}
+
+impl From<AccountStmt> for pb::AccountStmt {
+    fn from(stmt: AccountStmt) -> Self {
+        let financial = stmt.financial_stmt;
+        Self {
+            id: financial.id.to_string(),
+            posting: financial.posting.map(Into::into),
+            pst_time: Some(timestamp(financial.pst_time)),
+            stmt_status: pb::StmtStatus::from(financial.stmt_status) as i32,
+            latest_pst: financial.latest_pst.map(Into::into),
+            stmt_seq_nbr: financial.stmt_seq_nbr,
+            account: Some(stmt.account.into()),
+            youngest_pst: stmt.youngest_pst.map(Into::into),
+            total_debit: stmt.total_debit.to_string(),
+            total_credit: stmt.total_credit.to_string(),
+        }
+    }
+}
+
+impl TryFrom<pb::AccountStmt> for AccountStmt {
+    type Error = ConversionError;
+
+    fn try_from(stmt: pb::AccountStmt) -> Result<Self, Self::Error> {
+        Ok(Self {
+            financial_stmt: FinancialStmt {
+                id: parse_uuid("stmt.id", &stmt.id)?,
+                posting: stmt.posting.map(TryInto::try_into).transpose()?,
+                pst_time: parse_time("stmt.pst_time", stmt.pst_time)?,
+                stmt_status: parse_stmt_status(stmt.stmt_status)?,
+                latest_pst: stmt.latest_pst.map(TryInto::try_into).transpose()?,
+                stmt_seq_nbr: stmt.stmt_seq_nbr,
+            },
+            account: required("stmt.account", stmt.account)?.try_into()?,
+            youngest_pst: stmt.youngest_pst.map(TryInto::try_into).transpose()?,
+            total_debit: parse_decimal("stmt.total_debit", &stmt.total_debit)?,
+            total_credit: parse_decimal("stmt.total_credit", &stmt.total_credit)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn create_account(parent: Option<LedgerAccount>) -> LedgerAccount {
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: Ledger { id: Uuid::new_v4(), coa: coa.clone() },
+            parent: parent.map(Box::new),
+            coa,
+            balance_side: BalanceSide::DrCr,
+            category: AccountCategory::NOOP,
+            code: Some("4000.10".to_string()),
+        }
+    }
+
+    fn create_line(account: LedgerAccount) -> PostingLine {
+        let time = Utc.with_ymd_and_hms(2025, 3, 1, 12, 30, 0).unwrap() + chrono::Duration::nanoseconds(123_456_789);
+        PostingLine {
+            id: Uuid::new_v4(),
+            account,
+            debit_amount: BigDecimal::from_str("1234567890.0000000001").unwrap(),
+            credit_amount: BigDecimal::from(0),
+            details: Some([7; 34]),
+            src_account: None,
+            base_line: Some(Uuid::new_v4()),
+            sub_opr_src_id: None,
+            record_time: time,
+            opr_id: [1; 34],
+            opr_src: Some([2; 34]),
+            pst_time: time,
+            val_time: None,
+            pst_type: PostingType::PnLStmt,
+            pst_status: PostingStatus::Tax,
+            hash: Some([3; 34]),
+            additional_information: Some("rent".to_string()),
+            discarded_time: Some(time),
+        }
+    }
+
+    fn create_posting() -> Posting {
+        let account = create_account(Some(create_account(None)));
+        let line = create_line(account.clone());
+        Posting {
+            id: Uuid::new_v4(),
+            record_user: [4; 34],
+            record_time: line.record_time,
+            opr_id: [1; 34],
+            opr_time: line.pst_time,
+            opr_type: [5; 34],
+            opr_details: None,
+            opr_src: Some([6; 34]),
+            pst_time: line.pst_time,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: account.ledger.clone(),
+            val_time: Some(line.pst_time),
+            lines: vec![line.clone(), create_line(account)],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: Some(Uuid::new_v4()),
+            hash_record: HashRecord { antecedent_id: Some(Uuid::new_v4()), antecedent_hash: Some([8; 34]), hash: Some([9; 34]) },
+        }
+    }
+
+    #[test]
+    fn test_posting_round_trip() {
+        let posting = create_posting();
+
+        let message = pb::Posting::from(posting.clone());
+
+        assert_eq!(Posting::try_from(message).unwrap(), posting);
+    }
+
+    #[test]
+    fn test_account_stmt_round_trip() {
+        let posting = create_posting();
+        let account = posting.lines[0].account.clone();
+        let trace = PostingTrace {
+            id: Uuid::new_v4(),
+            tgt_pst_id: Uuid::new_v4(),
+            src_pst_time: posting.pst_time,
+            src_pst_id: posting.id,
+            src_opr_id: posting.opr_id,
+            account: account.clone(),
+            debit_amount: BigDecimal::from_str("10.50").unwrap(),
+            credit_amount: BigDecimal::from_str("-0.01").unwrap(),
+            src_pst_hash: None,
+        };
+        let stmt = AccountStmt {
+            financial_stmt: FinancialStmt {
+                id: Uuid::new_v4(),
+                posting: Some(posting.clone()),
+                pst_time: posting.pst_time,
+                stmt_status: StmtStatus::CLOSED,
+                latest_pst: Some(trace.clone()),
+                stmt_seq_nbr: 3,
+            },
+            account,
+            youngest_pst: Some(trace),
+            total_debit: BigDecimal::from_str("10.50").unwrap(),
+            total_credit: BigDecimal::from(0),
+        };
+
+        let message = pb::AccountStmt::from(stmt.clone());
+
+        assert_eq!(AccountStmt::try_from(message).unwrap(), stmt);
+    }
+
+    #[test]
+    fn test_amounts_and_hashes_keep_their_wire_form() {
+        let line = create_line(create_account(None));
+
+        let message = pb::PostingLine::from(line);
+
+        assert_eq!(message.debit_amount, "1234567890.0000000001");
+        assert_eq!(message.opr_id, vec![1; 34]);
+        assert_eq!(message.pst_type, pb::PostingType::PnlStmt as i32);
+    }
+
+    #[test]
+    fn test_malformed_fields_are_rejected() {
+        let mut short_hash = pb::PostingLine::from(create_line(create_account(None)));
+        short_hash.opr_id = vec![1; 32];
+        let mut bad_amount = pb::PostingLine::from(create_line(create_account(None)));
+        bad_amount.credit_amount = "ten".to_string();
+        let mut no_category = pb::LedgerAccount::from(create_account(None));
+        no_category.category = pb::AccountCategory::Unspecified as i32;
+
+        assert_eq!(PostingLine::try_from(short_hash).unwrap_err().field, "line.opr_id");
+        assert_eq!(PostingLine::try_from(bad_amount).unwrap_err().field, "line.credit_amount");
+        assert_eq!(LedgerAccount::try_from(no_category).unwrap_err().field, "category");
+    }
+}
@@ -0,0 +1,32 @@
+//! gRPC for the postings services, generated from `proto/postings/v1/postings.proto` with tonic.
+//!
+//! As with the HTTP crate, the backend behind the service traits is chosen by whoever builds
+//! [`GrpcServices`].
+
+use tokio::net::TcpListener;
+use tokio_stream::wrappers::TcpListenerStream;
+use tonic::transport::Server;
+
+pub mod convert;
+pub mod server;
+pub mod status;
+
+pub use server::GrpcServices;
+
+/// Generated messages, clients and server traits.
+pub mod pb {
+    tonic::include_proto!("postings.v1");
+}
+
+/// Serves the ledger, posting and statement services on `listener` until the server fails.
+pub async fn serve(listener: TcpListener, services: GrpcServices) -> Result<(), tonic::transport::Error> {
+    if let Ok(addr) = listener.local_addr() {
+        log::info!("postings-grpc listening on {addr}");
+    }
+    Server::builder()
+        .add_service(pb::ledger_service_server::LedgerServiceServer::new(services.clone()))
+        .add_service(pb::posting_service_server::PostingServiceServer::new(services.clone()))
+        .add_service(pb::statement_service_server::StatementServiceServer::new(services))
+        .serve_with_incoming(TcpListenerStream::new(listener))
+        .await
+}
@@ -0,0 +1,168 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use futures_util::{stream, Stream};
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::ledger_service::LedgerService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::ServiceError;
+use tonic::{Request, Response, Status};
+
+use crate::convert::{parse_hash, parse_opt_time, parse_time_axis, parse_uuid};
+use crate::pb;
+use crate::status::service_status;
+
+/// Services the gRPC handlers delegate to.
+#[derive(Clone)]
+pub struct GrpcServices {
+    pub ledger: Arc<dyn LedgerService + Send + Sync>,
+    pub posting: Arc<dyn PostingService + Send + Sync>,
+    pub stmt: Arc<dyn AccountStmtService + Send + Sync>,
+    /// Lines fetched per page while streaming statement lines.
+    pub stream_page_size: usize,
+}
+
+impl GrpcServices {
+    pub fn new(
+        ledger: Arc<dyn LedgerService + Send + Sync>,
+        posting: Arc<dyn PostingService + Send + Sync>,
+        stmt: Arc<dyn AccountStmtService + Send + Sync>,
+    ) -> Self {
+        Self { ledger, posting, stmt, stream_page_size: 100 }
+    }
+
+    pub fn with_stream_page_size(mut self, stream_page_size: usize) -> Self {
+        self.stream_page_size = stream_page_size.max(1);
+        self
+    }
+
+    async fn load_account(&self, id: &str) -> Result<LedgerAccount, Status> {
+        let id = parse_uuid("account_id", id)?;
+        self.ledger
+            .find_ledger_account_by_id(id)
+            .await
+            .map_err(service_status)?
+            .ok_or_else(|| service_status(ServiceError::LedgerAccountNotFound))
+    }
+}
+
+#[tonic::async_trait]
+impl pb::ledger_service_server::LedgerService for GrpcServices {
+    async fn get_ledger(&self, request: Request<pb::GetLedgerRequest>) -> Result<Response<pb::Ledger>, Status> {
+        let id = parse_uuid("id", &request.into_inner().id)?;
+        let ledger = self.ledger.find_ledger_by_id(id).await.map_err(service_status)?.ok_or_else(|| service_status(ServiceError::LedgerNotFound))?;
+        Ok(Response::new(ledger.into()))
+    }
+
+    async fn get_account(&self, request: Request<pb::GetAccountRequest>) -> Result<Response<pb::LedgerAccount>, Status> {
+        Ok(Response::new(self.load_account(&request.into_inner().id).await?.into()))
+    }
+
+    async fn get_account_by_code(&self, request: Request<pb::GetAccountByCodeRequest>) -> Result<Response<pb::LedgerAccount>, Status> {
+        let request = request.into_inner();
+        let ledger_id = parse_uuid("ledger_id", &request.ledger_id)?;
+        let ledger = self.ledger.find_ledger_by_id(ledger_id).await.map_err(service_status)?.ok_or_else(|| service_status(ServiceError::LedgerNotFound))?;
+        let account = self
+            .ledger
+            .find_ledger_account_by_code(&ledger, &request.code)
+            .await
+            .map_err(service_status)?
+            .ok_or_else(|| service_status(ServiceError::LedgerAccountNotFound))?;
+        Ok(Response::new(account.into()))
+    }
+}
+
+#[tonic::async_trait]
+impl pb::posting_service_server::PostingService for GrpcServices {
+    async fn create_posting(&self, request: Request<pb::Posting>) -> Result<Response<pb::Posting>, Status> {
+        let posting = Posting::try_from(request.into_inner())?;
+        Ok(Response::new(self.posting.new_posting(posting).await.map_err(service_status)?.into()))
+    }
+
+    async fn approve_posting(&self, request: Request<pb::ApprovePostingRequest>) -> Result<Response<pb::Posting>, Status> {
+        let request = request.into_inner();
+        let id = parse_uuid("id", &request.id)?;
+        let approver = parse_hash("approver", request.approver)?;
+        Ok(Response::new(self.posting.approve_posting(id, approver).await.map_err(service_status)?.into()))
+    }
+
+    async fn cancel_posting(&self, request: Request<pb::CancelPostingRequest>) -> Result<Response<pb::Posting>, Status> {
+        let id = parse_uuid("id", &request.into_inner().id)?;
+        Ok(Response::new(self.posting.cancel_posting(id).await.map_err(service_status)?.into()))
+    }
+
+    async fn list_pending_postings(&self, request: Request<pb::ListPendingPostingsRequest>) -> Result<Response<pb::PostingList>, Status> {
+        let ledger_id = parse_uuid("ledger_id", &request.into_inner().ledger_id)?;
+        let postings = self.posting.find_pending_postings(ledger_id).await.map_err(service_status)?;
+        Ok(Response::new(pb::PostingList { postings: postings.into_iter().map(Into::into).collect() }))
+    }
+}
+
+pub type StatementLineStream = Pin<Box<dyn Stream<Item = Result<pb::PostingLine, Status>> + Send>>;
+
+/// Position of a statement line stream: the lines of the current page not yet sent and the next
+/// page to read, or `None` once the last page has been read.
+struct LinePages {
+    posting: Arc<dyn PostingService + Send + Sync>,
+    account: LedgerAccount,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    page_size: usize,
+    buffered: VecDeque<PostingLine>,
+    next_page: Option<usize>,
+}
+
+impl LinePages {
+    async fn next(mut self) -> Option<(Result<pb::PostingLine, Status>, Self)> {
+        loop {
+            if let Some(line) = self.buffered.pop_front() {
+                return Some((Ok(line.into()), self));
+            }
+            let page = self.next_page?;
+            match self.posting.find_postings_by_dates_paged(self.account.clone(), self.from, self.to, page, self.page_size).await {
+                Ok(lines) => {
+                    self.next_page = (lines.content.len() == self.page_size).then_some(page + 1);
+                    self.buffered = lines.content.into();
+                }
+                Err(e) => {
+                    self.next_page = None;
+                    return Some((Err(service_status(e)), self));
+                }
+            }
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl pb::statement_service_server::StatementService for GrpcServices {
+    async fn get_statement(&self, request: Request<pb::GetStatementRequest>) -> Result<Response<pb::AccountStmt>, Status> {
+        let request = request.into_inner();
+        let account = self.load_account(&request.account_id).await?;
+        let at = parse_opt_time("at", request.at)?.unwrap_or_else(Utc::now);
+        let axis = parse_time_axis(request.axis)?;
+        let stmt = self.stmt.read_stmt_by_time_axis(account, at, axis).await.map_err(service_status)?;
+        Ok(Response::new(stmt.into()))
+    }
+
+    type StreamStatementLinesStream = StatementLineStream;
+
+    /// Reads the lines a page at a time as the client consumes them.
+    async fn stream_statement_lines(&self, request: Request<pb::StatementLinesRequest>) -> Result<Response<Self::StreamStatementLinesStream>, Status> {
+        let request = request.into_inner();
+        let pages = LinePages {
+            posting: self.posting.clone(),
+            account: self.load_account(&request.account_id).await?,
+            from: parse_opt_time("from", request.from)?.unwrap_or(DateTime::UNIX_EPOCH),
+            to: parse_opt_time("to", request.to)?.unwrap_or_else(Utc::now),
+            page_size: self.stream_page_size,
+            buffered: VecDeque::new(),
+            next_page: Some(0),
+        };
+        Ok(Response::new(Box::pin(stream::unfold(pages, LinePages::next))))
+    }
+}
@@ -0,0 +1,91 @@
+use postings_api::{DbFailure, ServiceError};
+use tonic::{Code, Status};
+
+/// Status of a failed call. Not found maps to `NOT_FOUND`, duplicates to `ALREADY_EXISTS`, a
+/// conflict with the current state of the books to `FAILED_PRECONDITION` and a request the books
+/// can never accept to `INVALID_ARGUMENT`. A lost database connection maps to `UNAVAILABLE` and a
+/// lost serialization conflict to `ABORTED`, both of which clients may retry.
+pub fn service_status(e: ServiceError) -> Status {
+    use ServiceError::*;
+    let code = match &e {
+        Db { failure: DbFailure::Connection, .. } => Code::Unavailable,
+        Db { failure: DbFailure::Conflict, .. } => Code::Aborted,
+        Db { failure: DbFailure::UniqueViolation, .. } => Code::AlreadyExists,
+        Db { failure: DbFailure::ForeignKeyViolation, .. } => Code::FailedPrecondition,
+        Db { .. } => Code::Internal,
+        ChartOfAccountNotFound | LedgerAccountNotFound | LedgerNotFound | PostingNotFound | StatementNotFound | NamedNotFound | HoldNotFound | ScheduleNotFound => Code::NotFound,
+        DuplicateName | DuplicateAccountCode => Code::AlreadyExists,
+        StatementAlreadyClosed
+        | InvalidStatusTransition
+        | AccountDebitBlocked
+        | AccountCreditBlocked
+        | AccountFrozen
+        | AccountClosed
+        | AccountBalanceNotZero
+        | OpenChildAccounts
+        | LimitExceeded { .. }
+        | HoldNotActive
+        | CaptureRequiresApproval
+        | ScheduleNotActive
+        | AccrualPeriodOverlap => Code::FailedPrecondition,
+        NotEnoughInfo
+        | ChartOfAccountMismatch
+        | DoubleEntry
+        | BaselineTime
+        | PostingTimeMissing
+        | NoCategory
+        | SelfApproval
+        | InvalidPeriod
+        | InvalidName
+        | InvalidAccountDefinition
+        | NonConformingAccount
+        | MissingAccountCode
+        | InvalidAccountCode
+        | InvalidCoaFile
+        | InvalidAccountLimit
+        | LedgerMismatch
+        | InvalidHoldAmount
+        | InvalidRecurrence => Code::InvalidArgument,
+    };
+    if matches!(code, Code::Internal | Code::Unavailable | Code::Aborted) {
+        log::error!("Call failed: {e:?}");
+    }
+    Status::new(code, e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn db_error(failure: DbFailure) -> ServiceError {
+        ServiceError::Db {
+            failure,
+            constraint: None,
+            context: "saving posting".to_string(),
+            source: Box::new(std::io::Error::other("database failed")),
+        }
+    }
+
+    #[test]
+    fn database_failures_map_to_codes() {
+        // Arrange
+        let cases = [
+            (DbFailure::Connection, Code::Unavailable),
+            (DbFailure::Conflict, Code::Aborted),
+            (DbFailure::UniqueViolation, Code::AlreadyExists),
+            (DbFailure::ForeignKeyViolation, Code::FailedPrecondition),
+            (DbFailure::CheckViolation, Code::Internal),
+            (DbFailure::NotFound, Code::Internal),
+            (DbFailure::Other, Code::Internal),
+        ];
+
+        for (failure, code) in cases {
+            // Act
+            let status = service_status(db_error(failure));
+
+            // Assert
+            assert_eq!(status.code(), code, "{failure:?}");
+            assert_eq!(status.message(), "Database error while saving posting");
+        }
+    }
+}
@@ -0,0 +1,205 @@
+#![cfg(test)]
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::str::FromStr;
+    use std::sync::Arc;
+
+    use bigdecimal::BigDecimal;
+    use chrono::{TimeZone, Utc};
+    use sqlx::PgPool;
+    use tokio::net::TcpListener;
+    use tonic::transport::Channel;
+    use tonic::Code;
+    use uuid::Uuid;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::service::chart_of_account_service::ChartOfAccountService;
+    use postings_api::service::ledger_service::LedgerService;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_grpc::convert::timestamp;
+    use postings_grpc::pb;
+    use postings_grpc::pb::ledger_service_client::LedgerServiceClient;
+    use postings_grpc::pb::posting_service_client::PostingServiceClient;
+    use postings_grpc::pb::statement_service_client::StatementServiceClient;
+    use postings_grpc::GrpcServices;
+    use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::posting_service::PostingServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    fn create_ledger_service(pool: PgPool) -> LedgerServiceImpl {
+        LedgerServiceImpl::new(create_shared_service(pool.clone()), ChartOfAccountServiceImpl::new(create_shared_service(pool)))
+    }
+
+    /// Starts a server on a free local port and connects to it.
+    async fn start_server(pool: PgPool) -> anyhow::Result<Channel> {
+        let services = GrpcServices::new(
+            Arc::new(create_ledger_service(pool.clone())),
+            Arc::new(PostingServiceImpl::new(create_shared_service(pool.clone()))),
+            Arc::new(AccountStmtServiceImpl::new(create_shared_service(pool))),
+        )
+        .with_stream_page_size(1);
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        tokio::spawn(postings_grpc::serve(listener, services));
+        Ok(Channel::from_shared(format!("http://{addr}"))?.connect().await?)
+    }
+
+    /// Creates a ledger with a cash and a loan account; returns the ledger id.
+    async fn setup_ledger(pool: PgPool) -> anyhow::Result<Uuid> {
+        let coa_service = ChartOfAccountServiceImpl::new(create_shared_service(pool.clone()));
+        let (coa, _) = coa_service.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+        let ledger_service = create_ledger_service(pool);
+        let (ledger, _) = ledger_service.new_ledger(Ledger { id: Uuid::new_v4(), coa: coa.clone() }, vec![]).await?;
+        for (code, category) in [("1000", AccountCategory::AS), ("2000", AccountCategory::LI)] {
+            let account = LedgerAccount {
+                id: Uuid::new_v4(),
+                ledger: ledger.clone(),
+                parent: None,
+                coa: coa.clone(),
+                balance_side: category.default_bs(),
+                category,
+                code: Some(code.to_string()),
+            };
+            ledger_service.new_ledger_account(account, vec![]).await?;
+        }
+        Ok(ledger.id)
+    }
+
+    fn create_line(account: &pb::LedgerAccount, debit: &str, credit: &str, day: u32) -> pb::PostingLine {
+        let time = timestamp(Utc.with_ymd_and_hms(2025, 1, day, 10, 0, 0).unwrap());
+        pb::PostingLine {
+            id: Uuid::new_v4().to_string(),
+            account: Some(account.clone()),
+            debit_amount: debit.to_string(),
+            credit_amount: credit.to_string(),
+            record_time: Some(time),
+            opr_id: vec![1; 34],
+            pst_time: Some(time),
+            pst_type: pb::PostingType::BusiTx as i32,
+            pst_status: pb::PostingStatus::Posted as i32,
+            ..Default::default()
+        }
+    }
+
+    fn create_posting(ledger: &pb::Ledger, lines: Vec<pb::PostingLine>, day: u32) -> pb::Posting {
+        let time = timestamp(Utc.with_ymd_and_hms(2025, 1, day, 10, 0, 0).unwrap());
+        pb::Posting {
+            record_user: vec![3; 34],
+            opr_id: vec![1; 34],
+            opr_time: Some(time),
+            opr_type: vec![2; 34],
+            pst_time: Some(time),
+            pst_type: pb::PostingType::BusiTx as i32,
+            pst_status: pb::PostingStatus::Posted as i32,
+            ledger: Some(ledger.clone()),
+            lines,
+            ..Default::default()
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_posting_round_trips_through_server(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+
+        // Arrange
+        let ledger_id = setup_ledger(pool.clone()).await?;
+        let channel = start_server(pool).await?;
+        let mut ledgers = LedgerServiceClient::new(channel.clone());
+        let mut postings = PostingServiceClient::new(channel.clone());
+        let mut statements = StatementServiceClient::new(channel);
+        let ledger = ledgers.get_ledger(pb::GetLedgerRequest { id: ledger_id.to_string() }).await?.into_inner();
+        let cash = ledgers.get_account_by_code(pb::GetAccountByCodeRequest { ledger_id: ledger_id.to_string(), code: "1000".into() }).await?.into_inner();
+        let loan = ledgers.get_account_by_code(pb::GetAccountByCodeRequest { ledger_id: ledger_id.to_string(), code: "2000".into() }).await?.into_inner();
+
+        // Act
+        let mut created = Vec::new();
+        for (day, amount) in [(10, "100.25"), (11, "50")] {
+            let lines = vec![create_line(&cash, amount, "0", day), create_line(&loan, "0", amount, day)];
+            created.push(postings.create_posting(create_posting(&ledger, lines, day)).await?.into_inner());
+        }
+        let stmt = statements
+            .get_statement(pb::GetStatementRequest { account_id: cash.id.clone(), at: Some(timestamp(Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap())), axis: 0 })
+            .await?
+            .into_inner();
+        let mut stream = statements
+            .stream_statement_lines(pb::StatementLinesRequest { account_id: cash.id.clone(), from: None, to: Some(timestamp(Utc.with_ymd_and_hms(2025, 2, 1, 0, 0, 0).unwrap())) })
+            .await?
+            .into_inner();
+        let mut streamed = Vec::new();
+        while let Some(line) = stream.message().await? {
+            streamed.push(line);
+        }
+
+        // Assert
+        assert!(created.iter().all(|p| p.hash_record.as_ref().and_then(|h| h.hash.as_ref()).is_some_and(|h| h.len() == 34)));
+        assert_eq!(BigDecimal::from_str(&stmt.total_debit)?, BigDecimal::from_str("150.25")?);
+        assert_eq!(stmt.account.as_ref().map(|a| a.id.as_str()), Some(cash.id.as_str()));
+        let streamed_amounts: Vec<BigDecimal> = streamed.iter().map(|l| BigDecimal::from_str(&l.debit_amount)).collect::<Result<_, _>>()?;
+        assert_eq!(streamed_amounts, vec![BigDecimal::from(50), BigDecimal::from_str("100.25")?]);
+        assert!(streamed.iter().all(|l| l.hash.as_ref().is_some_and(|h| h.len() == 34)));
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_errors_are_mapped_to_status_codes(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+
+        // Arrange
+        let ledger_id = setup_ledger(pool.clone()).await?;
+        let channel = start_server(pool).await?;
+        let mut ledgers = LedgerServiceClient::new(channel.clone());
+        let mut postings = PostingServiceClient::new(channel);
+        let ledger = ledgers.get_ledger(pb::GetLedgerRequest { id: ledger_id.to_string() }).await?.into_inner();
+        let cash = ledgers.get_account_by_code(pb::GetAccountByCodeRequest { ledger_id: ledger_id.to_string(), code: "1000".into() }).await?.into_inner();
+        let loan = ledgers.get_account_by_code(pb::GetAccountByCodeRequest { ledger_id: ledger_id.to_string(), code: "2000".into() }).await?.into_inner();
+
+        // Act
+        let unknown = ledgers.get_ledger(pb::GetLedgerRequest { id: Uuid::new_v4().to_string() }).await.unwrap_err();
+        let malformed = ledgers.get_ledger(pb::GetLedgerRequest { id: "not-a-uuid".into() }).await.unwrap_err();
+        let unbalanced_lines = vec![create_line(&cash, "100", "0", 10), create_line(&loan, "0", "90", 10)];
+        let unbalanced = postings.create_posting(create_posting(&ledger, unbalanced_lines, 10)).await.unwrap_err();
+        let posted_lines = vec![create_line(&cash, "100", "0", 10), create_line(&loan, "0", "100", 10)];
+        let posted = postings.create_posting(create_posting(&ledger, posted_lines, 10)).await?.into_inner();
+        let cancelled = postings.cancel_posting(pb::CancelPostingRequest { id: posted.id }).await.unwrap_err();
+
+        // Assert
+        assert_eq!(unknown.code(), Code::NotFound);
+        assert_eq!(malformed.code(), Code::InvalidArgument);
+        assert_eq!(unbalanced.code(), Code::InvalidArgument);
+        assert_eq!(cancelled.code(), Code::FailedPrecondition);
+        Ok(())
+    }
+}
@@ -0,0 +1,211 @@
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, Utc};
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::named::{ContainerType, Named};
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::domain::time_axis::TimeAxis;
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use utoipa::{IntoParams, ToSchema};
+use uuid::Uuid;
+
+/// Name of a chart, ledger or account in one language.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NameInput {
+    pub name: String,
+    /// Two-letter language code.
+    pub language: String,
+    #[serde(default)]
+    pub short_desc: Option<String>,
+    #[serde(default)]
+    pub long_desc: Option<String>,
+}
+
+impl NameInput {
+    /// The service fills in id, creation time, container and context.
+    pub fn into_named(self, container_type: ContainerType) -> Named {
+        Named {
+            id: Uuid::nil(),
+            container: Uuid::nil(),
+            context: Uuid::nil(),
+            name: self.name,
+            language: self.language,
+            created: DateTime::UNIX_EPOCH,
+            user_details: [0; 34],
+            short_desc: self.short_desc,
+            long_desc: self.long_desc,
+            container_type,
+            retired: None,
+        }
+    }
+}
+
+pub(crate) fn into_named(names: Vec<NameInput>, container_type: ContainerType) -> Vec<Named> {
+    names.into_iter().map(|n| n.into_named(container_type.clone())).collect()
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NewChartRequest {
+    #[serde(default)]
+    pub names: Vec<NameInput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ChartResponse {
+    pub chart: ChartOfAccount,
+    pub names: Vec<Named>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, IntoParams)]
+pub struct ChartSearch {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NewAccountDefinitionRequest {
+    pub code: String,
+    pub name: String,
+    pub language: String,
+    pub category: AccountCategory,
+    pub balance_side: BalanceSide,
+    #[serde(default)]
+    pub parent_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NewLedgerRequest {
+    pub coa_id: Uuid,
+    #[serde(default)]
+    pub names: Vec<NameInput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct LedgerResponse {
+    pub ledger: Ledger,
+    pub names: Vec<Named>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct InstantiatedLedgerResponse {
+    pub ledger: Ledger,
+    pub accounts: Vec<LedgerAccount>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NewAccountRequest {
+    pub ledger_id: Uuid,
+    #[serde(default)]
+    pub parent_id: Option<Uuid>,
+    pub category: AccountCategory,
+    /// Defaults to the usual side of the category.
+    #[serde(default)]
+    pub balance_side: Option<BalanceSide>,
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub names: Vec<NameInput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct AccountResponse {
+    pub account: LedgerAccount,
+    pub names: Vec<Named>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, IntoParams)]
+pub struct CodeRange {
+    pub from: String,
+    pub to: String,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NewPostingLineRequest {
+    pub account_id: Uuid,
+    #[serde(default)]
+    #[schema(value_type = String)]
+    pub debit_amount: BigDecimal,
+    #[serde(default)]
+    #[schema(value_type = String)]
+    pub credit_amount: BigDecimal,
+    /// Hex of the 34-byte hash of the line details.
+    #[serde_as(as = "Option<serde_with::hex::Hex>")]
+    #[serde(default)]
+    #[schema(value_type = Option<String>)]
+    pub details: Option<[u8; 34]>,
+    #[serde(default)]
+    pub additional_information: Option<String>,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct NewPostingRequest {
+    pub ledger_id: Uuid,
+    /// Hex of the 34-byte hash of the operation id.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    #[schema(value_type = String)]
+    pub opr_id: [u8; 34],
+    /// Hex of the 34-byte hash of the operation type.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    #[schema(value_type = String)]
+    pub opr_type: [u8; 34],
+    /// Hex of the 34-byte hash of the recording user.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    #[schema(value_type = String)]
+    pub record_user: [u8; 34],
+    /// Defaults to the posting time.
+    #[serde(default)]
+    pub opr_time: Option<DateTime<Utc>>,
+    pub pst_time: DateTime<Utc>,
+    #[serde(default = "default_pst_type")]
+    pub pst_type: PostingType,
+    #[serde(default = "default_pst_status")]
+    pub pst_status: PostingStatus,
+    #[serde(default)]
+    pub val_time: Option<DateTime<Utc>>,
+    pub lines: Vec<NewPostingLineRequest>,
+}
+
+fn default_pst_type() -> PostingType {
+    PostingType::BusiTx
+}
+
+fn default_pst_status() -> PostingStatus {
+    PostingStatus::Posted
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ApproveRequest {
+    /// Hex of the 34-byte hash of the approving user.
+    #[serde_as(as = "serde_with::hex::Hex")]
+    #[schema(value_type = String)]
+    pub approver: [u8; 34],
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, IntoParams)]
+pub struct AtTime {
+    /// Defaults to the time of the request.
+    pub at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, IntoParams)]
+pub struct StatementQuery {
+    /// Defaults to the time of the request.
+    pub at: Option<DateTime<Utc>>,
+    /// Defaults to the posting time.
+    #[param(value_type = Option<String>)]
+    pub axis: Option<TimeAxis>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct CloseStatementRequest {
+    /// Defaults to the time of the request.
+    #[serde(default)]
+    pub at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,121 @@
+use axum::extract::rejection::{JsonRejection, PathRejection, QueryRejection};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use postings_api::{DbFailure, ServiceError};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Body of every error response.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ErrorBody {
+    /// Stable identifier of the error, e.g. `LedgerNotFound` or `DoubleEntry`.
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub body: ErrorBody,
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, code: &str, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: ErrorBody { code: code.to_string(), message: message.into() },
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::BAD_REQUEST, "InvalidRequest", message)
+    }
+}
+
+/// Status and code of each service error. Not found maps to 404, a conflict with the current
+/// state of the books to 409, and a request the books can never accept to 422. Database errors
+/// worth retrying map to 503 and constraint violations not caught by the services to 409.
+fn classify(e: &ServiceError) -> (StatusCode, &'static str) {
+    use ServiceError::*;
+    match e {
+        Db { failure, .. } if failure.is_retryable() => (StatusCode::SERVICE_UNAVAILABLE, "Db"),
+        Db { failure: DbFailure::UniqueViolation | DbFailure::ForeignKeyViolation, .. } => (StatusCode::CONFLICT, "Db"),
+        Db { .. } => (StatusCode::INTERNAL_SERVER_ERROR, "Db"),
+        ChartOfAccountNotFound => (StatusCode::NOT_FOUND, "ChartOfAccountNotFound"),
+        LedgerAccountNotFound => (StatusCode::NOT_FOUND, "LedgerAccountNotFound"),
+        LedgerNotFound => (StatusCode::NOT_FOUND, "LedgerNotFound"),
+        PostingNotFound => (StatusCode::NOT_FOUND, "PostingNotFound"),
+        StatementNotFound => (StatusCode::NOT_FOUND, "StatementNotFound"),
+        NamedNotFound => (StatusCode::NOT_FOUND, "NamedNotFound"),
+        HoldNotFound => (StatusCode::NOT_FOUND, "HoldNotFound"),
+        ScheduleNotFound => (StatusCode::NOT_FOUND, "ScheduleNotFound"),
+        DuplicateName => (StatusCode::CONFLICT, "DuplicateName"),
+        DuplicateAccountCode => (StatusCode::CONFLICT, "DuplicateAccountCode"),
+        StatementAlreadyClosed => (StatusCode::CONFLICT, "StatementAlreadyClosed"),
+        InvalidStatusTransition => (StatusCode::CONFLICT, "InvalidStatusTransition"),
+        AccountDebitBlocked => (StatusCode::CONFLICT, "AccountDebitBlocked"),
+        AccountCreditBlocked => (StatusCode::CONFLICT, "AccountCreditBlocked"),
+        AccountFrozen => (StatusCode::CONFLICT, "AccountFrozen"),
+        AccountClosed => (StatusCode::CONFLICT, "AccountClosed"),
+        AccountBalanceNotZero => (StatusCode::CONFLICT, "AccountBalanceNotZero"),
+        OpenChildAccounts => (StatusCode::CONFLICT, "OpenChildAccounts"),
+        LimitExceeded { .. } => (StatusCode::CONFLICT, "LimitExceeded"),
+        HoldNotActive => (StatusCode::CONFLICT, "HoldNotActive"),
+        CaptureRequiresApproval => (StatusCode::CONFLICT, "CaptureRequiresApproval"),
+        ScheduleNotActive => (StatusCode::CONFLICT, "ScheduleNotActive"),
+        AccrualPeriodOverlap => (StatusCode::CONFLICT, "AccrualPeriodOverlap"),
+        NotEnoughInfo => (StatusCode::UNPROCESSABLE_ENTITY, "NotEnoughInfo"),
+        ChartOfAccountMismatch => (StatusCode::UNPROCESSABLE_ENTITY, "ChartOfAccountMismatch"),
+        DoubleEntry => (StatusCode::UNPROCESSABLE_ENTITY, "DoubleEntry"),
+        BaselineTime => (StatusCode::UNPROCESSABLE_ENTITY, "BaselineTime"),
+        PostingTimeMissing => (StatusCode::UNPROCESSABLE_ENTITY, "PostingTimeMissing"),
+        NoCategory => (StatusCode::UNPROCESSABLE_ENTITY, "NoCategory"),
+        SelfApproval => (StatusCode::UNPROCESSABLE_ENTITY, "SelfApproval"),
+        InvalidPeriod => (StatusCode::UNPROCESSABLE_ENTITY, "InvalidPeriod"),
+        InvalidName => (StatusCode::UNPROCESSABLE_ENTITY, "InvalidName"),
+        InvalidAccountDefinition => (StatusCode::UNPROCESSABLE_ENTITY, "InvalidAccountDefinition"),
+        NonConformingAccount => (StatusCode::UNPROCESSABLE_ENTITY, "NonConformingAccount"),
+        MissingAccountCode => (StatusCode::UNPROCESSABLE_ENTITY, "MissingAccountCode"),
+        InvalidAccountCode => (StatusCode::UNPROCESSABLE_ENTITY, "InvalidAccountCode"),
+        InvalidCoaFile => (StatusCode::UNPROCESSABLE_ENTITY, "InvalidCoaFile"),
+        InvalidAccountLimit => (StatusCode::UNPROCESSABLE_ENTITY, "InvalidAccountLimit"),
+        LedgerMismatch => (StatusCode::UNPROCESSABLE_ENTITY, "LedgerMismatch"),
+        InvalidHoldAmount => (StatusCode::UNPROCESSABLE_ENTITY, "InvalidHoldAmount"),
+        InvalidRecurrence => (StatusCode::UNPROCESSABLE_ENTITY, "InvalidRecurrence"),
+    }
+}
+
+impl From<ServiceError> for ApiError {
+    fn from(e: ServiceError) -> Self {
+        let (status, code) = classify(&e);
+        if status.is_server_error() {
+            log::error!("Request failed: {e:?}");
+        }
+        Self::new(status, code, e.to_string())
+    }
+}
+
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        Self::bad_request(rejection.body_text())
+    }
+}
+
+impl From<PathRejection> for ApiError {
+    fn from(rejection: PathRejection) -> Self {
+        Self::bad_request(rejection.body_text())
+    }
+}
+
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        Self::bad_request(rejection.body_text())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.status, Json(self.body)).into_response()
+    }
+}
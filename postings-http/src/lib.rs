@@ -0,0 +1,77 @@
+//! JSON over HTTP for the postings services.
+//!
+//! The crate only translates between HTTP and the service traits of `postings-api`; which
+//! backend sits behind them is decided by whoever builds [`HttpServices`].
+
+use std::sync::Arc;
+
+use axum::routing::{get, post};
+use axum::Router;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::chart_of_account_service::ChartOfAccountService;
+use postings_api::service::hold_service::HoldService;
+use postings_api::service::ledger_service::LedgerService;
+use postings_api::service::posting_service::PostingService;
+use tokio::net::TcpListener;
+use utoipa::OpenApi;
+
+pub mod dto;
+pub mod error;
+pub mod extract;
+pub mod openapi;
+mod routes;
+
+use routes::{accounts, charts, ledgers, postings};
+
+/// Services the handlers delegate to.
+#[derive(Clone)]
+pub struct HttpServices {
+    pub chart_of_account: Arc<dyn ChartOfAccountService + Send + Sync>,
+    pub ledger: Arc<dyn LedgerService + Send + Sync>,
+    pub posting: Arc<dyn PostingService + Send + Sync>,
+    pub stmt: Arc<dyn AccountStmtService + Send + Sync>,
+    pub hold: Arc<dyn HoldService + Send + Sync>,
+}
+
+impl HttpServices {
+    pub fn new(
+        chart_of_account: Arc<dyn ChartOfAccountService + Send + Sync>,
+        ledger: Arc<dyn LedgerService + Send + Sync>,
+        posting: Arc<dyn PostingService + Send + Sync>,
+        stmt: Arc<dyn AccountStmtService + Send + Sync>,
+        hold: Arc<dyn HoldService + Send + Sync>,
+    ) -> Self {
+        Self { chart_of_account, ledger, posting, stmt, hold }
+    }
+}
+
+/// All endpoints plus `GET /openapi.json`.
+pub fn router(services: HttpServices) -> Router {
+    Router::new()
+        .route("/openapi.json", get(|| async { axum::Json(openapi::ApiDoc::openapi()) }))
+        .route("/charts", post(charts::create_chart).get(charts::find_charts))
+        .route("/charts/{id}", get(charts::get_chart))
+        .route("/charts/{id}/definitions", post(charts::add_definitions).get(charts::get_definitions))
+        .route("/charts/{id}/ledgers", post(charts::instantiate_ledger))
+        .route("/ledgers", post(ledgers::create_ledger))
+        .route("/ledgers/{id}", get(ledgers::get_ledger))
+        .route("/ledgers/{id}/accounts", get(ledgers::find_accounts_by_code_range))
+        .route("/ledgers/{id}/accounts/{code}", get(ledgers::get_account_by_code))
+        .route("/ledgers/{id}/postings/pending", get(ledgers::find_pending_postings))
+        .route("/accounts", post(accounts::create_account))
+        .route("/accounts/{id}", get(accounts::get_account))
+        .route("/accounts/{id}/balance", get(accounts::get_balance))
+        .route("/accounts/{id}/statement", get(accounts::get_statement))
+        .route("/accounts/{id}/statement/close", post(accounts::close_statement))
+        .route("/postings", post(postings::create_posting))
+        .route("/postings/batch", post(postings::create_postings))
+        .route("/postings/{id}/approve", post(postings::approve_posting))
+        .route("/postings/{id}/cancel", post(postings::cancel_posting))
+        .with_state(services)
+}
+
+/// Serves [`router`] on `listener` until the server fails.
+pub async fn serve(listener: TcpListener, services: HttpServices) -> std::io::Result<()> {
+    log::info!("postings-http listening on {:?}", listener.local_addr()?);
+    axum::serve(listener, router(services)).await
+}
@@ -0,0 +1,38 @@
+use utoipa::OpenApi;
+
+use crate::routes::{accounts, charts, ledgers, postings};
+
+/// OpenAPI description of the HTTP API, served at `/openapi.json`.
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "postings", description = "Double-entry ledger over HTTP"),
+    paths(
+        charts::create_chart,
+        charts::find_charts,
+        charts::get_chart,
+        charts::add_definitions,
+        charts::get_definitions,
+        charts::instantiate_ledger,
+        ledgers::create_ledger,
+        ledgers::get_ledger,
+        ledgers::find_accounts_by_code_range,
+        ledgers::get_account_by_code,
+        ledgers::find_pending_postings,
+        accounts::create_account,
+        accounts::get_account,
+        accounts::get_balance,
+        accounts::get_statement,
+        accounts::close_statement,
+        postings::create_posting,
+        postings::create_postings,
+        postings::approve_posting,
+        postings::cancel_posting,
+    ),
+    tags(
+        (name = "charts", description = "Charts of accounts and their account definitions"),
+        (name = "ledgers", description = "Ledgers and account lookup"),
+        (name = "accounts", description = "Ledger accounts, balances and statements"),
+        (name = "postings", description = "Booking, approving and cancelling postings"),
+    )
+)]
+pub struct ApiDoc;
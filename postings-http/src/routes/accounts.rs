@@ -0,0 +1,86 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use chrono::Utc;
+use postings_api::domain::account_balance::AccountBalance;
+use postings_api::domain::account_stmt::AccountStmt;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::named::ContainerType;
+use postings_api::ServiceError;
+use uuid::Uuid;
+
+use crate::dto::{into_named, AccountResponse, AtTime, CloseStatementRequest, NewAccountRequest, StatementQuery};
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::{ApiJson, ApiPath, ApiQuery};
+use crate::routes::ledgers::load_ledger;
+use crate::HttpServices;
+
+pub(crate) async fn load_account(services: &HttpServices, id: Uuid) -> Result<LedgerAccount, ApiError> {
+    Ok(services.ledger.find_ledger_account_by_id(id).await?.ok_or(ServiceError::LedgerAccountNotFound)?)
+}
+
+#[utoipa::path(post, path = "/accounts", tag = "accounts", request_body = NewAccountRequest,
+    responses((status = 201, body = AccountResponse), (status = 404, body = ErrorBody), (status = 409, body = ErrorBody), (status = 422, body = ErrorBody)))]
+pub async fn create_account(
+    State(services): State<HttpServices>,
+    ApiJson(request): ApiJson<NewAccountRequest>,
+) -> Result<(StatusCode, ApiJson<AccountResponse>), ApiError> {
+    let ledger = load_ledger(&services, request.ledger_id).await?;
+    let parent = match request.parent_id {
+        Some(parent_id) => Some(Box::new(load_account(&services, parent_id).await?)),
+        None => None,
+    };
+    let account = LedgerAccount {
+        id: Uuid::nil(),
+        coa: ledger.coa.clone(),
+        ledger,
+        parent,
+        balance_side: request.balance_side.unwrap_or_else(|| request.category.default_bs()),
+        category: request.category,
+        code: request.code,
+    };
+    let (account, names) = services.ledger.new_ledger_account(account, into_named(request.names, ContainerType::LedgerAccount)).await?;
+    Ok((StatusCode::CREATED, ApiJson(AccountResponse { account, names })))
+}
+
+#[utoipa::path(get, path = "/accounts/{id}", tag = "accounts", params(("id" = Uuid, Path)),
+    responses((status = 200, body = LedgerAccount), (status = 404, body = ErrorBody)))]
+pub async fn get_account(State(services): State<HttpServices>, ApiPath(id): ApiPath<Uuid>) -> Result<ApiJson<LedgerAccount>, ApiError> {
+    Ok(ApiJson(load_account(&services, id).await?))
+}
+
+#[utoipa::path(get, path = "/accounts/{id}/balance", tag = "accounts", params(("id" = Uuid, Path), AtTime),
+    responses((status = 200, body = AccountBalance), (status = 404, body = ErrorBody)))]
+pub async fn get_balance(
+    State(services): State<HttpServices>,
+    ApiPath(id): ApiPath<Uuid>,
+    ApiQuery(query): ApiQuery<AtTime>,
+) -> Result<ApiJson<AccountBalance>, ApiError> {
+    let at = query.at.unwrap_or_else(Utc::now);
+    Ok(ApiJson(services.hold.find_account_balance(id, at).await?))
+}
+
+#[utoipa::path(get, path = "/accounts/{id}/statement", tag = "accounts", params(("id" = Uuid, Path), StatementQuery),
+    responses((status = 200, body = AccountStmt), (status = 404, body = ErrorBody)))]
+pub async fn get_statement(
+    State(services): State<HttpServices>,
+    ApiPath(id): ApiPath<Uuid>,
+    ApiQuery(query): ApiQuery<StatementQuery>,
+) -> Result<ApiJson<AccountStmt>, ApiError> {
+    let account = load_account(&services, id).await?;
+    let at = query.at.unwrap_or_else(Utc::now);
+    Ok(ApiJson(services.stmt.read_stmt_by_time_axis(account, at, query.axis.unwrap_or_default()).await?))
+}
+
+/// Closes the account's statement at the given time; later postings cannot be booked before it.
+#[utoipa::path(post, path = "/accounts/{id}/statement/close", tag = "accounts", params(("id" = Uuid, Path)),
+    request_body = CloseStatementRequest,
+    responses((status = 200, body = AccountStmt), (status = 404, body = ErrorBody), (status = 409, body = ErrorBody)))]
+pub async fn close_statement(
+    State(services): State<HttpServices>,
+    ApiPath(id): ApiPath<Uuid>,
+    ApiJson(request): ApiJson<CloseStatementRequest>,
+) -> Result<ApiJson<AccountStmt>, ApiError> {
+    let account = load_account(&services, id).await?;
+    let stmt = services.stmt.create_stmt(account, request.at.unwrap_or_else(Utc::now)).await?;
+    Ok(ApiJson(services.stmt.close_stmt(stmt).await?))
+}
@@ -0,0 +1,81 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::coa_account_definition::CoaAccountDefinition;
+use postings_api::domain::named::ContainerType;
+use postings_api::ServiceError;
+use uuid::Uuid;
+
+use crate::dto::{into_named, ChartResponse, ChartSearch, InstantiatedLedgerResponse, NameInput, NewAccountDefinitionRequest, NewChartRequest};
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::{ApiJson, ApiPath, ApiQuery};
+use crate::HttpServices;
+
+#[utoipa::path(post, path = "/charts", tag = "charts", request_body = NewChartRequest,
+    responses((status = 201, body = ChartResponse), (status = 409, body = ErrorBody)))]
+pub async fn create_chart(
+    State(services): State<HttpServices>,
+    ApiJson(request): ApiJson<NewChartRequest>,
+) -> Result<(StatusCode, ApiJson<ChartResponse>), ApiError> {
+    let named = into_named(request.names, ContainerType::ChartOfAccount);
+    let (chart, names) = services.chart_of_account.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, named).await?;
+    Ok((StatusCode::CREATED, ApiJson(ChartResponse { chart, names })))
+}
+
+#[utoipa::path(get, path = "/charts/{id}", tag = "charts", params(("id" = Uuid, Path)),
+    responses((status = 200, body = ChartOfAccount), (status = 404, body = ErrorBody)))]
+pub async fn get_chart(State(services): State<HttpServices>, ApiPath(id): ApiPath<Uuid>) -> Result<ApiJson<ChartOfAccount>, ApiError> {
+    let chart = services.chart_of_account.find_chart_of_accounts_by_id(id).await?.ok_or(ServiceError::ChartOfAccountNotFound)?;
+    Ok(ApiJson(chart))
+}
+
+#[utoipa::path(get, path = "/charts", tag = "charts", params(ChartSearch),
+    responses((status = 200, body = Vec<ChartOfAccount>)))]
+pub async fn find_charts(State(services): State<HttpServices>, ApiQuery(search): ApiQuery<ChartSearch>) -> Result<ApiJson<Vec<ChartOfAccount>>, ApiError> {
+    Ok(ApiJson(services.chart_of_account.find_chart_of_accounts_by_name(&search.name).await?))
+}
+
+#[utoipa::path(post, path = "/charts/{id}/definitions", tag = "charts", params(("id" = Uuid, Path)),
+    request_body = Vec<NewAccountDefinitionRequest>,
+    responses((status = 201, body = Vec<CoaAccountDefinition>), (status = 422, body = ErrorBody)))]
+pub async fn add_definitions(
+    State(services): State<HttpServices>,
+    ApiPath(id): ApiPath<Uuid>,
+    ApiJson(request): ApiJson<Vec<NewAccountDefinitionRequest>>,
+) -> Result<(StatusCode, ApiJson<Vec<CoaAccountDefinition>>), ApiError> {
+    let coa = services.chart_of_account.find_chart_of_accounts_by_id(id).await?.ok_or(ServiceError::ChartOfAccountNotFound)?;
+    let definitions = request
+        .into_iter()
+        .map(|d| CoaAccountDefinition {
+            id: Uuid::nil(),
+            coa: coa.clone(),
+            code: d.code,
+            name: d.name,
+            language: d.language,
+            category: d.category,
+            balance_side: d.balance_side,
+            parent_code: d.parent_code,
+        })
+        .collect();
+    let saved = services.chart_of_account.add_account_definitions(id, definitions).await?;
+    Ok((StatusCode::CREATED, ApiJson(saved)))
+}
+
+#[utoipa::path(get, path = "/charts/{id}/definitions", tag = "charts", params(("id" = Uuid, Path)),
+    responses((status = 200, body = Vec<CoaAccountDefinition>)))]
+pub async fn get_definitions(State(services): State<HttpServices>, ApiPath(id): ApiPath<Uuid>) -> Result<ApiJson<Vec<CoaAccountDefinition>>, ApiError> {
+    Ok(ApiJson(services.chart_of_account.find_account_definitions(id).await?))
+}
+
+/// Creates a ledger with one account per account definition of the chart.
+#[utoipa::path(post, path = "/charts/{id}/ledgers", tag = "charts", params(("id" = Uuid, Path)),
+    request_body = Vec<NameInput>,
+    responses((status = 201, body = InstantiatedLedgerResponse), (status = 404, body = ErrorBody)))]
+pub async fn instantiate_ledger(
+    State(services): State<HttpServices>,
+    ApiPath(id): ApiPath<Uuid>,
+    ApiJson(names): ApiJson<Vec<NameInput>>,
+) -> Result<(StatusCode, ApiJson<InstantiatedLedgerResponse>), ApiError> {
+    let (ledger, accounts) = services.ledger.instantiate_ledger_from_coa(id, into_named(names, ContainerType::Ledger)).await?;
+    Ok((StatusCode::CREATED, ApiJson(InstantiatedLedgerResponse { ledger, accounts })))
+}
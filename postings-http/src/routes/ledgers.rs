@@ -0,0 +1,64 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::named::ContainerType;
+use postings_api::domain::posting::Posting;
+use postings_api::ServiceError;
+use uuid::Uuid;
+
+use crate::dto::{into_named, CodeRange, LedgerResponse, NewLedgerRequest};
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::{ApiJson, ApiPath, ApiQuery};
+use crate::HttpServices;
+
+#[utoipa::path(post, path = "/ledgers", tag = "ledgers", request_body = NewLedgerRequest,
+    responses((status = 201, body = LedgerResponse), (status = 404, body = ErrorBody)))]
+pub async fn create_ledger(
+    State(services): State<HttpServices>,
+    ApiJson(request): ApiJson<NewLedgerRequest>,
+) -> Result<(StatusCode, ApiJson<LedgerResponse>), ApiError> {
+    let ledger = Ledger { id: Uuid::nil(), coa: ChartOfAccount { id: request.coa_id } };
+    let (ledger, names) = services.ledger.new_ledger(ledger, into_named(request.names, ContainerType::Ledger)).await?;
+    Ok((StatusCode::CREATED, ApiJson(LedgerResponse { ledger, names })))
+}
+
+pub(crate) async fn load_ledger(services: &HttpServices, id: Uuid) -> Result<Ledger, ApiError> {
+    Ok(services.ledger.find_ledger_by_id(id).await?.ok_or(ServiceError::LedgerNotFound)?)
+}
+
+#[utoipa::path(get, path = "/ledgers/{id}", tag = "ledgers", params(("id" = Uuid, Path)),
+    responses((status = 200, body = Ledger), (status = 404, body = ErrorBody)))]
+pub async fn get_ledger(State(services): State<HttpServices>, ApiPath(id): ApiPath<Uuid>) -> Result<ApiJson<Ledger>, ApiError> {
+    Ok(ApiJson(load_ledger(&services, id).await?))
+}
+
+#[utoipa::path(get, path = "/ledgers/{id}/accounts", tag = "ledgers", params(("id" = Uuid, Path), CodeRange),
+    responses((status = 200, body = Vec<LedgerAccount>), (status = 404, body = ErrorBody)))]
+pub async fn find_accounts_by_code_range(
+    State(services): State<HttpServices>,
+    ApiPath(id): ApiPath<Uuid>,
+    ApiQuery(range): ApiQuery<CodeRange>,
+) -> Result<ApiJson<Vec<LedgerAccount>>, ApiError> {
+    let ledger = load_ledger(&services, id).await?;
+    Ok(ApiJson(services.ledger.find_ledger_accounts_by_code_range(&ledger, &range.from, &range.to).await?))
+}
+
+#[utoipa::path(get, path = "/ledgers/{id}/accounts/{code}", tag = "ledgers", params(("id" = Uuid, Path), ("code" = String, Path)),
+    responses((status = 200, body = LedgerAccount), (status = 404, body = ErrorBody)))]
+pub async fn get_account_by_code(
+    State(services): State<HttpServices>,
+    ApiPath((id, code)): ApiPath<(Uuid, String)>,
+) -> Result<ApiJson<LedgerAccount>, ApiError> {
+    let ledger = load_ledger(&services, id).await?;
+    let account = services.ledger.find_ledger_account_by_code(&ledger, &code).await?.ok_or(ServiceError::LedgerAccountNotFound)?;
+    Ok(ApiJson(account))
+}
+
+/// Proposed postings waiting for approval, oldest first.
+#[utoipa::path(get, path = "/ledgers/{id}/postings/pending", tag = "ledgers", params(("id" = Uuid, Path)),
+    responses((status = 200, body = Vec<Posting>)))]
+pub async fn find_pending_postings(State(services): State<HttpServices>, ApiPath(id): ApiPath<Uuid>) -> Result<ApiJson<Vec<Posting>>, ApiError> {
+    Ok(ApiJson(services.posting.find_pending_postings(id).await?))
+}
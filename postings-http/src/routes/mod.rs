@@ -0,0 +1,4 @@
+pub mod accounts;
+pub mod charts;
+pub mod ledgers;
+pub mod postings;
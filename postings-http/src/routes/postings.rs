@@ -0,0 +1,102 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use chrono::DateTime;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use uuid::Uuid;
+
+use crate::dto::{ApproveRequest, NewPostingRequest};
+use crate::error::{ApiError, ErrorBody};
+use crate::extract::{ApiJson, ApiPath};
+use crate::routes::accounts::load_account;
+use crate::routes::ledgers::load_ledger;
+use crate::HttpServices;
+
+/// Resolves ledger and accounts of the request into a posting for the service, which assigns id,
+/// record time and hash chain.
+async fn to_posting(services: &HttpServices, request: NewPostingRequest) -> Result<Posting, ApiError> {
+    let ledger = load_ledger(services, request.ledger_id).await?;
+    let mut lines = Vec::with_capacity(request.lines.len());
+    for line in request.lines {
+        lines.push(PostingLine {
+            id: Uuid::nil(),
+            account: load_account(services, line.account_id).await?,
+            debit_amount: line.debit_amount,
+            credit_amount: line.credit_amount,
+            details: line.details,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: DateTime::UNIX_EPOCH,
+            opr_id: request.opr_id,
+            opr_src: None,
+            pst_time: request.pst_time,
+            val_time: request.val_time,
+            pst_type: request.pst_type.clone(),
+            pst_status: request.pst_status.clone(),
+            hash: None,
+            additional_information: line.additional_information,
+            discarded_time: None,
+        });
+    }
+    Ok(Posting {
+        id: Uuid::nil(),
+        record_user: request.record_user,
+        record_time: DateTime::UNIX_EPOCH,
+        opr_id: request.opr_id,
+        opr_time: request.opr_time.unwrap_or(request.pst_time),
+        opr_type: request.opr_type,
+        opr_details: None,
+        opr_src: None,
+        pst_time: request.pst_time,
+        pst_type: request.pst_type,
+        pst_status: request.pst_status,
+        ledger,
+        val_time: request.val_time,
+        lines,
+        discarded_id: None,
+        discarded_time: None,
+        discarding_id: None,
+        hash_record: Default::default(),
+    })
+}
+
+#[utoipa::path(post, path = "/postings", tag = "postings", request_body = NewPostingRequest,
+    responses((status = 201, body = Posting), (status = 404, body = ErrorBody), (status = 409, body = ErrorBody), (status = 422, body = ErrorBody)))]
+pub async fn create_posting(
+    State(services): State<HttpServices>,
+    ApiJson(request): ApiJson<NewPostingRequest>,
+) -> Result<(StatusCode, ApiJson<Posting>), ApiError> {
+    let posting = to_posting(&services, request).await?;
+    Ok((StatusCode::CREATED, ApiJson(services.posting.new_posting(posting).await?)))
+}
+
+/// Books all postings or none.
+#[utoipa::path(post, path = "/postings/batch", tag = "postings", request_body = Vec<NewPostingRequest>,
+    responses((status = 201, body = Vec<Posting>), (status = 404, body = ErrorBody), (status = 409, body = ErrorBody), (status = 422, body = ErrorBody)))]
+pub async fn create_postings(
+    State(services): State<HttpServices>,
+    ApiJson(requests): ApiJson<Vec<NewPostingRequest>>,
+) -> Result<(StatusCode, ApiJson<Vec<Posting>>), ApiError> {
+    let mut postings = Vec::with_capacity(requests.len());
+    for request in requests {
+        postings.push(to_posting(&services, request).await?);
+    }
+    Ok((StatusCode::CREATED, ApiJson(services.posting.new_postings(postings).await?)))
+}
+
+#[utoipa::path(post, path = "/postings/{id}/approve", tag = "postings", params(("id" = Uuid, Path)), request_body = ApproveRequest,
+    responses((status = 200, body = Posting), (status = 404, body = ErrorBody), (status = 409, body = ErrorBody), (status = 422, body = ErrorBody)))]
+pub async fn approve_posting(
+    State(services): State<HttpServices>,
+    ApiPath(id): ApiPath<Uuid>,
+    ApiJson(request): ApiJson<ApproveRequest>,
+) -> Result<ApiJson<Posting>, ApiError> {
+    Ok(ApiJson(services.posting.approve_posting(id, request.approver).await?))
+}
+
+#[utoipa::path(post, path = "/postings/{id}/cancel", tag = "postings", params(("id" = Uuid, Path)),
+    responses((status = 200, body = Posting), (status = 404, body = ErrorBody), (status = 409, body = ErrorBody)))]
+pub async fn cancel_posting(State(services): State<HttpServices>, ApiPath(id): ApiPath<Uuid>) -> Result<ApiJson<Posting>, ApiError> {
+    Ok(ApiJson(services.posting.cancel_posting(id).await?))
+}
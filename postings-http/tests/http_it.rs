@@ -0,0 +1,255 @@
+#![cfg(test)]
+
+use axum::http::StatusCode;
+use postings_api::{DbFailure, ServiceError};
+use postings_http::error::ApiError;
+use postings_http::openapi::ApiDoc;
+use utoipa::OpenApi;
+
+fn db_error(failure: DbFailure) -> ServiceError {
+    ServiceError::Db {
+        failure,
+        constraint: None,
+        context: "loading ledger".to_string(),
+        source: Box::new(std::io::Error::other("database failed")),
+    }
+}
+
+#[test]
+fn service_errors_map_to_status_codes() {
+    // Arrange
+    let cases = [
+        (ServiceError::LedgerNotFound, StatusCode::NOT_FOUND, "LedgerNotFound"),
+        (ServiceError::StatementAlreadyClosed, StatusCode::CONFLICT, "StatementAlreadyClosed"),
+        (ServiceError::DoubleEntry, StatusCode::UNPROCESSABLE_ENTITY, "DoubleEntry"),
+        (db_error(DbFailure::Other), StatusCode::INTERNAL_SERVER_ERROR, "Db"),
+        (db_error(DbFailure::CheckViolation), StatusCode::INTERNAL_SERVER_ERROR, "Db"),
+        (db_error(DbFailure::Connection), StatusCode::SERVICE_UNAVAILABLE, "Db"),
+        (db_error(DbFailure::Conflict), StatusCode::SERVICE_UNAVAILABLE, "Db"),
+        (db_error(DbFailure::UniqueViolation), StatusCode::CONFLICT, "Db"),
+        (db_error(DbFailure::ForeignKeyViolation), StatusCode::CONFLICT, "Db"),
+    ];
+
+    for (error, status, code) in cases {
+        // Act
+        let api_error = ApiError::from(error);
+
+        // Assert
+        assert_eq!(api_error.status, status);
+        assert_eq!(api_error.body.code, code);
+    }
+}
+
+#[test]
+fn openapi_document_describes_endpoints_and_domain_types() {
+    // Act
+    let doc = serde_json::to_value(ApiDoc::openapi()).unwrap();
+
+    // Assert
+    let paths = doc["paths"].as_object().unwrap();
+    assert!(paths.contains_key("/postings"));
+    assert!(paths.contains_key("/accounts/{id}/balance"));
+    assert!(paths.contains_key("/ledgers/{id}/accounts/{code}"));
+    let schemas = doc["components"]["schemas"].as_object().unwrap();
+    for name in ["Posting", "PostingLine", "LedgerAccount", "AccountStmt", "AccountBalance", "ErrorBody"] {
+        assert!(schemas.contains_key(name), "missing schema {name}");
+    }
+    assert_eq!(schemas["PostingLine"]["properties"]["debit_amount"]["type"], "string");
+}
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::sync::Arc;
+
+    use axum::body::Body;
+    use axum::http::{Method, Request, StatusCode};
+    use axum::Router;
+    use http_body_util::BodyExt;
+    use serde_json::{json, Value};
+    use sqlx::PgPool;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+    use tower::ServiceExt;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_http::HttpServices;
+    use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::hold_service::HoldServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::posting_service::PostingServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    fn create_services(pool: PgPool) -> HttpServices {
+        let ledger_service = || LedgerServiceImpl::new(create_shared_service(pool.clone()), ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())));
+        HttpServices::new(
+            Arc::new(ChartOfAccountServiceImpl::new(create_shared_service(pool.clone()))),
+            Arc::new(ledger_service()),
+            Arc::new(PostingServiceImpl::new(create_shared_service(pool.clone()))),
+            Arc::new(AccountStmtServiceImpl::new(create_shared_service(pool.clone()))),
+            Arc::new(HoldServiceImpl::new(create_shared_service(pool.clone()), ledger_service(), PostingServiceImpl::new(create_shared_service(pool.clone())))),
+        )
+    }
+
+    async fn send(app: &Router, method: Method, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+        let request = Request::builder().method(method).uri(uri).header("content-type", "application/json");
+        let request = match body {
+            Some(body) => request.body(Body::from(body.to_string())).unwrap(),
+            None => request.body(Body::empty()).unwrap(),
+        };
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = response.into_body().collect().await.unwrap().to_bytes();
+        let value = if bytes.is_empty() { Value::Null } else { serde_json::from_slice(&bytes).unwrap() };
+        (status, value)
+    }
+
+    fn hex34(byte: u8) -> String {
+        hex::encode([byte; 34])
+    }
+
+    /// Creates a chart, a ledger and a cash and a loan account; returns ledger and account ids.
+    async fn setup_ledger(app: &Router) -> (String, String, String) {
+        let (status, chart) = send(app, Method::POST, "/charts", Some(json!({ "names": [{ "name": "HTTP Chart", "language": "en" }] }))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let coa_id = chart["chart"]["id"].as_str().unwrap().to_string();
+
+        let (status, ledger) = send(app, Method::POST, "/ledgers", Some(json!({ "coa_id": coa_id, "names": [{ "name": "HTTP Ledger", "language": "en" }] }))).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let ledger_id = ledger["ledger"]["id"].as_str().unwrap().to_string();
+
+        let mut ids = Vec::new();
+        for (code, category, name) in [("1000", "AS", "Cash"), ("2000", "LI", "Loan")] {
+            let body = json!({ "ledger_id": ledger_id, "category": category, "code": code, "names": [{ "name": name, "language": "en" }] });
+            let (status, account) = send(app, Method::POST, "/accounts", Some(body)).await;
+            assert_eq!(status, StatusCode::CREATED, "{account}");
+            ids.push(account["account"]["id"].as_str().unwrap().to_string());
+        }
+        let loan = ids.pop().unwrap();
+        let cash = ids.pop().unwrap();
+        (ledger_id, cash, loan)
+    }
+
+    fn posting_body(ledger_id: &str, debit_account: &str, credit_account: &str, debit: &str, credit: &str) -> Value {
+        json!({
+            "ledger_id": ledger_id,
+            "opr_id": hex34(1),
+            "opr_type": hex34(2),
+            "record_user": hex34(3),
+            "pst_time": "2025-01-15T10:00:00Z",
+            "lines": [
+                { "account_id": debit_account, "debit_amount": debit },
+                { "account_id": credit_account, "debit_amount": "0", "credit_amount": credit },
+            ],
+        })
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_posting_is_reflected_in_balance_and_statement(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+
+        // Arrange
+        let app = postings_http::router(create_services(pool));
+        let (ledger_id, cash, loan) = setup_ledger(&app).await;
+
+        // Act
+        let (status, posting) = send(&app, Method::POST, "/postings", Some(posting_body(&ledger_id, &cash, &loan, "100", "100"))).await;
+
+        // Assert
+        assert_eq!(status, StatusCode::CREATED, "{posting}");
+        assert_eq!(posting["lines"].as_array().unwrap().len(), 2);
+        assert!(posting["hash"].is_string());
+
+        let (status, by_code) = send(&app, Method::GET, &format!("/ledgers/{ledger_id}/accounts/1000"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(by_code["id"], cash.as_str());
+
+        let (status, balance) = send(&app, Method::GET, &format!("/accounts/{cash}/balance?at=2025-02-01T00:00:00Z"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(balance["booked"].as_str().unwrap().parse::<f64>()?, 100.0);
+
+        let (status, stmt) = send(&app, Method::GET, &format!("/accounts/{loan}/statement?at=2025-02-01T00:00:00Z"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stmt["total_credit"].as_str().unwrap().parse::<f64>()?, 100.0);
+
+        let (status, closed) = send(&app, Method::POST, &format!("/accounts/{cash}/statement/close"), Some(json!({ "at": "2025-01-01T00:00:00Z" }))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(closed["stmt_status"], "CLOSED");
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_service_errors_are_mapped_to_status_codes(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+
+        // Arrange
+        let app = postings_http::router(create_services(pool));
+        let (ledger_id, cash, loan) = setup_ledger(&app).await;
+
+        // Act
+        let (unknown_status, unknown) = send(&app, Method::GET, &format!("/ledgers/{}", uuid::Uuid::new_v4()), None).await;
+        let (unbalanced_status, unbalanced) = send(&app, Method::POST, "/postings", Some(posting_body(&ledger_id, &cash, &loan, "100", "90"))).await;
+        let (malformed_status, _) = send(&app, Method::GET, "/ledgers/not-a-uuid", None).await;
+        let (_, posted) = send(&app, Method::POST, "/postings", Some(posting_body(&ledger_id, &cash, &loan, "100", "100"))).await;
+        let (cancel_status, cancel) = send(&app, Method::POST, &format!("/postings/{}/cancel", posted["id"].as_str().unwrap()), None).await;
+
+        // Assert
+        assert_eq!(unknown_status, StatusCode::NOT_FOUND);
+        assert_eq!(unknown["code"], "LedgerNotFound");
+        assert_eq!(unbalanced_status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(unbalanced["code"], "DoubleEntry");
+        assert_eq!(malformed_status, StatusCode::BAD_REQUEST);
+        assert_eq!(cancel_status, StatusCode::CONFLICT);
+        assert_eq!(cancel["code"], "InvalidStatusTransition");
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_server_serves_openapi_document(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").await?;
+        let addr = listener.local_addr()?;
+        let server = tokio::spawn(postings_http::serve(listener, create_services(pool)));
+
+        // Act
+        let mut stream = TcpStream::connect(addr).await?;
+        stream.write_all(b"GET /openapi.json HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n").await?;
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await?;
+
+        // Assert
+        assert!(response.starts_with("HTTP/1.1 200"));
+        assert!(response.contains("\"/charts/{id}/definitions\""));
+        server.abort();
+        Ok(())
+    }
+}
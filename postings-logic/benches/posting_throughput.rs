@@ -0,0 +1,184 @@
+//! Compares the throughput of `new_posting` called once per posting with a single
+//! `new_postings` batch. Requires a migrated PostgreSQL database reachable through
+//! `DATABASE_URL` (see `.env.postgres`):
+//!
+//! ```bash
+//! cargo bench -p postings-logic --features postgres_tests --bench posting_throughput
+//! ```
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use bigdecimal::BigDecimal;
+use chrono::Utc;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::service::posting_service::PostingService;
+use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+use postings_logic::services::posting_service::PostingServiceImpl;
+use postings_logic::services::shared_service::SharedService;
+
+const POSTINGS_PER_RUN: usize = 2_000;
+
+fn create_service(pool: PgPool) -> PostingServiceImpl {
+    let shared_service = SharedService::new(
+        Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+        Arc::new(PostgresLedgerRepository::new(pool.clone())),
+        Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+        Arc::new(PostgresNamedRepository::new(pool.clone())),
+        Arc::new(PostgresPostingRepository::new(pool.clone())),
+        Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+        Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+        Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+        Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+        Arc::new(PostgresHoldRepository::new(pool.clone())),
+        Arc::new(PostgresScheduleRepository::new(pool.clone())),
+        Arc::new(PostgresOutboxRepository::new(pool)),
+    );
+    PostingServiceImpl::new(shared_service)
+}
+
+async fn setup_accounts(pool: &PgPool) -> anyhow::Result<(LedgerAccount, LedgerAccount)> {
+    let coa = ChartOfAccount { id: Uuid::new_v4() };
+    sqlx::query("INSERT INTO chart_of_account (id) VALUES ($1)")
+        .bind(coa.id)
+        .execute(pool)
+        .await?;
+
+    let ledger = Ledger { id: Uuid::new_v4(), coa: coa.clone() };
+    sqlx::query("INSERT INTO ledger (id, coa_id) VALUES ($1, $2)")
+        .bind(ledger.id)
+        .bind(coa.id)
+        .execute(pool)
+        .await?;
+
+    let mut accounts = Vec::new();
+    for (balance_side, category) in [(BalanceSide::Dr, AccountCategory::AS), (BalanceSide::Cr, AccountCategory::LI)] {
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: coa.clone(),
+            balance_side,
+            category,
+            code: None,
+        };
+        sqlx::query("INSERT INTO ledger_account (id, ledger_id, coa_id, balance_side, category) VALUES ($1, $2, $3, $4::balance_side, $5::account_category)")
+            .bind(account.id)
+            .bind(ledger.id)
+            .bind(coa.id)
+            .bind(account.balance_side.to_string())
+            .bind(account.category.to_string())
+            .execute(pool)
+            .await?;
+        accounts.push(account);
+    }
+
+    let credit_account = accounts.pop().unwrap();
+    let debit_account = accounts.pop().unwrap();
+    Ok((debit_account, credit_account))
+}
+
+fn posting_line(account: &LedgerAccount, debit: i64, credit: i64) -> PostingLine {
+    let now = Utc::now();
+    PostingLine {
+        id: Uuid::new_v4(),
+        account: account.clone(),
+        debit_amount: BigDecimal::from(debit),
+        credit_amount: BigDecimal::from(credit),
+        details: None,
+        src_account: None,
+        base_line: None,
+        sub_opr_src_id: None,
+        record_time: now,
+        opr_id: [0; 34],
+        opr_src: None,
+        pst_time: now,
+        val_time: None,
+        pst_type: PostingType::BusiTx,
+        pst_status: PostingStatus::Posted,
+        hash: None,
+        additional_information: None,
+        discarded_time: None,
+    }
+}
+
+fn posting(debit_account: &LedgerAccount, credit_account: &LedgerAccount) -> Posting {
+    let now = Utc::now();
+    let mut opr_id = [0u8; 34];
+    opr_id[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+    Posting {
+        id: Uuid::nil(),
+        record_user: [0; 34],
+        record_time: now,
+        opr_id,
+        opr_time: now,
+        opr_type: [0; 34],
+        opr_details: None,
+        opr_src: None,
+        pst_time: now,
+        pst_type: PostingType::BusiTx,
+        pst_status: PostingStatus::Posted,
+        ledger: debit_account.ledger.clone(),
+        val_time: None,
+        lines: vec![
+            posting_line(debit_account, 100, 0),
+            posting_line(credit_account, 0, 100),
+        ],
+        discarded_id: None,
+        discarded_time: None,
+        discarding_id: None,
+        hash_record: Default::default(),
+    }
+}
+
+fn report(label: &str, elapsed: Duration) {
+    let per_second = POSTINGS_PER_RUN as f64 / elapsed.as_secs_f64();
+    println!("{label:<12} {POSTINGS_PER_RUN} postings in {elapsed:>10.2?} ({per_second:>10.0} postings/s)");
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenvy::from_filename(".env.postgres").ok();
+    let database_url = std::env::var("DATABASE_URL")?;
+    let pool = PgPool::connect(&database_url).await?;
+    let service = create_service(pool.clone());
+
+    let (debit_account, credit_account) = setup_accounts(&pool).await?;
+    let single: Vec<Posting> = (0..POSTINGS_PER_RUN).map(|_| posting(&debit_account, &credit_account)).collect();
+    let start = Instant::now();
+    for p in single {
+        service.new_posting(p).await?;
+    }
+    report("new_posting", start.elapsed());
+
+    let (debit_account, credit_account) = setup_accounts(&pool).await?;
+    let batch: Vec<Posting> = (0..POSTINGS_PER_RUN).map(|_| posting(&debit_account, &credit_account)).collect();
+    let start = Instant::now();
+    service.new_postings(batch).await?;
+    report("new_postings", start.elapsed());
+
+    pool.close().await;
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use std::hash::Hash;
+use std::time::Duration;
+
+use moka::future::Cache;
+
+/// Time to live of [`CacheConfig::default`]. It bounds how long an instance can serve stale
+/// entries when invalidation events from other instances do not reach it.
+pub const DEFAULT_TIME_TO_LIVE: Duration = Duration::from_secs(300);
+
+/// Size and lifetime of the entries of a caching repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CacheConfig {
+    /// Maximum number of entries per cache; the least recently used ones are evicted first.
+    pub max_capacity: u64,
+    /// Entries are dropped this long after they were loaded, bounding how stale they can get when
+    /// another instance writes to the same database. `None` keeps them until evicted or
+    /// invalidated, which is only safe for a single instance.
+    pub time_to_live: Option<Duration>,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            max_capacity: 1000,
+            time_to_live: Some(DEFAULT_TIME_TO_LIVE),
+        }
+    }
+}
+
+impl CacheConfig {
+    pub fn new(max_capacity: u64) -> Self {
+        Self { max_capacity, ..Self::default() }
+    }
+
+    pub fn with_time_to_live(mut self, time_to_live: Duration) -> Self {
+        self.time_to_live = Some(time_to_live);
+        self
+    }
+
+    pub(crate) fn build<K, V>(&self) -> Cache<K, V>
+    where
+        K: Hash + Eq + Send + Sync + 'static,
+        V: Clone + Send + Sync + 'static,
+    {
+        let builder = Cache::builder().max_capacity(self.max_capacity);
+        match self.time_to_live {
+            Some(time_to_live) => builder.time_to_live(time_to_live).build(),
+            None => builder.build(),
+        }
+    }
+}
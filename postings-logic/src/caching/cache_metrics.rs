@@ -0,0 +1,53 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Hit and miss counters of a caching repository, over all of its lookups.
+#[derive(Debug, Default)]
+pub struct CacheMetrics {
+    hits: AtomicU64,
+    l2_hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheMetrics {
+    pub(crate) fn hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn l2_hit(&self) {
+        self.l2_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            l2_hits: self.l2_hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Lookups answered by the in-process cache.
+    pub hits: u64,
+    /// Lookups answered by the L2 cache after missing the in-process one.
+    pub l2_hits: u64,
+    /// Lookups that went to the database.
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Share of lookups answered from either cache level, zero before the first lookup.
+    pub fn hit_ratio(&self) -> f64 {
+        let lookups = self.hits + self.l2_hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            (self.hits + self.l2_hits) as f64 / lookups as f64
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use std::fmt::Display;
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::warn;
+use moka::future::Cache;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::caching::cache_config::CacheConfig;
+use crate::caching::cache_metrics::CacheMetrics;
+use crate::caching::l2_cache::L2Cache;
+use crate::caching::l2_codec;
+
+struct L2Tier {
+    cache: Arc<dyn L2Cache + Send + Sync>,
+    namespace: &'static str,
+    time_to_live: Option<Duration>,
+}
+
+/// An in-process moka cache, backed by the L2 cache once one is attached. Lookups that miss the
+/// first level are tried on the second and, if found there, copied into the first.
+pub(crate) struct CacheTier<K, V> {
+    l1: Cache<K, V>,
+    l2: Option<L2Tier>,
+    time_to_live: Option<Duration>,
+}
+
+impl<K, V> CacheTier<K, V>
+where
+    K: Hash + Eq + Clone + Display + Send + Sync + 'static,
+    V: Clone + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+    pub(crate) fn new(config: &CacheConfig) -> Self {
+        Self {
+            l1: config.build(),
+            l2: None,
+            time_to_live: config.time_to_live,
+        }
+    }
+
+    /// Entries get the same time to live on both levels. Keys are stored in the L2 cache as
+    /// `postings:<namespace>:<key>`.
+    pub(crate) fn attach_l2(&mut self, cache: Arc<dyn L2Cache + Send + Sync>, namespace: &'static str) {
+        self.l2 = Some(L2Tier {
+            cache,
+            namespace,
+            time_to_live: self.time_to_live,
+        });
+    }
+
+    fn l2_key(l2: &L2Tier, key: &K) -> String {
+        format!("postings:{}:{}", l2.namespace, key)
+    }
+
+    pub(crate) async fn get(&self, key: &K, metrics: &CacheMetrics) -> Option<V> {
+        if let Some(value) = self.l1.get(key).await {
+            metrics.hit();
+            return Some(value);
+        }
+        if let Some(l2) = &self.l2 {
+            match l2.cache.get(&Self::l2_key(l2, key)).await {
+                Ok(Some(bytes)) => {
+                    if let Some(value) = l2_codec::decode::<V>(&bytes) {
+                        metrics.l2_hit();
+                        self.l1.insert(key.clone(), value.clone()).await;
+                        return Some(value);
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => warn!("L2 cache get failed, reading through: {}", e),
+            }
+        }
+        metrics.miss();
+        None
+    }
+
+    pub(crate) async fn insert(&self, key: K, value: V) {
+        if let Some(l2) = &self.l2 {
+            match l2_codec::encode(&value) {
+                Ok(bytes) => {
+                    if let Err(e) = l2.cache.set(&Self::l2_key(l2, &key), &bytes, l2.time_to_live).await {
+                        warn!("L2 cache set failed: {}", e);
+                    }
+                }
+                Err(e) => warn!("L2 cache value not serializable: {}", e),
+            }
+        }
+        self.l1.insert(key, value).await;
+    }
+
+    pub(crate) async fn invalidate(&self, key: &K) {
+        self.l1.invalidate(key).await;
+        if let Some(l2) = &self.l2 {
+            if let Err(e) = l2.cache.invalidate(&Self::l2_key(l2, key)).await {
+                warn!("L2 cache invalidation failed: {}", e);
+            }
+        }
+    }
+
+    /// Drops the entry from the first level only, for changes another instance made and already
+    /// removed from the shared second level.
+    pub(crate) async fn evict(&self, key: &K) {
+        self.l1.invalidate(key).await;
+    }
+
+    pub(crate) fn evict_all(&self) {
+        self.l1.invalidate_all();
+    }
+}
@@ -0,0 +1,190 @@
+use std::sync::Arc;
+use std::time::Duration;
+use log::{info, warn};
+use postings_db::invalidation_bus::{InvalidationBus, InvalidationEvent, InvalidationSubscription};
+use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::repositories::named_repository::NamedRepository;
+use postings_db::DbError;
+use tokio::task::JoinHandle;
+use crate::caching::cache_config::CacheConfig;
+use crate::caching::cache_metrics::CacheStats;
+use crate::caching::chart_of_account_repository::CachingChartOfAccountRepository;
+use crate::caching::l2_cache::L2Cache;
+use crate::caching::ledger_account_repository::CachingLedgerAccountRepository;
+use crate::caching::ledger_repository::CachingLedgerRepository;
+use crate::caching::named_repository::CachingNamedRepository;
+
+/// First wait before resubscribing to the invalidation bus; it doubles up to
+/// [`MAX_RESUBSCRIBE_DELAY`] while subscribing keeps failing.
+const FIRST_RESUBSCRIBE_DELAY: Duration = Duration::from_millis(100);
+const MAX_RESUBSCRIBE_DELAY: Duration = Duration::from_secs(30);
+
+/// The caching decorators of a backend's master data repositories, wired so that names saved
+/// along with a chart of accounts, ledger or account also clear the name caches.
+#[derive(Clone)]
+pub struct CachingRepositories {
+    pub coa_repo: Arc<CachingChartOfAccountRepository>,
+    pub ledger_repo: Arc<CachingLedgerRepository>,
+    pub ledger_account_repo: Arc<CachingLedgerAccountRepository>,
+    pub named_repo: Arc<CachingNamedRepository>,
+}
+
+impl CachingRepositories {
+    pub fn builder() -> CachingRepositoriesBuilder {
+        CachingRepositoriesBuilder::default()
+    }
+
+    pub fn stats(&self) -> CachingStats {
+        CachingStats {
+            coa: self.coa_repo.stats(),
+            ledger: self.ledger_repo.stats(),
+            ledger_account: self.ledger_account_repo.stats(),
+            named: self.named_repo.stats(),
+        }
+    }
+
+    /// Drops what an invalidation event names from the in-process caches.
+    pub async fn apply(&self, event: &InvalidationEvent) {
+        self.coa_repo.apply(event).await;
+        self.ledger_repo.apply(event).await;
+        self.ledger_account_repo.apply(event).await;
+        self.named_repo.apply(event).await;
+    }
+
+    /// Applies the subscription's events. When it ends, for instance because its connection was
+    /// lost, the caches are cleared and the bus is subscribed to again, with a growing delay while
+    /// that fails; once it succeeds the caches are cleared again, as events may have been missed
+    /// in between. Runs until the task is aborted.
+    pub async fn listen(&self, bus: Arc<dyn InvalidationBus + Send + Sync>, mut subscription: Box<dyn InvalidationSubscription + Send>) {
+        loop {
+            while let Some(event) = subscription.next().await {
+                self.apply(&event).await;
+            }
+            warn!("Cache invalidation subscription ended, clearing caches and subscribing again");
+            self.apply(&InvalidationEvent::All).await;
+            subscription = self.resubscribe(bus.as_ref()).await;
+            self.apply(&InvalidationEvent::All).await;
+        }
+    }
+
+    async fn resubscribe(&self, bus: &(dyn InvalidationBus + Send + Sync)) -> Box<dyn InvalidationSubscription + Send> {
+        let mut delay = FIRST_RESUBSCRIBE_DELAY;
+        loop {
+            tokio::time::sleep(delay).await;
+            match bus.subscribe().await {
+                Ok(subscription) => {
+                    info!("Cache invalidation subscription re-established");
+                    return subscription;
+                }
+                Err(e) => {
+                    delay = (delay * 2).min(MAX_RESUBSCRIBE_DELAY);
+                    warn!("Subscribing to cache invalidations failed, retrying in {:?}: {}", delay, e);
+                }
+            }
+        }
+    }
+
+    /// Subscribes to the bus and applies its events on a task of the current Tokio runtime,
+    /// resubscribing whenever the subscription ends. Events published once this returns are
+    /// applied.
+    pub async fn spawn_listener(&self, bus: Arc<dyn InvalidationBus + Send + Sync>) -> Result<JoinHandle<()>, DbError> {
+        let subscription = bus.subscribe().await?;
+        let caches = self.clone();
+        Ok(tokio::spawn(async move { caches.listen(bus, subscription).await }))
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CachingStats {
+    pub coa: CacheStats,
+    pub ledger: CacheStats,
+    pub ledger_account: CacheStats,
+    pub named: CacheStats,
+}
+
+/// Takes one [`CacheConfig`] for all repositories, which single repositories can override, and
+/// optionally an L2 cache they share and an invalidation bus they publish their saves to. Events
+/// from other instances are applied once [`CachingRepositories::spawn_listener`] is called.
+#[derive(Clone, Default)]
+pub struct CachingRepositoriesBuilder {
+    config: CacheConfig,
+    l2_cache: Option<Arc<dyn L2Cache + Send + Sync>>,
+    invalidation_bus: Option<Arc<dyn InvalidationBus + Send + Sync>>,
+    coa_config: Option<CacheConfig>,
+    ledger_config: Option<CacheConfig>,
+    ledger_account_config: Option<CacheConfig>,
+    named_config: Option<CacheConfig>,
+}
+
+impl CachingRepositoriesBuilder {
+    pub fn config(mut self, config: CacheConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    pub fn l2_cache(mut self, l2_cache: Arc<dyn L2Cache + Send + Sync>) -> Self {
+        self.l2_cache = Some(l2_cache);
+        self
+    }
+
+    pub fn invalidation_bus(mut self, invalidation_bus: Arc<dyn InvalidationBus + Send + Sync>) -> Self {
+        self.invalidation_bus = Some(invalidation_bus);
+        self
+    }
+
+    pub fn coa_config(mut self, config: CacheConfig) -> Self {
+        self.coa_config = Some(config);
+        self
+    }
+
+    pub fn ledger_config(mut self, config: CacheConfig) -> Self {
+        self.ledger_config = Some(config);
+        self
+    }
+
+    pub fn ledger_account_config(mut self, config: CacheConfig) -> Self {
+        self.ledger_account_config = Some(config);
+        self
+    }
+
+    pub fn named_config(mut self, config: CacheConfig) -> Self {
+        self.named_config = Some(config);
+        self
+    }
+
+    pub fn wrap(
+        self,
+        coa_repo: Arc<dyn ChartOfAccountRepository + Send + Sync>,
+        ledger_repo: Arc<dyn LedgerRepository + Send + Sync>,
+        ledger_account_repo: Arc<dyn LedgerAccountRepository + Send + Sync>,
+        named_repo: Arc<dyn NamedRepository + Send + Sync>,
+    ) -> CachingRepositories {
+        let config_for = |config: &Option<CacheConfig>| config.clone().unwrap_or_else(|| self.config.clone());
+        let mut named_repo = CachingNamedRepository::with_config(named_repo, &config_for(&self.named_config));
+        let mut coa_repo = CachingChartOfAccountRepository::with_config(coa_repo, &config_for(&self.coa_config));
+        let mut ledger_repo = CachingLedgerRepository::with_config(ledger_repo, &config_for(&self.ledger_config));
+        let mut ledger_account_repo = CachingLedgerAccountRepository::with_config(ledger_account_repo, &config_for(&self.ledger_account_config));
+        if let Some(l2_cache) = &self.l2_cache {
+            named_repo = named_repo.with_l2(l2_cache.clone());
+            coa_repo = coa_repo.with_l2(l2_cache.clone());
+            ledger_repo = ledger_repo.with_l2(l2_cache.clone());
+            ledger_account_repo = ledger_account_repo.with_l2(l2_cache.clone());
+        }
+        if let Some(bus) = &self.invalidation_bus {
+            named_repo = named_repo.with_invalidation_bus(bus.clone());
+            coa_repo = coa_repo.with_invalidation_bus(bus.clone());
+            ledger_repo = ledger_repo.with_invalidation_bus(bus.clone());
+            ledger_account_repo = ledger_account_repo.with_invalidation_bus(bus.clone());
+        }
+
+        let named_repo = Arc::new(named_repo);
+        CachingRepositories {
+            coa_repo: Arc::new(coa_repo.with_named_cache(named_repo.clone())),
+            ledger_repo: Arc::new(ledger_repo.with_named_cache(named_repo.clone())),
+            ledger_account_repo: Arc::new(ledger_account_repo.with_named_cache(named_repo.clone())),
+            named_repo,
+        }
+    }
+}
@@ -1,21 +1,80 @@
 use std::sync::Arc;
 use async_trait::async_trait;
-use moka::future::Cache;
 use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::coa_account_definition::CoaAccountDefinition;
+use postings_db::models::named::Named;
 use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::invalidation_bus::{InvalidationBus, InvalidationEvent};
 use postings_db::DbError;
 use uuid::Uuid;
+use crate::caching::cache_config::CacheConfig;
+use crate::caching::cache_metrics::{CacheMetrics, CacheStats};
+use crate::caching::cache_tier::CacheTier;
+use crate::caching::invalidation_publisher::InvalidationPublisher;
+use crate::caching::l2_cache::L2Cache;
+use crate::caching::named_repository::CachingNamedRepository;
 
 pub struct CachingChartOfAccountRepository {
     inner: Arc<dyn ChartOfAccountRepository + Send + Sync>,
-    cache_by_id: Cache<Uuid, ChartOfAccount>,
+    cache_by_id: CacheTier<Uuid, ChartOfAccount>,
+    metrics: CacheMetrics,
+    named_cache: Option<Arc<CachingNamedRepository>>,
+    publisher: InvalidationPublisher,
 }
 
 impl CachingChartOfAccountRepository {
     pub fn new(inner: Arc<dyn ChartOfAccountRepository + Send + Sync>) -> Self {
+        Self::with_config(inner, &CacheConfig::default())
+    }
+
+    pub fn with_config(inner: Arc<dyn ChartOfAccountRepository + Send + Sync>, config: &CacheConfig) -> Self {
         Self {
             inner,
-            cache_by_id: Cache::new(1000),
+            cache_by_id: CacheTier::new(config),
+            metrics: CacheMetrics::default(),
+            named_cache: None,
+            publisher: InvalidationPublisher::default(),
+        }
+    }
+
+    /// Clears the name caches when a chart of accounts is saved with its names.
+    pub fn with_named_cache(mut self, named_cache: Arc<CachingNamedRepository>) -> Self {
+        self.named_cache = Some(named_cache);
+        self
+    }
+
+    pub fn with_l2(mut self, l2: Arc<dyn L2Cache + Send + Sync>) -> Self {
+        self.cache_by_id.attach_l2(l2, "coa");
+        self
+    }
+
+    /// Tells the other instances on the bus about every saved chart of accounts.
+    pub fn with_invalidation_bus(mut self, bus: Arc<dyn InvalidationBus + Send + Sync>) -> Self {
+        self.publisher.set_bus(bus);
+        self
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.metrics.stats()
+    }
+
+    /// Drops what another instance changed from this instance's memory.
+    pub async fn apply(&self, event: &InvalidationEvent) {
+        match event {
+            InvalidationEvent::ChartOfAccount(id) => self.cache_by_id.evict(id).await,
+            InvalidationEvent::All => self.cache_by_id.evict_all(),
+            _ => {}
+        }
+    }
+
+    async fn invalidate(&self, id: Uuid) {
+        self.cache_by_id.invalidate(&id).await;
+        self.publisher.publish(InvalidationEvent::ChartOfAccount(id)).await;
+    }
+
+    async fn invalidate_named(&self, named: &[Named]) {
+        if let Some(named_cache) = &self.named_cache {
+            named_cache.invalidate(named).await;
         }
     }
 }
@@ -23,7 +82,7 @@ impl CachingChartOfAccountRepository {
 #[async_trait]
 impl ChartOfAccountRepository for CachingChartOfAccountRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<ChartOfAccount>, DbError> {
-        if let Some(coa) = self.cache_by_id.get(&id).await {
+        if let Some(coa) = self.cache_by_id.get(&id, &self.metrics).await {
             return Ok(Some(coa));
         }
 
@@ -36,7 +95,22 @@ impl ChartOfAccountRepository for CachingChartOfAccountRepository {
 
     async fn save(&self, coa: &ChartOfAccount) -> Result<(), DbError> {
         self.inner.save(coa).await?;
-        self.cache_by_id.invalidate(&coa.id).await;
+        self.invalidate(coa.id).await;
+        Ok(())
+    }
+
+    async fn save_with_named(&self, coa: &ChartOfAccount, named: &[Named]) -> Result<(), DbError> {
+        self.inner.save_with_named(coa, named).await?;
+        self.invalidate_named(named).await;
+        self.invalidate(coa.id).await;
         Ok(())
     }
+
+    async fn save_account_definitions(&self, definitions: &[CoaAccountDefinition]) -> Result<(), DbError> {
+        self.inner.save_account_definitions(definitions).await
+    }
+
+    async fn find_account_definitions_by_coa_id(&self, coa_id: Uuid) -> Result<Vec<CoaAccountDefinition>, DbError> {
+        self.inner.find_account_definitions_by_coa_id(coa_id).await
+    }
 }
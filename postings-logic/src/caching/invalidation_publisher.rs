@@ -0,0 +1,41 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use log::warn;
+use postings_db::invalidation_bus::{InvalidationBus, InvalidationEvent};
+
+/// Attempts per event, with a doubling delay between them starting at [`FIRST_RETRY_DELAY`].
+const PUBLISH_ATTEMPTS: u32 = 3;
+const FIRST_RETRY_DELAY: Duration = Duration::from_millis(50);
+
+/// Sends a caching repository's invalidations to the bus, if one is set. A failed send is retried
+/// a few times. The change is already committed by then, so a send that keeps failing is logged
+/// and other instances catch up when their entries expire, after at most the cache's time to live.
+#[derive(Clone, Default)]
+pub(crate) struct InvalidationPublisher {
+    bus: Option<Arc<dyn InvalidationBus + Send + Sync>>,
+}
+
+impl InvalidationPublisher {
+    pub(crate) fn set_bus(&mut self, bus: Arc<dyn InvalidationBus + Send + Sync>) {
+        self.bus = Some(bus);
+    }
+
+    pub(crate) async fn publish(&self, event: InvalidationEvent) {
+        let Some(bus) = &self.bus else {
+            return;
+        };
+        let mut delay = FIRST_RETRY_DELAY;
+        for attempt in 1..=PUBLISH_ATTEMPTS {
+            match bus.publish(&event).await {
+                Ok(()) => return,
+                Err(e) if attempt < PUBLISH_ATTEMPTS => {
+                    warn!("Publishing cache invalidation {} failed, retrying in {:?}: {}", event, delay, e);
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                Err(e) => warn!("Publishing cache invalidation {} failed, other instances keep it until it expires: {}", event, e),
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum L2CacheError {
+    #[error("L2 cache connection error: {0}")]
+    Connection(String),
+    #[error("L2 cache protocol error: {0}")]
+    Protocol(String),
+}
+
+/// Shared cache between the in-process caches of the caching repositories and the database,
+/// typically out of process so several instances share it. Values are opaque bytes, written by
+/// [`l2_codec`](crate::caching::l2_codec).
+///
+/// The caching repositories treat failures as misses, so an unavailable L2 cache slows them
+/// down but does not fail them.
+#[async_trait]
+pub trait L2Cache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, L2CacheError>;
+    /// Stores the value, dropping it after `ttl` if one is given.
+    async fn set(&self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<(), L2CacheError>;
+    async fn invalidate(&self, key: &str) -> Result<(), L2CacheError>;
+}
+
+/// Stored value and the instant it expires at, if any.
+type LocalEntry = (Vec<u8>, Option<Instant>);
+
+/// Process-local L2 cache. Repositories holding the same instance share it the way instances
+/// share an external cache, which makes it the stand-in for tests and single-process setups.
+#[derive(Debug, Default)]
+pub struct LocalL2Cache {
+    entries: Mutex<HashMap<String, LocalEntry>>,
+}
+
+impl LocalL2Cache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of entries, expired ones included until they are next read.
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+#[async_trait]
+impl L2Cache for LocalL2Cache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, L2CacheError> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((_, Some(expiry))) if *expiry <= Instant::now() => {
+                entries.remove(key);
+                Ok(None)
+            }
+            Some((value, _)) => Ok(Some(value.clone())),
+            None => Ok(None),
+        }
+    }
+
+    async fn set(&self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<(), L2CacheError> {
+        let expiry = ttl.map(|ttl| Instant::now() + ttl);
+        self.entries.lock().unwrap().insert(key.to_string(), (value.to_vec(), expiry));
+        Ok(())
+    }
+
+    async fn invalidate(&self, key: &str) -> Result<(), L2CacheError> {
+        self.entries.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
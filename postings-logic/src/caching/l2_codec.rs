@@ -0,0 +1,56 @@
+//! Format of the values the caching repositories keep in the L2 cache: a version byte followed
+//! by the model as JSON. Values of another version, e.g. written by an older release sharing the
+//! cache, decode as misses and are replaced on the next load.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+pub const FORMAT_VERSION: u8 = 1;
+
+pub fn encode<T: Serialize>(value: &T) -> Result<Vec<u8>, serde_json::Error> {
+    let mut bytes = vec![FORMAT_VERSION];
+    serde_json::to_writer(&mut bytes, value)?;
+    Ok(bytes)
+}
+
+pub fn decode<T: DeserializeOwned>(bytes: &[u8]) -> Option<T> {
+    match bytes.split_first() {
+        Some((&FORMAT_VERSION, json)) => serde_json::from_slice(json).ok(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use postings_db::models::named::{ContainerType, Named};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_round_trip() {
+        let named = Named {
+            id: Uuid::new_v4(),
+            container: Uuid::new_v4(),
+            context: Uuid::nil(),
+            name: "Cash".to_string(),
+            language: "en".to_string(),
+            created: chrono::Utc::now(),
+            user_details: [7; 34],
+            short_desc: None,
+            long_desc: Some("Cash at bank".to_string()),
+            container_type: ContainerType::LedgerAccount,
+            retired: None,
+        };
+        let bytes = encode(&named).unwrap();
+        assert_eq!(bytes[0], FORMAT_VERSION);
+        assert_eq!(decode::<Named>(&bytes), Some(named));
+    }
+
+    #[test]
+    fn test_other_versions_are_misses() {
+        let mut bytes = encode(&Uuid::nil()).unwrap();
+        bytes[0] = FORMAT_VERSION + 1;
+        assert_eq!(decode::<Uuid>(&bytes), None);
+        assert_eq!(decode::<Uuid>(&[]), None);
+    }
+}
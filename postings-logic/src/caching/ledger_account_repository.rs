@@ -0,0 +1,189 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use moka::future::Cache;
+use postings_db::models::account_limit::AccountLimit;
+use postings_db::models::account_state::AccountStateChange;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
+use postings_db::invalidation_bus::{InvalidationBus, InvalidationEvent};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::caching::cache_config::CacheConfig;
+use crate::caching::cache_metrics::{CacheMetrics, CacheStats};
+use crate::caching::cache_tier::CacheTier;
+use crate::caching::invalidation_publisher::InvalidationPublisher;
+use crate::caching::l2_cache::L2Cache;
+use crate::caching::named_repository::CachingNamedRepository;
+
+/// Caches accounts by id and by code, and the state changes every posting checks. Listings and
+/// limits are read through, the limits being enforced by the database anyway.
+pub struct CachingLedgerAccountRepository {
+    inner: Arc<dyn LedgerAccountRepository + Send + Sync>,
+    cache_by_id: CacheTier<Uuid, LedgerAccount>,
+    cache_by_code: Cache<(Uuid, String), LedgerAccount>,
+    cache_state_changes: CacheTier<Uuid, Vec<AccountStateChange>>,
+    metrics: CacheMetrics,
+    named_cache: Option<Arc<CachingNamedRepository>>,
+    publisher: InvalidationPublisher,
+}
+
+impl CachingLedgerAccountRepository {
+    pub fn new(inner: Arc<dyn LedgerAccountRepository + Send + Sync>) -> Self {
+        Self::with_config(inner, &CacheConfig::default())
+    }
+
+    pub fn with_config(inner: Arc<dyn LedgerAccountRepository + Send + Sync>, config: &CacheConfig) -> Self {
+        Self {
+            inner,
+            cache_by_id: CacheTier::new(config),
+            cache_by_code: config.build(),
+            cache_state_changes: CacheTier::new(config),
+            metrics: CacheMetrics::default(),
+            named_cache: None,
+            publisher: InvalidationPublisher::default(),
+        }
+    }
+
+    /// Clears the name caches when an account is saved with its names.
+    pub fn with_named_cache(mut self, named_cache: Arc<CachingNamedRepository>) -> Self {
+        self.named_cache = Some(named_cache);
+        self
+    }
+
+    /// Backs the account and state change caches with the L2 cache; the code index stays in process.
+    pub fn with_l2(mut self, l2: Arc<dyn L2Cache + Send + Sync>) -> Self {
+        self.cache_by_id.attach_l2(l2.clone(), "ledger_account");
+        self.cache_state_changes.attach_l2(l2, "account_state_changes");
+        self
+    }
+
+    /// Tells the other instances on the bus about saved accounts and state changes.
+    pub fn with_invalidation_bus(mut self, bus: Arc<dyn InvalidationBus + Send + Sync>) -> Self {
+        self.publisher.set_bus(bus);
+        self
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.metrics.stats()
+    }
+
+    async fn invalidate_named(&self, named: &[Named]) {
+        if let Some(named_cache) = &self.named_cache {
+            named_cache.invalidate(named).await;
+        }
+    }
+
+    /// Drops accounts and state changes another instance saved from this instance's memory.
+    pub async fn apply(&self, event: &InvalidationEvent) {
+        match event {
+            InvalidationEvent::LedgerAccount(id) => {
+                self.cache_by_id.evict(id).await;
+                self.cache_by_code.invalidate_all();
+            }
+            InvalidationEvent::AccountState(account_id) => self.cache_state_changes.evict(account_id).await,
+            InvalidationEvent::All => {
+                self.cache_by_id.evict_all();
+                self.cache_by_code.invalidate_all();
+                self.cache_state_changes.evict_all();
+            }
+            _ => {}
+        }
+    }
+
+    /// A save may change the account's code, so entries under its old code go as well.
+    async fn invalidate_account(&self, id: Uuid) {
+        self.cache_by_id.invalidate(&id).await;
+        self.cache_by_code.invalidate_all();
+        self.publisher.publish(InvalidationEvent::LedgerAccount(id)).await;
+    }
+}
+
+#[async_trait]
+impl LedgerAccountRepository for CachingLedgerAccountRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<LedgerAccount>, DbError> {
+        if let Some(account) = self.cache_by_id.get(&id, &self.metrics).await {
+            return Ok(Some(account));
+        }
+
+        let account_opt = self.inner.find_by_id(id).await?;
+        if let Some(account) = &account_opt {
+            self.cache_by_id.insert(id, account.clone()).await;
+        }
+        Ok(account_opt)
+    }
+
+    async fn find_by_ledger_id(&self, ledger_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        self.inner.find_by_ledger_id(ledger_id).await
+    }
+
+    async fn find_by_ledger_id_and_code(&self, ledger_id: Uuid, code: &str) -> Result<Option<LedgerAccount>, DbError> {
+        let key = (ledger_id, code.to_string());
+        if let Some(account) = self.cache_by_code.get(&key).await {
+            self.metrics.hit();
+            return Ok(Some(account));
+        }
+        self.metrics.miss();
+
+        let account_opt = self.inner.find_by_ledger_id_and_code(ledger_id, code).await?;
+        if let Some(account) = &account_opt {
+            self.cache_by_code.insert(key, account.clone()).await;
+        }
+        Ok(account_opt)
+    }
+
+    async fn find_by_ledger_id_and_code_in(&self, ledger_id: Uuid, codes: &[String]) -> Result<Vec<LedgerAccount>, DbError> {
+        self.inner.find_by_ledger_id_and_code_in(ledger_id, codes).await
+    }
+
+    async fn find_by_ledger_id_and_code_between(&self, ledger_id: Uuid, from: &str, to: &str) -> Result<Vec<LedgerAccount>, DbError> {
+        self.inner.find_by_ledger_id_and_code_between(ledger_id, from, to).await
+    }
+
+    async fn find_by_parent_id(&self, parent_id: Uuid) -> Result<Vec<LedgerAccount>, DbError> {
+        self.inner.find_by_parent_id(parent_id).await
+    }
+
+    async fn save(&self, ledger_account: &LedgerAccount, record_time: DateTime<Utc>) -> Result<(), DbError> {
+        self.inner.save(ledger_account, record_time).await?;
+        self.invalidate_account(ledger_account.id).await;
+        Ok(())
+    }
+
+    async fn save_with_named(&self, ledger_account: &LedgerAccount, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        self.inner.save_with_named(ledger_account, named, record_time).await?;
+        self.invalidate_named(named).await;
+        self.invalidate_account(ledger_account.id).await;
+        Ok(())
+    }
+
+    async fn save_state_change(&self, change: &AccountStateChange) -> Result<(), DbError> {
+        self.inner.save_state_change(change).await?;
+        self.cache_state_changes.invalidate(&change.account_id).await;
+        self.publisher.publish(InvalidationEvent::AccountState(change.account_id)).await;
+        Ok(())
+    }
+
+    async fn find_state_changes_by_account_id(&self, account_id: Uuid) -> Result<Vec<AccountStateChange>, DbError> {
+        if let Some(changes) = self.cache_state_changes.get(&account_id, &self.metrics).await {
+            return Ok(changes);
+        }
+
+        let changes = self.inner.find_state_changes_by_account_id(account_id).await?;
+        self.cache_state_changes.insert(account_id, changes.clone()).await;
+        Ok(changes)
+    }
+
+    async fn save_limit(&self, limit: &AccountLimit) -> Result<(), DbError> {
+        self.inner.save_limit(limit).await
+    }
+
+    async fn find_limit_by_account_id(&self, account_id: Uuid) -> Result<Option<AccountLimit>, DbError> {
+        self.inner.find_limit_by_account_id(account_id).await
+    }
+
+    async fn delete_limit(&self, account_id: Uuid) -> Result<(), DbError> {
+        self.inner.delete_limit(account_id).await
+    }
+}
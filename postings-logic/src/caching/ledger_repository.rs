@@ -0,0 +1,116 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::invalidation_bus::{InvalidationBus, InvalidationEvent};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::caching::cache_config::CacheConfig;
+use crate::caching::cache_metrics::{CacheMetrics, CacheStats};
+use crate::caching::cache_tier::CacheTier;
+use crate::caching::invalidation_publisher::InvalidationPublisher;
+use crate::caching::l2_cache::L2Cache;
+use crate::caching::named_repository::CachingNamedRepository;
+
+pub struct CachingLedgerRepository {
+    inner: Arc<dyn LedgerRepository + Send + Sync>,
+    cache_by_id: CacheTier<Uuid, Ledger>,
+    metrics: CacheMetrics,
+    named_cache: Option<Arc<CachingNamedRepository>>,
+    publisher: InvalidationPublisher,
+}
+
+impl CachingLedgerRepository {
+    pub fn new(inner: Arc<dyn LedgerRepository + Send + Sync>) -> Self {
+        Self::with_config(inner, &CacheConfig::default())
+    }
+
+    pub fn with_config(inner: Arc<dyn LedgerRepository + Send + Sync>, config: &CacheConfig) -> Self {
+        Self {
+            inner,
+            cache_by_id: CacheTier::new(config),
+            metrics: CacheMetrics::default(),
+            named_cache: None,
+            publisher: InvalidationPublisher::default(),
+        }
+    }
+
+    /// Clears the name caches when a ledger is saved with its names or accounts.
+    pub fn with_named_cache(mut self, named_cache: Arc<CachingNamedRepository>) -> Self {
+        self.named_cache = Some(named_cache);
+        self
+    }
+
+    pub fn with_l2(mut self, l2: Arc<dyn L2Cache + Send + Sync>) -> Self {
+        self.cache_by_id.attach_l2(l2, "ledger");
+        self
+    }
+
+    /// Tells the other instances on the bus about every saved ledger.
+    pub fn with_invalidation_bus(mut self, bus: Arc<dyn InvalidationBus + Send + Sync>) -> Self {
+        self.publisher.set_bus(bus);
+        self
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.metrics.stats()
+    }
+
+    /// Drops a ledger another instance saved from this instance's memory.
+    pub async fn apply(&self, event: &InvalidationEvent) {
+        match event {
+            InvalidationEvent::Ledger(id) => self.cache_by_id.evict(id).await,
+            InvalidationEvent::All => self.cache_by_id.evict_all(),
+            _ => {}
+        }
+    }
+
+    async fn invalidate(&self, id: Uuid) {
+        self.cache_by_id.invalidate(&id).await;
+        self.publisher.publish(InvalidationEvent::Ledger(id)).await;
+    }
+
+    async fn invalidate_named(&self, named: &[Named]) {
+        if let Some(named_cache) = &self.named_cache {
+            named_cache.invalidate(named).await;
+        }
+    }
+}
+
+#[async_trait]
+impl LedgerRepository for CachingLedgerRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Ledger>, DbError> {
+        if let Some(ledger) = self.cache_by_id.get(&id, &self.metrics).await {
+            return Ok(Some(ledger));
+        }
+
+        let ledger_opt = self.inner.find_by_id(id).await?;
+        if let Some(ledger) = &ledger_opt {
+            self.cache_by_id.insert(id, ledger.clone()).await;
+        }
+        Ok(ledger_opt)
+    }
+
+    async fn save(&self, ledger: &Ledger, record_time: DateTime<Utc>) -> Result<(), DbError> {
+        self.inner.save(ledger, record_time).await?;
+        self.invalidate(ledger.id).await;
+        Ok(())
+    }
+
+    async fn save_with_named(&self, ledger: &Ledger, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        self.inner.save_with_named(ledger, named, record_time).await?;
+        self.invalidate_named(named).await;
+        self.invalidate(ledger.id).await;
+        Ok(())
+    }
+
+    async fn save_with_accounts(&self, ledger: &Ledger, accounts: &[LedgerAccount], named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError> {
+        self.inner.save_with_accounts(ledger, accounts, named, record_time).await?;
+        self.invalidate_named(named).await;
+        self.invalidate(ledger.id).await;
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use postings_db::invalidation_bus::{InvalidationBus, InvalidationEvent, InvalidationSubscription};
+use postings_db::DbError;
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+
+/// In-process invalidation bus on a broadcast channel, for stacks running in one process such as
+/// tests, or several service stacks behind one server.
+pub struct LocalInvalidationBus {
+    sender: broadcast::Sender<InvalidationEvent>,
+}
+
+impl LocalInvalidationBus {
+    /// `capacity` events are kept for subscribers that fall behind; beyond that they get
+    /// [`InvalidationEvent::All`] instead.
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+}
+
+impl Default for LocalInvalidationBus {
+    fn default() -> Self {
+        Self::new(1024)
+    }
+}
+
+#[async_trait]
+impl InvalidationBus for LocalInvalidationBus {
+    async fn publish(&self, event: &InvalidationEvent) -> Result<(), DbError> {
+        // Without subscribers there is no one to notify, which is not an error.
+        let _ = self.sender.send(event.clone());
+        Ok(())
+    }
+
+    async fn subscribe(&self) -> Result<Box<dyn InvalidationSubscription + Send>, DbError> {
+        Ok(Box::new(LocalInvalidationSubscription { receiver: self.sender.subscribe() }))
+    }
+}
+
+struct LocalInvalidationSubscription {
+    receiver: broadcast::Receiver<InvalidationEvent>,
+}
+
+#[async_trait]
+impl InvalidationSubscription for LocalInvalidationSubscription {
+    async fn next(&mut self) -> Option<InvalidationEvent> {
+        match self.receiver.recv().await {
+            Ok(event) => Some(event),
+            Err(RecvError::Lagged(_)) => Some(InvalidationEvent::All),
+            Err(RecvError::Closed) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_subscribers_get_events_in_order() {
+        let bus = LocalInvalidationBus::default();
+        let mut first = bus.subscribe().await.unwrap();
+        let mut second = bus.subscribe().await.unwrap();
+        let ledger_id = Uuid::new_v4();
+
+        bus.publish(&InvalidationEvent::Ledger(ledger_id)).await.unwrap();
+        bus.publish(&InvalidationEvent::AccountState(ledger_id)).await.unwrap();
+
+        assert_eq!(first.next().await, Some(InvalidationEvent::Ledger(ledger_id)));
+        assert_eq!(first.next().await, Some(InvalidationEvent::AccountState(ledger_id)));
+        assert_eq!(second.next().await, Some(InvalidationEvent::Ledger(ledger_id)));
+    }
+
+    #[tokio::test]
+    async fn test_lagging_subscriber_gets_all() {
+        let bus = LocalInvalidationBus::new(1);
+        let mut subscription = bus.subscribe().await.unwrap();
+
+        bus.publish(&InvalidationEvent::Ledger(Uuid::new_v4())).await.unwrap();
+        bus.publish(&InvalidationEvent::Ledger(Uuid::new_v4())).await.unwrap();
+
+        assert_eq!(subscription.next().await, Some(InvalidationEvent::All));
+    }
+}
@@ -1 +1,14 @@
+pub mod cache_config;
+pub mod cache_metrics;
+mod cache_tier;
+pub mod caching_repositories;
 pub mod chart_of_account_repository;
+mod invalidation_publisher;
+pub mod l2_cache;
+pub mod l2_codec;
+pub mod ledger_account_repository;
+pub mod ledger_repository;
+pub mod local_invalidation_bus;
+pub mod named_repository;
+#[cfg(feature = "redis_cache")]
+pub mod redis_l2_cache;
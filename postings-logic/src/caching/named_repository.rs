@@ -0,0 +1,156 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use moka::future::Cache;
+use postings_db::models::named::{ContainerType, Named};
+use postings_db::repositories::named_repository::NamedRepository;
+use postings_db::invalidation_bus::{InvalidationBus, InvalidationEvent};
+use postings_db::DbError;
+use uuid::Uuid;
+use crate::caching::cache_config::CacheConfig;
+use crate::caching::cache_metrics::{CacheMetrics, CacheStats};
+use crate::caching::cache_tier::CacheTier;
+use crate::caching::invalidation_publisher::InvalidationPublisher;
+use crate::caching::l2_cache::L2Cache;
+
+/// Caches names by id and by container, and the results of the name lookups.
+pub struct CachingNamedRepository {
+    inner: Arc<dyn NamedRepository + Send + Sync>,
+    cache_by_id: CacheTier<Uuid, Named>,
+    cache_by_container: CacheTier<Uuid, Vec<Named>>,
+    cache_by_name: Cache<(String, ContainerType), Vec<Named>>,
+    cache_by_name_and_context: Cache<(String, ContainerType, Uuid), Vec<Named>>,
+    metrics: CacheMetrics,
+    publisher: InvalidationPublisher,
+}
+
+impl CachingNamedRepository {
+    pub fn new(inner: Arc<dyn NamedRepository + Send + Sync>) -> Self {
+        Self::with_config(inner, &CacheConfig::default())
+    }
+
+    pub fn with_config(inner: Arc<dyn NamedRepository + Send + Sync>, config: &CacheConfig) -> Self {
+        Self {
+            inner,
+            cache_by_id: CacheTier::new(config),
+            cache_by_container: CacheTier::new(config),
+            cache_by_name: config.build(),
+            cache_by_name_and_context: config.build(),
+            metrics: CacheMetrics::default(),
+            publisher: InvalidationPublisher::default(),
+        }
+    }
+
+    /// Backs the id and container caches with the L2 cache. Name lookups stay in process, as a
+    /// save clears them as a whole.
+    pub fn with_l2(mut self, l2: Arc<dyn L2Cache + Send + Sync>) -> Self {
+        self.cache_by_id.attach_l2(l2.clone(), "named");
+        self.cache_by_container.attach_l2(l2, "named_container");
+        self
+    }
+
+    /// Tells the other instances on the bus about every saved name, including those saved by
+    /// the repositories that call [`Self::invalidate`].
+    pub fn with_invalidation_bus(mut self, bus: Arc<dyn InvalidationBus + Send + Sync>) -> Self {
+        self.publisher.set_bus(bus);
+        self
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.metrics.stats()
+    }
+
+    /// Drops what the given names may have changed. Repositories that save names along with
+    /// their container call this, since those saves bypass [`NamedRepository::save`].
+    pub async fn invalidate(&self, named: &[Named]) {
+        for n in named {
+            self.cache_by_id.invalidate(&n.id).await;
+            self.cache_by_container.invalidate(&n.container).await;
+        }
+        self.cache_by_name.invalidate_all();
+        self.cache_by_name_and_context.invalidate_all();
+        for n in named {
+            self.publisher.publish(InvalidationEvent::Named { id: n.id, container: n.container }).await;
+        }
+    }
+
+    /// Drops names another instance saved from this instance's memory.
+    pub async fn apply(&self, event: &InvalidationEvent) {
+        match event {
+            InvalidationEvent::Named { id, container } => {
+                self.cache_by_id.evict(id).await;
+                self.cache_by_container.evict(container).await;
+            }
+            InvalidationEvent::All => {
+                self.cache_by_id.evict_all();
+                self.cache_by_container.evict_all();
+            }
+            _ => return,
+        }
+        self.cache_by_name.invalidate_all();
+        self.cache_by_name_and_context.invalidate_all();
+    }
+
+    async fn cached<K>(&self, cache: &Cache<K, Vec<Named>>, key: K) -> Option<Vec<Named>>
+    where
+        K: std::hash::Hash + Eq + Send + Sync + 'static,
+    {
+        let named = cache.get(&key).await;
+        match named {
+            Some(_) => self.metrics.hit(),
+            None => self.metrics.miss(),
+        }
+        named
+    }
+}
+
+#[async_trait]
+impl NamedRepository for CachingNamedRepository {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Named>, DbError> {
+        if let Some(named) = self.cache_by_id.get(&id, &self.metrics).await {
+            return Ok(Some(named));
+        }
+
+        let named_opt = self.inner.find_by_id(id).await?;
+        if let Some(named) = &named_opt {
+            self.cache_by_id.insert(id, named.clone()).await;
+        }
+        Ok(named_opt)
+    }
+
+    async fn find_by_container(&self, container_id: Uuid) -> Result<Vec<Named>, DbError> {
+        if let Some(named) = self.cache_by_container.get(&container_id, &self.metrics).await {
+            return Ok(named);
+        }
+        let named = self.inner.find_by_container(container_id).await?;
+        self.cache_by_container.insert(container_id, named.clone()).await;
+        Ok(named)
+    }
+
+    async fn find_by_name_and_type(&self, name: &str, container_type: ContainerType) -> Result<Vec<Named>, DbError> {
+        let key = (name.to_string(), container_type.clone());
+        if let Some(named) = self.cached(&self.cache_by_name, key.clone()).await {
+            return Ok(named);
+        }
+        let named = self.inner.find_by_name_and_type(name, container_type).await?;
+        self.cache_by_name.insert(key, named.clone()).await;
+        Ok(named)
+    }
+
+    async fn find_by_name_and_type_and_context(&self, name: &str, container_type: ContainerType, context: Uuid) -> Result<Vec<Named>, DbError> {
+        let key = (name.to_string(), container_type.clone(), context);
+        if let Some(named) = self.cached(&self.cache_by_name_and_context, key.clone()).await {
+            return Ok(named);
+        }
+        let named = self.inner.find_by_name_and_type_and_context(name, container_type, context).await?;
+        self.cache_by_name_and_context.insert(key, named.clone()).await;
+        Ok(named)
+    }
+
+    /// A save can add, rename or retire a name, which changes the lookups of both its old and
+    /// its new name, so the name caches are cleared as a whole.
+    async fn save(&self, named: Named) -> Result<Named, DbError> {
+        let saved = self.inner.save(named).await?;
+        self.invalidate(std::slice::from_ref(&saved)).await;
+        Ok(saved)
+    }
+}
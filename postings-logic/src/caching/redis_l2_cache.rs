@@ -0,0 +1,128 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufStream};
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+use tokio::time::timeout;
+
+use crate::caching::l2_cache::{L2Cache, L2CacheError};
+
+enum Reply {
+    Status,
+    Integer,
+    Bulk(Option<Vec<u8>>),
+}
+
+/// Default bound on waiting for the connection, connecting and each command.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// L2 cache on a server speaking the Redis protocol (RESP), such as Redis, Valkey or KeyDB. It
+/// keeps one connection, opened on first use and reopened after a failure; commands on it run
+/// one at a time. Waiting for the connection, connecting and each command are bounded by a
+/// timeout, so a hung server turns into cache misses instead of stalled callers.
+pub struct RedisL2Cache {
+    address: String,
+    timeout: Duration,
+    connection: Mutex<Option<BufStream<TcpStream>>>,
+}
+
+impl RedisL2Cache {
+    /// `address` is the `host:port` of the server.
+    pub fn new(address: impl Into<String>) -> Self {
+        Self {
+            address: address.into(),
+            timeout: DEFAULT_TIMEOUT,
+            connection: Mutex::new(None),
+        }
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    async fn command(&self, args: &[&[u8]]) -> Result<Reply, L2CacheError> {
+        let timed_out = |what: &str| L2CacheError::Connection(format!("{what} {} timed out after {:?}", self.address, self.timeout));
+        let mut connection = timeout(self.timeout, self.connection.lock()).await.map_err(|_| timed_out("waiting for"))?;
+        if connection.is_none() {
+            let stream = timeout(self.timeout, TcpStream::connect(&self.address))
+                .await
+                .map_err(|_| timed_out("connecting to"))?
+                .map_err(|e| L2CacheError::Connection(e.to_string()))?;
+            *connection = Some(BufStream::new(stream));
+        }
+        let result = match timeout(self.timeout, Self::round_trip(connection.as_mut().unwrap(), args)).await {
+            Ok(result) => result,
+            Err(_) => Err(timed_out("command on")),
+        };
+        if let Err(L2CacheError::Connection(_)) = &result {
+            // The stream may be half-read; start over with a fresh one.
+            *connection = None;
+        }
+        result
+    }
+
+    async fn round_trip(stream: &mut BufStream<TcpStream>, args: &[&[u8]]) -> Result<Reply, L2CacheError> {
+        let io = |e: std::io::Error| L2CacheError::Connection(e.to_string());
+        let mut request = format!("*{}\r\n", args.len()).into_bytes();
+        for arg in args {
+            request.extend_from_slice(format!("${}\r\n", arg.len()).as_bytes());
+            request.extend_from_slice(arg);
+            request.extend_from_slice(b"\r\n");
+        }
+        stream.write_all(&request).await.map_err(io)?;
+        stream.flush().await.map_err(io)?;
+
+        let mut line = String::new();
+        if stream.read_line(&mut line).await.map_err(io)? == 0 {
+            return Err(L2CacheError::Connection("connection closed by server".to_string()));
+        }
+        let line = line.trim_end_matches("\r\n");
+        let (kind, rest) = line.split_at(line.len().min(1));
+        match kind {
+            "+" => Ok(Reply::Status),
+            ":" => Ok(Reply::Integer),
+            "-" => Err(L2CacheError::Protocol(rest.to_string())),
+            "$" => {
+                let len: i64 = rest.parse().map_err(|_| L2CacheError::Connection(format!("invalid bulk length: {rest}")))?;
+                if len < 0 {
+                    return Ok(Reply::Bulk(None));
+                }
+                let mut value = vec![0; len as usize + 2];
+                stream.read_exact(&mut value).await.map_err(io)?;
+                value.truncate(len as usize);
+                Ok(Reply::Bulk(Some(value)))
+            }
+            _ => Err(L2CacheError::Connection(format!("unexpected reply: {line}"))),
+        }
+    }
+}
+
+#[async_trait]
+impl L2Cache for RedisL2Cache {
+    async fn get(&self, key: &str) -> Result<Option<Vec<u8>>, L2CacheError> {
+        match self.command(&[b"GET", key.as_bytes()]).await? {
+            Reply::Bulk(value) => Ok(value),
+            _ => Err(L2CacheError::Protocol("GET did not return a bulk string".to_string())),
+        }
+    }
+
+    async fn set(&self, key: &str, value: &[u8], ttl: Option<Duration>) -> Result<(), L2CacheError> {
+        match ttl {
+            Some(ttl) => {
+                let millis = ttl.as_millis().max(1).to_string();
+                self.command(&[b"SET", key.as_bytes(), value, b"PX", millis.as_bytes()]).await?;
+            }
+            None => {
+                self.command(&[b"SET", key.as_bytes(), value]).await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn invalidate(&self, key: &str) -> Result<(), L2CacheError> {
+        self.command(&[b"DEL", key.as_bytes()]).await?;
+        Ok(())
+    }
+}
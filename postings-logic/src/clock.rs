@@ -0,0 +1,91 @@
+use std::sync::Mutex;
+
+use chrono::{DateTime, Duration, Utc};
+
+/// Source of the current time for record times, approvals and other timestamps the services set.
+pub trait Clock {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// Wall-clock time, the default.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Stands still at a set time until it is moved, e.g. to backdate a simulation or pin a test.
+#[derive(Debug)]
+pub struct FixedClock {
+    time: Mutex<DateTime<Utc>>,
+}
+
+impl FixedClock {
+    pub fn new(time: DateTime<Utc>) -> Self {
+        Self { time: Mutex::new(time) }
+    }
+
+    pub fn set(&self, time: DateTime<Utc>) {
+        *self.time.lock().unwrap() = time;
+    }
+
+    pub fn advance(&self, by: Duration) {
+        *self.time.lock().unwrap() += by;
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.time.lock().unwrap()
+    }
+}
+
+/// Returns `start` first and moves on by `step` on every reading, so successive timestamps are
+/// distinct and predictable.
+#[derive(Debug)]
+pub struct SteppingClock {
+    next: Mutex<DateTime<Utc>>,
+    step: Duration,
+}
+
+impl SteppingClock {
+    pub fn new(start: DateTime<Utc>, step: Duration) -> Self {
+        Self { next: Mutex::new(start), step }
+    }
+}
+
+impl Clock for SteppingClock {
+    fn now(&self) -> DateTime<Utc> {
+        let mut next = self.next.lock().unwrap();
+        let now = *next;
+        *next += self.step;
+        now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_fixed_clock() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let clock = FixedClock::new(start);
+        assert_eq!(clock.now(), start);
+        assert_eq!(clock.now(), start);
+        clock.advance(Duration::days(1));
+        assert_eq!(clock.now(), start + Duration::days(1));
+    }
+
+    #[test]
+    fn test_stepping_clock() {
+        let start = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let clock = SteppingClock::new(start, Duration::seconds(1));
+        assert_eq!(clock.now(), start);
+        assert_eq!(clock.now(), start + Duration::seconds(1));
+    }
+}
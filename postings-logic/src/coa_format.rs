@@ -0,0 +1,144 @@
+use std::path::Path;
+
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::coa_document::{CoaAccount, CoaDocument, CoaName};
+use postings_api::ServiceError;
+
+/// File formats COA definition files can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoaFormat {
+    Yaml,
+    Json,
+    /// One row per name, with a `kind` column telling chart, ledger and account rows apart.
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum CsvKind {
+    Chart,
+    Ledger,
+    Account,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CsvRow {
+    kind: CsvKind,
+    code: Option<String>,
+    parent_code: Option<String>,
+    category: Option<AccountCategory>,
+    balance_side: Option<BalanceSide>,
+    language: String,
+    name: String,
+}
+
+fn invalid(e: impl std::fmt::Display) -> ServiceError {
+    info!("Invalid COA definition file: {e}");
+    ServiceError::InvalidCoaFile
+}
+
+impl CoaFormat {
+    /// Picks the format from the file extension (`yaml`, `yml`, `json` or `csv`).
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "yaml" | "yml" => Some(Self::Yaml),
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+
+    pub fn read(&self, input: &str) -> Result<CoaDocument, ServiceError> {
+        match self {
+            Self::Yaml => serde_yaml::from_str(input).map_err(invalid),
+            Self::Json => serde_json::from_str(input).map_err(invalid),
+            Self::Csv => Self::read_csv(input),
+        }
+    }
+
+    pub fn write(&self, document: &CoaDocument) -> Result<String, ServiceError> {
+        match self {
+            Self::Yaml => serde_yaml::to_string(document).map_err(invalid),
+            Self::Json => serde_json::to_string_pretty(document).map_err(invalid),
+            Self::Csv => Self::write_csv(document),
+        }
+    }
+
+    fn read_csv(input: &str) -> Result<CoaDocument, ServiceError> {
+        let mut document = CoaDocument { chart: vec![], ledger: vec![], accounts: vec![] };
+        let mut reader = csv::Reader::from_reader(input.as_bytes());
+        for row in reader.deserialize::<CsvRow>() {
+            let row = row.map_err(invalid)?;
+            let name = CoaName { language: row.language, name: row.name };
+            match row.kind {
+                CsvKind::Chart => document.chart.push(name),
+                CsvKind::Ledger => document.ledger.push(name),
+                CsvKind::Account => {
+                    let code = row.code.ok_or_else(|| invalid("account row without code"))?;
+                    // Further rows of an account only add names; whatever else they repeat has to agree.
+                    if let Some(account) = document.accounts.iter_mut().find(|a| a.code == code) {
+                        if row.parent_code.is_some_and(|p| account.parent_code.as_ref() != Some(&p))
+                            || row.category.is_some_and(|c| c != account.category)
+                            || row.balance_side.is_some_and(|b| b != account.balance_side)
+                        {
+                            return Err(invalid(format!("conflicting rows for account {code}")));
+                        }
+                        account.names.push(name);
+                    } else {
+                        document.accounts.push(CoaAccount {
+                            code,
+                            parent_code: row.parent_code,
+                            category: row.category.ok_or_else(|| invalid("account row without category"))?,
+                            balance_side: row.balance_side.ok_or_else(|| invalid("account row without balance side"))?,
+                            names: vec![name],
+                        });
+                    }
+                }
+            }
+        }
+        Ok(document)
+    }
+
+    fn write_csv(document: &CoaDocument) -> Result<String, ServiceError> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        let header_rows = document
+            .chart
+            .iter()
+            .map(|n| (CsvKind::Chart, n))
+            .chain(document.ledger.iter().map(|n| (CsvKind::Ledger, n)));
+        for (kind, name) in header_rows {
+            writer
+                .serialize(CsvRow {
+                    kind,
+                    code: None,
+                    parent_code: None,
+                    category: None,
+                    balance_side: None,
+                    language: name.language.clone(),
+                    name: name.name.clone(),
+                })
+                .map_err(invalid)?;
+        }
+        for account in &document.accounts {
+            for name in &account.names {
+                writer
+                    .serialize(CsvRow {
+                        kind: CsvKind::Account,
+                        code: Some(account.code.clone()),
+                        parent_code: account.parent_code.clone(),
+                        category: Some(account.category.clone()),
+                        balance_side: Some(account.balance_side.clone()),
+                        language: name.language.clone(),
+                        name: name.name.clone(),
+                    })
+                    .map_err(invalid)?;
+            }
+        }
+        let bytes = writer.into_inner().map_err(invalid)?;
+        String::from_utf8(bytes).map_err(invalid)
+    }
+}
@@ -0,0 +1,153 @@
+use std::sync::Arc;
+
+use postings_api::domain::account_code_scheme::AccountCodeScheme;
+use postings_db::line_feed::LineNotifier;
+use postings_db::repositories::Repositories;
+
+use crate::caching::caching_repositories::{CachingRepositories, CachingRepositoriesBuilder};
+use crate::clock::Clock;
+use crate::id_generator::IdGenerator;
+use crate::services::account_lifecycle_service::AccountLifecycleServiceImpl;
+use crate::services::account_stmt_service::AccountStmtServiceImpl;
+use crate::services::change_feed_service::ChangeFeedServiceImpl;
+use crate::services::chart_of_account_service::ChartOfAccountServiceImpl;
+use crate::services::coa_exchange_service::CoaExchangeServiceImpl;
+use crate::services::hold_service::HoldServiceImpl;
+use crate::services::interest_accrual_service::InterestAccrualServiceImpl;
+use crate::services::ledger_service::LedgerServiceImpl;
+use crate::services::named_service::NamedServiceImpl;
+use crate::services::outbox_service::OutboxServiceImpl;
+use crate::services::posting_service::PostingServiceImpl;
+use crate::services::schedule_service::ScheduleServiceImpl;
+use crate::services::shared_service::SharedService;
+
+/// All services on one set of repositories, sharing a single [`SharedService`] and so the same
+/// repositories, caches, clock and id generator.
+#[derive(Clone)]
+pub struct PostingsEngine {
+    pub shared: Arc<SharedService>,
+    pub chart_of_account: Arc<ChartOfAccountServiceImpl>,
+    pub ledger: Arc<LedgerServiceImpl>,
+    pub named: Arc<NamedServiceImpl>,
+    pub posting: Arc<PostingServiceImpl>,
+    pub stmt: Arc<AccountStmtServiceImpl>,
+    pub hold: Arc<HoldServiceImpl>,
+    pub schedule: Arc<ScheduleServiceImpl>,
+    pub account_lifecycle: Arc<AccountLifecycleServiceImpl>,
+    pub interest_accrual: Arc<InterestAccrualServiceImpl>,
+    pub coa_exchange: Arc<CoaExchangeServiceImpl>,
+    pub outbox: Arc<OutboxServiceImpl>,
+    pub change_feed: Arc<ChangeFeedServiceImpl>,
+    /// The caches in front of the master data repositories, if caching was configured. Call
+    /// [`CachingRepositories::spawn_listener`] on them to apply changes made by other instances.
+    pub caches: Option<CachingRepositories>,
+}
+
+impl PostingsEngine {
+    /// Starts from the repositories of a backend, e.g. `postings_db_postgres::repositories(pool)`.
+    pub fn builder(repositories: Repositories) -> PostingsEngineBuilder {
+        PostingsEngineBuilder {
+            repositories,
+            caching: None,
+            clock: None,
+            id_generator: None,
+            code_scheme: None,
+            line_notifier: None,
+        }
+    }
+}
+
+pub struct PostingsEngineBuilder {
+    repositories: Repositories,
+    caching: Option<CachingRepositoriesBuilder>,
+    clock: Option<Arc<dyn Clock + Send + Sync>>,
+    id_generator: Option<Arc<dyn IdGenerator + Send + Sync>>,
+    code_scheme: Option<AccountCodeScheme>,
+    line_notifier: Option<Arc<dyn LineNotifier + Send + Sync>>,
+}
+
+impl PostingsEngineBuilder {
+    /// Puts caches, configured by `caching`, in front of the chart of account, ledger, ledger
+    /// account and name repositories. Without it every read goes to the backend.
+    pub fn caching(mut self, caching: CachingRepositoriesBuilder) -> Self {
+        self.caching = Some(caching);
+        self
+    }
+
+    pub fn clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    pub fn id_generator(mut self, id_generator: Arc<dyn IdGenerator + Send + Sync>) -> Self {
+        self.id_generator = Some(id_generator);
+        self
+    }
+
+    /// Account codes new ledger accounts must follow.
+    pub fn code_scheme(mut self, code_scheme: AccountCodeScheme) -> Self {
+        self.code_scheme = Some(code_scheme);
+        self
+    }
+
+    /// Wakes change feed subscriptions when lines are committed instead of at their next poll.
+    pub fn line_notifier(mut self, line_notifier: Arc<dyn LineNotifier + Send + Sync>) -> Self {
+        self.line_notifier = Some(line_notifier);
+        self
+    }
+
+    pub fn build(self) -> PostingsEngine {
+        let mut repositories = self.repositories;
+        let caches = self.caching.map(|caching| {
+            let caches = caching.wrap(
+                repositories.coa.clone(),
+                repositories.ledger.clone(),
+                repositories.ledger_account.clone(),
+                repositories.named.clone(),
+            );
+            repositories.coa = caches.coa_repo.clone();
+            repositories.ledger = caches.ledger_repo.clone();
+            repositories.ledger_account = caches.ledger_account_repo.clone();
+            repositories.named = caches.named_repo.clone();
+            caches
+        });
+
+        let mut shared = SharedService::from_repositories(repositories);
+        if let Some(clock) = self.clock {
+            shared = shared.with_clock(clock);
+        }
+        if let Some(id_generator) = self.id_generator {
+            shared = shared.with_id_generator(id_generator);
+        }
+        let shared = Arc::new(shared);
+
+        let chart_of_account = ChartOfAccountServiceImpl::new(shared.clone());
+        let mut ledger = LedgerServiceImpl::new(shared.clone(), chart_of_account.clone());
+        if let Some(code_scheme) = self.code_scheme {
+            ledger = ledger.with_code_scheme(code_scheme);
+        }
+        let named = NamedServiceImpl::new(shared.clone());
+        let posting = PostingServiceImpl::new(shared.clone());
+        let mut change_feed = ChangeFeedServiceImpl::new(shared.clone(), ledger.clone());
+        if let Some(line_notifier) = self.line_notifier {
+            change_feed = change_feed.with_notifier(line_notifier);
+        }
+
+        PostingsEngine {
+            stmt: Arc::new(AccountStmtServiceImpl::new(shared.clone())),
+            hold: Arc::new(HoldServiceImpl::new(shared.clone(), ledger.clone(), posting.clone())),
+            schedule: Arc::new(ScheduleServiceImpl::new(shared.clone(), ledger.clone(), posting.clone())),
+            account_lifecycle: Arc::new(AccountLifecycleServiceImpl::new(shared.clone())),
+            interest_accrual: Arc::new(InterestAccrualServiceImpl::new(shared.clone(), posting.clone())),
+            coa_exchange: Arc::new(CoaExchangeServiceImpl::new(shared.clone(), chart_of_account.clone(), ledger.clone(), named.clone())),
+            outbox: Arc::new(OutboxServiceImpl::new(shared.clone())),
+            change_feed: Arc::new(change_feed),
+            chart_of_account: Arc::new(chart_of_account),
+            ledger: Arc::new(ledger),
+            named: Arc::new(named),
+            posting: Arc::new(posting),
+            shared,
+            caches,
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use uuid::Uuid;
+
+/// Source of the ids the services give new records.
+pub trait IdGenerator {
+    fn new_id(&self) -> Uuid;
+}
+
+/// Random version 4 ids, the default.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RandomIdGenerator;
+
+impl IdGenerator for RandomIdGenerator {
+    fn new_id(&self) -> Uuid {
+        Uuid::new_v4()
+    }
+}
+
+/// Version 7 ids, which sort by creation time and so keep index inserts local.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TimeOrderedIdGenerator;
+
+impl IdGenerator for TimeOrderedIdGenerator {
+    fn new_id(&self) -> Uuid {
+        Uuid::now_v7()
+    }
+}
+
+/// Counts up from one, for tests that assert on ids.
+#[derive(Debug, Default)]
+pub struct SequentialIdGenerator {
+    last: AtomicU64,
+}
+
+impl IdGenerator for SequentialIdGenerator {
+    fn new_id(&self) -> Uuid {
+        Uuid::from_u128(u128::from(self.last.fetch_add(1, Ordering::Relaxed) + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequential_ids() {
+        let ids = SequentialIdGenerator::default();
+        assert_eq!(ids.new_id(), Uuid::from_u128(1));
+        assert_eq!(ids.new_id(), Uuid::from_u128(2));
+    }
+
+    #[test]
+    fn test_time_ordered_ids() {
+        let ids = TimeOrderedIdGenerator;
+        let (first, second) = (ids.new_id(), ids.new_id());
+        assert_eq!(first.get_version_num(), 7);
+        assert!(first < second);
+    }
+}
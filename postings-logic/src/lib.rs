@@ -1,4 +1,8 @@
 pub mod caching;
+pub mod clock;
+pub mod coa_format;
+pub mod engine;
 pub mod hash_utils;
+pub mod id_generator;
 pub mod mappers;
 pub mod services;
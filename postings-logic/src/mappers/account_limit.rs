@@ -0,0 +1,22 @@
+use postings_api::domain::account_limit::AccountLimit as AccountLimitBO;
+use postings_db::models::account_limit::AccountLimit as AccountLimitModel;
+
+pub struct AccountLimitMapper;
+
+impl AccountLimitMapper {
+    pub fn to_bo(model: AccountLimitModel) -> AccountLimitBO {
+        AccountLimitBO {
+            account_id: model.account_id,
+            min_balance: model.min_balance,
+            max_balance: model.max_balance,
+        }
+    }
+
+    pub fn to_model(bo: AccountLimitBO) -> AccountLimitModel {
+        AccountLimitModel {
+            account_id: bo.account_id,
+            min_balance: bo.min_balance,
+            max_balance: bo.max_balance,
+        }
+    }
+}
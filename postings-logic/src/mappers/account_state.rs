@@ -0,0 +1,50 @@
+use postings_api::domain::account_state::{AccountState as AccountStateBO, AccountStateChange as AccountStateChangeBO};
+use postings_db::models::account_state::{AccountState as AccountStateModel, AccountStateChange as AccountStateChangeModel};
+
+pub struct AccountStateMapper;
+
+impl AccountStateMapper {
+    pub fn to_bo(model: AccountStateChangeModel) -> AccountStateChangeBO {
+        AccountStateChangeBO {
+            id: model.id,
+            account_id: model.account_id,
+            state: Self::state_to_bo(model.state),
+            effective_time: model.effective_time,
+            record_user: model.record_user,
+            record_time: model.record_time,
+            reason: model.reason,
+        }
+    }
+
+    pub fn to_model(bo: AccountStateChangeBO) -> AccountStateChangeModel {
+        AccountStateChangeModel {
+            id: bo.id,
+            account_id: bo.account_id,
+            state: Self::state_to_model(bo.state),
+            effective_time: bo.effective_time,
+            record_user: bo.record_user,
+            record_time: bo.record_time,
+            reason: bo.reason,
+        }
+    }
+
+    pub fn state_to_bo(model: AccountStateModel) -> AccountStateBO {
+        match model {
+            AccountStateModel::Open => AccountStateBO::Open,
+            AccountStateModel::DebitBlocked => AccountStateBO::DebitBlocked,
+            AccountStateModel::CreditBlocked => AccountStateBO::CreditBlocked,
+            AccountStateModel::Frozen => AccountStateBO::Frozen,
+            AccountStateModel::Closed => AccountStateBO::Closed,
+        }
+    }
+
+    pub fn state_to_model(bo: AccountStateBO) -> AccountStateModel {
+        match bo {
+            AccountStateBO::Open => AccountStateModel::Open,
+            AccountStateBO::DebitBlocked => AccountStateModel::DebitBlocked,
+            AccountStateBO::CreditBlocked => AccountStateModel::CreditBlocked,
+            AccountStateBO::Frozen => AccountStateModel::Frozen,
+            AccountStateBO::Closed => AccountStateModel::Closed,
+        }
+    }
+}
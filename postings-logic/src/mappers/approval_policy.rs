@@ -0,0 +1,20 @@
+use postings_api::domain::approval_policy::ApprovalPolicy as ApprovalPolicyBO;
+use postings_db::models::approval_policy::ApprovalPolicy as ApprovalPolicyModel;
+
+pub struct ApprovalPolicyMapper;
+
+impl ApprovalPolicyMapper {
+    pub fn to_bo(model: ApprovalPolicyModel, ledger_bo: postings_api::domain::ledger::Ledger) -> ApprovalPolicyBO {
+        ApprovalPolicyBO {
+            ledger: ledger_bo,
+            amount_threshold: model.amount_threshold,
+        }
+    }
+
+    pub fn to_model(bo: ApprovalPolicyBO) -> ApprovalPolicyModel {
+        ApprovalPolicyModel {
+            ledger_id: bo.ledger.id,
+            amount_threshold: bo.amount_threshold,
+        }
+    }
+}
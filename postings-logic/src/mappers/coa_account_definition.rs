@@ -0,0 +1,34 @@
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::coa_account_definition::CoaAccountDefinition as CoaAccountDefinitionBO;
+use postings_db::models::coa_account_definition::CoaAccountDefinition as CoaAccountDefinitionModel;
+use crate::mappers::ledger_account::LedgerAccountMapper;
+
+pub struct CoaAccountDefinitionMapper;
+
+impl CoaAccountDefinitionMapper {
+    pub fn to_bo(model: CoaAccountDefinitionModel, coa_bo: ChartOfAccount) -> CoaAccountDefinitionBO {
+        CoaAccountDefinitionBO {
+            id: model.id,
+            coa: coa_bo,
+            code: model.code,
+            name: model.name,
+            language: model.language,
+            category: LedgerAccountMapper::category_to_bo(model.category),
+            balance_side: LedgerAccountMapper::balance_side_to_bo(model.balance_side),
+            parent_code: model.parent_code,
+        }
+    }
+
+    pub fn to_model(bo: CoaAccountDefinitionBO) -> CoaAccountDefinitionModel {
+        CoaAccountDefinitionModel {
+            id: bo.id,
+            coa_id: bo.coa.id,
+            code: bo.code,
+            name: bo.name,
+            language: bo.language,
+            category: LedgerAccountMapper::category_to_model(bo.category),
+            balance_side: LedgerAccountMapper::balance_side_to_model(bo.balance_side),
+            parent_code: bo.parent_code,
+        }
+    }
+}
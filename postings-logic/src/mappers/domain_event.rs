@@ -0,0 +1,56 @@
+use postings_api::domain::domain_event::{DomainEvent as DomainEventBO, DomainEventPayload as DomainEventPayloadBO};
+use postings_api::{DbFailure, ServiceError};
+use postings_db::models::outbox_event::{EventPayload as EventPayloadModel, OutboxEvent as OutboxEventModel};
+
+use crate::mappers::account_state::AccountStateMapper;
+use crate::mappers::posting::PostingMapper;
+
+pub struct DomainEventMapper;
+
+impl DomainEventMapper {
+    /// Fails with [`ServiceError::Db`] if the stored payload does not decode.
+    pub fn to_bo(model: OutboxEventModel) -> Result<DomainEventBO, ServiceError> {
+        let payload = model.decode_payload().map_err(|e| ServiceError::Db {
+            failure: DbFailure::Other,
+            constraint: None,
+            context: format!("decoding payload of event {}", model.id),
+            source: Box::new(e),
+        })?;
+        Ok(DomainEventBO {
+            id: model.id,
+            ledger_id: model.ledger_id,
+            sequence: model.ledger_seq as u64,
+            occurred_at: model.occurred_at,
+            dispatched_at: model.dispatched_at,
+            payload: Self::payload_to_bo(payload),
+        })
+    }
+
+    pub fn payload_to_bo(payload: EventPayloadModel) -> DomainEventPayloadBO {
+        match payload {
+            EventPayloadModel::LedgerCreated { ledger_id, coa_id } => DomainEventPayloadBO::LedgerCreated { ledger_id, coa_id },
+            EventPayloadModel::LedgerAccountCreated { account_id, parent_id, code } => {
+                DomainEventPayloadBO::LedgerAccountCreated { account_id, parent_id, code }
+            }
+            EventPayloadModel::AccountStateChanged { account_id, state, effective_time } => DomainEventPayloadBO::AccountStateChanged {
+                account_id,
+                state: AccountStateMapper::state_to_bo(state),
+                effective_time,
+            },
+            EventPayloadModel::PostingCreated { posting_id, opr_id, pst_status, pst_time } => DomainEventPayloadBO::PostingCreated {
+                posting_id,
+                opr_id,
+                pst_status: PostingMapper::status_to_bo(pst_status),
+                pst_time,
+            },
+            EventPayloadModel::PostingStatusChanged { posting_id, pst_status } => DomainEventPayloadBO::PostingStatusChanged {
+                posting_id,
+                pst_status: PostingMapper::status_to_bo(pst_status),
+            },
+            EventPayloadModel::PostingDiscarded { posting_id } => DomainEventPayloadBO::PostingDiscarded { posting_id },
+            EventPayloadModel::StatementClosed { stmt_id, account_id, posting_id, pst_time } => {
+                DomainEventPayloadBO::StatementClosed { stmt_id, account_id, posting_id, pst_time }
+            }
+        }
+    }
+}
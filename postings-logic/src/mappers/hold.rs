@@ -0,0 +1,54 @@
+use postings_api::domain::hold::{Hold as HoldBO, HoldStatus as HoldStatusBO};
+use postings_db::models::hold::{Hold as HoldModel, HoldStatus as HoldStatusModel};
+
+pub struct HoldMapper;
+
+impl HoldMapper {
+    pub fn to_bo(model: HoldModel) -> HoldBO {
+        HoldBO {
+            id: model.id,
+            account_id: model.account_id,
+            amount: model.amount,
+            expiry: model.expiry,
+            opr_id: model.opr_id,
+            status: Self::status_to_bo(model.status),
+            record_time: model.record_time,
+            closed_time: model.closed_time,
+            captured_amount: model.captured_amount,
+            posting_id: model.posting_id,
+        }
+    }
+
+    pub fn to_model(bo: HoldBO) -> HoldModel {
+        HoldModel {
+            id: bo.id,
+            account_id: bo.account_id,
+            amount: bo.amount,
+            expiry: bo.expiry,
+            opr_id: bo.opr_id,
+            status: Self::status_to_model(bo.status),
+            record_time: bo.record_time,
+            closed_time: bo.closed_time,
+            captured_amount: bo.captured_amount,
+            posting_id: bo.posting_id,
+        }
+    }
+
+    pub fn status_to_bo(model: HoldStatusModel) -> HoldStatusBO {
+        match model {
+            HoldStatusModel::Active => HoldStatusBO::Active,
+            HoldStatusModel::Captured => HoldStatusBO::Captured,
+            HoldStatusModel::Released => HoldStatusBO::Released,
+            HoldStatusModel::Expired => HoldStatusBO::Expired,
+        }
+    }
+
+    pub fn status_to_model(bo: HoldStatusBO) -> HoldStatusModel {
+        match bo {
+            HoldStatusBO::Active => HoldStatusModel::Active,
+            HoldStatusBO::Captured => HoldStatusModel::Captured,
+            HoldStatusBO::Released => HoldStatusModel::Released,
+            HoldStatusBO::Expired => HoldStatusModel::Expired,
+        }
+    }
+}
@@ -1,4 +1,8 @@
+use postings_api::domain::account_category::AccountCategory as AccountCategoryBO;
+use postings_api::domain::balance_side::BalanceSide as BalanceSideBO;
 use postings_api::domain::ledger_account::LedgerAccount as LedgerAccountBO;
+use postings_db::models::account_category::AccountCategory as AccountCategoryModel;
+use postings_db::models::balance_side::BalanceSide as BalanceSideModel;
 use postings_db::models::ledger_account::LedgerAccount as LedgerAccountModel;
 
 pub struct LedgerAccountMapper;
@@ -10,21 +14,9 @@ impl LedgerAccountMapper {
             ledger: ledger_bo,
             parent: parent_bo,
             coa: coa_bo,
-            balance_side: match model.balance_side {
-                postings_db::models::balance_side::BalanceSide::Dr => postings_api::domain::balance_side::BalanceSide::Dr,
-                postings_db::models::balance_side::BalanceSide::Cr => postings_api::domain::balance_side::BalanceSide::Cr,
-                postings_db::models::balance_side::BalanceSide::DrCr => postings_api::domain::balance_side::BalanceSide::DrCr,
-            },
-            category: match model.category {
-                postings_db::models::account_category::AccountCategory::RE => postings_api::domain::account_category::AccountCategory::RE,
-                postings_db::models::account_category::AccountCategory::EX => postings_api::domain::account_category::AccountCategory::EX,
-                postings_db::models::account_category::AccountCategory::AS => postings_api::domain::account_category::AccountCategory::AS,
-                postings_db::models::account_category::AccountCategory::LI => postings_api::domain::account_category::AccountCategory::LI,
-                postings_db::models::account_category::AccountCategory::EQ => postings_api::domain::account_category::AccountCategory::EQ,
-                postings_db::models::account_category::AccountCategory::NOOP => postings_api::domain::account_category::AccountCategory::NOOP,
-                postings_db::models::account_category::AccountCategory::NORE => postings_api::domain::account_category::AccountCategory::NORE,
-                postings_db::models::account_category::AccountCategory::NOEX => postings_api::domain::account_category::AccountCategory::NOEX,
-            },
+            balance_side: Self::balance_side_to_bo(model.balance_side),
+            category: Self::category_to_bo(model.category),
+            code: model.code,
         }
     }
 
@@ -34,22 +26,51 @@ impl LedgerAccountMapper {
             ledger_id: bo.ledger.id,
             parent_id: bo.parent.map(|p| p.id),
             coa_id: bo.coa.id,
-            balance_side: match bo.balance_side {
-                postings_api::domain::balance_side::BalanceSide::Dr => postings_db::models::balance_side::BalanceSide::Dr,
-                postings_api::domain::balance_side::BalanceSide::Cr => postings_db::models::balance_side::BalanceSide::Cr,
-                postings_api::domain::balance_side::BalanceSide::DrCr => postings_db::models::balance_side::BalanceSide::DrCr,
-            },
-            category: match bo.category {
-                postings_api::domain::account_category::AccountCategory::RE => postings_db::models::account_category::AccountCategory::RE,
-                postings_api::domain::account_category::AccountCategory::EX => postings_db::models::account_category::AccountCategory::EX,
-                postings_api::domain::account_category::AccountCategory::AS => postings_db::models::account_category::AccountCategory::AS,
-                postings_api::domain::account_category::AccountCategory::LI => postings_db::models::account_category::AccountCategory::LI,
-                postings_api::domain::account_category::AccountCategory::EQ => postings_db::models::account_category::AccountCategory::EQ,
-                postings_api::domain::account_category::AccountCategory::NOOP => postings_db::models::account_category::AccountCategory::NOOP,
-                postings_api::domain::account_category::AccountCategory::NORE => postings_db::models::account_category::AccountCategory::NORE,
-                postings_api::domain::account_category::AccountCategory::NOEX => postings_db::models::account_category::AccountCategory::NOEX,
-            },
+            balance_side: Self::balance_side_to_model(bo.balance_side),
+            category: Self::category_to_model(bo.category),
+            code: bo.code,
+        }
+    }
+
+    pub fn balance_side_to_bo(model: BalanceSideModel) -> BalanceSideBO {
+        match model {
+            BalanceSideModel::Dr => BalanceSideBO::Dr,
+            BalanceSideModel::Cr => BalanceSideBO::Cr,
+            BalanceSideModel::DrCr => BalanceSideBO::DrCr,
+        }
+    }
+
+    pub fn balance_side_to_model(bo: BalanceSideBO) -> BalanceSideModel {
+        match bo {
+            BalanceSideBO::Dr => BalanceSideModel::Dr,
+            BalanceSideBO::Cr => BalanceSideModel::Cr,
+            BalanceSideBO::DrCr => BalanceSideModel::DrCr,
         }
     }
-}
 
+    pub fn category_to_bo(model: AccountCategoryModel) -> AccountCategoryBO {
+        match model {
+            AccountCategoryModel::RE => AccountCategoryBO::RE,
+            AccountCategoryModel::EX => AccountCategoryBO::EX,
+            AccountCategoryModel::AS => AccountCategoryBO::AS,
+            AccountCategoryModel::LI => AccountCategoryBO::LI,
+            AccountCategoryModel::EQ => AccountCategoryBO::EQ,
+            AccountCategoryModel::NOOP => AccountCategoryBO::NOOP,
+            AccountCategoryModel::NORE => AccountCategoryBO::NORE,
+            AccountCategoryModel::NOEX => AccountCategoryBO::NOEX,
+        }
+    }
+
+    pub fn category_to_model(bo: AccountCategoryBO) -> AccountCategoryModel {
+        match bo {
+            AccountCategoryBO::RE => AccountCategoryModel::RE,
+            AccountCategoryBO::EX => AccountCategoryModel::EX,
+            AccountCategoryBO::AS => AccountCategoryModel::AS,
+            AccountCategoryBO::LI => AccountCategoryModel::LI,
+            AccountCategoryBO::EQ => AccountCategoryModel::EQ,
+            AccountCategoryBO::NOOP => AccountCategoryModel::NOOP,
+            AccountCategoryBO::NORE => AccountCategoryModel::NORE,
+            AccountCategoryBO::NOEX => AccountCategoryModel::NOEX,
+        }
+    }
+}
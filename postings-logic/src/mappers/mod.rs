@@ -1,4 +1,5 @@
 pub mod chart_of_account;
+pub mod coa_account_definition;
 pub mod ledger;
 pub mod ledger_account;
 pub mod named;
@@ -6,3 +7,11 @@ pub mod posting;
 pub mod posting_line;
 pub mod account_stmt;
 pub mod posting_trace;
+pub mod approval_policy;
+pub mod posting_approval;
+pub mod time_axis;
+pub mod account_state;
+pub mod account_limit;
+pub mod hold;
+pub mod posting_schedule;
+pub mod domain_event;
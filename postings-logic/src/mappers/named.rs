@@ -1,5 +1,5 @@
-use postings_api::domain::named::Named as NamedBO;
-use postings_db::models::named::Named as NamedModel;
+use postings_api::domain::named::{ContainerType as ContainerTypeBO, Named as NamedBO};
+use postings_db::models::named::{ContainerType as ContainerTypeModel, Named as NamedModel};
 
 pub struct NamedMapper;
 
@@ -15,11 +15,8 @@ impl NamedMapper {
             user_details: model.user_details,
             short_desc: model.short_desc,
             long_desc: model.long_desc,
-            container_type: match model.container_type {
-                postings_db::models::named::ContainerType::Ledger => postings_api::domain::named::ContainerType::Ledger,
-                postings_db::models::named::ContainerType::ChartOfAccount => postings_api::domain::named::ContainerType::ChartOfAccount,
-                postings_db::models::named::ContainerType::LedgerAccount => postings_api::domain::named::ContainerType::LedgerAccount,
-            }
+            container_type: Self::container_type_to_bo(model.container_type),
+            retired: model.retired,
         }
     }
 
@@ -34,11 +31,24 @@ impl NamedMapper {
             user_details: bo.user_details,
             short_desc: bo.short_desc,
             long_desc: bo.long_desc,
-            container_type: match bo.container_type {
-                postings_api::domain::named::ContainerType::Ledger => postings_db::models::named::ContainerType::Ledger,
-                postings_api::domain::named::ContainerType::ChartOfAccount => postings_db::models::named::ContainerType::ChartOfAccount,
-                postings_api::domain::named::ContainerType::LedgerAccount => postings_db::models::named::ContainerType::LedgerAccount,
-            }
+            container_type: Self::container_type_to_model(bo.container_type),
+            retired: bo.retired,
+        }
+    }
+
+    pub fn container_type_to_bo(model: ContainerTypeModel) -> ContainerTypeBO {
+        match model {
+            ContainerTypeModel::Ledger => ContainerTypeBO::Ledger,
+            ContainerTypeModel::ChartOfAccount => ContainerTypeBO::ChartOfAccount,
+            ContainerTypeModel::LedgerAccount => ContainerTypeBO::LedgerAccount,
+        }
+    }
+
+    pub fn container_type_to_model(bo: ContainerTypeBO) -> ContainerTypeModel {
+        match bo {
+            ContainerTypeBO::Ledger => ContainerTypeModel::Ledger,
+            ContainerTypeBO::ChartOfAccount => ContainerTypeModel::ChartOfAccount,
+            ContainerTypeBO::LedgerAccount => ContainerTypeModel::LedgerAccount,
         }
     }
 }
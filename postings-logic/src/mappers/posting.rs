@@ -16,25 +16,8 @@ impl PostingMapper {
             opr_details: model.opr_details,
             opr_src: model.opr_src,
             pst_time: model.pst_time,
-            pst_type: match model.pst_type {
-                postings_db::models::posting_type::PostingType::BusiTx => postings_api::domain::posting_type::PostingType::BusiTx,
-                postings_db::models::posting_type::PostingType::AdjTx => postings_api::domain::posting_type::PostingType::AdjTx,
-                postings_db::models::posting_type::PostingType::BalStmt => postings_api::domain::posting_type::PostingType::BalStmt,
-                postings_db::models::posting_type::PostingType::PnlStmt => postings_api::domain::posting_type::PostingType::PnLStmt,
-                postings_db::models::posting_type::PostingType::BsStmt => postings_api::domain::posting_type::PostingType::BsStmt,
-                postings_db::models::posting_type::PostingType::LdgClsng => postings_api::domain::posting_type::PostingType::LdgClsng,
-                postings_db::models::posting_type::PostingType::Unknown => postings_api::domain::posting_type::PostingType::Unknown,
-            },
-            pst_status: match model.pst_status {
-                postings_db::models::posting_status::PostingStatus::Deferred => postings_api::domain::posting_status::PostingStatus::Deferred,
-                postings_db::models::posting_status::PostingStatus::Posted => postings_api::domain::posting_status::PostingStatus::Posted,
-                postings_db::models::posting_status::PostingStatus::Proposed => postings_api::domain::posting_status::PostingStatus::Proposed,
-                postings_db::models::posting_status::PostingStatus::Simulated => postings_api::domain::posting_status::PostingStatus::Simulated,
-                postings_db::models::posting_status::PostingStatus::Tax => postings_api::domain::posting_status::PostingStatus::Tax,
-                postings_db::models::posting_status::PostingStatus::Unposted => postings_api::domain::posting_status::PostingStatus::Unposted,
-                postings_db::models::posting_status::PostingStatus::Cancelled => postings_api::domain::posting_status::PostingStatus::Cancelled,
-                postings_db::models::posting_status::PostingStatus::Other => postings_api::domain::posting_status::PostingStatus::Other,
-            },
+            pst_type: Self::type_to_bo(model.pst_type),
+            pst_status: Self::status_to_bo(model.pst_status),
             ledger: ledger_bo,
             val_time: model.val_time,
             lines: lines_bo,
@@ -60,25 +43,8 @@ impl PostingMapper {
             opr_details: bo.opr_details,
             opr_src: bo.opr_src,
             pst_time: bo.pst_time,
-            pst_type: match bo.pst_type {
-                postings_api::domain::posting_type::PostingType::BusiTx => postings_db::models::posting_type::PostingType::BusiTx,
-                postings_api::domain::posting_type::PostingType::AdjTx => postings_db::models::posting_type::PostingType::AdjTx,
-                postings_api::domain::posting_type::PostingType::BalStmt => postings_db::models::posting_type::PostingType::BalStmt,
-                postings_api::domain::posting_type::PostingType::PnLStmt => postings_db::models::posting_type::PostingType::PnlStmt,
-                postings_api::domain::posting_type::PostingType::BsStmt => postings_db::models::posting_type::PostingType::BsStmt,
-                postings_api::domain::posting_type::PostingType::LdgClsng => postings_db::models::posting_type::PostingType::LdgClsng,
-                postings_api::domain::posting_type::PostingType::Unknown => postings_db::models::posting_type::PostingType::Unknown,
-            },
-            pst_status: match bo.pst_status {
-                postings_api::domain::posting_status::PostingStatus::Deferred => postings_db::models::posting_status::PostingStatus::Deferred,
-                postings_api::domain::posting_status::PostingStatus::Posted => postings_db::models::posting_status::PostingStatus::Posted,
-                postings_api::domain::posting_status::PostingStatus::Proposed => postings_db::models::posting_status::PostingStatus::Proposed,
-                postings_api::domain::posting_status::PostingStatus::Simulated => postings_db::models::posting_status::PostingStatus::Simulated,
-                postings_api::domain::posting_status::PostingStatus::Tax => postings_db::models::posting_status::PostingStatus::Tax,
-                postings_api::domain::posting_status::PostingStatus::Unposted => postings_db::models::posting_status::PostingStatus::Unposted,
-                postings_api::domain::posting_status::PostingStatus::Cancelled => postings_db::models::posting_status::PostingStatus::Cancelled,
-                postings_api::domain::posting_status::PostingStatus::Other => postings_db::models::posting_status::PostingStatus::Other,
-            },
+            pst_type: Self::type_to_model(bo.pst_type),
+            pst_status: Self::status_to_model(bo.pst_status),
             ledger_id: bo.ledger.id,
             val_time: bo.val_time,
             discarded_id: bo.discarded_id,
@@ -89,4 +55,54 @@ impl PostingMapper {
             hash: bo.hash_record.hash,
         }
     }
+
+    pub fn type_to_bo(pst_type: postings_db::models::posting_type::PostingType) -> postings_api::domain::posting_type::PostingType {
+        match pst_type {
+            postings_db::models::posting_type::PostingType::BusiTx => postings_api::domain::posting_type::PostingType::BusiTx,
+            postings_db::models::posting_type::PostingType::AdjTx => postings_api::domain::posting_type::PostingType::AdjTx,
+            postings_db::models::posting_type::PostingType::BalStmt => postings_api::domain::posting_type::PostingType::BalStmt,
+            postings_db::models::posting_type::PostingType::PnlStmt => postings_api::domain::posting_type::PostingType::PnLStmt,
+            postings_db::models::posting_type::PostingType::BsStmt => postings_api::domain::posting_type::PostingType::BsStmt,
+            postings_db::models::posting_type::PostingType::LdgClsng => postings_api::domain::posting_type::PostingType::LdgClsng,
+            postings_db::models::posting_type::PostingType::Unknown => postings_api::domain::posting_type::PostingType::Unknown,
+        }
+    }
+
+    pub fn type_to_model(pst_type: postings_api::domain::posting_type::PostingType) -> postings_db::models::posting_type::PostingType {
+        match pst_type {
+            postings_api::domain::posting_type::PostingType::BusiTx => postings_db::models::posting_type::PostingType::BusiTx,
+            postings_api::domain::posting_type::PostingType::AdjTx => postings_db::models::posting_type::PostingType::AdjTx,
+            postings_api::domain::posting_type::PostingType::BalStmt => postings_db::models::posting_type::PostingType::BalStmt,
+            postings_api::domain::posting_type::PostingType::PnLStmt => postings_db::models::posting_type::PostingType::PnlStmt,
+            postings_api::domain::posting_type::PostingType::BsStmt => postings_db::models::posting_type::PostingType::BsStmt,
+            postings_api::domain::posting_type::PostingType::LdgClsng => postings_db::models::posting_type::PostingType::LdgClsng,
+            postings_api::domain::posting_type::PostingType::Unknown => postings_db::models::posting_type::PostingType::Unknown,
+        }
+    }
+
+    pub fn status_to_bo(status: postings_db::models::posting_status::PostingStatus) -> postings_api::domain::posting_status::PostingStatus {
+        match status {
+            postings_db::models::posting_status::PostingStatus::Deferred => postings_api::domain::posting_status::PostingStatus::Deferred,
+            postings_db::models::posting_status::PostingStatus::Posted => postings_api::domain::posting_status::PostingStatus::Posted,
+            postings_db::models::posting_status::PostingStatus::Proposed => postings_api::domain::posting_status::PostingStatus::Proposed,
+            postings_db::models::posting_status::PostingStatus::Simulated => postings_api::domain::posting_status::PostingStatus::Simulated,
+            postings_db::models::posting_status::PostingStatus::Tax => postings_api::domain::posting_status::PostingStatus::Tax,
+            postings_db::models::posting_status::PostingStatus::Unposted => postings_api::domain::posting_status::PostingStatus::Unposted,
+            postings_db::models::posting_status::PostingStatus::Cancelled => postings_api::domain::posting_status::PostingStatus::Cancelled,
+            postings_db::models::posting_status::PostingStatus::Other => postings_api::domain::posting_status::PostingStatus::Other,
+        }
+    }
+
+    pub fn status_to_model(status: postings_api::domain::posting_status::PostingStatus) -> postings_db::models::posting_status::PostingStatus {
+        match status {
+            postings_api::domain::posting_status::PostingStatus::Deferred => postings_db::models::posting_status::PostingStatus::Deferred,
+            postings_api::domain::posting_status::PostingStatus::Posted => postings_db::models::posting_status::PostingStatus::Posted,
+            postings_api::domain::posting_status::PostingStatus::Proposed => postings_db::models::posting_status::PostingStatus::Proposed,
+            postings_api::domain::posting_status::PostingStatus::Simulated => postings_db::models::posting_status::PostingStatus::Simulated,
+            postings_api::domain::posting_status::PostingStatus::Tax => postings_db::models::posting_status::PostingStatus::Tax,
+            postings_api::domain::posting_status::PostingStatus::Unposted => postings_db::models::posting_status::PostingStatus::Unposted,
+            postings_api::domain::posting_status::PostingStatus::Cancelled => postings_db::models::posting_status::PostingStatus::Cancelled,
+            postings_api::domain::posting_status::PostingStatus::Other => postings_db::models::posting_status::PostingStatus::Other,
+        }
+    }
 }
@@ -0,0 +1,24 @@
+use postings_api::domain::posting_approval::PostingApproval as PostingApprovalBO;
+use postings_db::models::posting_approval::PostingApproval as PostingApprovalModel;
+
+pub struct PostingApprovalMapper;
+
+impl PostingApprovalMapper {
+    pub fn to_bo(model: PostingApprovalModel) -> PostingApprovalBO {
+        PostingApprovalBO {
+            id: model.id,
+            posting_id: model.posting_id,
+            approver: model.approver,
+            approval_time: model.approval_time,
+        }
+    }
+
+    pub fn to_model(bo: PostingApprovalBO) -> PostingApprovalModel {
+        PostingApprovalModel {
+            id: bo.id,
+            posting_id: bo.posting_id,
+            approver: bo.approver,
+            approval_time: bo.approval_time,
+        }
+    }
+}
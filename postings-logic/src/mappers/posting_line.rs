@@ -1,5 +1,7 @@
 use postings_api::domain::posting_line::PostingLine as PostingLineBO;
 use postings_db::models::posting_line::PostingLine as PostingLineModel;
+use uuid::Uuid;
+use crate::mappers::posting::PostingMapper;
 
 pub struct PostingLineMapper;
 
@@ -19,6 +21,7 @@ impl PostingLineMapper {
             opr_id: model.opr_id,
             opr_src: model.opr_src,
             pst_time: model.pst_time,
+            val_time: model.val_time,
             pst_type: match model.pst_type {
                 postings_db::models::posting_type::PostingType::BusiTx => postings_api::domain::posting_type::PostingType::BusiTx,
                 postings_db::models::posting_type::PostingType::AdjTx => postings_api::domain::posting_type::PostingType::AdjTx,
@@ -28,25 +31,18 @@ impl PostingLineMapper {
                 postings_db::models::posting_type::PostingType::LdgClsng => postings_api::domain::posting_type::PostingType::LdgClsng,
                 postings_db::models::posting_type::PostingType::Unknown => postings_api::domain::posting_type::PostingType::Unknown,
             },
-            pst_status: match model.pst_status {
-                postings_db::models::posting_status::PostingStatus::Deferred => postings_api::domain::posting_status::PostingStatus::Deferred,
-                postings_db::models::posting_status::PostingStatus::Posted => postings_api::domain::posting_status::PostingStatus::Posted,
-                postings_db::models::posting_status::PostingStatus::Proposed => postings_api::domain::posting_status::PostingStatus::Proposed,
-                postings_db::models::posting_status::PostingStatus::Simulated => postings_api::domain::posting_status::PostingStatus::Simulated,
-                postings_db::models::posting_status::PostingStatus::Tax => postings_api::domain::posting_status::PostingStatus::Tax,
-                postings_db::models::posting_status::PostingStatus::Unposted => postings_api::domain::posting_status::PostingStatus::Unposted,
-                postings_db::models::posting_status::PostingStatus::Cancelled => postings_api::domain::posting_status::PostingStatus::Cancelled,
-                postings_db::models::posting_status::PostingStatus::Other => postings_api::domain::posting_status::PostingStatus::Other,
-            },
+            pst_status: PostingMapper::status_to_bo(model.pst_status),
             hash: model.hash,
             additional_information: None, // Not in DB model
             discarded_time: model.discarded_time,
         }
     }
 
-    pub fn from_bo(bo: PostingLineBO) -> PostingLineModel {
+    /// The model of a line of the posting with the given id.
+    pub fn from_bo(bo: PostingLineBO, posting_id: Uuid) -> PostingLineModel {
         PostingLineModel {
             id: bo.id,
+            posting_id: Some(posting_id),
             account_id: bo.account.id,
             debit_amount: bo.debit_amount,
             credit_amount: bo.credit_amount,
@@ -58,6 +54,7 @@ impl PostingLineMapper {
             opr_id: bo.opr_id,
             opr_src: bo.opr_src,
             pst_time: bo.pst_time,
+            val_time: bo.val_time,
             pst_type: match bo.pst_type {
                 postings_api::domain::posting_type::PostingType::BusiTx => postings_db::models::posting_type::PostingType::BusiTx,
                 postings_api::domain::posting_type::PostingType::AdjTx => postings_db::models::posting_type::PostingType::AdjTx,
@@ -67,16 +64,7 @@ impl PostingLineMapper {
                 postings_api::domain::posting_type::PostingType::LdgClsng => postings_db::models::posting_type::PostingType::LdgClsng,
                 postings_api::domain::posting_type::PostingType::Unknown => postings_db::models::posting_type::PostingType::Unknown,
             },
-            pst_status: match bo.pst_status {
-                postings_api::domain::posting_status::PostingStatus::Deferred => postings_db::models::posting_status::PostingStatus::Deferred,
-                postings_api::domain::posting_status::PostingStatus::Posted => postings_db::models::posting_status::PostingStatus::Posted,
-                postings_api::domain::posting_status::PostingStatus::Proposed => postings_db::models::posting_status::PostingStatus::Proposed,
-                postings_api::domain::posting_status::PostingStatus::Simulated => postings_db::models::posting_status::PostingStatus::Simulated,
-                postings_api::domain::posting_status::PostingStatus::Tax => postings_db::models::posting_status::PostingStatus::Tax,
-                postings_api::domain::posting_status::PostingStatus::Unposted => postings_db::models::posting_status::PostingStatus::Unposted,
-                postings_api::domain::posting_status::PostingStatus::Cancelled => postings_db::models::posting_status::PostingStatus::Cancelled,
-                postings_api::domain::posting_status::PostingStatus::Other => postings_db::models::posting_status::PostingStatus::Other,
-            },
+            pst_status: PostingMapper::status_to_model(bo.pst_status),
             hash: bo.hash,
             discarded_time: bo.discarded_time,
         }
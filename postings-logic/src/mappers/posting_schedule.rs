@@ -0,0 +1,120 @@
+use postings_api::domain::ledger::Ledger as LedgerBO;
+use postings_api::domain::posting_schedule::{
+    CatchUp as CatchUpBO, PostingSchedule as PostingScheduleBO, ScheduleRun as ScheduleRunBO, ScheduleStatus as ScheduleStatusBO,
+    ScheduledLine as ScheduledLineBO,
+};
+use postings_api::ServiceError;
+use postings_db::models::posting_schedule::{
+    CatchUp as CatchUpModel, PostingSchedule as PostingScheduleModel, ScheduleRun as ScheduleRunModel,
+    ScheduleStatus as ScheduleStatusModel, ScheduledLine as ScheduledLineModel,
+};
+
+use crate::mappers::posting::PostingMapper;
+
+pub struct PostingScheduleMapper;
+
+impl PostingScheduleMapper {
+    /// Fails with [`ServiceError::InvalidRecurrence`] if the stored rule no longer parses.
+    pub fn to_bo(model: PostingScheduleModel, ledger_bo: LedgerBO, lines: Vec<ScheduledLineModel>) -> Result<PostingScheduleBO, ServiceError> {
+        Ok(PostingScheduleBO {
+            id: model.id,
+            ledger: ledger_bo,
+            recurrence: model.rrule.parse()?,
+            start_time: model.start_time,
+            catch_up: Self::catch_up_to_bo(model.catch_up),
+            record_user: model.record_user,
+            opr_type: model.opr_type,
+            pst_type: PostingMapper::type_to_bo(model.pst_type),
+            lines: lines.into_iter().map(Self::line_to_bo).collect(),
+            status: Self::status_to_bo(model.status),
+            next_index: model.next_index as u32,
+            next_time: model.next_time,
+            record_time: model.record_time,
+        })
+    }
+
+    pub fn to_model(bo: PostingScheduleBO) -> PostingScheduleModel {
+        PostingScheduleModel {
+            id: bo.id,
+            ledger_id: bo.ledger.id,
+            rrule: bo.recurrence.to_string(),
+            start_time: bo.start_time,
+            catch_up: Self::catch_up_to_model(bo.catch_up),
+            record_user: bo.record_user,
+            opr_type: bo.opr_type,
+            pst_type: PostingMapper::type_to_model(bo.pst_type),
+            status: Self::status_to_model(bo.status),
+            next_index: bo.next_index as i32,
+            next_time: bo.next_time,
+            record_time: bo.record_time,
+        }
+    }
+
+    pub fn line_to_bo(model: ScheduledLineModel) -> ScheduledLineBO {
+        ScheduledLineBO {
+            account_id: model.account_id,
+            debit_amount: model.debit_amount,
+            credit_amount: model.credit_amount,
+        }
+    }
+
+    pub fn line_to_model(bo: ScheduledLineBO, id: uuid::Uuid, schedule_id: uuid::Uuid) -> ScheduledLineModel {
+        ScheduledLineModel {
+            id,
+            schedule_id,
+            account_id: bo.account_id,
+            debit_amount: bo.debit_amount,
+            credit_amount: bo.credit_amount,
+        }
+    }
+
+    pub fn run_to_bo(model: ScheduleRunModel) -> ScheduleRunBO {
+        ScheduleRunBO {
+            schedule_id: model.schedule_id,
+            occurrence_index: model.occurrence_index as u32,
+            occurrence_time: model.occurrence_time,
+            posting_id: model.posting_id,
+            run_time: model.run_time,
+        }
+    }
+
+    pub fn run_to_model(bo: ScheduleRunBO) -> ScheduleRunModel {
+        ScheduleRunModel {
+            schedule_id: bo.schedule_id,
+            occurrence_index: bo.occurrence_index as i32,
+            occurrence_time: bo.occurrence_time,
+            posting_id: bo.posting_id,
+            run_time: bo.run_time,
+        }
+    }
+
+    pub fn status_to_bo(model: ScheduleStatusModel) -> ScheduleStatusBO {
+        match model {
+            ScheduleStatusModel::Active => ScheduleStatusBO::Active,
+            ScheduleStatusModel::Finished => ScheduleStatusBO::Finished,
+            ScheduleStatusModel::Cancelled => ScheduleStatusBO::Cancelled,
+        }
+    }
+
+    pub fn status_to_model(bo: ScheduleStatusBO) -> ScheduleStatusModel {
+        match bo {
+            ScheduleStatusBO::Active => ScheduleStatusModel::Active,
+            ScheduleStatusBO::Finished => ScheduleStatusModel::Finished,
+            ScheduleStatusBO::Cancelled => ScheduleStatusModel::Cancelled,
+        }
+    }
+
+    pub fn catch_up_to_bo(model: CatchUpModel) -> CatchUpBO {
+        match model {
+            CatchUpModel::All => CatchUpBO::All,
+            CatchUpModel::Latest => CatchUpBO::Latest,
+        }
+    }
+
+    pub fn catch_up_to_model(bo: CatchUpBO) -> CatchUpModel {
+        match bo {
+            CatchUpBO::All => CatchUpModel::All,
+            CatchUpBO::Latest => CatchUpModel::Latest,
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use postings_api::domain::time_axis::TimeAxis as TimeAxisBO;
+use postings_db::models::time_axis::TimeAxis as TimeAxisModel;
+
+pub struct TimeAxisMapper;
+
+impl TimeAxisMapper {
+    pub fn to_model(bo: TimeAxisBO) -> TimeAxisModel {
+        match bo {
+            TimeAxisBO::PstTime => TimeAxisModel::PstTime,
+            TimeAxisBO::ValTime => TimeAxisModel::ValTime,
+            TimeAxisBO::RecordTime => TimeAxisModel::RecordTime,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use postings_api::domain::account_state::{AccountState, AccountStateChange};
+use postings_api::service::account_lifecycle_service::AccountLifecycleService;
+use postings_api::ServiceError;
+use postings_db::DbError;
+
+use crate::mappers::account_state::AccountStateMapper;
+use crate::services::shared_service::SharedService;
+
+#[derive(Clone)]
+pub struct AccountLifecycleServiceImpl {
+    shared: Arc<SharedService>,
+}
+
+impl AccountLifecycleServiceImpl {
+    pub fn new(shared: impl Into<Arc<SharedService>>) -> Self {
+        Self { shared: shared.into() }
+    }
+
+    async fn check_children_closed(&self, account_id: Uuid, effective_time: DateTime<Utc>) -> Result<(), ServiceError> {
+        let children = self
+            .shared
+            .ledger_account_repo
+            .find_by_parent_id(account_id)
+            .await
+            .map_err(|e| e.into_service_error(format!("loading child accounts of ledger account {account_id}")))?;
+        for child in children {
+            let changes = self.shared.load_account_state_changes(child.id).await?;
+            if AccountStateChange::state_at(&changes, effective_time) != AccountState::Closed {
+                return Err(ServiceError::OpenChildAccounts);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AccountLifecycleService for AccountLifecycleServiceImpl {
+    async fn change_account_state(
+        &self,
+        account_id: Uuid,
+        state: AccountState,
+        effective_time: DateTime<Utc>,
+        record_user: [u8; 34],
+        reason: Option<String>,
+    ) -> Result<AccountStateChange, ServiceError> {
+        self.shared
+            .load_ledger_account(account_id)
+            .await?
+            .ok_or(ServiceError::LedgerAccountNotFound)?;
+        let changes = self.shared.load_account_state_changes(account_id).await?;
+        if changes.iter().any(|c| c.state == AccountState::Closed) {
+            return Err(ServiceError::AccountClosed);
+        }
+        if state == AccountState::Closed {
+            self.check_children_closed(account_id, effective_time).await?;
+        }
+
+        let change = AccountStateChange {
+            id: self.shared.new_id(),
+            account_id,
+            state,
+            effective_time,
+            record_user,
+            record_time: self.shared.now(),
+            reason,
+        };
+        self.shared
+            .ledger_account_repo
+            .save_state_change(&AccountStateMapper::to_model(change.clone()))
+            .await
+            .map_err(|e| match e {
+                // Everything booked so far counts, including postings dated after the closing.
+                DbError::BalanceNotZero { .. } => ServiceError::AccountBalanceNotZero,
+                e => e.into_service_error(format!("saving state change of ledger account {account_id}")),
+            })?;
+        Ok(change)
+    }
+
+    async fn find_account_state(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<AccountState, ServiceError> {
+        let changes = self.find_account_state_changes(account_id).await?;
+        Ok(AccountStateChange::state_at(&changes, at))
+    }
+
+    async fn find_account_state_changes(&self, account_id: Uuid) -> Result<Vec<AccountStateChange>, ServiceError> {
+        self.shared
+            .load_ledger_account(account_id)
+            .await?
+            .ok_or(ServiceError::LedgerAccountNotFound)?;
+        self.shared.load_account_state_changes(account_id).await
+    }
+}
@@ -1,14 +1,15 @@
+use std::sync::Arc;
 use async_trait::async_trait;
 use bigdecimal::BigDecimal;
 use chrono::{DateTime, Utc};
 use log::{error, info};
-use uuid::Uuid;
 
 use postings_api::domain::account_stmt::AccountStmt;
 use postings_api::domain::financial_stmt::FinancialStmt;
 use postings_api::domain::ledger_account::LedgerAccount;
 use postings_api::domain::posting_status::PostingStatus;
 use postings_api::domain::posting_type::PostingType;
+use postings_api::domain::time_axis::TimeAxis;
 use postings_api::service::account_stmt_service::AccountStmtService;
 use postings_api::ServiceError;
 use postings_db::models::posting_line::PostingLine;
@@ -17,24 +18,28 @@ use postings_db::models::stmt_status::StmtStatus;
 
 use crate::hash_utils::hash_serialize;
 use crate::mappers::account_stmt::AccountStmtMapper;
+use crate::mappers::chart_of_account::ChartOfAccountMapper;
 use crate::mappers::ledger::LedgerMapper;
 use crate::mappers::posting::PostingMapper;
 use crate::mappers::posting_trace::PostingTraceMapper;
+use crate::mappers::time_axis::TimeAxisMapper;
 use crate::services::shared_service::SharedService;
 
+#[derive(Clone)]
 pub struct AccountStmtServiceImpl {
-    shared: SharedService,
+    shared: Arc<SharedService>,
 }
 
 impl AccountStmtServiceImpl {
-    pub fn new(shared: SharedService) -> Self {
-        Self { shared }
+    pub fn new(shared: impl Into<Arc<SharedService>>) -> Self {
+        Self { shared: shared.into() }
     }
 
     async fn stmt(
         &self,
         ledger_account: LedgerAccount,
         ref_time: DateTime<Utc>,
+        axis: TimeAxis,
     ) -> Result<AccountStmt, ServiceError> {
         info!(
             "Generating statement for account: {} at time: {}",
@@ -46,7 +51,7 @@ impl AccountStmtServiceImpl {
             .await
             .map_err(|e| {
                 info!("Error loading ledger account: {e:?}");
-                ServiceError::Db
+                e
             })?
             .ok_or_else(|| {
                 info!("Ledger account not found");
@@ -54,40 +59,46 @@ impl AccountStmtServiceImpl {
             })?;
 
         info!("Loaded account model: {}", account_model.id);
-        let last_closed_stmt = self
-            .shared
-            .stmt_repo
-            .find_first_by_account_and_status_and_pst_time_less_than_ordered(
-                account_model.id,
-                StmtStatus::Closed,
-                ref_time,
-            )
-            .await
-            .map_err(|e| {
-                info!("Error finding last closed statement: {e:?}");
-                ServiceError::Db
-            })?;
+        // Statements are closed on posting time, so on other axes they cannot stand in for the
+        // lines before them and all lines up to `ref_time` are read.
+        let last_closed_stmt = if axis == TimeAxis::PstTime {
+            self.shared
+                .stmt_repo
+                .find_first_by_account_and_status_and_pst_time_less_than_ordered(
+                    account_model.id,
+                    StmtStatus::Closed,
+                    ref_time,
+                )
+                .await
+                .map_err(|e| {
+                    info!("Error finding last closed statement: {e:?}");
+                    e.into_service_error(format!("finding last closed statement of ledger account {} before {ref_time}", account_model.id))
+                })?
+        } else {
+            None
+        };
 
         let (mut stmt, posting_lines) = if let Some(last_stmt) = last_closed_stmt {
             info!("Found last closed statement: {}", last_stmt.id);
             let lines = self
                 .shared
                 .line_repo
-                .find_by_account_and_pst_time_between(
+                .find_by_account_and_time_between(
                     account_model.id,
+                    TimeAxisMapper::to_model(axis),
                     last_stmt.pst_time,
                     ref_time,
                 )
                 .await
                 .map_err(|e| {
                     info!("Error finding posting lines for existing statement: {e:?}");
-                    ServiceError::Db
+                    e.into_service_error(format!("loading lines of ledger account {} after statement {}", account_model.id, last_stmt.id))
                 })?;
             (last_stmt, lines)
         } else {
             info!("No closed statement found, creating new simulated statement");
             let new_stmt = postings_db::models::account_stmt::AccountStmt {
-                id: Uuid::new_v4(),
+                id: self.shared.new_id(),
                 account_id: account_model.id,
                 youngest_pst_id: None,
                 total_debit: BigDecimal::from(0),
@@ -101,17 +112,20 @@ impl AccountStmtServiceImpl {
             let lines = self
                 .shared
                 .line_repo
-                .find_by_account_and_pst_time_less_than_equal(account_model.id, ref_time)
+                .find_by_account_and_time_less_than_equal(account_model.id, TimeAxisMapper::to_model(axis), ref_time)
                 .await
                 .map_err(|e| {
                     info!("Error finding posting lines for new statement: {e:?}");
-                    ServiceError::Db
+                    e.into_service_error(format!("loading lines of ledger account {} up to {ref_time}", account_model.id))
                 })?;
             (new_stmt, lines)
         };
 
         info!("Found {} posting lines", posting_lines.len());
-        for line in posting_lines {
+        for line in posting_lines
+            .into_iter()
+            .filter(|line| PostingMapper::status_to_bo(line.pst_status.clone()).is_effective())
+        {
             self.refresh_statement(&mut stmt, &line)
                 .await
                 .map_err(|e| {
@@ -125,7 +139,7 @@ impl AccountStmtServiceImpl {
                 .trace_repo
                 .find_by_id(id)
                 .await
-                .map_err(|_| ServiceError::Db)?
+                .map_err(|e| e.into_service_error(format!("loading posting trace {id}")))?
                 .map(|tm| PostingTraceMapper::to_bo(tm, ledger_account.clone()))
         } else {
             None
@@ -135,7 +149,7 @@ impl AccountStmtServiceImpl {
                 .trace_repo
                 .find_by_id(id)
                 .await
-                .map_err(|_| ServiceError::Db)?
+                .map_err(|e| e.into_service_error(format!("loading posting trace {id}")))?
                 .map(|tm| PostingTraceMapper::to_bo(tm, ledger_account.clone()))
         } else {
             None
@@ -145,7 +159,7 @@ impl AccountStmtServiceImpl {
                 .posting_repo
                 .find_by_id(id)
                 .await
-                .map_err(|_| ServiceError::Db)?
+                .map_err(|e| e.into_service_error(format!("loading posting {id}")))?
                 .map(|pm| {
                     // This mapping is incomplete as it requires more context (ledger, lines, etc.)
                     // For now, we create a simplified Posting BO, as the full details are not needed for the statement view.
@@ -191,9 +205,10 @@ impl AccountStmtServiceImpl {
         stmt.total_debit += line.debit_amount.clone();
         stmt.total_credit += line.credit_amount.clone();
 
+        let trace_id = trace.id;
         self.shared.trace_repo.save(trace).await.map_err(|e| {
             info!("Error saving posting trace: {e:?}");
-            ServiceError::Db
+            e.into_service_error(format!("saving posting trace {trace_id} of line {}", line.id))
         })?;
         Ok(())
     }
@@ -204,7 +219,7 @@ impl AccountStmtServiceImpl {
         line: &PostingLine,
     ) -> PostingTrace {
         PostingTrace {
-            id: Uuid::new_v4(),
+            id: self.shared.new_id(),
             tgt_pst_id: stmt.id,
             src_pst_time: line.pst_time,
             src_pst_id: line.id,
@@ -224,7 +239,16 @@ impl AccountStmtService for AccountStmtServiceImpl {
         ledger_account: LedgerAccount,
         ref_time: DateTime<Utc>,
     ) -> Result<AccountStmt, ServiceError> {
-        self.stmt(ledger_account, ref_time).await
+        self.stmt(ledger_account, ref_time, TimeAxis::PstTime).await
+    }
+
+    async fn read_stmt_by_time_axis(
+        &self,
+        ledger_account: LedgerAccount,
+        ref_time: DateTime<Utc>,
+        axis: TimeAxis,
+    ) -> Result<AccountStmt, ServiceError> {
+        self.stmt(ledger_account, ref_time, axis).await
     }
 
     async fn create_stmt(
@@ -232,11 +256,11 @@ impl AccountStmtService for AccountStmtServiceImpl {
         ledger_account: LedgerAccount,
         ref_time: DateTime<Utc>,
     ) -> Result<AccountStmt, ServiceError> {
-        let stmt_bo = self.stmt(ledger_account, ref_time).await?;
+        let stmt_bo = self.stmt(ledger_account, ref_time, TimeAxis::PstTime).await?;
         let stmt_model = AccountStmtMapper::from_bo(stmt_bo.clone());
-        self.shared.stmt_repo.save(stmt_model).await.map_err(|e| {
+        self.shared.stmt_repo.save(stmt_model, self.shared.now()).await.map_err(|e| {
             error!("Failed to save statement: {e:?}");
-            ServiceError::Db
+            e.into_service_error(format!("saving statement {}", stmt_bo.financial_stmt.id))
         })?;
         Ok(stmt_bo)
     }
@@ -247,36 +271,23 @@ impl AccountStmtService for AccountStmtServiceImpl {
             .stmt_repo
             .find_by_id(stmt.financial_stmt.id)
             .await
-            .map_err(|_| ServiceError::Db)?
+            .map_err(|e| e.into_service_error(format!("loading statement {}", stmt.financial_stmt.id)))?
             .ok_or(ServiceError::StatementNotFound)?;
 
         if stmt_model.stmt_status == StmtStatus::Closed {
             return Err(ServiceError::StatementAlreadyClosed);
         }
 
-        let ledger_model = self
-            .shared
-            .ledger_repo
-            .find_by_id(stmt.account.ledger.id)
-            .await
-            .map_err(|_| ServiceError::Db)?
-            .unwrap();
-        let coa_bo = self
-            .shared
-            .coa_repo
-            .find_by_id(ledger_model.coa_id)
-            .await
-            .map_err(|_| ServiceError::Db)?
-            .map(crate::mappers::chart_of_account::ChartOfAccountMapper::to_bo)
-            .unwrap();
+        let ledger_model = self.shared.load_ledger(stmt.account.ledger.id).await?;
+        let coa_bo = ChartOfAccountMapper::to_bo(self.shared.load_coa(ledger_model.coa_id).await?);
         let ledger_bo = LedgerMapper::to_bo(ledger_model, coa_bo);
 
         let mut closing_posting = postings_api::domain::posting::Posting {
-            id: Uuid::new_v4(),
+            id: self.shared.new_id(),
             record_user: [0; 34],
-            record_time: Utc::now(),
+            record_time: self.shared.now(),
             opr_id: [0; 34],
-            opr_time: Utc::now(),
+            opr_time: self.shared.now(),
             opr_type: [0; 34],
             opr_details: None,
             opr_src: None,
@@ -284,7 +295,7 @@ impl AccountStmtService for AccountStmtServiceImpl {
             pst_type: PostingType::BalStmt,
             pst_status: PostingStatus::Posted,
             ledger: ledger_bo,
-            val_time: Some(Utc::now()),
+            val_time: Some(self.shared.now()),
             lines: vec![],
             discarded_id: None,
             discarded_time: None,
@@ -295,11 +306,11 @@ impl AccountStmtService for AccountStmtServiceImpl {
         let antecedent = self
             .shared
             .posting_repo
-            .find_first_by_ledger_order_by_record_time_desc(
+            .find_first_by_ledger_and_hash_is_not_null_order_by_record_time_desc(
                 closing_posting.ledger.id,
             )
             .await
-            .map_err(|_| ServiceError::Db)?;
+            .map_err(|e| e.into_service_error(format!("loading chain head of ledger {}", closing_posting.ledger.id)))?;
         if let Some(ant) = antecedent {
             closing_posting.hash_record.antecedent_id = Some(ant.id);
             closing_posting.hash_record.antecedent_hash = ant.hash;
@@ -308,19 +319,14 @@ impl AccountStmtService for AccountStmtServiceImpl {
         closing_posting.hash_record.hash = Some(hash);
 
         let posting_model = PostingMapper::to_model(closing_posting.clone());
-        self.shared
-            .posting_repo
-            .save(&posting_model)
-            .await
-            .map_err(|_| ServiceError::Db)?;
-
         stmt_model.stmt_status = StmtStatus::Closed;
         stmt_model.posting_id = Some(closing_posting.id);
+        // The closing posting and the closed statement commit together, so neither exists without the other.
         self.shared
             .stmt_repo
-            .save(stmt_model.clone())
+            .close(stmt_model.clone(), &posting_model, self.shared.now())
             .await
-            .map_err(|_| ServiceError::Db)?;
+            .map_err(|e| e.into_service_error(format!("closing statement {} with posting {}", stmt_model.id, posting_model.id)))?;
 
         let mut closed_stmt_bo = stmt;
         closed_stmt_bo.financial_stmt.stmt_status =
@@ -0,0 +1,189 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use log::warn;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{broadcast, Mutex};
+use uuid::Uuid;
+
+use postings_api::domain::account_movement::{AccountMovement, FeedCursor};
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::service::change_feed_service::{ChangeFeedService, MovementStream};
+use postings_api::service::ledger_service::LedgerService;
+use postings_api::ServiceError;
+use postings_db::line_feed::{LineAppended, LineNotifier};
+use postings_db::models::posting_line::SequencedPostingLine;
+use postings_db::repositories::posting_line_repository::PostingLineRepository;
+
+use crate::mappers::posting_line::PostingLineMapper;
+use crate::services::ledger_service::LedgerServiceImpl;
+use crate::services::shared_service::SharedService;
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const DEFAULT_BATCH_SIZE: u32 = 100;
+/// Signals buffered per subscription; a subscription that falls further behind re-reads anyway.
+const WAKEUP_CAPACITY: usize = 1024;
+
+pub struct ChangeFeedServiceImpl {
+    shared: Arc<SharedService>,
+    ledger_service: LedgerServiceImpl,
+    notifier: Option<Arc<dyn LineNotifier + Send + Sync>>,
+    /// Fans the notifier's signals out to all subscriptions; set up by the first one.
+    wakeups: Mutex<Option<broadcast::Sender<LineAppended>>>,
+    poll_interval: Duration,
+    batch_size: u32,
+}
+
+impl ChangeFeedServiceImpl {
+    pub fn new(shared: impl Into<Arc<SharedService>>, ledger_service: LedgerServiceImpl) -> Self {
+        Self {
+            shared: shared.into(),
+            ledger_service,
+            notifier: None,
+            wakeups: Mutex::new(None),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            batch_size: DEFAULT_BATCH_SIZE,
+        }
+    }
+
+    /// Wakes subscriptions as soon as lines are committed instead of at the next poll. All
+    /// subscriptions share one listener.
+    pub fn with_notifier(mut self, notifier: Arc<dyn LineNotifier + Send + Sync>) -> Self {
+        self.notifier = Some(notifier);
+        self
+    }
+
+    /// How long a caught-up subscription waits before reading again, one second by default. With
+    /// a notifier it only bounds the delay of signals that got lost.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Lines read per query, 100 by default.
+    pub fn with_batch_size(mut self, batch_size: u32) -> Self {
+        self.batch_size = batch_size.max(1);
+        self
+    }
+
+    /// Receiver of the shared signals, starting the listener on first use. `None` leaves the
+    /// subscription to polling, e.g. when listening fails; the next subscription tries again.
+    async fn wakeups(&self) -> Option<broadcast::Receiver<LineAppended>> {
+        let notifier = self.notifier.as_ref()?;
+        let mut wakeups = self.wakeups.lock().await;
+        if let Some(sender) = wakeups.as_ref() {
+            return Some(sender.subscribe());
+        }
+        let mut notifications = match notifier.listen().await {
+            Ok(notifications) => notifications,
+            Err(e) => {
+                warn!("Could not listen for new lines, change feeds poll: {e:?}");
+                return None;
+            }
+        };
+        let (sender, receiver) = broadcast::channel(WAKEUP_CAPACITY);
+        let forward = sender.clone();
+        tokio::spawn(async move {
+            while let Some(signal) = notifications.next().await {
+                // Fails only while no subscription is open.
+                let _ = forward.send(signal);
+            }
+            warn!("Line notifications ended, change feeds fall back to polling");
+        });
+        *wakeups = Some(sender);
+        Some(receiver)
+    }
+}
+
+/// Subscription state, advanced each time the stream is polled.
+struct Feed {
+    line_repo: Arc<dyn PostingLineRepository + Send + Sync>,
+    account: LedgerAccount,
+    cursor: i64,
+    buffer: VecDeque<SequencedPostingLine>,
+    wakeups: Option<broadcast::Receiver<LineAppended>>,
+    poll_interval: Duration,
+    batch_size: u32,
+    failed: bool,
+}
+
+impl Feed {
+    async fn next_movement(mut self) -> Option<(Result<AccountMovement, ServiceError>, Self)> {
+        if self.failed {
+            return None;
+        }
+        loop {
+            if let Some(sequenced) = self.buffer.pop_front() {
+                self.cursor = sequenced.line_seq;
+                // Lines not yet in the books come back under a new sequence number once they are.
+                if !sequenced.line.pst_status.is_effective() {
+                    continue;
+                }
+                let movement = AccountMovement {
+                    cursor: FeedCursor(sequenced.line_seq as u64),
+                    line: PostingLineMapper::to_bo(sequenced.line, self.account.clone()),
+                };
+                return Some((Ok(movement), self));
+            }
+            match self.line_repo.find_by_account_after_seq(self.account.id, self.cursor, self.batch_size as i64).await {
+                Ok(lines) if lines.is_empty() => self.wait().await,
+                Ok(lines) => self.buffer.extend(lines),
+                Err(e) => {
+                    warn!("Reading the change feed of account {} failed: {e:?}", self.account.id);
+                    self.failed = true;
+                    let context = format!("reading the change feed of ledger account {} after {}", self.account.id, self.cursor);
+                    return Some((Err(e.into_service_error(context)), self));
+                }
+            }
+        }
+    }
+
+    /// Waits for a signal concerning the account, or at most the poll interval. Signals sent
+    /// since the last read are still queued, so none that matter can slip through.
+    async fn wait(&mut self) {
+        let account_id = self.account.id;
+        let Some(wakeups) = self.wakeups.as_mut() else {
+            tokio::time::sleep(self.poll_interval).await;
+            return;
+        };
+        let signal = async {
+            loop {
+                match wakeups.recv().await {
+                    Ok(LineAppended::Account(id)) if id != account_id => continue,
+                    Ok(_) | Err(RecvError::Lagged(_)) => return,
+                    Err(RecvError::Closed) => std::future::pending::<()>().await,
+                }
+            }
+        };
+        let _ = tokio::time::timeout(self.poll_interval, signal).await;
+    }
+}
+
+#[async_trait]
+impl ChangeFeedService for ChangeFeedServiceImpl {
+    async fn subscribe(&self, account_id: Uuid, from_cursor: Option<FeedCursor>) -> Result<MovementStream, ServiceError> {
+        let account = self
+            .ledger_service
+            .find_ledger_account_by_id(account_id)
+            .await?
+            .ok_or(ServiceError::LedgerAccountNotFound)?;
+        let feed = Feed {
+            line_repo: self.shared.line_repo.clone(),
+            account,
+            cursor: from_cursor.unwrap_or(FeedCursor::START).0 as i64,
+            buffer: VecDeque::new(),
+            wakeups: self.wakeups().await,
+            poll_interval: self.poll_interval,
+            batch_size: self.batch_size,
+            failed: false,
+        };
+        Ok(Box::pin(futures_util::stream::unfold(feed, Feed::next_movement)))
+    }
+
+    async fn current_cursor(&self, account_id: Uuid) -> Result<FeedCursor, ServiceError> {
+        let last_seq = self.shared.line_repo.find_last_seq_by_account(account_id).await.map_err(|e| e.into_service_error(format!("loading the last line of ledger account {account_id}")))?;
+        Ok(FeedCursor(last_seq as u64))
+    }
+}
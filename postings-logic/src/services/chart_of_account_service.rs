@@ -1,19 +1,52 @@
+use std::collections::HashMap;
+use std::sync::Arc;
 use async_trait::async_trait;
 use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::coa_account_definition::CoaAccountDefinition;
 use postings_api::service::chart_of_account_service::ChartOfAccountService;
 use postings_api::ServiceError;
 use postings_db::models::named::ContainerType;
 use crate::services::shared_service::SharedService;
 use uuid::Uuid;
 use crate::mappers::chart_of_account::ChartOfAccountMapper;
+use crate::mappers::coa_account_definition::CoaAccountDefinitionMapper;
+use postings_db::DbError;
 
+#[derive(Clone)]
 pub struct ChartOfAccountServiceImpl {
-    shared: SharedService,
+    shared: Arc<SharedService>,
 }
 
 impl ChartOfAccountServiceImpl {
-    pub fn new(shared: SharedService) -> Self {
-        Self { shared }
+    pub fn new(shared: impl Into<Arc<SharedService>>) -> Self {
+        Self { shared: shared.into() }
+    }
+
+    /// Orders definitions so that every parent comes before its children, rejecting duplicate
+    /// codes, unknown parent codes and cycles.
+    pub fn order_parents_first(definitions: &[CoaAccountDefinition]) -> Result<Vec<&CoaAccountDefinition>, ServiceError> {
+        let mut parents: HashMap<&str, Option<&str>> = HashMap::new();
+        for definition in definitions {
+            if definition.code.is_empty() || parents.insert(&definition.code, definition.parent_code.as_deref()).is_some() {
+                return Err(ServiceError::InvalidAccountDefinition);
+            }
+        }
+
+        let mut ordered = Vec::with_capacity(definitions.len());
+        for definition in definitions {
+            let mut depth = 0;
+            let mut current = definition.parent_code.as_deref();
+            while let Some(code) = current {
+                depth += 1;
+                if depth > definitions.len() {
+                    return Err(ServiceError::InvalidAccountDefinition);
+                }
+                current = *parents.get(code).ok_or(ServiceError::InvalidAccountDefinition)?;
+            }
+            ordered.push((depth, definition));
+        }
+        ordered.sort_by(|(a_depth, a), (b_depth, b)| a_depth.cmp(b_depth).then_with(|| a.code.cmp(&b.code)));
+        Ok(ordered.into_iter().map(|(_, definition)| definition).collect())
     }
 }
 
@@ -23,18 +56,20 @@ use crate::mappers::named::NamedMapper;
 #[async_trait]
 impl ChartOfAccountService for ChartOfAccountServiceImpl {
     async fn new_chart_of_account(&self, mut chart_of_account: ChartOfAccount, named: Vec<postings_api::domain::named::Named>) -> Result<(ChartOfAccount, Vec<postings_api::domain::named::Named>), ServiceError> {
-        chart_of_account.id = Uuid::new_v4();
+        chart_of_account.id = self.shared.new_id();
         let model = ChartOfAccountMapper::to_model(chart_of_account);
-        self.shared.coa_repo.save(&model).await.map_err(|_| ServiceError::Db)?;
+        let named_models: Vec<_> = named
+            .into_iter()
+            .map(|mut n| {
+                n.id = self.shared.new_id();
+                n.created = self.shared.now();
+                n.container = model.id;
+                NamedMapper::to_model(n)
+            })
+            .collect();
+        self.shared.coa_repo.save_with_named(&model, &named_models).await.map_err(|e| SharedService::map_named_error(e, format!("saving chart of account {}", model.id)))?;
         let coa_bo = ChartOfAccountMapper::to_bo(model);
-
-        let mut saved_named = Vec::new();
-        for mut n in named {
-            n.container = coa_bo.id;
-            let named_model = NamedMapper::to_model(n);
-            let saved_named_model = self.shared.named_repo.save(named_model).await.map_err(|_| ServiceError::Db)?;
-            saved_named.push(NamedMapper::to_bo(saved_named_model));
-        }
+        let saved_named = named_models.into_iter().map(NamedMapper::to_bo).collect();
 
         Ok((coa_bo, saved_named))
     }
@@ -43,7 +78,7 @@ impl ChartOfAccountService for ChartOfAccountServiceImpl {
         let named_models = self.shared.named_repo
             .find_by_name_and_type(name, ContainerType::ChartOfAccount)
             .await
-            .map_err(|_| ServiceError::Db)?;
+            .map_err(|e| e.into_service_error(format!("finding charts of accounts named {name}")))?;
         
         let mut chart_of_accounts = Vec::new();
         
@@ -51,7 +86,7 @@ impl ChartOfAccountService for ChartOfAccountServiceImpl {
             let coa_model = self.shared.coa_repo
                 .find_by_id(nm.container)
                 .await
-                .map_err(|_| ServiceError::Db)?;
+                .map_err(|e| e.into_service_error(format!("loading chart of account {}", nm.container)))?;
             
             if let Some(model) = coa_model {
                 chart_of_accounts.push(ChartOfAccountMapper::to_bo(model));
@@ -62,7 +97,7 @@ impl ChartOfAccountService for ChartOfAccountServiceImpl {
     }
 
     async fn find_chart_of_accounts_by_id(&self, id: Uuid) -> Result<Option<ChartOfAccount>, ServiceError> {
-        let coa_model = self.shared.coa_repo.find_by_id(id).await.map_err(|_| ServiceError::Db)?;
+        let coa_model = self.shared.coa_repo.find_by_id(id).await.map_err(|e| e.into_service_error(format!("loading chart of account {id}")))?;
         if let Some(cm) = coa_model {
             let coa_bo = ChartOfAccountMapper::to_bo(cm);
             Ok(Some(coa_bo))
@@ -70,4 +105,32 @@ impl ChartOfAccountService for ChartOfAccountServiceImpl {
             Ok(None)
         }
     }
-}
\ No newline at end of file
+
+    async fn add_account_definitions(&self, coa_id: Uuid, definitions: Vec<CoaAccountDefinition>) -> Result<Vec<CoaAccountDefinition>, ServiceError> {
+        let coa_bo = ChartOfAccountMapper::to_bo(self.shared.load_coa(coa_id).await?);
+        let mut all = self.find_account_definitions(coa_id).await?;
+        let added: Vec<CoaAccountDefinition> = definitions
+            .into_iter()
+            .map(|mut d| {
+                d.id = self.shared.new_id();
+                d.coa = coa_bo.clone();
+                d
+            })
+            .collect();
+        all.extend(added.iter().cloned());
+        Self::order_parents_first(&all)?;
+
+        let models: Vec<_> = added.iter().cloned().map(CoaAccountDefinitionMapper::to_model).collect();
+        self.shared.coa_repo.save_account_definitions(&models).await.map_err(|e| match e {
+            DbError::Duplicate { .. } => ServiceError::InvalidAccountDefinition,
+            e => e.into_service_error(format!("saving account definitions of chart of account {coa_id}")),
+        })?;
+        Ok(added)
+    }
+
+    async fn find_account_definitions(&self, coa_id: Uuid) -> Result<Vec<CoaAccountDefinition>, ServiceError> {
+        let coa_bo = ChartOfAccountMapper::to_bo(self.shared.load_coa(coa_id).await?);
+        let models = self.shared.coa_repo.find_account_definitions_by_coa_id(coa_id).await.map_err(|e| e.into_service_error(format!("loading account definitions of chart of account {coa_id}")))?;
+        Ok(models.into_iter().map(|m| CoaAccountDefinitionMapper::to_bo(m, coa_bo.clone())).collect())
+    }
+}
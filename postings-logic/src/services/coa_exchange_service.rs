@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::coa_account_definition::CoaAccountDefinition;
+use postings_api::domain::coa_document::{CoaAccount, CoaDocument, CoaName};
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::named::{ContainerType, Named};
+use postings_api::service::chart_of_account_service::ChartOfAccountService;
+use postings_api::service::coa_exchange_service::CoaExchangeService;
+use postings_api::service::ledger_service::LedgerService;
+use postings_api::service::named_service::NamedService;
+use postings_api::ServiceError;
+
+use crate::mappers::ledger_account::LedgerAccountMapper;
+use crate::services::chart_of_account_service::ChartOfAccountServiceImpl;
+use crate::services::ledger_service::LedgerServiceImpl;
+use crate::services::named_service::NamedServiceImpl;
+use crate::services::shared_service::SharedService;
+
+#[derive(Clone)]
+pub struct CoaExchangeServiceImpl {
+    shared: Arc<SharedService>,
+    coa_service: ChartOfAccountServiceImpl,
+    ledger_service: LedgerServiceImpl,
+    named_service: NamedServiceImpl,
+}
+
+impl CoaExchangeServiceImpl {
+    pub fn new(
+        shared: impl Into<Arc<SharedService>>,
+        coa_service: ChartOfAccountServiceImpl,
+        ledger_service: LedgerServiceImpl,
+        named_service: NamedServiceImpl,
+    ) -> Self {
+        Self {
+            shared: shared.into(),
+            coa_service,
+            ledger_service,
+            named_service,
+        }
+    }
+
+    fn named(&self, name: &CoaName, container: Uuid, context: Uuid, container_type: ContainerType) -> Named {
+        Named {
+            id: self.shared.new_id(),
+            container,
+            context,
+            name: name.name.clone(),
+            language: name.language.clone(),
+            created: self.shared.now(),
+            user_details: [0; 34],
+            short_desc: None,
+            long_desc: None,
+            container_type,
+            retired: None,
+        }
+    }
+
+    fn definition(coa: &ChartOfAccount, account: &CoaAccount) -> CoaAccountDefinition {
+        let name = &account.names[0];
+        CoaAccountDefinition {
+            id: Uuid::nil(),
+            coa: coa.clone(),
+            code: account.code.clone(),
+            name: name.name.clone(),
+            language: name.language.clone(),
+            category: account.category.clone(),
+            balance_side: account.balance_side.clone(),
+            parent_code: account.parent_code.clone(),
+        }
+    }
+
+    /// Adds the names in languages the container has no active name in yet. Names that differ
+    /// from the document in an existing language are left alone.
+    async fn add_missing_names(&self, names: &[CoaName], container: Uuid, context: Uuid, container_type: ContainerType) -> Result<(), ServiceError> {
+        let existing = self.named_service.find_by_container_id(container).await?;
+        for name in names {
+            if !existing.iter().any(|n| n.language == name.language) {
+                self.named_service
+                    .add_named(self.named(name, container, context, container_type.clone()))
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+
+    async fn ensure_chart(&self, document: &CoaDocument) -> Result<ChartOfAccount, ServiceError> {
+        let found = self
+            .coa_service
+            .find_chart_of_accounts_by_name(&document.chart[0].name)
+            .await?
+            .into_iter()
+            .next();
+        let coa = match found {
+            Some(coa) => coa,
+            None => {
+                let named = document
+                    .chart
+                    .iter()
+                    .map(|n| self.named(n, Uuid::nil(), Uuid::nil(), ContainerType::ChartOfAccount))
+                    .collect();
+                self.coa_service
+                    .new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, named)
+                    .await?
+                    .0
+            }
+        };
+        self.add_missing_names(&document.chart, coa.id, Uuid::nil(), ContainerType::ChartOfAccount).await?;
+        Ok(coa)
+    }
+
+    /// Adds the definitions the chart lacks; a definition that exists under the same code has to agree.
+    async fn ensure_definitions(&self, coa: &ChartOfAccount, accounts: &[CoaAccount]) -> Result<Vec<CoaAccountDefinition>, ServiceError> {
+        let existing = self.coa_service.find_account_definitions(coa.id).await?;
+        let mut missing = Vec::new();
+        for account in accounts {
+            match existing.iter().find(|d| d.code == account.code) {
+                Some(d) if d.category != account.category
+                    || d.balance_side != account.balance_side
+                    || d.parent_code != account.parent_code =>
+                {
+                    return Err(ServiceError::InvalidAccountDefinition);
+                }
+                Some(_) => {}
+                None => missing.push(Self::definition(coa, account)),
+            }
+        }
+        if !missing.is_empty() {
+            self.coa_service.add_account_definitions(coa.id, missing).await?;
+        }
+        self.coa_service.find_account_definitions(coa.id).await
+    }
+
+    async fn ensure_ledger(&self, coa: &ChartOfAccount, names: &[CoaName]) -> Result<Ledger, ServiceError> {
+        let found = self
+            .ledger_service
+            .find_ledger_by_name(&names[0].name, coa.id)
+            .await?
+            .into_iter()
+            .next();
+        let ledger = match found {
+            Some(ledger) => ledger,
+            None => {
+                let named = names
+                    .iter()
+                    .map(|n| self.named(n, Uuid::nil(), coa.id, ContainerType::Ledger))
+                    .collect();
+                self.ledger_service.instantiate_ledger_from_coa(coa.id, named).await?.0
+            }
+        };
+        self.add_missing_names(names, ledger.id, coa.id, ContainerType::Ledger).await?;
+        Ok(ledger)
+    }
+
+    async fn accounts_by_code(&self, ledger_id: Uuid) -> Result<HashMap<String, LedgerAccount>, ServiceError> {
+        let models = self
+            .shared
+            .ledger_account_repo
+            .find_by_ledger_id(ledger_id)
+            .await
+            .map_err(|e| e.into_service_error(format!("loading accounts of ledger {ledger_id}")))?;
+        let mut accounts = HashMap::new();
+        for model in models {
+            if let Some(code) = model.code {
+                let account = self
+                    .ledger_service
+                    .find_ledger_account_by_id(model.id)
+                    .await?
+                    .ok_or(ServiceError::LedgerAccountNotFound)?;
+                accounts.insert(code, account);
+            }
+        }
+        Ok(accounts)
+    }
+
+    async fn names_of(&self, container_id: Uuid) -> Result<Vec<CoaName>, ServiceError> {
+        let mut names: Vec<CoaName> = self
+            .named_service
+            .find_by_container_id(container_id)
+            .await?
+            .into_iter()
+            .map(|n| CoaName { language: n.language, name: n.name })
+            .collect();
+        names.sort_by(|a, b| a.language.cmp(&b.language));
+        Ok(names)
+    }
+}
+
+#[async_trait]
+impl CoaExchangeService for CoaExchangeServiceImpl {
+    async fn import_coa(&self, document: CoaDocument) -> Result<(Ledger, Vec<LedgerAccount>), ServiceError> {
+        if document.chart.is_empty() || document.ledger.is_empty() || document.accounts.iter().any(|a| a.names.is_empty()) {
+            return Err(ServiceError::InvalidCoaFile);
+        }
+
+        let coa = self.ensure_chart(&document).await?;
+        let definitions = self.ensure_definitions(&coa, &document.accounts).await?;
+        let ledger = self.ensure_ledger(&coa, &document.ledger).await?;
+
+        let mut accounts = self.accounts_by_code(ledger.id).await?;
+        for definition in ChartOfAccountServiceImpl::order_parents_first(&definitions)? {
+            let Some(entry) = document.accounts.iter().find(|a| a.code == definition.code) else {
+                continue;
+            };
+            if accounts.contains_key(&entry.code) {
+                continue;
+            }
+            let parent = match &entry.parent_code {
+                Some(code) => Some(Box::new(accounts.get(code).cloned().ok_or(ServiceError::LedgerAccountNotFound)?)),
+                None => None,
+            };
+            let account = LedgerAccount {
+                id: self.shared.new_id(),
+                ledger: ledger.clone(),
+                parent,
+                coa: coa.clone(),
+                balance_side: entry.balance_side.clone(),
+                category: entry.category.clone(),
+                code: Some(entry.code.clone()),
+            };
+            let named = entry
+                .names
+                .iter()
+                .map(|n| self.named(n, account.id, ledger.id, ContainerType::LedgerAccount))
+                .collect();
+            let (account, _) = self.ledger_service.new_ledger_account(account, named).await?;
+            accounts.insert(entry.code.clone(), account);
+        }
+
+        let mut imported = Vec::with_capacity(document.accounts.len());
+        for entry in &document.accounts {
+            let account = accounts.remove(&entry.code).ok_or(ServiceError::LedgerAccountNotFound)?;
+            self.add_missing_names(&entry.names, account.id, ledger.id, ContainerType::LedgerAccount).await?;
+            imported.push(account);
+        }
+        Ok((ledger, imported))
+    }
+
+    /// Chart and ledger names are listed by language. Account names start with the language of the
+    /// account definition, followed by the others by language, and accounts are listed by code.
+    async fn export_coa(&self, ledger_id: Uuid) -> Result<CoaDocument, ServiceError> {
+        let ledger = self
+            .ledger_service
+            .find_ledger_by_id(ledger_id)
+            .await?
+            .ok_or(ServiceError::LedgerNotFound)?;
+        let definitions = self.coa_service.find_account_definitions(ledger.coa.id).await?;
+        let models = self
+            .shared
+            .ledger_account_repo
+            .find_by_ledger_id(ledger_id)
+            .await
+            .map_err(|e| e.into_service_error(format!("loading accounts of ledger {ledger_id}")))?;
+        let codes: HashMap<Uuid, String> = models
+            .iter()
+            .map(|m| m.code.clone().map(|code| (m.id, code)).ok_or(ServiceError::MissingAccountCode))
+            .collect::<Result<_, _>>()?;
+
+        let mut accounts = Vec::with_capacity(models.len());
+        for model in models {
+            let code = codes[&model.id].clone();
+            let primary_language = definitions.iter().find(|d| d.code == code).map(|d| d.language.as_str());
+            let mut names = self.names_of(model.id).await?;
+            names.sort_by_key(|n| (Some(n.language.as_str()) != primary_language, n.language.clone()));
+            accounts.push(CoaAccount {
+                parent_code: model.parent_id.and_then(|id| codes.get(&id).cloned()),
+                category: LedgerAccountMapper::category_to_bo(model.category),
+                balance_side: LedgerAccountMapper::balance_side_to_bo(model.balance_side),
+                code,
+                names,
+            });
+        }
+
+        Ok(CoaDocument {
+            chart: self.names_of(ledger.coa.id).await?,
+            ledger: self.names_of(ledger.id).await?,
+            accounts,
+        })
+    }
+}
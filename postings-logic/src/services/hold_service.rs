@@ -0,0 +1,263 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use postings_api::domain::account_balance::AccountBalance;
+use postings_api::domain::account_state::{AccountState, AccountStateChange};
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::hold::{Hold, HoldStatus};
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::service::hold_service::HoldService;
+use postings_api::service::ledger_service::LedgerService;
+use postings_api::ServiceError;
+use postings_db::models::account_limit::BalanceCheck;
+use postings_db::models::time_axis::TimeAxis;
+use postings_db::DbError;
+
+use crate::mappers::hold::HoldMapper;
+use crate::mappers::posting::PostingMapper;
+use crate::mappers::posting_line::PostingLineMapper;
+use crate::services::ledger_service::LedgerServiceImpl;
+use crate::services::posting_service::PostingServiceImpl;
+use crate::services::shared_service::SharedService;
+
+#[derive(Clone)]
+pub struct HoldServiceImpl {
+    shared: Arc<SharedService>,
+    ledger_service: LedgerServiceImpl,
+    posting_service: PostingServiceImpl,
+}
+
+impl HoldServiceImpl {
+    pub fn new(shared: impl Into<Arc<SharedService>>, ledger_service: LedgerServiceImpl, posting_service: PostingServiceImpl) -> Self {
+        Self {
+            shared: shared.into(),
+            ledger_service,
+            posting_service,
+        }
+    }
+
+    async fn load_account(&self, account_id: Uuid) -> Result<LedgerAccount, ServiceError> {
+        self.ledger_service
+            .find_ledger_account_by_id(account_id)
+            .await?
+            .ok_or(ServiceError::LedgerAccountNotFound)
+    }
+
+    async fn load_hold(&self, hold_id: Uuid) -> Result<Hold, ServiceError> {
+        self.find_hold_by_id(hold_id).await?.ok_or(ServiceError::HoldNotFound)
+    }
+
+    async fn load_active_hold(&self, hold_id: Uuid, at: DateTime<Utc>) -> Result<Hold, ServiceError> {
+        let hold = self.load_hold(hold_id).await?;
+        if !hold.is_active_at(at) {
+            return Err(ServiceError::HoldNotActive);
+        }
+        Ok(hold)
+    }
+
+    /// Held funds leave the account on its balance side: debits for `Cr` accounts, credits otherwise.
+    fn draws_debit(account: &LedgerAccount) -> bool {
+        account.balance_side == BalanceSide::Cr
+    }
+
+    /// Debits minus credits of taking `amount` off the account's balance side.
+    fn reduction(account: &LedgerAccount, amount: &BigDecimal) -> BigDecimal {
+        if Self::draws_debit(account) {
+            amount.clone()
+        } else {
+            -amount
+        }
+    }
+
+    fn map_hold_error(e: DbError, context: impl Into<String>) -> ServiceError {
+        match e {
+            DbError::NotFound => ServiceError::HoldNotActive,
+            e => PostingServiceImpl::map_save_error(e, context),
+        }
+    }
+
+    fn line(&self, account: &LedgerAccount, debit: BigDecimal, credit: BigDecimal, now: DateTime<Utc>) -> PostingLine {
+        PostingLine {
+            id: self.shared.new_id(),
+            account: account.clone(),
+            debit_amount: debit,
+            credit_amount: credit,
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: now,
+            opr_id: [0; 34],
+            opr_src: None,
+            pst_time: now,
+            val_time: None,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+        }
+    }
+
+    fn capture_posting(&self, hold: &Hold, held: &LedgerAccount, counter: &LedgerAccount, amount: &BigDecimal, record_user: [u8; 34], now: DateTime<Utc>) -> Posting {
+        let (debited, credited) = if Self::draws_debit(held) { (held, counter) } else { (counter, held) };
+        Posting {
+            id: Uuid::nil(),
+            record_user,
+            record_time: now,
+            opr_id: hold.opr_id,
+            opr_time: hold.record_time,
+            opr_type: [0; 34],
+            opr_details: None,
+            opr_src: None,
+            pst_time: now,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: held.ledger.clone(),
+            val_time: None,
+            lines: vec![
+                self.line(debited, amount.clone(), BigDecimal::zero(), now),
+                self.line(credited, BigDecimal::zero(), amount.clone(), now),
+            ],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        }
+    }
+}
+
+#[async_trait]
+impl HoldService for HoldServiceImpl {
+    async fn place_hold(&self, account_id: Uuid, amount: BigDecimal, expiry: DateTime<Utc>, opr_id: [u8; 34]) -> Result<Hold, ServiceError> {
+        let now = self.shared.now();
+        if amount <= BigDecimal::zero() {
+            return Err(ServiceError::InvalidHoldAmount);
+        }
+        if expiry <= now {
+            return Err(ServiceError::InvalidPeriod);
+        }
+        let account = self.load_account(account_id).await?;
+        let state = AccountStateChange::state_at(&self.shared.load_account_state_changes(account_id).await?, now);
+        match state {
+            AccountState::Closed => return Err(ServiceError::AccountClosed),
+            AccountState::Frozen => return Err(ServiceError::AccountFrozen),
+            _ if Self::draws_debit(&account) && !state.allows_debit() => return Err(ServiceError::AccountDebitBlocked),
+            _ if !Self::draws_debit(&account) && !state.allows_credit() => return Err(ServiceError::AccountCreditBlocked),
+            _ => {}
+        }
+
+        let hold = Hold {
+            id: self.shared.new_id(),
+            account_id,
+            amount: amount.clone(),
+            expiry,
+            opr_id,
+            status: HoldStatus::Active,
+            record_time: now,
+            closed_time: None,
+            captured_amount: None,
+            posting_id: None,
+        };
+        let check = BalanceCheck { account_id, delta: Self::reduction(&account, &amount), at: now };
+        self.shared
+            .hold_repo
+            .save(&HoldMapper::to_model(hold.clone()), &check)
+            .await
+            .map_err(|e| PostingServiceImpl::map_save_error(e, format!("saving hold {}", hold.id)))?;
+        Ok(hold)
+    }
+
+    async fn capture_hold(
+        &self,
+        hold_id: Uuid,
+        counter_account_id: Uuid,
+        amount: Option<BigDecimal>,
+        record_user: [u8; 34],
+    ) -> Result<(Hold, Posting), ServiceError> {
+        let now = self.shared.now();
+        let hold = self.load_active_hold(hold_id, now).await?;
+        let amount = amount.unwrap_or_else(|| hold.amount.clone());
+        if amount <= BigDecimal::zero() || amount > hold.amount {
+            return Err(ServiceError::InvalidHoldAmount);
+        }
+        let held = self.load_account(hold.account_id).await?;
+        let counter = self.load_account(counter_account_id).await?;
+        if held.ledger.id != counter.ledger.id {
+            return Err(ServiceError::LedgerMismatch);
+        }
+
+        let posting = self.capture_posting(&hold, &held, &counter, &amount, record_user, now);
+        let posting = self.posting_service.prepare_posting(posting).await?;
+        if !posting.pst_status.is_effective() {
+            return Err(ServiceError::CaptureRequiresApproval);
+        }
+        let db_lines: Vec<_> = posting.lines.iter().cloned().map(|l| PostingLineMapper::from_bo(l, posting.id)).collect();
+        let checks = PostingServiceImpl::balance_checks([&posting]);
+        self.shared
+            .hold_repo
+            .capture(hold.id, &amount, now, &PostingMapper::to_model(posting.clone()), &db_lines, &checks)
+            .await
+            .map_err(|e| Self::map_hold_error(e, format!("capturing hold {}", hold.id)))?;
+
+        Ok((self.load_hold(hold.id).await?, posting))
+    }
+
+    async fn release_hold(&self, hold_id: Uuid) -> Result<Hold, ServiceError> {
+        let now = self.shared.now();
+        let hold = self.load_active_hold(hold_id, now).await?;
+        self.shared.hold_repo.release(hold.id, now).await.map_err(|e| Self::map_hold_error(e, format!("releasing hold {}", hold.id)))?;
+        self.load_hold(hold.id).await
+    }
+
+    async fn expire_holds(&self, ref_time: DateTime<Utc>) -> Result<u64, ServiceError> {
+        self.shared.hold_repo.expire(ref_time).await.map_err(|e| e.into_service_error(format!("expiring holds due at {ref_time}")))
+    }
+
+    async fn find_hold_by_id(&self, id: Uuid) -> Result<Option<Hold>, ServiceError> {
+        let hold = self.shared.hold_repo.find_by_id(id).await.map_err(|e| e.into_service_error(format!("loading hold {id}")))?;
+        Ok(hold.map(HoldMapper::to_bo))
+    }
+
+    async fn find_active_holds(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<Vec<Hold>, ServiceError> {
+        let holds = self
+            .shared
+            .hold_repo
+            .find_active_by_account_id(account_id, at)
+            .await
+            .map_err(|e| e.into_service_error(format!("finding active holds of ledger account {account_id}")))?;
+        Ok(holds.into_iter().map(HoldMapper::to_bo).collect())
+    }
+
+    async fn find_account_balance(&self, account_id: Uuid, at: DateTime<Utc>) -> Result<AccountBalance, ServiceError> {
+        let account = self.load_account(account_id).await?;
+        let lines = self
+            .shared
+            .line_repo
+            .find_by_account_and_time_less_than_equal(account_id, TimeAxis::PstTime, at)
+            .await
+            .map_err(|e| e.into_service_error(format!("loading lines of ledger account {account_id}")))?;
+        let debits_minus_credits: BigDecimal = lines
+            .into_iter()
+            .filter(|line| PostingMapper::status_to_bo(line.pst_status.clone()).is_effective())
+            .map(|line| line.debit_amount - line.credit_amount)
+            .sum();
+        let booked = if account.balance_side == BalanceSide::Cr { -debits_minus_credits } else { debits_minus_credits };
+        let held: BigDecimal = self.find_active_holds(account_id, at).await?.into_iter().map(|h| h.amount).sum();
+
+        Ok(AccountBalance {
+            account_id,
+            time: at,
+            available: &booked - &held,
+            booked,
+            held,
+        })
+    }
+}
@@ -0,0 +1,226 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::{BigDecimal, RoundingMode, Zero};
+use chrono::{DateTime, Days, NaiveDate, NaiveTime, Utc};
+use uuid::Uuid;
+
+use postings_api::domain::balance_side::BalanceSide;
+use postings_api::domain::hash_record::HashRecord;
+use postings_api::domain::interest_accrual::{DailyAccrual, InterestAccrual, InterestAccrualRule};
+use postings_api::domain::interest_rate_schedule::InterestRateSchedule;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::domain::time_axis::TimeAxis;
+use postings_api::service::interest_accrual_service::InterestAccrualService;
+use postings_api::ServiceError;
+
+use postings_db::models::interest_accrual::AccrualPeriod;
+use postings_db::repositories::posting_repository::UNIQUE_ACCRUAL_OPR_ID;
+use postings_db::DbError;
+
+use crate::hash_utils::hash_serialize;
+use crate::mappers::posting::PostingMapper;
+use crate::mappers::posting_line::PostingLineMapper;
+use crate::mappers::time_axis::TimeAxisMapper;
+use crate::services::posting_service::PostingServiceImpl;
+use crate::services::shared_service::SharedService;
+
+const ACCRUAL_OPR_TYPE: &str = "INTEREST_ACCRUAL";
+
+#[derive(Clone)]
+pub struct InterestAccrualServiceImpl {
+    shared: Arc<SharedService>,
+    posting_service: PostingServiceImpl,
+}
+
+impl InterestAccrualServiceImpl {
+    pub fn new(shared: impl Into<Arc<SharedService>>, posting_service: PostingServiceImpl) -> Self {
+        Self {
+            shared: shared.into(),
+            posting_service,
+        }
+    }
+
+    fn start_of_day(date: NaiveDate) -> DateTime<Utc> {
+        date.and_time(NaiveTime::MIN).and_utc()
+    }
+
+    /// Balance movement of a line, positive on the account's balance side.
+    fn signed_amount(account: &LedgerAccount, line: &postings_db::models::posting_line::PostingLine) -> BigDecimal {
+        match account.balance_side {
+            BalanceSide::Cr => &line.credit_amount - &line.debit_amount,
+            BalanceSide::Dr | BalanceSide::DrCr => &line.debit_amount - &line.credit_amount,
+        }
+    }
+
+    /// The operation id is derived from account and period, so each period is booked at most once per account.
+    fn accrual_opr_id(account_id: Uuid, from: NaiveDate, to: NaiveDate) -> Result<[u8; 34], ServiceError> {
+        hash_serialize(&(ACCRUAL_OPR_TYPE, account_id, from, to)).map_err(|_| ServiceError::NotEnoughInfo)
+    }
+
+    fn accrual_line(&self, account: &LedgerAccount, debit_amount: BigDecimal, credit_amount: BigDecimal, opr_id: [u8; 34], val_time: DateTime<Utc>) -> PostingLine {
+        let now = self.shared.now();
+        PostingLine {
+            id: self.shared.new_id(),
+            account: account.clone(),
+            debit_amount,
+            credit_amount,
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: now,
+            opr_id,
+            opr_src: None,
+            pst_time: now,
+            val_time: Some(val_time),
+            pst_type: PostingType::AdjTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+        }
+    }
+
+    /// Interest raises the account's balance on its balance side and is offset on the interest account.
+    fn accrual_posting(&self, rule: &InterestAccrualRule, accrual: &InterestAccrual, opr_id: [u8; 34], record_user: [u8; 34]) -> Result<Posting, ServiceError> {
+        let now = self.shared.now();
+        let val_time = Self::start_of_day(accrual.to);
+        let amount = accrual.amount.abs();
+        let increases_credit = (rule.account.balance_side == BalanceSide::Cr) != (accrual.amount < BigDecimal::zero());
+        let (debit_account, credit_account) = if increases_credit {
+            (&rule.interest_account, &rule.account)
+        } else {
+            (&rule.account, &rule.interest_account)
+        };
+
+        Ok(Posting {
+            id: Uuid::nil(),
+            record_user,
+            record_time: now,
+            opr_id,
+            opr_time: now,
+            opr_type: hash_serialize(&ACCRUAL_OPR_TYPE).map_err(|_| ServiceError::NotEnoughInfo)?,
+            opr_details: None,
+            opr_src: None,
+            pst_time: now,
+            pst_type: PostingType::AdjTx,
+            pst_status: PostingStatus::Posted,
+            ledger: rule.account.ledger.clone(),
+            val_time: Some(val_time),
+            lines: vec![
+                self.accrual_line(debit_account, amount.clone(), BigDecimal::zero(), opr_id, val_time),
+                self.accrual_line(credit_account, BigDecimal::zero(), amount, opr_id, val_time),
+            ],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: HashRecord::default(),
+        })
+    }
+
+    /// Saves the accrual posting together with the period it covers. Returns `None` if a
+    /// concurrent call booked the same period first.
+    async fn book(&self, accrual: &InterestAccrual, posting: Posting) -> Result<Option<Posting>, ServiceError> {
+        let posting = self.posting_service.prepare_posting(posting).await?;
+        let period = AccrualPeriod {
+            opr_id: posting.opr_id,
+            account_id: accrual.account.id,
+            period_from: accrual.from,
+            period_to: accrual.to,
+            posting_id: posting.id,
+        };
+        let db_lines: Vec<_> = posting.lines.iter().cloned().map(|l| PostingLineMapper::from_bo(l, posting.id)).collect();
+        let checks = PostingServiceImpl::balance_checks([&posting]);
+        match self.shared.posting_repo.save_accrual(&period, &PostingMapper::to_model(posting.clone()), &db_lines, &checks).await {
+            Ok(()) => Ok(Some(posting)),
+            Err(DbError::Duplicate { constraint }) if constraint.as_deref() == Some(UNIQUE_ACCRUAL_OPR_ID) => Ok(None),
+            Err(DbError::PeriodOverlap { .. }) => Err(ServiceError::AccrualPeriodOverlap),
+            Err(e) => Err(PostingServiceImpl::map_save_error(e, format!("booking the accrual of ledger account {} from {} to {}", accrual.account.id, accrual.from, accrual.to))),
+        }
+    }
+}
+
+#[async_trait]
+impl InterestAccrualService for InterestAccrualServiceImpl {
+    async fn compute_accrual(&self, account: LedgerAccount, schedule: &InterestRateSchedule, from: NaiveDate, to: NaiveDate) -> Result<InterestAccrual, ServiceError> {
+        if from >= to {
+            return Err(ServiceError::InvalidPeriod);
+        }
+        let period_start = Self::start_of_day(from);
+        let lines = self
+            .shared
+            .line_repo
+            .find_by_account_and_time_less_than_equal(account.id, TimeAxisMapper::to_model(TimeAxis::ValTime), Self::start_of_day(to))
+            .await
+            .map_err(|e| e.into_service_error(format!("loading lines of ledger account {}", account.id)))?;
+
+        // Opening balance plus the net movement of each day, keyed by the line's value date.
+        let day_count = (to - from).num_days() as usize;
+        let mut opening = BigDecimal::zero();
+        let mut movements = vec![BigDecimal::zero(); day_count];
+        for line in lines
+            .iter()
+            .filter(|line| PostingMapper::status_to_bo(line.pst_status.clone()).is_effective())
+        {
+            let value_time = line.val_time.unwrap_or(line.pst_time);
+            if value_time < period_start {
+                opening += Self::signed_amount(&account, line);
+            } else if let Some(movement) = movements.get_mut((value_time.date_naive() - from).num_days() as usize) {
+                *movement += Self::signed_amount(&account, line);
+            }
+        }
+
+        let mut balance = opening;
+        let mut days = Vec::with_capacity(day_count);
+        let mut total = BigDecimal::zero();
+        for (offset, movement) in movements.into_iter().enumerate() {
+            let date = from + Days::new(offset as u64);
+            balance += movement;
+            // Divide last so that 1/360 or 1/365 is not truncated before it is scaled by the balance.
+            let convention = &schedule.convention;
+            let interest = schedule.annual_interest(&balance) * BigDecimal::from(convention.day_count(date, date + Days::new(1)))
+                / BigDecimal::from(convention.days_in_year());
+            total += &interest;
+            days.push(DailyAccrual {
+                date,
+                balance: balance.clone(),
+                interest,
+            });
+        }
+
+        Ok(InterestAccrual {
+            account,
+            from,
+            to,
+            days,
+            amount: total.with_scale_round(2, RoundingMode::HalfEven),
+            posting: None,
+        })
+    }
+
+    async fn book_accruals(&self, rules: Vec<InterestAccrualRule>, from: NaiveDate, to: NaiveDate, record_user: [u8; 34]) -> Result<Vec<InterestAccrual>, ServiceError> {
+        let mut accruals = Vec::with_capacity(rules.len());
+        for rule in rules {
+            let mut accrual = self.compute_accrual(rule.account.clone(), &rule.schedule, from, to).await?;
+            let opr_id = Self::accrual_opr_id(rule.account.id, from, to)?;
+            let booked = self
+                .shared
+                .posting_repo
+                .find_by_opr_id_and_discarding_id_is_null(&opr_id)
+                .await
+                .map_err(|e| e.into_service_error(format!("finding the accrual of ledger account {} from {from} to {to}", rule.account.id)))?
+                .is_some();
+
+            if !booked && !accrual.amount.is_zero() {
+                let posting = self.accrual_posting(&rule, &accrual, opr_id, record_user)?;
+                accrual.posting = self.book(&accrual, posting).await?;
+            }
+            accruals.push(accrual);
+        }
+        Ok(accruals)
+    }
+}
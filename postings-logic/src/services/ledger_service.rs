@@ -1,8 +1,13 @@
+use std::sync::Arc;
+use crate::mappers::account_limit::AccountLimitMapper;
 use crate::mappers::ledger::LedgerMapper;
 use crate::mappers::ledger_account::LedgerAccountMapper;
 use crate::services::chart_of_account_service::ChartOfAccountServiceImpl;
 use crate::services::shared_service::SharedService;
 use async_trait::async_trait;
+use postings_api::domain::account_code_scheme::AccountCodeScheme;
+use postings_api::domain::account_limit::AccountLimit;
+use postings_api::domain::account_state::{AccountState, AccountStateChange};
 use postings_api::domain::chart_of_account::ChartOfAccount;
 use postings_api::domain::ledger::Ledger;
 use postings_api::domain::ledger_account::LedgerAccount;
@@ -10,22 +15,33 @@ use postings_api::service::chart_of_account_service::ChartOfAccountService;
 use postings_api::service::ledger_service::LedgerService;
 use postings_api::ServiceError;
 use postings_db::models::named::ContainerType;
+use postings_db::models::named::Named as NamedModel;
+use postings_db::models::ledger_account::LedgerAccount as LedgerAccountModel;
+use postings_db::DbError;
 use std::collections::HashMap;
 use uuid::Uuid;
 
+#[derive(Clone)]
 pub struct LedgerServiceImpl {
-    shared: SharedService,
+    shared: Arc<SharedService>,
     coa_service: ChartOfAccountServiceImpl,
+    code_scheme: AccountCodeScheme,
 }
 
 impl LedgerServiceImpl {
-    pub fn new(shared: SharedService, coa_service: ChartOfAccountServiceImpl) -> Self {
+    pub fn new(shared: impl Into<Arc<SharedService>>, coa_service: ChartOfAccountServiceImpl) -> Self {
         Self {
-            shared,
+            shared: shared.into(),
             coa_service,
+            code_scheme: AccountCodeScheme::default(),
         }
     }
 
+    pub fn with_code_scheme(mut self, code_scheme: AccountCodeScheme) -> Self {
+        self.code_scheme = code_scheme;
+        self
+    }
+
     async fn load_chart_of_account(&self, coa_id: Uuid) -> Result<ChartOfAccount, ServiceError> {
         self.coa_service
             .find_chart_of_accounts_by_id(coa_id)
@@ -33,6 +49,45 @@ impl LedgerServiceImpl {
             .ok_or(ServiceError::ChartOfAccountNotFound)
     }
 
+    async fn find_model_by_code(&self, ledger_id: Uuid, code: &str) -> Result<Option<LedgerAccountModel>, ServiceError> {
+        self.shared
+            .ledger_account_repo
+            .find_by_ledger_id_and_code(ledger_id, code)
+            .await
+            .map_err(|e| e.into_service_error(format!("finding ledger account {code} of ledger {ledger_id}")))
+    }
+
+    async fn check_code(&self, ledger_id: Uuid, code: &str) -> Result<(), ServiceError> {
+        if !self.code_scheme.is_valid(code) {
+            return Err(ServiceError::InvalidAccountCode);
+        }
+        if self.find_model_by_code(ledger_id, code).await?.is_some() {
+            return Err(ServiceError::DuplicateAccountCode);
+        }
+        Ok(())
+    }
+
+    /// The account of the ledger with the longest code that is a proper prefix of `code`.
+    async fn implied_parent(&self, ledger_id: Uuid, code: &str) -> Result<Option<LedgerAccount>, ServiceError> {
+        let prefixes: Vec<String> = code.char_indices().skip(1).map(|(end, _)| code[..end].to_string()).collect();
+        let candidates = self
+            .shared
+            .ledger_account_repo
+            .find_by_ledger_id_and_code_in(ledger_id, &prefixes)
+            .await
+            .map_err(|e| e.into_service_error(format!("finding the parent of ledger account {code} of ledger {ledger_id}")))?;
+        let parent = candidates.into_iter().max_by_key(|a| a.code.as_ref().map(String::len));
+        match parent {
+            Some(parent) => self.find_ledger_account_by_id(parent.id).await,
+            None => Ok(None),
+        }
+    }
+
+    async fn to_bo(&self, model: LedgerAccountModel) -> Result<LedgerAccount, ServiceError> {
+        let (ledger_bo, coa_bo, parent_bo) = self.load_ledger_account_dependencies(&model).await?;
+        Ok(LedgerAccountMapper::to_bo(model, ledger_bo, coa_bo, parent_bo))
+    }
+
     async fn load_ledger_account_dependencies(&self, model: &postings_db::models::ledger_account::LedgerAccount) -> Result<(Ledger, ChartOfAccount, Option<Box<LedgerAccount>>), ServiceError> {
         let ledger_bo = self
             .find_ledger_by_id(model.ledger_id)
@@ -47,6 +102,30 @@ impl LedgerServiceImpl {
 
         Ok((ledger_bo, coa_bo, parent_bo))
     }
+
+    async fn check_conformance(&self, ledger_account: &LedgerAccount) -> Result<(), ServiceError> {
+        let definitions = self.coa_service.find_account_definitions(ledger_account.coa.id).await?;
+        if definitions.is_empty() {
+            return Ok(());
+        }
+        let definition = ledger_account
+            .code
+            .as_ref()
+            .and_then(|code| definitions.iter().find(|d| &d.code == code))
+            .ok_or(ServiceError::NonConformingAccount)?;
+        let parent_code = match &ledger_account.parent {
+            Some(parent) => self.shared.load_ledger_account(parent.id).await?.ok_or(ServiceError::LedgerAccountNotFound)?.code,
+            None => None,
+        };
+
+        if definition.category != ledger_account.category
+            || definition.balance_side != ledger_account.balance_side
+            || definition.parent_code != parent_code
+        {
+            return Err(ServiceError::NonConformingAccount);
+        }
+        Ok(())
+    }
 }
 
 use crate::mappers::named::NamedMapper;
@@ -60,39 +139,107 @@ impl LedgerService for LedgerServiceImpl {
         // Retung an error if the COA is not found
         let coa_bo = self.load_chart_of_account(ledger.coa.id).await?;
 
-        let model = LedgerMapper::to_model(ledger);
+        let mut model = LedgerMapper::to_model(ledger);
+        if model.id.is_nil() {
+            model.id = self.shared.new_id();
+        }
+        let named_models: Vec<_> = named
+            .into_iter()
+            .map(|mut n| {
+                n.id = self.shared.new_id();
+                n.created = self.shared.now();
+                n.container = model.id;
+                n.context = model.coa_id; // Ledger's context is its Chart of Account
+                NamedMapper::to_model(n)
+            })
+            .collect();
         self.shared
             .ledger_repo
-            .save(&model)
+            .save_with_named(&model, &named_models, self.shared.now())
             .await
-            .map_err(|_| ServiceError::Db)?;
-
-        let mut saved_named = Vec::new();
-        for mut n in named {
-            n.container = model.id;
-            n.context = model.coa_id; // Ledger's context is its Chart of Account
-            let named_model = NamedMapper::to_model(n);
-            let saved_named_model = self
-                .shared
-                .named_repo
-                .save(named_model)
-                .await
-                .map_err(|_| ServiceError::Db)?;
-            saved_named.push(NamedMapper::to_bo(saved_named_model));
-        }
+            .map_err(|e| SharedService::map_named_error(e, format!("saving ledger {}", model.id)))?;
+        let saved_named = named_models.into_iter().map(NamedMapper::to_bo).collect();
 
         let ledger_bo = LedgerMapper::to_bo(model, coa_bo);
 
         Ok((ledger_bo, saved_named))
     }
 
+    async fn instantiate_ledger_from_coa(&self, coa_id: Uuid, named: Vec<Named>) -> Result<(Ledger, Vec<LedgerAccount>), ServiceError> {
+        let coa_bo = self.load_chart_of_account(coa_id).await?;
+        let definitions = self.coa_service.find_account_definitions(coa_id).await?;
+        let ordered = ChartOfAccountServiceImpl::order_parents_first(&definitions)?;
+        if ordered.iter().any(|d| !self.code_scheme.is_valid(&d.code)) {
+            return Err(ServiceError::InvalidAccountCode);
+        }
+
+        let ledger_model = postings_db::models::ledger::Ledger { id: self.shared.new_id(), coa_id };
+        let mut named_models: Vec<NamedModel> = named
+            .into_iter()
+            .map(|mut n| {
+                n.id = self.shared.new_id();
+                n.container = ledger_model.id;
+                n.context = coa_id;
+                n.created = self.shared.now();
+                NamedMapper::to_model(n)
+            })
+            .collect();
+
+        let mut ids_by_code: HashMap<&str, Uuid> = HashMap::new();
+        let mut account_models = Vec::with_capacity(ordered.len());
+        for definition in ordered {
+            let account_id = self.shared.new_id();
+            ids_by_code.insert(&definition.code, account_id);
+            account_models.push(postings_db::models::ledger_account::LedgerAccount {
+                id: account_id,
+                ledger_id: ledger_model.id,
+                parent_id: definition.parent_code.as_deref().and_then(|code| ids_by_code.get(code).copied()),
+                coa_id,
+                balance_side: LedgerAccountMapper::balance_side_to_model(definition.balance_side.clone()),
+                category: LedgerAccountMapper::category_to_model(definition.category.clone()),
+                code: Some(definition.code.clone()),
+            });
+            named_models.push(NamedModel {
+                id: self.shared.new_id(),
+                container: account_id,
+                context: ledger_model.id,
+                name: definition.name.clone(),
+                language: definition.language.clone(),
+                created: self.shared.now(),
+                user_details: [0; 34],
+                short_desc: None,
+                long_desc: None,
+                container_type: ContainerType::LedgerAccount,
+                retired: None,
+            });
+        }
+
+        self.shared
+            .ledger_repo
+            .save_with_accounts(&ledger_model, &account_models, &named_models, self.shared.now())
+            .await
+            .map_err(|e| SharedService::map_named_error(e, format!("saving ledger {} with its accounts", ledger_model.id)))?;
+
+        let ledger_bo = LedgerMapper::to_bo(ledger_model, coa_bo.clone());
+        let mut accounts_by_id: HashMap<Uuid, LedgerAccount> = HashMap::new();
+        let mut accounts = Vec::with_capacity(account_models.len());
+        for model in account_models {
+            let parent_bo = model.parent_id.and_then(|id| accounts_by_id.get(&id)).map(|p| Box::new(p.clone()));
+            let account_bo = LedgerAccountMapper::to_bo(model, ledger_bo.clone(), coa_bo.clone(), parent_bo);
+            accounts_by_id.insert(account_bo.id, account_bo.clone());
+            accounts.push(account_bo);
+        }
+
+        Ok((ledger_bo, accounts))
+    }
+
     async fn find_ledger_by_id(&self, id: Uuid) -> Result<Option<Ledger>, ServiceError> {
         let ledger_model = self
             .shared
             .ledger_repo
             .find_by_id(id)
             .await
-            .map_err(|_| ServiceError::Db)?;
+            .map_err(|e| e.into_service_error(format!("loading ledger {id}")))?;
         if let Some(model) = ledger_model {
             let coa_bo = self.load_chart_of_account(model.coa_id).await?;
             let ledger_bo = LedgerMapper::to_bo(model, coa_bo);
@@ -110,7 +257,7 @@ impl LedgerService for LedgerServiceImpl {
             .named_repo
             .find_by_name_and_type_and_context(name, ContainerType::Ledger, coa_id)
             .await
-            .map_err(|_| ServiceError::Db)?;
+            .map_err(|e| e.into_service_error(format!("finding ledgers named {name} in chart of account {coa_id}")))?;
         
         let mut ledgers = Vec::new();        
         for nm in named_models {
@@ -119,7 +266,7 @@ impl LedgerService for LedgerServiceImpl {
                 .ledger_repo
                 .find_by_id(nm.container)
                 .await
-                .map_err(|_| ServiceError::Db)? 
+                .map_err(|e| e.into_service_error(format!("loading ledger {}", nm.container)))? 
             {
                 Some(model) => model,
                 None => continue, // Skip if ledger not found
@@ -134,7 +281,7 @@ impl LedgerService for LedgerServiceImpl {
 
     async fn new_ledger_account(
         &self,
-        ledger_account: LedgerAccount,
+        mut ledger_account: LedgerAccount,
         named: Vec<Named>,
     ) -> Result<(LedgerAccount, Vec<Named>), ServiceError> {
         // Load the ledger to ensure it exists
@@ -143,37 +290,54 @@ impl LedgerService for LedgerServiceImpl {
             .ledger_repo
             .find_by_id(ledger_account.ledger.id)
             .await
-            .map_err(|_| ServiceError::Db)?
+            .map_err(|e| e.into_service_error(format!("loading ledger {}", ledger_account.ledger.id)))?
             .ok_or(ServiceError::LedgerNotFound)?;
         // Make sure the COA in the ledger is the same as the one referenced
         if leddger.coa_id != ledger_account.coa.id {
             return Err(ServiceError::ChartOfAccountMismatch);
         }
+        if let Some(code) = &ledger_account.code {
+            self.check_code(ledger_account.ledger.id, code).await?;
+            if ledger_account.parent.is_none() {
+                ledger_account.parent = self.implied_parent(ledger_account.ledger.id, code).await?.map(Box::new);
+            }
+        }
+        if let Some(parent) = &ledger_account.parent {
+            let changes = self.shared.load_account_state_changes(parent.id).await?;
+            if AccountStateChange::state_at(&changes, self.shared.now()) == AccountState::Closed {
+                return Err(ServiceError::AccountClosed);
+            }
+        }
+        self.check_conformance(&ledger_account).await?;
 
-        let model = LedgerAccountMapper::to_model(ledger_account);
-        self.shared
-            .ledger_account_repo
-            .save(&model)
-            .await
-            .map_err(|_| ServiceError::Db)?;
+        let mut model = LedgerAccountMapper::to_model(ledger_account);
+        if model.id.is_nil() {
+            model.id = self.shared.new_id();
+        }
+        let named_models: Vec<_> = named
+            .into_iter()
+            .map(|mut n| {
+                n.id = self.shared.new_id();
+                n.created = self.shared.now();
+                n.container = model.id;
+                n.context = model.ledger_id; // LedgerAccount's context is its Ledger
+                NamedMapper::to_model(n)
+            })
+            .collect();
+        if let Err(e) = self.shared.ledger_account_repo.save_with_named(&model, &named_models, self.shared.now()).await {
+            // The code was free when checked, so a duplicate may stem from a concurrent insert with the same code.
+            if let (DbError::Duplicate { .. }, Some(code)) = (&e, &model.code) {
+                if self.find_model_by_code(model.ledger_id, code).await?.is_some() {
+                    return Err(ServiceError::DuplicateAccountCode);
+                }
+            }
+            return Err(SharedService::map_named_error(e, format!("saving ledger account {}", model.id)));
+        }
+        let saved_named = named_models.into_iter().map(NamedMapper::to_bo).collect();
 
         let (ledger_bo, coa_bo, parent_bo) = self.load_ledger_account_dependencies(&model).await?;
         let la_bo = LedgerAccountMapper::to_bo(model, ledger_bo, coa_bo, parent_bo);
 
-        let mut saved_named = Vec::new();
-        for mut n in named {
-            n.container = la_bo.id;
-            n.context = la_bo.ledger.id; // LedgerAccount's context is its Ledger
-            let named_model = NamedMapper::to_model(n);
-            let saved_named_model = self
-                .shared
-                .named_repo
-                .save(named_model)
-                .await
-                .map_err(|_| ServiceError::Db)?;
-            saved_named.push(NamedMapper::to_bo(saved_named_model));
-        }
-
         Ok((la_bo, saved_named))
     }
 
@@ -186,7 +350,7 @@ impl LedgerService for LedgerServiceImpl {
             .ledger_account_repo
             .find_by_id(id)
             .await
-            .map_err(|_| ServiceError::Db)?
+            .map_err(|e| e.into_service_error(format!("loading ledger account {id}")))?
         {
             let (ledger_bo, coa_bo, parent_bo) = self.load_ledger_account_dependencies(&model).await?;
             let la_bo = LedgerAccountMapper::to_bo(model, ledger_bo, coa_bo, parent_bo);
@@ -196,6 +360,27 @@ impl LedgerService for LedgerServiceImpl {
         }
     }
 
+    async fn find_ledger_account_by_code(&self, ledger: &Ledger, code: &str) -> Result<Option<LedgerAccount>, ServiceError> {
+        match self.find_model_by_code(ledger.id, code).await? {
+            Some(model) => Ok(Some(self.to_bo(model).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_ledger_accounts_by_code_range(&self, ledger: &Ledger, from: &str, to: &str) -> Result<Vec<LedgerAccount>, ServiceError> {
+        let models = self
+            .shared
+            .ledger_account_repo
+            .find_by_ledger_id_and_code_between(ledger.id, from, to)
+            .await
+            .map_err(|e| e.into_service_error(format!("finding accounts of ledger {} with codes from {from} to {to}", ledger.id)))?;
+        let mut accounts = Vec::with_capacity(models.len());
+        for model in models {
+            accounts.push(self.to_bo(model).await?);
+        }
+        Ok(accounts)
+    }
+
     async fn find_ledger_account_by_name(
         &self,
         ledger: &Ledger,
@@ -206,7 +391,7 @@ impl LedgerService for LedgerServiceImpl {
             .named_repo
             .find_by_name_and_type(name, ContainerType::LedgerAccount)
             .await
-            .map_err(|_| ServiceError::Db)?;
+            .map_err(|e| e.into_service_error(format!("finding ledger accounts named {name}")))?;
         let mut result = Vec::new();
         for named in nm {
             // load ledger account by named container
@@ -229,6 +414,39 @@ impl LedgerService for LedgerServiceImpl {
         Ok(!result.is_empty())
     }
 
+    async fn set_account_limit(&self, limit: AccountLimit) -> Result<AccountLimit, ServiceError> {
+        if !limit.is_valid() {
+            return Err(ServiceError::InvalidAccountLimit);
+        }
+        if self.shared.load_ledger_account(limit.account_id).await?.is_none() {
+            return Err(ServiceError::LedgerAccountNotFound);
+        }
+        self.shared
+            .ledger_account_repo
+            .save_limit(&AccountLimitMapper::to_model(limit.clone()))
+            .await
+            .map_err(|e| e.into_service_error(format!("saving limit of ledger account {}", limit.account_id)))?;
+        Ok(limit)
+    }
+
+    async fn find_account_limit(&self, account_id: Uuid) -> Result<Option<AccountLimit>, ServiceError> {
+        let limit = self
+            .shared
+            .ledger_account_repo
+            .find_limit_by_account_id(account_id)
+            .await
+            .map_err(|e| e.into_service_error(format!("loading limit of ledger account {account_id}")))?;
+        Ok(limit.map(AccountLimitMapper::to_bo))
+    }
+
+    async fn remove_account_limit(&self, account_id: Uuid) -> Result<(), ServiceError> {
+        self.shared
+            .ledger_account_repo
+            .delete_limit(account_id)
+            .await
+            .map_err(|e| e.into_service_error(format!("removing limit of ledger account {account_id}")))
+    }
+
     async fn find_ledger_accounts_by_ibans(
         &self,
         ibans: Vec<String>,
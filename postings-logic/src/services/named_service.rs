@@ -1,39 +1,112 @@
+use std::sync::Arc;
 use crate::mappers::named::NamedMapper;
 use crate::services::shared_service::SharedService;
 use async_trait::async_trait;
 use postings_api::domain::named::{Named, ContainerType};
 use postings_api::service::named_service::NamedService;
 use postings_api::ServiceError;
+use type_rules::Validator;
 use uuid::Uuid;
 
+#[derive(Clone)]
 pub struct NamedServiceImpl {
-    shared: SharedService,
+    shared: Arc<SharedService>,
 }
 
 impl NamedServiceImpl {
-    pub fn new(shared: SharedService) -> Self {
-        Self { shared }
+    pub fn new(shared: impl Into<Arc<SharedService>>) -> Self {
+        Self { shared: shared.into() }
+    }
+
+    fn validate(named: &Named) -> Result<(), ServiceError> {
+        named.check_validity().map_err(|e| {
+            log::info!("Invalid name {}: {e}", named.name);
+            ServiceError::InvalidName
+        })
+    }
+
+    async fn find_active(&self, id: Uuid) -> Result<Named, ServiceError> {
+        self.shared
+            .named_repo
+            .find_by_id(id)
+            .await
+            .map_err(|e| e.into_service_error(format!("loading name {id}")))?
+            .filter(|n| n.retired.is_none())
+            .map(NamedMapper::to_bo)
+            .ok_or(ServiceError::NamedNotFound)
+    }
+
+    async fn save(&self, named: Named) -> Result<Named, ServiceError> {
+        let id = named.id;
+        let saved = self
+            .shared
+            .named_repo
+            .save(NamedMapper::to_model(named))
+            .await
+            .map_err(|e| SharedService::map_named_error(e, format!("saving name {id}")))?;
+        Ok(NamedMapper::to_bo(saved))
     }
 }
 
 #[async_trait]
 impl NamedService for NamedServiceImpl {
+    async fn add_named(&self, mut named: Named) -> Result<Named, ServiceError> {
+        Self::validate(&named)?;
+        named.id = self.shared.new_id();
+        named.created = self.shared.now();
+        named.retired = None;
+        self.save(named).await
+    }
+
+    async fn update_named(&self, named: Named) -> Result<Named, ServiceError> {
+        Self::validate(&named)?;
+        let mut existing = self.find_active(named.id).await?;
+        existing.name = named.name;
+        existing.language = named.language;
+        existing.user_details = named.user_details;
+        existing.short_desc = named.short_desc;
+        existing.long_desc = named.long_desc;
+        self.save(existing).await
+    }
+
+    async fn retire_named(&self, id: Uuid) -> Result<Named, ServiceError> {
+        let mut existing = self.find_active(id).await?;
+        existing.retired = Some(self.shared.now());
+        self.save(existing).await
+    }
+
     async fn find_by_container_id(&self, container_id: Uuid) -> Result<Vec<Named>, ServiceError> {
         let named_models = self
             .shared
             .named_repo
             .find_by_container(container_id)
             .await
-            .map_err(|_| ServiceError::Db)?;
-        Ok(named_models.into_iter().map(NamedMapper::to_bo).collect())
+            .map_err(|e| e.into_service_error(format!("loading names of container {container_id}")))?;
+        let mut named: Vec<Named> = named_models
+            .into_iter()
+            .filter(|n| n.retired.is_none())
+            .map(NamedMapper::to_bo)
+            .collect();
+        named.sort_by_key(|n| n.created);
+        Ok(named)
     }
-    async fn find_by_name_and_type(&self, name: String, container_type: ContainerType) -> Result<Vec<Named>, ServiceError> {
+
+    async fn find_by_name_and_type(&self, name: &str, container_type: ContainerType) -> Result<Vec<Named>, ServiceError> {
+        let container_type = NamedMapper::container_type_to_model(container_type);
         let named_models = self
             .shared
             .named_repo
             .find_by_name_and_type(name, container_type)
             .await
-            .map_err(|_| ServiceError::Db)?;
-        Ok(named_models.into_iter().map(NamedMapper::to_bo).collect())
+            .map_err(|e| e.into_service_error(format!("finding names {name}")))?;
+        Ok(named_models.into_iter().filter(|n| n.retired.is_none()).map(NamedMapper::to_bo).collect())
+    }
+
+    async fn resolve_name(&self, container_id: Uuid, languages: &[String]) -> Result<Option<Named>, ServiceError> {
+        let named = self.find_by_container_id(container_id).await?;
+        let preferred = languages
+            .iter()
+            .find_map(|language| named.iter().find(|n| n.language.eq_ignore_ascii_case(language)));
+        Ok(preferred.or(named.first()).cloned())
     }
 }
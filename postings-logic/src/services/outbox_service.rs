@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use postings_api::domain::domain_event::{DomainEvent, RelayReport};
+use postings_api::service::outbox_service::{EventSink, OutboxService};
+use postings_api::ServiceError;
+
+use crate::mappers::domain_event::DomainEventMapper;
+use crate::services::shared_service::SharedService;
+
+#[derive(Clone)]
+pub struct OutboxServiceImpl {
+    shared: Arc<SharedService>,
+}
+
+impl OutboxServiceImpl {
+    pub fn new(shared: impl Into<Arc<SharedService>>) -> Self {
+        Self { shared: shared.into() }
+    }
+
+    /// Delivers the events of one ledger in order up to the first failure and returns the ids of
+    /// the delivered ones.
+    async fn deliver_ledger(sink: &(dyn EventSink + Send + Sync), events: &[DomainEvent]) -> (Vec<i64>, bool) {
+        let mut delivered = Vec::with_capacity(events.len());
+        for event in events {
+            if let Err(e) = sink.deliver(event).await {
+                log::warn!("Delivery of event {} (ledger {}, sequence {}) failed: {}", event.id, event.ledger_id, event.sequence, e);
+                return (delivered, true);
+            }
+            delivered.push(event.id);
+        }
+        (delivered, false)
+    }
+}
+
+#[async_trait]
+impl OutboxService for OutboxServiceImpl {
+    async fn find_pending_events(&self, limit: u32) -> Result<Vec<DomainEvent>, ServiceError> {
+        let events = self.shared.outbox_repo.find_pending(limit as i64).await.map_err(|e| e.into_service_error("finding pending events"))?;
+        events.into_iter().map(DomainEventMapper::to_bo).collect()
+    }
+
+    async fn find_ledger_events(&self, ledger_id: Uuid, after_sequence: u64, limit: u32) -> Result<Vec<DomainEvent>, ServiceError> {
+        let events = self
+            .shared
+            .outbox_repo
+            .find_by_ledger_id(ledger_id, after_sequence as i64, limit as i64)
+            .await
+            .map_err(|e| e.into_service_error(format!("finding events of ledger {ledger_id} after {after_sequence}")))?;
+        events.into_iter().map(DomainEventMapper::to_bo).collect()
+    }
+
+    async fn mark_dispatched(&self, ids: &[i64]) -> Result<(), ServiceError> {
+        self.shared.outbox_repo.mark_dispatched(ids, self.shared.now()).await.map_err(|e| e.into_service_error(format!("marking {} events dispatched", ids.len())))
+    }
+
+    async fn relay(&self, sink: &(dyn EventSink + Send + Sync), limit: u32) -> Result<RelayReport, ServiceError> {
+        let mut by_ledger: BTreeMap<Uuid, Vec<DomainEvent>> = BTreeMap::new();
+        let events = self.shared.outbox_repo.find_pending_per_ledger(limit as i64).await.map_err(|e| e.into_service_error("finding pending events"))?;
+        for event in events {
+            let event = DomainEventMapper::to_bo(event)?;
+            by_ledger.entry(event.ledger_id).or_default().push(event);
+        }
+
+        let mut report = RelayReport::default();
+        for (ledger_id, mut events) in by_ledger {
+            events.sort_by_key(|event| event.sequence);
+            let (delivered, blocked) = Self::deliver_ledger(sink, &events).await;
+            // Marked per ledger so a failure elsewhere cannot cause these to be delivered again.
+            self.mark_dispatched(&delivered).await?;
+            report.delivered += delivered.len();
+            if blocked {
+                report.blocked_ledgers.push(ledger_id);
+            }
+        }
+        Ok(report)
+    }
+}
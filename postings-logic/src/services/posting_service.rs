@@ -1,88 +1,561 @@
+use std::sync::Arc;
 use async_trait::async_trait;
+use postings_api::domain::account_state::{AccountState, AccountStateChange};
+use postings_api::domain::approval_policy::ApprovalPolicy;
+use postings_api::domain::hash_chain::{ChainBreak, ChainVerification};
 use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_approval::PostingApproval;
 use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::ledger::Ledger;
 use postings_api::domain::ledger_account::LedgerAccount;
 use postings_api::service::posting_service::{PostingService, Page};
 use postings_api::ServiceError;
 use crate::services::shared_service::SharedService;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use log::warn;
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeMap, HashMap};
 use uuid::Uuid;
-use bigdecimal::BigDecimal;
+use bigdecimal::{BigDecimal, Zero};
+use postings_db::models::account_limit::BalanceCheck;
+use postings_db::DbError;
 use crate::hash_utils::hash_serialize;
+use crate::mappers::approval_policy::ApprovalPolicyMapper;
+use crate::mappers::chart_of_account::ChartOfAccountMapper;
+use crate::mappers::ledger::LedgerMapper;
+use crate::mappers::ledger_account::LedgerAccountMapper;
 use crate::mappers::posting::PostingMapper;
+use crate::mappers::posting_approval::PostingApprovalMapper;
 use crate::mappers::posting_line::PostingLineMapper;
 
+/// Id, hash and record time of the posting a new posting is chained to.
+type ChainHead = Option<(Uuid, Option<[u8; 34]>, DateTime<Utc>)>;
+
+#[derive(Clone)]
 pub struct PostingServiceImpl {
-    shared: SharedService,
+    shared: Arc<SharedService>,
     // posting_repo, stmt_repo, line_repo would be here
 }
 
 impl PostingServiceImpl {
-    pub fn new(shared: SharedService) -> Self {
-        Self { shared }
+    pub fn new(shared: impl Into<Arc<SharedService>>) -> Self {
+        Self { shared: shared.into() }
     }
-}
 
-#[async_trait]
-impl PostingService for PostingServiceImpl {
-    async fn new_posting(&self, mut posting: Posting) -> Result<Posting, ServiceError> {
+    fn validate_double_entry(posting: &Posting) -> Result<(), ServiceError> {
         let debit_sum: BigDecimal = posting.lines.iter().map(|l| l.debit_amount.clone()).sum();
         let credit_sum: BigDecimal = posting.lines.iter().map(|l| l.credit_amount.clone()).sum();
 
         if debit_sum != credit_sum {
             return Err(ServiceError::DoubleEntry);
         }
+        Ok(())
+    }
 
-        posting.id = Uuid::new_v4();
-        posting.record_time = Utc::now();
+    fn validate(posting: &Posting) -> Result<(), ServiceError> {
+        Self::validate_double_entry(posting)?;
+        if posting.pst_status == PostingStatus::Deferred && posting.val_time.is_none() {
+            return Err(ServiceError::NotEnoughInfo);
+        }
+        Ok(())
+    }
+
+    /// Rejects lines that debit or credit an account whose state at the posting time does not allow
+    /// it, and lines into an account already closed now, whatever the posting time. State changes
+    /// already loaded are kept in `changes` for further postings of a batch.
+    async fn check_account_states(&self, posting: &Posting, changes: &mut HashMap<Uuid, Vec<AccountStateChange>>) -> Result<(), ServiceError> {
+        for line in posting.lines.iter() {
+            let account_changes = match changes.entry(line.account.id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(self.shared.load_account_state_changes(line.account.id).await?),
+            };
+            if AccountStateChange::state_at(account_changes, self.shared.now()) == AccountState::Closed {
+                return Err(ServiceError::AccountClosed);
+            }
+            let state = AccountStateChange::state_at(account_changes, posting.pst_time);
+            match state {
+                AccountState::Closed => return Err(ServiceError::AccountClosed),
+                AccountState::Frozen => return Err(ServiceError::AccountFrozen),
+                _ if !line.debit_amount.is_zero() && !state.allows_debit() => return Err(ServiceError::AccountDebitBlocked),
+                _ if !line.credit_amount.is_zero() && !state.allows_credit() => return Err(ServiceError::AccountCreditBlocked),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates a single posting and readies it for saving: ids, approval policy, line alignment
+    /// and, for effective postings, the link into the hash chain.
+    pub(crate) async fn prepare_posting(&self, mut posting: Posting) -> Result<Posting, ServiceError> {
+        Self::validate(&posting)?;
+        self.check_account_states(&posting, &mut HashMap::new()).await?;
+
+        self.identify(&mut posting, self.shared.now());
+        let policy = self.load_approval_policy(posting.ledger.id).await?;
+        Self::apply_approval_policy(&mut posting, policy);
+        Self::align_lines(&mut posting);
 
         // Simplified predecessor logic
         let opr_id_bytes = posting.opr_id;
-        if let Some(_predecessor) = self.shared.posting_repo.find_by_opr_id_and_discarding_id_is_null(&opr_id_bytes).await.map_err(|_| ServiceError::Db)? {
+        if let Some(_predecessor) = self.shared.posting_repo.find_by_opr_id_and_discarding_id_is_null(&opr_id_bytes).await.map_err(|e| e.into_service_error(format!("finding predecessor of posting {}", posting.id)))? {
             // Discard predecessor, not fully implemented here
         }
 
-        let antecedent = self.shared.posting_repo.find_first_by_ledger_order_by_record_time_desc(posting.ledger.id).await.map_err(|_| ServiceError::Db)?;
-        if let Some(ant) = antecedent {
-            posting.hash_record.antecedent_id = Some(ant.id);
-            posting.hash_record.antecedent_hash = ant.hash;
+        if posting.pst_status.is_effective() {
+            let head = self.load_chain_head(posting.ledger.id).await?;
+            Self::chain(&mut posting, head)?;
+        }
+
+        Ok(posting)
+    }
+
+    /// Net move of each account by the effective postings, debits minus credits, for the
+    /// repository to hold against the account limits as of the latest posting's record time.
+    pub(crate) fn balance_checks<'a>(postings: impl IntoIterator<Item = &'a Posting>) -> Vec<BalanceCheck> {
+        let mut deltas: HashMap<Uuid, BigDecimal> = HashMap::new();
+        let mut at = DateTime::<Utc>::MIN_UTC;
+        for posting in postings.into_iter().filter(|p| p.pst_status.is_effective()) {
+            at = at.max(posting.record_time);
+            for line in posting.lines.iter() {
+                *deltas.entry(line.account.id).or_insert_with(BigDecimal::zero) += &line.debit_amount - &line.credit_amount;
+            }
+        }
+        deltas
+            .into_iter()
+            .map(|(account_id, delta)| BalanceCheck { account_id, delta, at })
+            .collect()
+    }
+
+    pub(crate) fn map_save_error(e: DbError, context: impl Into<String>) -> ServiceError {
+        match e {
+            DbError::LimitExceeded { account_id, shortfall } => ServiceError::LimitExceeded { account_id, shortfall },
+            DbError::AccountClosed { .. } => ServiceError::AccountClosed,
+            e => e.into_service_error(context),
+        }
+    }
+
+    async fn load_approval_policy(&self, ledger_id: Uuid) -> Result<Option<postings_db::models::approval_policy::ApprovalPolicy>, ServiceError> {
+        self.shared.approval_repo.find_policy_by_ledger_id(ledger_id).await.map_err(|e| e.into_service_error(format!("loading approval policy of ledger {ledger_id}")))
+    }
+
+    /// Holds back postings that fall under the ledger's four-eyes rule until a second user approves
+    /// them. Deferred postings stay deferred; they are held back when they fall due instead.
+    fn apply_approval_policy(posting: &mut Posting, policy: Option<postings_db::models::approval_policy::ApprovalPolicy>) {
+        let Some(policy) = policy else {
+            return;
+        };
+        if !posting.pst_status.is_effective() {
+            return;
+        }
+        let amount: BigDecimal = posting.lines.iter().map(|l| l.debit_amount.clone()).sum();
+        if ApprovalPolicyMapper::to_bo(policy, posting.ledger.clone()).requires_approval(&amount) {
+            posting.pst_status = PostingStatus::Proposed;
+        }
+    }
+
+    /// Gives a new posting a fresh id, its lines an id where they have none, and all of them the
+    /// record time it enters the books at.
+    fn identify(&self, posting: &mut Posting, record_time: DateTime<Utc>) {
+        posting.id = self.shared.new_id();
+        posting.record_time = record_time;
+        for line in posting.lines.iter_mut() {
+            if line.id.is_nil() {
+                line.id = self.shared.new_id();
+            }
+            line.record_time = record_time;
         }
-        
+    }
+
+    /// Lines follow the operation id, status and value time of their posting.
+    fn align_lines(posting: &mut Posting) {
+        for line in posting.lines.iter_mut() {
+            line.opr_id = posting.opr_id;
+            line.pst_status = posting.pst_status.clone();
+            line.val_time = posting.val_time;
+        }
+    }
+
+    async fn load_chain_head(&self, ledger_id: Uuid) -> Result<ChainHead, ServiceError> {
+        // Only effective postings carry a hash, so the chain skips proposed, deferred and cancelled ones.
+        let antecedent = self.shared.posting_repo.find_first_by_ledger_and_hash_is_not_null_order_by_record_time_desc(ledger_id).await.map_err(|e| e.into_service_error(format!("loading chain head of ledger {ledger_id}")))?;
+        Ok(antecedent.map(|ant| (ant.id, ant.hash, ant.record_time)))
+    }
+
+    async fn load_ledger_bo(&self, ledger_id: Uuid) -> Result<Ledger, ServiceError> {
+        let ledger_model = self.shared.load_ledger(ledger_id).await?;
+        let coa_bo = ChartOfAccountMapper::to_bo(self.shared.load_coa(ledger_model.coa_id).await?);
+        Ok(LedgerMapper::to_bo(ledger_model, coa_bo))
+    }
+
+    async fn load_ledger_account_bo(&self, account_id: Uuid) -> Result<LedgerAccount, ServiceError> {
+        let model = self.shared.load_ledger_account(account_id).await?.ok_or(ServiceError::LedgerAccountNotFound)?;
+        let ledger_bo = self.load_ledger_bo(model.ledger_id).await?;
+        let coa_bo = ChartOfAccountMapper::to_bo(self.shared.load_coa(model.coa_id).await?);
+        let parent_bo = match model.parent_id {
+            Some(parent_id) => Some(Box::new(Box::pin(self.load_ledger_account_bo(parent_id)).await?)),
+            None => None,
+        };
+        Ok(LedgerAccountMapper::to_bo(model, ledger_bo, coa_bo, parent_bo))
+    }
+
+    async fn load_posting(&self, model: postings_db::models::posting::Posting) -> Result<Posting, ServiceError> {
+        let ledger_bo = self.load_ledger_bo(model.ledger_id).await?;
+        // Postings of one operation share its id, so lines are found through their posting.
+        let line_models = self.shared.line_repo.find_by_posting_id(model.id).await.map_err(|e| e.into_service_error(format!("loading lines of posting {}", model.id)))?;
+
+        let mut lines = Vec::new();
+        for line in line_models {
+            if line.discarded_time.is_some() {
+                continue;
+            }
+            let account_bo = self.load_ledger_account_bo(line.account_id).await?;
+            lines.push(PostingLineMapper::to_bo(line, account_bo));
+        }
+        Ok(PostingMapper::to_bo(model, ledger_bo, lines))
+    }
+
+    async fn transition(&self, model: postings_db::models::posting::Posting, target: PostingStatus, approval: Option<postings_db::models::posting_approval::PostingApproval>) -> Result<Posting, ServiceError> {
+        let from = model.pst_status.clone();
+        let mut posting = self.load_posting(model).await?;
+        if !posting.pst_status.can_transition_to(&target) {
+            return Err(ServiceError::InvalidStatusTransition);
+        }
+
+        posting.pst_status = target;
+        Self::align_lines(&mut posting);
+        if posting.pst_status.is_effective() {
+            self.check_account_states(&posting, &mut HashMap::new()).await?;
+            // The posting enters the books now and becomes the newest link of the ledger's chain.
+            posting.record_time = self.shared.now();
+            for line in posting.lines.iter_mut() {
+                line.record_time = posting.record_time;
+            }
+            let head = self.load_chain_head(posting.ledger.id).await?;
+            Self::chain(&mut posting, head)?;
+        }
+
+        let db_posting = PostingMapper::to_model(posting.clone());
+        let db_lines: Vec<_> = posting.lines.iter().cloned().map(|l| PostingLineMapper::from_bo(l, posting.id)).collect();
+        let checks = Self::balance_checks([&posting]);
+        self.shared
+            .posting_repo
+            .update_status(&db_posting, from, &db_lines, approval.as_ref(), &checks)
+            .await
+            .map_err(|e| match e {
+                // Another transition of the posting committed since it was loaded.
+                DbError::StatusChanged { .. } => ServiceError::InvalidStatusTransition,
+                e => Self::map_save_error(e, format!("updating status of posting {}", posting.id)),
+            })?;
+        Ok(posting)
+    }
+
+    /// Whether the posting falls under the four-eyes rule of its ledger.
+    async fn requires_approval(&self, model: &postings_db::models::posting::Posting) -> Result<bool, ServiceError> {
+        let Some(policy) = self.load_approval_policy(model.ledger_id).await? else {
+            return Ok(false);
+        };
+        let lines = self.shared.line_repo.find_by_posting_id(model.id).await.map_err(|e| e.into_service_error(format!("loading lines of posting {}", model.id)))?;
+        let amount: BigDecimal = lines.iter().map(|l| l.debit_amount.clone()).sum();
+        let ledger = self.load_ledger_bo(model.ledger_id).await?;
+        Ok(ApprovalPolicyMapper::to_bo(policy, ledger).requires_approval(&amount))
+    }
+
+    async fn load_approvals(&self, posting_id: Uuid) -> Result<Vec<postings_db::models::posting_approval::PostingApproval>, ServiceError> {
+        self.shared.approval_repo.find_by_posting_id(posting_id).await.map_err(|e| e.into_service_error(format!("loading approvals of posting {posting_id}")))
+    }
+
+    async fn find_posting_model(&self, posting_id: Uuid) -> Result<postings_db::models::posting::Posting, ServiceError> {
+        self.shared
+            .posting_repo
+            .find_by_id(posting_id)
+            .await
+            .map_err(|e| e.into_service_error(format!("loading posting {posting_id}")))?
+            .ok_or(ServiceError::PostingNotFound)
+    }
+
+    /// Checks the links between hashed postings given oldest first: the first names no antecedent,
+    /// every other one names a posting of the list by id and hash, and no two name the same one.
+    fn verify_links(ledger_id: Uuid, postings: &[postings_db::models::posting::Posting]) -> ChainVerification {
+        let hashes: HashMap<Uuid, Option<[u8; 34]>> = postings.iter().map(|p| (p.id, p.hash)).collect();
+        let mut successors: BTreeMap<Uuid, Vec<Uuid>> = BTreeMap::new();
+        let mut breaks = Vec::new();
+        for (index, posting) in postings.iter().enumerate() {
+            let Some(antecedent_id) = posting.antecedent_id else {
+                if index > 0 {
+                    breaks.push(ChainBreak::MissingAntecedent { posting_id: posting.id });
+                }
+                continue;
+            };
+            match hashes.get(&antecedent_id) {
+                None => breaks.push(ChainBreak::UnknownAntecedent { posting_id: posting.id, antecedent_id }),
+                Some(hash) if *hash != posting.antecedent_hash => breaks.push(ChainBreak::HashMismatch { posting_id: posting.id, antecedent_id }),
+                Some(_) => {}
+            }
+            successors.entry(antecedent_id).or_default().push(posting.id);
+        }
+        breaks.extend(
+            successors
+                .into_iter()
+                .filter(|(_, posting_ids)| posting_ids.len() > 1)
+                .map(|(antecedent_id, posting_ids)| ChainBreak::Fork { antecedent_id, posting_ids }),
+        );
+
+        ChainVerification {
+            ledger_id,
+            postings: postings.len() as u64,
+            head: postings.last().map(|p| p.id),
+            breaks,
+        }
+    }
+
+    fn chain(posting: &mut Posting, head: ChainHead) -> Result<(), ServiceError> {
+        if let Some((antecedent_id, antecedent_hash, antecedent_time)) = head {
+            posting.hash_record.antecedent_id = Some(antecedent_id);
+            posting.hash_record.antecedent_hash = antecedent_hash;
+            // The head is looked up by the latest record time, so a link must come after its
+            // antecedent even when the clock has not moved on since.
+            if posting.record_time <= antecedent_time {
+                posting.record_time = antecedent_time + Duration::microseconds(1);
+                for line in posting.lines.iter_mut() {
+                    line.record_time = posting.record_time;
+                }
+            }
+        }
+
         let hash = hash_serialize(&posting).map_err(|_| ServiceError::NotEnoughInfo)?; // Simplified error
         posting.hash_record.hash = Some(hash);
+        // Statement traces reference the source posting through the hash on each line.
+        for line in posting.lines.iter_mut() {
+            line.hash = Some(hash);
+        }
+        Ok(())
+    }
+}
 
+#[async_trait]
+impl PostingService for PostingServiceImpl {
+    async fn new_posting(&self, posting: Posting) -> Result<Posting, ServiceError> {
+        let posting = self.prepare_posting(posting).await?;
+
+        // The posting and its lines go in one transaction that first locks the limits of the
+        // accounts, so concurrent postings cannot breach a limit together.
         let db_posting = PostingMapper::to_model(posting.clone());
-        self.shared.posting_repo.save(&db_posting).await.map_err(|_| ServiceError::Db)?;
+        let db_lines: Vec<_> = posting.lines.iter().cloned().map(|l| PostingLineMapper::from_bo(l, posting.id)).collect();
+        let checks = Self::balance_checks([&posting]);
+        self.shared
+            .posting_repo
+            .save_batch(&[db_posting], &db_lines, &checks)
+            .await
+            .map_err(|e| Self::map_save_error(e, format!("saving posting {}", posting.id)))?;
 
-        for line in posting.lines.iter() {
-            let db_line = PostingLineMapper::from_bo(line.clone());
-            self.shared.line_repo.save(db_line).await.map_err(|_| ServiceError::Db)?;
-        }
-        
         Ok(posting)
     }
 
+    async fn new_postings(&self, postings: Vec<Posting>) -> Result<Vec<Posting>, ServiceError> {
+        if postings.is_empty() {
+            return Ok(postings);
+        }
+        let mut state_changes = HashMap::new();
+        for posting in postings.iter() {
+            Self::validate(posting)?;
+            self.check_account_states(posting, &mut state_changes).await?;
+        }
+
+        let record_time = self.shared.now();
+        let mut heads: HashMap<Uuid, ChainHead> = HashMap::new();
+        let mut policies: HashMap<Uuid, Option<postings_db::models::approval_policy::ApprovalPolicy>> = HashMap::new();
+        let mut chained = Vec::with_capacity(postings.len());
+        for mut posting in postings {
+            self.identify(&mut posting, record_time);
+            let policy = match policies.get(&posting.ledger.id) {
+                Some(policy) => policy.clone(),
+                None => {
+                    let policy = self.load_approval_policy(posting.ledger.id).await?;
+                    policies.insert(posting.ledger.id, policy.clone());
+                    policy
+                }
+            };
+            Self::apply_approval_policy(&mut posting, policy);
+            Self::align_lines(&mut posting);
+
+            if posting.pst_status.is_effective() {
+                let head = match heads.get(&posting.ledger.id) {
+                    Some(head) => *head,
+                    None => self.load_chain_head(posting.ledger.id).await?,
+                };
+                Self::chain(&mut posting, head)?;
+                heads.insert(posting.ledger.id, Some((posting.id, posting.hash_record.hash, posting.record_time)));
+            }
+            chained.push(posting);
+        }
+
+        let db_postings: Vec<_> = chained.iter().cloned().map(PostingMapper::to_model).collect();
+        let db_lines: Vec<_> = chained
+            .iter()
+            .flat_map(|p| p.lines.iter().cloned().map(|l| PostingLineMapper::from_bo(l, p.id)))
+            .collect();
+        let checks = Self::balance_checks(&chained);
+        self.shared
+            .posting_repo
+            .save_batch(&db_postings, &db_lines, &checks)
+            .await
+            .map_err(|e| Self::map_save_error(e, format!("saving a batch of {} postings", chained.len())))?;
+
+        Ok(chained)
+    }
+
+    async fn approve_posting(&self, posting_id: Uuid, approver: [u8; 34]) -> Result<Posting, ServiceError> {
+        let model = self.find_posting_model(posting_id).await?;
+        let deferred = model.pst_status == PostingMapper::status_to_model(PostingStatus::Deferred);
+        if !deferred && model.pst_status != PostingMapper::status_to_model(PostingStatus::Proposed) {
+            return Err(ServiceError::InvalidStatusTransition);
+        }
+        if model.record_user == approver {
+            return Err(ServiceError::SelfApproval);
+        }
+
+        let approval = PostingApprovalMapper::to_model(PostingApproval {
+            id: self.shared.new_id(),
+            posting_id,
+            approver,
+            approval_time: self.shared.now(),
+        });
+        if !deferred {
+            return self.transition(model, PostingStatus::Posted, Some(approval)).await;
+        }
+
+        // Deferred postings still wait for their value time; the approval lets them be posted then.
+        if !self.requires_approval(&model).await? || !self.load_approvals(posting_id).await?.is_empty() {
+            return Err(ServiceError::InvalidStatusTransition);
+        }
+        self.shared
+            .approval_repo
+            .save(&approval)
+            .await
+            .map_err(|e| e.into_service_error(format!("saving approval of posting {posting_id}")))?;
+        self.load_posting(model).await
+    }
+
+    async fn cancel_posting(&self, posting_id: Uuid) -> Result<Posting, ServiceError> {
+        let model = self.find_posting_model(posting_id).await?;
+        self.transition(model, PostingStatus::Cancelled, None).await
+    }
+
+    async fn post_deferred_postings(&self, ref_time: DateTime<Utc>) -> Result<Vec<Posting>, ServiceError> {
+        let due = self
+            .shared
+            .posting_repo
+            .find_by_pst_status_and_val_time_less_than_equal(PostingMapper::status_to_model(PostingStatus::Deferred), ref_time)
+            .await
+            .map_err(|e| e.into_service_error(format!("finding deferred postings due at {ref_time}")))?;
+
+        let mut posted = Vec::with_capacity(due.len());
+        for model in due {
+            let posting_id = model.id;
+            if self.requires_approval(&model).await? && self.load_approvals(posting_id).await?.is_empty() {
+                warn!("Deferred posting {posting_id} stays deferred until it is approved");
+                continue;
+            }
+            match self.transition(model, PostingStatus::Posted, None).await {
+                Ok(posting) => posted.push(posting),
+                Err(
+                    e @ (ServiceError::LimitExceeded { .. }
+                    | ServiceError::AccountClosed
+                    | ServiceError::AccountFrozen
+                    | ServiceError::AccountDebitBlocked
+                    | ServiceError::AccountCreditBlocked),
+                ) => warn!("Deferred posting {posting_id} stays deferred: {e}"),
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(posted)
+    }
+
+    async fn find_pending_postings(&self, ledger_id: Uuid) -> Result<Vec<Posting>, ServiceError> {
+        self.shared.load_ledger(ledger_id).await?;
+        let models = self
+            .shared
+            .posting_repo
+            .find_by_ledger_and_pst_status(ledger_id, PostingMapper::status_to_model(PostingStatus::Proposed))
+            .await
+            .map_err(|e| e.into_service_error(format!("finding pending postings of ledger {ledger_id}")))?;
+
+        let mut pending = Vec::with_capacity(models.len());
+        for model in models {
+            pending.push(self.load_posting(model).await?);
+        }
+        Ok(pending)
+    }
+
+    async fn find_posting_approvals(&self, posting_id: Uuid) -> Result<Vec<PostingApproval>, ServiceError> {
+        let approvals = self.shared.approval_repo.find_by_posting_id(posting_id).await.map_err(|e| e.into_service_error(format!("loading approvals of posting {posting_id}")))?;
+        Ok(approvals.into_iter().map(PostingApprovalMapper::to_bo).collect())
+    }
+
+    async fn set_approval_policy(&self, policy: ApprovalPolicy) -> Result<ApprovalPolicy, ServiceError> {
+        self.shared.load_ledger(policy.ledger.id).await?;
+        let model = ApprovalPolicyMapper::to_model(policy.clone());
+        self.shared.approval_repo.save_policy(&model).await.map_err(|e| e.into_service_error(format!("saving approval policy of ledger {}", model.ledger_id)))?;
+        Ok(policy)
+    }
+
+    async fn find_approval_policy(&self, ledger_id: Uuid) -> Result<Option<ApprovalPolicy>, ServiceError> {
+        match self.load_approval_policy(ledger_id).await? {
+            Some(model) => {
+                let ledger_bo = self.load_ledger_bo(ledger_id).await?;
+                Ok(Some(ApprovalPolicyMapper::to_bo(model, ledger_bo)))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn remove_approval_policy(&self, ledger_id: Uuid) -> Result<(), ServiceError> {
+        self.shared.approval_repo.delete_policy_by_ledger_id(ledger_id).await.map_err(|e| e.into_service_error(format!("removing approval policy of ledger {ledger_id}")))
+    }
+
     async fn find_postings_by_operation_id(&self, opr_id: &[u8; 34]) -> Result<Vec<Posting>, ServiceError> {
         // Simplified, mapping needed
-        self.shared.posting_repo.find_by_opr_id(opr_id).await.map_err(|_| ServiceError::Db)?;
+        self.shared.posting_repo.find_by_opr_id(opr_id).await.map_err(|e| e.into_service_error("finding postings by operation id"))?;
         Ok(vec![])
     }
 
+    async fn find_postings_by_ledger(&self, ledger_id: Uuid, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<Vec<Posting>, ServiceError> {
+        self.shared.load_ledger(ledger_id).await?;
+        let models = self.shared.posting_repo.find_by_ledger_and_pst_time_between(ledger_id, date_from, date_to).await.map_err(|e| e.into_service_error(format!("finding postings of ledger {ledger_id} from {date_from} to {date_to}")))?;
+        let mut postings = Vec::with_capacity(models.len());
+        for model in models {
+            postings.push(self.load_posting(model).await?);
+        }
+        Ok(postings)
+    }
+
+    async fn verify_hash_chain(&self, ledger_id: Uuid) -> Result<ChainVerification, ServiceError> {
+        self.shared.load_ledger(ledger_id).await?;
+        let postings = self.shared.posting_repo.find_by_ledger_and_hash_is_not_null_order_by_record_time(ledger_id).await.map_err(|e| e.into_service_error(format!("loading hash chain of ledger {ledger_id}")))?;
+        Ok(Self::verify_links(ledger_id, &postings))
+    }
+
     async fn find_postings_by_dates(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>) -> Result<Vec<PostingLine>, ServiceError> {
-        // Simplified, mapping needed
-        self.shared.line_repo.find_by_account_and_pst_time_between(ledger_account.id, date_from, date_to).await.map_err(|_| ServiceError::Db)?;
-        Ok(vec![])
+        let lines = self.shared.line_repo.find_by_account_and_pst_time_between(ledger_account.id, date_from, date_to).await.map_err(|e| e.into_service_error(format!("finding lines of ledger account {} from {date_from} to {date_to}", ledger_account.id)))?;
+        Ok(lines.into_iter().map(|line| PostingLineMapper::to_bo(line, ledger_account.clone())).collect())
     }
 
-    async fn find_postings_by_dates_paged(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>, _page: usize, _size: usize) -> Result<Page<PostingLine>, ServiceError> {
-        // Simplified, proper pagination and mapping needed
-        let lines = self.shared.line_repo.find_by_account_and_pst_time_between(ledger_account.id, date_from, date_to).await.map_err(|_| ServiceError::Db)?;
-        Ok(Page { content: vec![], total_elements: lines.len() as u64 })
+    async fn find_postings_by_dates_paged(&self, ledger_account: LedgerAccount, date_from: DateTime<Utc>, date_to: DateTime<Utc>, page: usize, size: usize) -> Result<Page<PostingLine>, ServiceError> {
+        let lines = self.shared.line_repo.find_by_account_and_pst_time_between(ledger_account.id, date_from, date_to).await.map_err(|e| e.into_service_error(format!("finding lines of ledger account {} from {date_from} to {date_to}", ledger_account.id)))?;
+        let total_elements = lines.len() as u64;
+        let content = lines
+            .into_iter()
+            .skip(page.saturating_mul(size))
+            .take(size)
+            .map(|line| PostingLineMapper::to_bo(line, ledger_account.clone()))
+            .collect();
+        Ok(Page { content, total_elements })
     }
 
     async fn find_posting_line_by_id(&self, ledger_account: LedgerAccount, transaction_id: Uuid) -> Result<PostingLine, ServiceError> {
         // Simplified, mapping needed
-        self.shared.line_repo.find_by_id_and_account_id(transaction_id, ledger_account.id).await.map_err(|_| ServiceError::Db)?;
+        self.shared.line_repo.find_by_id_and_account_id(transaction_id, ledger_account.id).await.map_err(|e| e.into_service_error(format!("loading line {transaction_id} of ledger account {}", ledger_account.id)))?;
         Err(ServiceError::PostingNotFound)
     }
 }
@@ -0,0 +1,285 @@
+use std::sync::Arc;
+use async_trait::async_trait;
+use bigdecimal::{BigDecimal, Zero};
+use chrono::{DateTime, Utc};
+use log::warn;
+use uuid::Uuid;
+
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::posting_schedule::{CatchUp, PostingSchedule, ScheduleRun, ScheduleStatus};
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::service::ledger_service::LedgerService;
+use postings_api::service::schedule_service::ScheduleService;
+use postings_api::ServiceError;
+use postings_db::DbError;
+
+use crate::hash_utils::hash_serialize;
+use crate::mappers::posting::PostingMapper;
+use crate::mappers::posting_line::PostingLineMapper;
+use crate::mappers::posting_schedule::PostingScheduleMapper;
+use crate::services::ledger_service::LedgerServiceImpl;
+use crate::services::posting_service::PostingServiceImpl;
+use crate::services::shared_service::SharedService;
+
+const SCHEDULE_OPR_TYPE: &str = "POSTING_SCHEDULE";
+
+#[derive(Clone)]
+pub struct ScheduleServiceImpl {
+    shared: Arc<SharedService>,
+    ledger_service: LedgerServiceImpl,
+    posting_service: PostingServiceImpl,
+}
+
+impl ScheduleServiceImpl {
+    pub fn new(shared: impl Into<Arc<SharedService>>, ledger_service: LedgerServiceImpl, posting_service: PostingServiceImpl) -> Self {
+        Self {
+            shared: shared.into(),
+            ledger_service,
+            posting_service,
+        }
+    }
+
+    async fn load_ledger(&self, ledger_id: Uuid) -> Result<Ledger, ServiceError> {
+        self.ledger_service.find_ledger_by_id(ledger_id).await?.ok_or(ServiceError::LedgerNotFound)
+    }
+
+    async fn load_account(&self, account_id: Uuid) -> Result<LedgerAccount, ServiceError> {
+        self.ledger_service
+            .find_ledger_account_by_id(account_id)
+            .await?
+            .ok_or(ServiceError::LedgerAccountNotFound)
+    }
+
+    async fn to_bo(&self, model: postings_db::models::posting_schedule::PostingSchedule) -> Result<PostingSchedule, ServiceError> {
+        let ledger = self.load_ledger(model.ledger_id).await?;
+        let lines = self
+            .shared
+            .schedule_repo
+            .find_lines_by_schedule_id(model.id)
+            .await
+            .map_err(|e| e.into_service_error(format!("loading lines of schedule {}", model.id)))?;
+        PostingScheduleMapper::to_bo(model, ledger, lines)
+    }
+
+    async fn load_schedule(&self, id: Uuid) -> Result<PostingSchedule, ServiceError> {
+        self.find_schedule_by_id(id).await?.ok_or(ServiceError::ScheduleNotFound)
+    }
+
+    /// The operation id is derived from schedule and occurrence, so replays of an occurrence share it.
+    fn occurrence_opr_id(schedule_id: Uuid, index: u32) -> Result<[u8; 34], ServiceError> {
+        hash_serialize(&(SCHEDULE_OPR_TYPE, schedule_id, index)).map_err(|_| ServiceError::NotEnoughInfo)
+    }
+
+    /// Index and time of the occurrence to post next: the schedule's next one, or with
+    /// [`CatchUp::Latest`] the last one due at `now`.
+    fn due_occurrence(schedule: &PostingSchedule, now: DateTime<Utc>) -> Option<(u32, DateTime<Utc>)> {
+        let next = (schedule.next_index, schedule.next_time.filter(|time| *time <= now)?);
+        if schedule.catch_up == CatchUp::All {
+            return Some(next);
+        }
+        Some(
+            schedule
+                .recurrence
+                .occurrences(schedule.start_time)
+                .zip(0u32..)
+                .skip(schedule.next_index as usize + 1)
+                .take_while(|(time, _)| *time <= now)
+                .last()
+                .map_or(next, |(time, index)| (index, time)),
+        )
+    }
+
+    fn occurrence_posting(&self, schedule: &PostingSchedule, accounts: &[LedgerAccount], index: u32, time: DateTime<Utc>) -> Result<Posting, ServiceError> {
+        let opr_id = Self::occurrence_opr_id(schedule.id, index)?;
+        let lines = schedule
+            .lines
+            .iter()
+            .zip(accounts)
+            .map(|(line, account)| PostingLine {
+                id: self.shared.new_id(),
+                account: account.clone(),
+                debit_amount: line.debit_amount.clone(),
+                credit_amount: line.credit_amount.clone(),
+                details: None,
+                src_account: None,
+                base_line: None,
+                sub_opr_src_id: None,
+                record_time: time,
+                opr_id,
+                opr_src: None,
+                pst_time: time,
+                val_time: None,
+                pst_type: schedule.pst_type.clone(),
+                pst_status: PostingStatus::Posted,
+                hash: None,
+                additional_information: None,
+                discarded_time: None,
+            })
+            .collect();
+        Ok(Posting {
+            id: Uuid::nil(),
+            record_user: schedule.record_user,
+            record_time: time,
+            opr_id,
+            opr_time: time,
+            opr_type: schedule.opr_type,
+            opr_details: None,
+            opr_src: None,
+            pst_time: time,
+            pst_type: schedule.pst_type.clone(),
+            pst_status: PostingStatus::Posted,
+            ledger: schedule.ledger.clone(),
+            val_time: None,
+            lines,
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        })
+    }
+
+    /// Posts the due occurrences of one schedule in order, adding each posting to `posted` as it
+    /// is saved. Stops without error once another run has moved the schedule on.
+    async fn run_schedule(&self, mut schedule: PostingSchedule, now: DateTime<Utc>, posted: &mut Vec<Posting>) -> Result<(), ServiceError> {
+        let mut accounts = Vec::with_capacity(schedule.lines.len());
+        for line in schedule.lines.iter() {
+            accounts.push(self.load_account(line.account_id).await?);
+        }
+
+        while let Some((index, time)) = Self::due_occurrence(&schedule, now) {
+            let expected_index = schedule.next_index;
+            schedule.next_index = index + 1;
+            schedule.next_time = schedule.recurrence.occurrence(schedule.start_time, schedule.next_index);
+            if schedule.next_time.is_none() {
+                schedule.status = ScheduleStatus::Finished;
+            }
+
+            let posting = self.occurrence_posting(&schedule, &accounts, index, time)?;
+            let posting = self.posting_service.prepare_posting(posting).await?;
+            let run = ScheduleRun {
+                schedule_id: schedule.id,
+                occurrence_index: index,
+                occurrence_time: time,
+                posting_id: posting.id,
+                run_time: self.shared.now(),
+            };
+            let db_lines: Vec<_> = posting.lines.iter().cloned().map(|l| PostingLineMapper::from_bo(l, posting.id)).collect();
+            let checks = PostingServiceImpl::balance_checks([&posting]);
+            let saved = self
+                .shared
+                .schedule_repo
+                .save_run(
+                    &PostingScheduleMapper::to_model(schedule.clone()),
+                    expected_index as i32,
+                    &PostingScheduleMapper::run_to_model(run),
+                    &PostingMapper::to_model(posting.clone()),
+                    &db_lines,
+                    &checks,
+                )
+                .await;
+            match saved {
+                Ok(()) => posted.push(posting),
+                Err(DbError::NotFound) => break,
+                Err(e) => return Err(PostingServiceImpl::map_save_error(e, format!("saving run {expected_index} of schedule {}", schedule.id))),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ScheduleService for ScheduleServiceImpl {
+    async fn new_schedule(&self, mut schedule: PostingSchedule) -> Result<PostingSchedule, ServiceError> {
+        let debit_sum: BigDecimal = schedule.lines.iter().map(|l| l.debit_amount.clone()).sum();
+        let credit_sum: BigDecimal = schedule.lines.iter().map(|l| l.credit_amount.clone()).sum();
+        if schedule.lines.is_empty() || debit_sum != credit_sum || debit_sum.is_zero() {
+            return Err(ServiceError::DoubleEntry);
+        }
+        schedule.ledger = self.load_ledger(schedule.ledger.id).await?;
+        for line in schedule.lines.iter() {
+            if self.load_account(line.account_id).await?.ledger.id != schedule.ledger.id {
+                return Err(ServiceError::LedgerMismatch);
+            }
+        }
+
+        schedule.id = self.shared.new_id();
+        schedule.record_time = self.shared.now();
+        schedule.next_index = 0;
+        schedule.next_time = schedule.recurrence.occurrence(schedule.start_time, 0);
+        schedule.status = if schedule.next_time.is_some() { ScheduleStatus::Active } else { ScheduleStatus::Finished };
+
+        let lines: Vec<_> = schedule
+            .lines
+            .iter()
+            .cloned()
+            .map(|line| PostingScheduleMapper::line_to_model(line, self.shared.new_id(), schedule.id))
+            .collect();
+        self.shared
+            .schedule_repo
+            .save(&PostingScheduleMapper::to_model(schedule.clone()), &lines)
+            .await
+            .map_err(|e| e.into_service_error(format!("saving schedule {}", schedule.id)))?;
+        Ok(schedule)
+    }
+
+    async fn cancel_schedule(&self, id: Uuid) -> Result<PostingSchedule, ServiceError> {
+        match self.shared.schedule_repo.cancel(id).await {
+            Ok(()) => self.load_schedule(id).await,
+            Err(DbError::NotFound) => {
+                self.load_schedule(id).await?;
+                Err(ServiceError::ScheduleNotActive)
+            }
+            Err(e) => Err(e.into_service_error(format!("cancelling schedule {id}"))),
+        }
+    }
+
+    async fn find_schedule_by_id(&self, id: Uuid) -> Result<Option<PostingSchedule>, ServiceError> {
+        match self.shared.schedule_repo.find_by_id(id).await.map_err(|e| e.into_service_error(format!("loading schedule {id}")))? {
+            Some(model) => Ok(Some(self.to_bo(model).await?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_schedules_by_ledger(&self, ledger_id: Uuid) -> Result<Vec<PostingSchedule>, ServiceError> {
+        let models = self
+            .shared
+            .schedule_repo
+            .find_by_ledger_id(ledger_id)
+            .await
+            .map_err(|e| e.into_service_error(format!("finding schedules of ledger {ledger_id}")))?;
+        let mut schedules = Vec::with_capacity(models.len());
+        for model in models {
+            schedules.push(self.to_bo(model).await?);
+        }
+        Ok(schedules)
+    }
+
+    async fn find_schedule_runs(&self, schedule_id: Uuid) -> Result<Vec<ScheduleRun>, ServiceError> {
+        let runs = self
+            .shared
+            .schedule_repo
+            .find_runs_by_schedule_id(schedule_id)
+            .await
+            .map_err(|e| e.into_service_error(format!("loading runs of schedule {schedule_id}")))?;
+        Ok(runs.into_iter().map(PostingScheduleMapper::run_to_bo).collect())
+    }
+
+    async fn run_due(&self, now: DateTime<Utc>) -> Result<Vec<Posting>, ServiceError> {
+        let due = self.shared.schedule_repo.find_due(now).await.map_err(|e| e.into_service_error(format!("finding schedules due at {now}")))?;
+        let mut postings = Vec::new();
+        for model in due {
+            let schedule_id = model.id;
+            let result = match self.to_bo(model).await {
+                Ok(schedule) => self.run_schedule(schedule, now, &mut postings).await,
+                Err(e) => Err(e),
+            };
+            if let Err(e) = result {
+                warn!("Schedule {schedule_id} failed, the next run retries it from the same occurrence: {e:?}");
+            }
+        }
+        Ok(postings)
+    }
+}
@@ -2,14 +2,23 @@ use std::sync::Arc;
 use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
 use postings_db::repositories::ledger_account_repository::LedgerAccountRepository;
 use postings_db::repositories::ledger_repository::LedgerRepository;
-use postings_db::repositories::named_repository::NamedRepository;
+use postings_db::repositories::named_repository::{NamedRepository, UNIQUE_NAME_INDEX};
 use postings_db::repositories::posting_repository::PostingRepository;
 use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
 use postings_db::repositories::posting_line_repository::PostingLineRepository;
 use postings_db::repositories::posting_trace_repository::PostingTraceRepository;
+use postings_db::repositories::posting_approval_repository::PostingApprovalRepository;
+use postings_db::repositories::hold_repository::HoldRepository;
+use postings_db::repositories::schedule_repository::ScheduleRepository;
+use postings_db::repositories::outbox_repository::OutboxRepository;
+use postings_db::repositories::Repositories;
+use postings_api::domain::account_state::AccountStateChange;
 use postings_api::ServiceError;
 use postings_db::DbError;
 use uuid::Uuid;
+use crate::clock::{Clock, SystemClock};
+use crate::id_generator::{IdGenerator, RandomIdGenerator};
+use crate::mappers::account_state::AccountStateMapper;
 
 pub struct SharedService {
     pub coa_repo: Arc<dyn ChartOfAccountRepository + Send + Sync>,
@@ -20,6 +29,12 @@ pub struct SharedService {
     pub stmt_repo: Arc<dyn AccountStmtRepository + Send + Sync>,
     pub line_repo: Arc<dyn PostingLineRepository + Send + Sync>,
     pub trace_repo: Arc<dyn PostingTraceRepository + Send + Sync>,
+    pub approval_repo: Arc<dyn PostingApprovalRepository + Send + Sync>,
+    pub hold_repo: Arc<dyn HoldRepository + Send + Sync>,
+    pub schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+    pub outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
+    pub clock: Arc<dyn Clock + Send + Sync>,
+    pub id_generator: Arc<dyn IdGenerator + Send + Sync>,
 }
 
 impl SharedService {
@@ -33,6 +48,10 @@ impl SharedService {
         stmt_repo: Arc<dyn AccountStmtRepository + Send + Sync>,
         line_repo: Arc<dyn PostingLineRepository + Send + Sync>,
         trace_repo: Arc<dyn PostingTraceRepository + Send + Sync>,
+        approval_repo: Arc<dyn PostingApprovalRepository + Send + Sync>,
+        hold_repo: Arc<dyn HoldRepository + Send + Sync>,
+        schedule_repo: Arc<dyn ScheduleRepository + Send + Sync>,
+        outbox_repo: Arc<dyn OutboxRepository + Send + Sync>,
     ) -> Self {
         Self {
             coa_repo,
@@ -43,6 +62,58 @@ impl SharedService {
             stmt_repo,
             line_repo,
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
+            clock: Arc::new(SystemClock),
+            id_generator: Arc::new(RandomIdGenerator),
+        }
+    }
+
+    pub fn from_repositories(repositories: Repositories) -> Self {
+        Self::new(
+            repositories.coa,
+            repositories.ledger,
+            repositories.ledger_account,
+            repositories.named,
+            repositories.posting,
+            repositories.stmt,
+            repositories.line,
+            repositories.trace,
+            repositories.approval,
+            repositories.hold,
+            repositories.schedule,
+            repositories.outbox,
+        )
+    }
+
+    /// Replaces the system clock, e.g. to pin or backdate record times.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock + Send + Sync>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Replaces the random version 4 ids.
+    pub fn with_id_generator(mut self, id_generator: Arc<dyn IdGenerator + Send + Sync>) -> Self {
+        self.id_generator = id_generator;
+        self
+    }
+
+    pub fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        self.clock.now()
+    }
+
+    pub fn new_id(&self) -> Uuid {
+        self.id_generator.new_id()
+    }
+
+    /// Tells name collisions, rejected by the unique name index, apart from other database errors.
+    /// A duplicate the database did not name a constraint for is taken to be a name collision.
+    pub fn map_named_error(e: DbError, context: impl Into<String>) -> ServiceError {
+        match e {
+            DbError::Duplicate { constraint } if constraint.as_deref().is_none_or(|c| c == UNIQUE_NAME_INDEX) => ServiceError::DuplicateName,
+            e => e.into_service_error(context),
         }
     }
 
@@ -50,7 +121,7 @@ impl SharedService {
         self.coa_repo
             .find_by_id(coa_id)
             .await
-            .map_err(|_| ServiceError::Db)?
+            .map_err(|e| e.into_service_error(format!("loading chart of account {coa_id}")))?
             .ok_or(ServiceError::ChartOfAccountNotFound)
     }
     
@@ -58,7 +129,7 @@ impl SharedService {
         self.ledger_repo
             .find_by_id(ledger_id)
             .await
-            .map_err(|_| ServiceError::Db)?
+            .map_err(|e| e.into_service_error(format!("loading ledger {ledger_id}")))?
             .ok_or(ServiceError::LedgerNotFound)
     }
 
@@ -71,8 +142,16 @@ impl SharedService {
             Err(DbError::NotFound) => Ok(None),
             Err(e) => {
                 log::error!("Database error loading ledger account: {e:?}");
-                Err(ServiceError::Db)
+                Err(e.into_service_error(format!("loading ledger account {ledger_account_id}")))
             }
         }
     }
+
+    pub async fn load_account_state_changes(&self, ledger_account_id: Uuid) -> Result<Vec<AccountStateChange>, ServiceError> {
+        let changes = self.ledger_account_repo
+            .find_state_changes_by_account_id(ledger_account_id)
+            .await
+            .map_err(|e| e.into_service_error(format!("loading state changes of ledger account {ledger_account_id}")))?;
+        Ok(changes.into_iter().map(AccountStateMapper::to_bo).collect())
+    }
 }
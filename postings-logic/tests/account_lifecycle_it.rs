@@ -0,0 +1,260 @@
+#![cfg(test)]
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::sync::Arc;
+    use bigdecimal::BigDecimal;
+    use chrono::{Duration, Utc};
+    use sqlx::PgPool;
+    use uuid::Uuid;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::account_state::AccountState;
+    use postings_api::domain::balance_side::BalanceSide;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::domain::posting::Posting;
+    use postings_api::domain::posting_line::PostingLine;
+    use postings_api::domain::posting_status::PostingStatus;
+    use postings_api::domain::posting_type::PostingType;
+    use postings_api::service::account_lifecycle_service::AccountLifecycleService;
+    use postings_api::service::chart_of_account_service::ChartOfAccountService;
+    use postings_api::service::ledger_service::LedgerService;
+    use postings_api::service::posting_service::PostingService;
+    use postings_api::ServiceError;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_logic::services::account_lifecycle_service::AccountLifecycleServiceImpl;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::posting_service::PostingServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    struct Services {
+        ledger: LedgerServiceImpl,
+        posting: PostingServiceImpl,
+        lifecycle: AccountLifecycleServiceImpl,
+    }
+
+    fn create_services(pool: PgPool) -> Services {
+        Services {
+            ledger: LedgerServiceImpl::new(
+                create_shared_service(pool.clone()),
+                ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())),
+            ),
+            posting: PostingServiceImpl::new(create_shared_service(pool.clone())),
+            lifecycle: AccountLifecycleServiceImpl::new(create_shared_service(pool)),
+        }
+    }
+
+    async fn setup_ledger(pool: &PgPool, services: &Services) -> anyhow::Result<Ledger> {
+        let coa_service = ChartOfAccountServiceImpl::new(create_shared_service(pool.clone()));
+        let (coa, _) = coa_service.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+        let (ledger, _) = services.ledger.new_ledger(Ledger { id: Uuid::new_v4(), coa }, vec![]).await?;
+        Ok(ledger)
+    }
+
+    async fn setup_account(services: &Services, ledger: &Ledger, parent: Option<&LedgerAccount>) -> anyhow::Result<LedgerAccount> {
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: parent.map(|p| Box::new(p.clone())),
+            coa: ledger.coa.clone(),
+            balance_side: BalanceSide::Dr,
+            category: AccountCategory::AS,
+            code: None,
+        };
+        Ok(services.ledger.new_ledger_account(account, vec![]).await?.0)
+    }
+
+    fn line(account: &LedgerAccount, debit: i64, credit: i64) -> PostingLine {
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: account.clone(),
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [0; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            val_time: None,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+        }
+    }
+
+    /// Moves `amount` from `credited` to `debited`.
+    fn transfer(debited: &LedgerAccount, credited: &LedgerAccount, amount: i64) -> Posting {
+        let mut opr_id = [0u8; 34];
+        opr_id[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+        Posting {
+            id: Uuid::nil(),
+            record_user: [0; 34],
+            record_time: Utc::now(),
+            opr_id,
+            opr_time: Utc::now(),
+            opr_type: [0; 34],
+            opr_details: None,
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: debited.ledger.clone(),
+            val_time: None,
+            lines: vec![line(debited, amount, 0), line(credited, 0, amount)],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_account_states_restrict_postings(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let cash = setup_account(&services, &ledger, None).await?;
+        let bank = setup_account(&services, &ledger, None).await?;
+        let past = Utc::now() - Duration::hours(1);
+        services.lifecycle.change_account_state(cash.id, AccountState::DebitBlocked, past, [1; 34], None).await?;
+        services.lifecycle.change_account_state(bank.id, AccountState::Frozen, Utc::now() + Duration::days(1), [1; 34], None).await?;
+
+        // Act
+        let debit_cash = services.posting.new_posting(transfer(&cash, &bank, 100)).await;
+        let credit_cash = services.posting.new_posting(transfer(&bank, &cash, 100)).await;
+        let mut after_freeze = transfer(&bank, &cash, 100);
+        after_freeze.pst_time = Utc::now() + Duration::days(2);
+        let frozen = services.posting.new_postings(vec![after_freeze]).await;
+
+        // Assert
+        assert!(matches!(debit_cash, Err(ServiceError::AccountDebitBlocked)));
+        assert!(credit_cash.is_ok());
+        assert!(matches!(frozen, Err(ServiceError::AccountFrozen)));
+        assert_eq!(services.lifecycle.find_account_state(cash.id, Utc::now()).await?, AccountState::DebitBlocked);
+        assert_eq!(services.lifecycle.find_account_state(bank.id, Utc::now()).await?, AccountState::Open);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_close_account(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let parent = setup_account(&services, &ledger, None).await?;
+        let child = setup_account(&services, &ledger, Some(&parent)).await?;
+        let bank = setup_account(&services, &ledger, None).await?;
+        services.posting.new_posting(transfer(&child, &bank, 100)).await?;
+        let lifecycle = &services.lifecycle;
+        let now = Utc::now();
+
+        // Act
+        let parent_with_open_child = lifecycle.change_account_state(parent.id, AccountState::Closed, now, [1; 34], None).await;
+        let child_with_balance = lifecycle.change_account_state(child.id, AccountState::Closed, now, [1; 34], None).await;
+        services.posting.new_posting(transfer(&bank, &child, 100)).await?;
+        let closed_child = lifecycle.change_account_state(child.id, AccountState::Closed, Utc::now(), [1; 34], Some("Settled".to_string())).await;
+        let closed_parent = lifecycle.change_account_state(parent.id, AccountState::Closed, Utc::now(), [1; 34], None).await;
+        let reopened = lifecycle.change_account_state(child.id, AccountState::Open, Utc::now(), [1; 34], None).await;
+        let posting_to_closed = services.posting.new_posting(transfer(&child, &bank, 100)).await;
+        let new_child = setup_account(&services, &ledger, Some(&parent)).await;
+
+        // Assert
+        assert!(matches!(parent_with_open_child, Err(ServiceError::OpenChildAccounts)));
+        assert!(matches!(child_with_balance, Err(ServiceError::AccountBalanceNotZero)));
+        assert!(closed_child.is_ok());
+        assert!(closed_parent.is_ok());
+        assert!(matches!(reopened, Err(ServiceError::AccountClosed)));
+        assert!(matches!(posting_to_closed, Err(ServiceError::AccountClosed)));
+        assert!(new_child.is_err());
+        let audit = lifecycle.find_account_state_changes(child.id).await?;
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].reason.as_deref(), Some("Settled"));
+        assert_eq!(audit[0].record_user, [1; 34]);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_backdated_posting_into_closed_account_is_rejected(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let cash = setup_account(&services, &ledger, None).await?;
+        let bank = setup_account(&services, &ledger, None).await?;
+        services.lifecycle.change_account_state(cash.id, AccountState::Closed, Utc::now(), [1; 34], None).await?;
+        let mut backdated = transfer(&cash, &bank, 100);
+        backdated.pst_time = Utc::now() - Duration::days(1);
+
+        // Act
+        let result = services.posting.new_posting(backdated).await;
+
+        // Assert
+        assert!(matches!(result, Err(ServiceError::AccountClosed)));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_closing_and_posting_concurrently_keep_closed_balance_zero(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let cash = setup_account(&services, &ledger, None).await?;
+        let bank = setup_account(&services, &ledger, None).await?;
+
+        // Act
+        let (closed, posted) = tokio::join!(
+            services.lifecycle.change_account_state(cash.id, AccountState::Closed, Utc::now(), [1; 34], None),
+            services.posting.new_posting(transfer(&cash, &bank, 100)),
+        );
+
+        // Assert
+        assert!(closed.is_ok() != posted.is_ok(), "closed: {closed:?}, posted: {posted:?}");
+        if closed.is_err() {
+            assert!(matches!(closed, Err(ServiceError::AccountBalanceNotZero)));
+        } else {
+            assert!(matches!(posted, Err(ServiceError::AccountClosed)));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,468 @@
+#![cfg(test)]
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use bigdecimal::BigDecimal;
+    use chrono::{Duration, Utc};
+    use sqlx::PgPool;
+    use uuid::Uuid;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::account_limit::AccountLimit;
+    use postings_api::domain::approval_policy::ApprovalPolicy;
+    use postings_api::domain::balance_side::BalanceSide;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::domain::posting::Posting;
+    use postings_api::domain::posting_line::PostingLine;
+    use postings_api::domain::posting_status::PostingStatus;
+    use postings_api::domain::posting_type::PostingType;
+    use postings_api::service::chart_of_account_service::ChartOfAccountService;
+    use postings_api::service::ledger_service::LedgerService;
+    use postings_api::service::posting_service::PostingService;
+    use postings_api::ServiceError;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::posting_service::PostingServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    struct Services {
+        ledger: LedgerServiceImpl,
+        posting: PostingServiceImpl,
+    }
+
+    fn limit(account: &LedgerAccount, min: Option<&str>, max: Option<&str>) -> AccountLimit {
+        AccountLimit {
+            account_id: account.id,
+            min_balance: min.map(|m| BigDecimal::from_str(m).unwrap()),
+            max_balance: max.map(|m| BigDecimal::from_str(m).unwrap()),
+        }
+    }
+
+    fn create_services(pool: PgPool) -> Services {
+        Services {
+            ledger: LedgerServiceImpl::new(
+                create_shared_service(pool.clone()),
+                ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())),
+            ),
+            posting: PostingServiceImpl::new(create_shared_service(pool)),
+        }
+    }
+
+    async fn setup_ledger(pool: &PgPool, services: &Services) -> anyhow::Result<Ledger> {
+        let coa_service = ChartOfAccountServiceImpl::new(create_shared_service(pool.clone()));
+        let (coa, _) = coa_service.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+        let (ledger, _) = services.ledger.new_ledger(Ledger { id: Uuid::new_v4(), coa }, vec![]).await?;
+        Ok(ledger)
+    }
+
+    async fn setup_account(services: &Services, ledger: &Ledger, balance_side: BalanceSide, category: AccountCategory) -> anyhow::Result<LedgerAccount> {
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side,
+            category,
+            code: None,
+        };
+        Ok(services.ledger.new_ledger_account(account, vec![]).await?.0)
+    }
+
+    fn line(account: &LedgerAccount, debit: i64, credit: i64) -> PostingLine {
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: account.clone(),
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [0; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            val_time: None,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+        }
+    }
+
+    /// Moves `amount` from `credited` to `debited`.
+    fn transfer(debited: &LedgerAccount, credited: &LedgerAccount, amount: i64) -> Posting {
+        let mut opr_id = [0u8; 34];
+        opr_id[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+        Posting {
+            id: Uuid::nil(),
+            record_user: [0; 34],
+            record_time: Utc::now(),
+            opr_id,
+            opr_time: Utc::now(),
+            opr_type: [0; 34],
+            opr_details: None,
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: debited.ledger.clone(),
+            val_time: None,
+            lines: vec![line(debited, amount, 0), line(credited, 0, amount)],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_wallet_cannot_be_overdrawn(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        services.ledger.set_account_limit(limit(&wallet, Some("0"), None)).await?;
+        services.posting.new_posting(transfer(&bank, &wallet, 100)).await?;
+
+        // Act
+        let overdraft = services.posting.new_posting(transfer(&wallet, &bank, 150)).await;
+        let withdrawal = services.posting.new_posting(transfer(&wallet, &bank, 100)).await;
+        let invalid = services.ledger.set_account_limit(limit(&wallet, Some("10"), Some("0"))).await;
+
+        // Assert
+        match overdraft {
+            Err(ServiceError::LimitExceeded { account_id, shortfall }) => {
+                assert_eq!(account_id, wallet.id);
+                assert_eq!(shortfall, BigDecimal::from(50));
+            }
+            other => panic!("expected LimitExceeded, got {other:?}"),
+        }
+        assert!(withdrawal.is_ok());
+        assert!(matches!(invalid, Err(ServiceError::InvalidAccountLimit)));
+        assert_eq!(services.ledger.find_account_limit(wallet.id).await?, Some(limit(&wallet, Some("0"), None)));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_credit_line_within_batch(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let customer = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        services.ledger.set_account_limit(limit(&customer, Some("-500"), Some("1000"))).await?;
+
+        // Act
+        let beyond_credit_line = services
+            .posting
+            .new_postings(vec![transfer(&customer, &bank, 300), transfer(&customer, &bank, 300)])
+            .await;
+        let within_credit_line = services
+            .posting
+            .new_postings(vec![transfer(&customer, &bank, 300), transfer(&customer, &bank, 200)])
+            .await;
+        let above_max = services.posting.new_posting(transfer(&bank, &customer, 1501)).await;
+        services.ledger.remove_account_limit(customer.id).await?;
+        let unlimited = services.posting.new_posting(transfer(&customer, &bank, 1)).await;
+
+        // Assert
+        assert!(matches!(beyond_credit_line, Err(ServiceError::LimitExceeded { shortfall, .. }) if shortfall == BigDecimal::from(100)));
+        assert!(within_credit_line.is_ok());
+        assert!(matches!(above_max, Err(ServiceError::LimitExceeded { shortfall, .. }) if shortfall == BigDecimal::from(1)));
+        assert!(unlimited.is_ok());
+        assert_eq!(services.ledger.find_account_limit(customer.id).await?, None);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_concurrent_postings_respect_limit(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        services.ledger.set_account_limit(limit(&wallet, Some("0"), None)).await?;
+        services.posting.new_posting(transfer(&bank, &wallet, 100)).await?;
+        let posting_service = Arc::new(services.posting);
+
+        // Act
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let service = posting_service.clone();
+                let posting = transfer(&wallet, &bank, 30);
+                tokio::spawn(async move { service.new_posting(posting).await })
+            })
+            .collect();
+        let mut accepted = 0;
+        for handle in handles {
+            match handle.await? {
+                Ok(_) => accepted += 1,
+                Err(ServiceError::LimitExceeded { .. }) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        // Assert
+        assert_eq!(accepted, 3);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_approval_and_deferred_posting_respect_limit(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        services.posting.new_posting(transfer(&bank, &wallet, 100)).await?;
+        services.ledger.set_account_limit(limit(&wallet, Some("0"), None)).await?;
+        let mut deferred = transfer(&wallet, &bank, 80);
+        deferred.pst_status = PostingStatus::Deferred;
+        deferred.val_time = Some(Utc::now() - Duration::minutes(1));
+        let deferred = services.posting.new_posting(deferred).await?;
+        services.posting.set_approval_policy(ApprovalPolicy { ledger: ledger.clone(), amount_threshold: Some(BigDecimal::from(50)) }).await?;
+        let first = services.posting.new_posting(transfer(&wallet, &bank, 60)).await?;
+        let second = services.posting.new_posting(transfer(&wallet, &bank, 60)).await?;
+
+        // Act
+        let first_approved = services.posting.approve_posting(first.id, [9; 34]).await;
+        let second_approved = services.posting.approve_posting(second.id, [9; 34]).await;
+        let posted = services.posting.post_deferred_postings(Utc::now()).await?;
+
+        // Assert
+        assert_eq!(second.pst_status, PostingStatus::Proposed);
+        assert!(first_approved.is_ok());
+        assert!(matches!(second_approved, Err(ServiceError::LimitExceeded { shortfall, .. }) if shortfall == BigDecimal::from(20)));
+        assert!(posted.is_empty());
+        let status: String = sqlx::query_scalar("SELECT pst_status::text FROM posting WHERE id = $1").bind(deferred.id).fetch_one(&pool).await?;
+        assert_eq!(status, "DEFERRED");
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mariadb_tests")]
+mod mariadb_tests {
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use bigdecimal::BigDecimal;
+    use chrono::Utc;
+    use sqlx::MySqlPool;
+    use uuid::Uuid;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::account_limit::AccountLimit;
+    use postings_api::domain::balance_side::BalanceSide;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::domain::posting::Posting;
+    use postings_api::domain::posting_line::PostingLine;
+    use postings_api::domain::posting_status::PostingStatus;
+    use postings_api::domain::posting_type::PostingType;
+    use postings_api::service::chart_of_account_service::ChartOfAccountService;
+    use postings_api::service::ledger_service::LedgerService;
+    use postings_api::service::posting_service::PostingService;
+    use postings_api::ServiceError;
+    use postings_db_mariadb::repositories::account_stmt_repository::MariaDbAccountStmtRepository;
+    use postings_db_mariadb::repositories::chart_of_account_repository::MariaDbChartOfAccountRepository;
+    use postings_db_mariadb::repositories::ledger_account_repository::MariaDbLedgerAccountRepository;
+    use postings_db_mariadb::repositories::ledger_repository::MariaDbLedgerRepository;
+    use postings_db_mariadb::repositories::named_repository::MariaDbNamedRepository;
+    use postings_db_mariadb::repositories::posting_approval_repository::MariaDbPostingApprovalRepository;
+    use postings_db_mariadb::repositories::hold_repository::MariaDbHoldRepository;
+    use postings_db_mariadb::repositories::schedule_repository::MariaDbScheduleRepository;
+    use postings_db_mariadb::repositories::outbox_repository::MariaDbOutboxRepository;
+    use postings_db_mariadb::repositories::posting_line_repository::MariaDbPostingLineRepository;
+    use postings_db_mariadb::repositories::posting_repository::MariaDbPostingRepository;
+    use postings_db_mariadb::repositories::posting_trace_repository::MariaDbPostingTraceRepository;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::posting_service::PostingServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: MySqlPool) -> SharedService {
+        SharedService::new(
+            Arc::new(MariaDbChartOfAccountRepository::new(pool.clone())),
+            Arc::new(MariaDbLedgerRepository::new(pool.clone())),
+            Arc::new(MariaDbLedgerAccountRepository::new(pool.clone())),
+            Arc::new(MariaDbNamedRepository::new(pool.clone())),
+            Arc::new(MariaDbPostingRepository::new(pool.clone())),
+            Arc::new(MariaDbAccountStmtRepository::new(pool.clone())),
+            Arc::new(MariaDbPostingLineRepository::new(pool.clone())),
+            Arc::new(MariaDbPostingTraceRepository::new(pool.clone())),
+            Arc::new(MariaDbPostingApprovalRepository::new(pool.clone())),
+            Arc::new(MariaDbHoldRepository::new(pool.clone())),
+            Arc::new(MariaDbScheduleRepository::new(pool.clone())),
+            Arc::new(MariaDbOutboxRepository::new(pool)),
+        )
+    }
+
+    struct Services {
+        ledger: LedgerServiceImpl,
+        posting: PostingServiceImpl,
+    }
+
+    fn limit(account: &LedgerAccount, min: Option<&str>, max: Option<&str>) -> AccountLimit {
+        AccountLimit {
+            account_id: account.id,
+            min_balance: min.map(|m| BigDecimal::from_str(m).unwrap()),
+            max_balance: max.map(|m| BigDecimal::from_str(m).unwrap()),
+        }
+    }
+
+    fn create_services(pool: MySqlPool) -> Services {
+        Services {
+            ledger: LedgerServiceImpl::new(
+                create_shared_service(pool.clone()),
+                ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())),
+            ),
+            posting: PostingServiceImpl::new(create_shared_service(pool)),
+        }
+    }
+
+    async fn setup_ledger(pool: &MySqlPool, services: &Services) -> anyhow::Result<Ledger> {
+        let coa_service = ChartOfAccountServiceImpl::new(create_shared_service(pool.clone()));
+        let (coa, _) = coa_service.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+        let (ledger, _) = services.ledger.new_ledger(Ledger { id: Uuid::new_v4(), coa }, vec![]).await?;
+        Ok(ledger)
+    }
+
+    async fn setup_account(services: &Services, ledger: &Ledger, balance_side: BalanceSide, category: AccountCategory) -> anyhow::Result<LedgerAccount> {
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side,
+            category,
+            code: None,
+        };
+        Ok(services.ledger.new_ledger_account(account, vec![]).await?.0)
+    }
+
+    fn line(account: &LedgerAccount, debit: i64, credit: i64) -> PostingLine {
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: account.clone(),
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [0; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            val_time: None,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+        }
+    }
+
+    /// Moves `amount` from `credited` to `debited`.
+    fn transfer(debited: &LedgerAccount, credited: &LedgerAccount, amount: i64) -> Posting {
+        let mut opr_id = [0u8; 34];
+        opr_id[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+        Posting {
+            id: Uuid::nil(),
+            record_user: [0; 34],
+            record_time: Utc::now(),
+            opr_id,
+            opr_time: Utc::now(),
+            opr_type: [0; 34],
+            opr_details: None,
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: debited.ledger.clone(),
+            val_time: None,
+            lines: vec![line(debited, amount, 0), line(credited, 0, amount)],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-mariadb/migrations")]
+    async fn test_concurrent_postings_respect_limit(pool: MySqlPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.mariadb").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        services.ledger.set_account_limit(limit(&wallet, Some("0"), None)).await?;
+        services.posting.new_posting(transfer(&bank, &wallet, 100)).await?;
+        let posting_service = Arc::new(services.posting);
+
+        // Act
+        let handles: Vec<_> = (0..10)
+            .map(|_| {
+                let service = posting_service.clone();
+                let posting = transfer(&wallet, &bank, 30);
+                tokio::spawn(async move { service.new_posting(posting).await })
+            })
+            .collect();
+        let mut accepted = 0;
+        for handle in handles {
+            match handle.await? {
+                Ok(_) => accepted += 1,
+                Err(ServiceError::LimitExceeded { .. }) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        // Assert
+        assert_eq!(accepted, 3);
+
+        Ok(())
+    }
+}
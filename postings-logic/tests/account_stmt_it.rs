@@ -4,7 +4,7 @@
 mod postgres_tests {
     use std::sync::Arc;
     use bigdecimal::BigDecimal;
-    use chrono::Utc;
+    use chrono::{Duration, Utc};
     use sqlx::{PgPool, Type};
     use uuid::Uuid;
 
@@ -12,6 +12,7 @@ mod postgres_tests {
         account_category::AccountCategory, balance_side::BalanceSide, chart_of_account::ChartOfAccount,
         ledger::Ledger, ledger_account::LedgerAccount,
     };
+    use postings_api::domain::time_axis::TimeAxis;
     use postings_api::service::account_stmt_service::AccountStmtService;
     use postings_db::models::posting_line::PostingLine as PostingLineModel;
     use postings_db_postgres::repositories::{
@@ -22,6 +23,10 @@ mod postgres_tests {
         named_repository::PostgresNamedRepository,
         posting_line_repository::PostgresPostingLineRepository,
         posting_repository::PostgresPostingRepository,
+        posting_approval_repository::PostgresPostingApprovalRepository,
+        hold_repository::PostgresHoldRepository,
+        schedule_repository::PostgresScheduleRepository,
+        outbox_repository::PostgresOutboxRepository,
         posting_trace_repository::PostgresPostingTraceRepository,
     };
     use postings_logic::services::{
@@ -75,6 +80,7 @@ mod postgres_tests {
             coa,
             balance_side: BalanceSide::Dr,
             category: AccountCategory::AS,
+            code: None,
         };
         sqlx::query("INSERT INTO ledger_account (id, ledger_id, coa_id, balance_side, category) VALUES ($1, $2, $3, $4, $5)")
             .bind(ledger_account.id)
@@ -101,6 +107,56 @@ mod postgres_tests {
         Ok((ledger_account, ledger))
     }
 
+    fn create_service(pool: PgPool) -> AccountStmtServiceImpl {
+        let coa_repo = Arc::new(PostgresChartOfAccountRepository::new(pool.clone()));
+        let ledger_repo = Arc::new(PostgresLedgerRepository::new(pool.clone()));
+        let ledger_account_repo = Arc::new(PostgresLedgerAccountRepository::new(pool.clone()));
+        let named_repo = Arc::new(PostgresNamedRepository::new(pool.clone()));
+        let posting_repo = Arc::new(PostgresPostingRepository::new(pool.clone()));
+        let stmt_repo = Arc::new(PostgresAccountStmtRepository::new(pool.clone()));
+        let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
+        let trace_repo = Arc::new(PostgresPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(PostgresPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(PostgresHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(PostgresScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(PostgresOutboxRepository::new(pool.clone()));
+
+        let shared_service = SharedService::new(
+            coa_repo,
+            ledger_repo,
+            ledger_account_repo,
+            named_repo,
+            posting_repo,
+            stmt_repo,
+            line_repo,
+            trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
+        );
+        AccountStmtServiceImpl::new(shared_service)
+    }
+
+    async fn insert_line(pool: &PgPool, line: &PostingLineModel) -> anyhow::Result<()> {
+        sqlx::query("INSERT INTO posting_line (id, account_id, debit_amount, credit_amount, details, record_time, opr_id, pst_time, val_time, pst_type, pst_status, hash) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)")
+            .bind(line.id)
+            .bind(line.account_id)
+            .bind(&line.debit_amount)
+            .bind(&line.credit_amount)
+            .bind(line.details)
+            .bind(line.record_time)
+            .bind(line.opr_id)
+            .bind(line.pst_time)
+            .bind(line.val_time)
+            .bind(&line.pst_type)
+            .bind(&line.pst_status)
+            .bind(line.hash)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+
     #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
     async fn test_read_stmt(pool: PgPool) -> anyhow::Result<()> {
         let _ = env_logger::builder().is_test(true).try_init();
@@ -112,6 +168,7 @@ mod postgres_tests {
 
         let line1 = PostingLineModel {
             id: Uuid::new_v4(),
+            posting_id: None,
             account_id: ledger_account.id,
             debit_amount: BigDecimal::from(100),
             credit_amount: BigDecimal::from(0),
@@ -123,6 +180,7 @@ mod postgres_tests {
             opr_id: [0; 34],
             opr_src: None,
             pst_time: now,
+            val_time: None,
             pst_type: postings_db::models::posting_type::PostingType::BusiTx,
             pst_status: postings_db::models::posting_status::PostingStatus::Posted,
             hash: Some([0; 34]),
@@ -130,6 +188,7 @@ mod postgres_tests {
         };
         let line2 = PostingLineModel {
             id: Uuid::new_v4(),
+            posting_id: None,
             account_id: ledger_account.id,
             debit_amount: BigDecimal::from(50),
             credit_amount: BigDecimal::from(0),
@@ -141,6 +200,7 @@ mod postgres_tests {
             opr_id: [0; 34],
             opr_src: None,
             pst_time: now,
+            val_time: None,
             pst_type: postings_db::models::posting_type::PostingType::BusiTx,
             pst_status: postings_db::models::posting_status::PostingStatus::Posted,
             hash: Some([0; 34]),
@@ -175,26 +235,7 @@ mod postgres_tests {
             .execute(&pool)
             .await?;
 
-        let coa_repo = Arc::new(PostgresChartOfAccountRepository::new(pool.clone()));
-        let ledger_repo = Arc::new(PostgresLedgerRepository::new(pool.clone()));
-        let ledger_account_repo = Arc::new(PostgresLedgerAccountRepository::new(pool.clone()));
-        let named_repo = Arc::new(PostgresNamedRepository::new(pool.clone()));
-        let posting_repo = Arc::new(PostgresPostingRepository::new(pool.clone()));
-        let stmt_repo = Arc::new(PostgresAccountStmtRepository::new(pool.clone()));
-        let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
-        let trace_repo = Arc::new(PostgresPostingTraceRepository::new(pool.clone()));
-
-        let shared_service = SharedService::new(
-            coa_repo,
-            ledger_repo,
-            ledger_account_repo,
-            named_repo,
-            posting_repo,
-            stmt_repo,
-            line_repo,
-            trace_repo,
-        );
-        let service = AccountStmtServiceImpl::new(shared_service);
+        let service = create_service(pool.clone());
 
         // Act
         let result = service.read_stmt(ledger_account, Utc::now()).await?;
@@ -205,4 +246,89 @@ mod postgres_tests {
 
         Ok(())
     }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_read_stmt_ignores_lines_not_posted(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let (ledger_account, _ledger) = setup_test_data(&pool).await?;
+        let now = Utc::now();
+        let posted = PostingLineModel {
+            id: Uuid::new_v4(),
+            account_id: ledger_account.id,
+            debit_amount: BigDecimal::from(100),
+            record_time: now,
+            pst_time: now,
+            pst_type: postings_db::models::posting_type::PostingType::BusiTx,
+            pst_status: postings_db::models::posting_status::PostingStatus::Posted,
+            hash: Some([0; 34]),
+            ..Default::default()
+        };
+        let proposed = PostingLineModel {
+            id: Uuid::new_v4(),
+            debit_amount: BigDecimal::from(70),
+            pst_status: postings_db::models::posting_status::PostingStatus::Proposed,
+            ..posted.clone()
+        };
+        let cancelled = PostingLineModel {
+            id: Uuid::new_v4(),
+            debit_amount: BigDecimal::from(30),
+            pst_status: postings_db::models::posting_status::PostingStatus::Cancelled,
+            ..posted.clone()
+        };
+        insert_line(&pool, &posted).await?;
+        insert_line(&pool, &proposed).await?;
+        insert_line(&pool, &cancelled).await?;
+        let service = create_service(pool.clone());
+
+        // Act
+        let result = service.read_stmt(ledger_account, Utc::now()).await?;
+
+        // Assert
+        assert_eq!(result.total_debit, BigDecimal::from(100));
+        assert_eq!(result.total_credit, BigDecimal::from(0));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_read_stmt_by_value_time(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let (ledger_account, _ledger) = setup_test_data(&pool).await?;
+        let now = Utc::now();
+        let value_dated = PostingLineModel {
+            id: Uuid::new_v4(),
+            account_id: ledger_account.id,
+            debit_amount: BigDecimal::from(100),
+            record_time: now - Duration::days(2),
+            pst_time: now - Duration::days(2),
+            val_time: Some(now + Duration::days(1)),
+            pst_type: postings_db::models::posting_type::PostingType::BusiTx,
+            pst_status: postings_db::models::posting_status::PostingStatus::Posted,
+            hash: Some([0; 34]),
+            ..Default::default()
+        };
+        let without_value_time = PostingLineModel {
+            id: Uuid::new_v4(),
+            debit_amount: BigDecimal::from(50),
+            val_time: None,
+            ..value_dated.clone()
+        };
+        insert_line(&pool, &value_dated).await?;
+        insert_line(&pool, &without_value_time).await?;
+        let service = create_service(pool.clone());
+
+        // Act
+        let by_pst_time = service.read_stmt_by_time_axis(ledger_account.clone(), now, TimeAxis::PstTime).await?;
+        let by_val_time = service.read_stmt_by_time_axis(ledger_account.clone(), now, TimeAxis::ValTime).await?;
+        let later_by_val_time = service.read_stmt_by_time_axis(ledger_account, now + Duration::days(2), TimeAxis::ValTime).await?;
+
+        // Assert
+        assert_eq!(by_pst_time.total_debit, BigDecimal::from(150));
+        assert_eq!(by_val_time.total_debit, BigDecimal::from(50));
+        assert_eq!(later_by_val_time.total_debit, BigDecimal::from(150));
+
+        Ok(())
+    }
 }
@@ -1,10 +1,25 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use mockall::mock;
 use postings_db::models::chart_of_account::ChartOfAccount;
+use postings_db::models::coa_account_definition::CoaAccountDefinition;
+use postings_db::models::ledger::Ledger;
+use postings_db::models::ledger_account::LedgerAccount;
+use postings_db::models::named::Named;
 use postings_db::repositories::chart_of_account_repository::ChartOfAccountRepository;
+use postings_db::repositories::ledger_repository::LedgerRepository;
+use postings_db::invalidation_bus::{InvalidationBus, InvalidationEvent, InvalidationSubscription};
 use postings_db::DbError;
+use postings_logic::caching::cache_config::CacheConfig;
+use postings_logic::caching::cache_metrics::CacheStats;
 use postings_logic::caching::chart_of_account_repository::CachingChartOfAccountRepository;
+use postings_logic::caching::l2_cache::{L2Cache, LocalL2Cache};
+use postings_logic::caching::ledger_repository::CachingLedgerRepository;
+use postings_logic::caching::local_invalidation_bus::LocalInvalidationBus;
+use postings_logic::caching::caching_repositories::CachingRepositories;
+use std::time::{Duration, Instant};
 use uuid::Uuid;
 
 mock! {
@@ -18,6 +33,15 @@ mock! {
         async fn save(&self, coa: &ChartOfAccount) -> Result<(), DbError> {
             unimplemented!()
         }
+        async fn save_with_named(&self, coa: &ChartOfAccount, named: &[Named]) -> Result<(), DbError> {
+            unimplemented!()
+        }
+        async fn save_account_definitions(&self, definitions: &[CoaAccountDefinition]) -> Result<(), DbError> {
+            unimplemented!()
+        }
+        async fn find_account_definitions_by_coa_id(&self, coa_id: Uuid) -> Result<Vec<CoaAccountDefinition>, DbError> {
+            unimplemented!()
+        }
     }
 }
 
@@ -51,6 +75,7 @@ async fn test_find_by_id_caches_result() {
 }
 
 #[tokio::test]
+#[allow(clippy::let_unit_value)]
 async fn test_save_invalidates_caches() {
     // Arrange
     let mut mock_repo = MockChartOfAccountRepository::new();
@@ -87,3 +112,561 @@ async fn test_save_invalidates_caches() {
     // 4. Find again, should hit the mock repo again.
     let _ = caching_repo.find_by_id(coa_id).await.unwrap();
 }
+
+mock! {
+    pub LedgerRepository {}
+
+    #[async_trait]
+    impl LedgerRepository for LedgerRepository {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Ledger>, DbError>;
+        async fn save(&self, ledger: &Ledger, record_time: DateTime<Utc>) -> Result<(), DbError>;
+        async fn save_with_named(&self, ledger: &Ledger, named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError>;
+        async fn save_with_accounts(&self, ledger: &Ledger, accounts: &[LedgerAccount], named: &[Named], record_time: DateTime<Utc>) -> Result<(), DbError>;
+    }
+}
+
+#[tokio::test]
+async fn test_ledger_cache_counts_hits_and_misses() {
+    // Arrange
+    let mut mock_repo = MockLedgerRepository::new();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: Uuid::new_v4() };
+    let ledger_clone = ledger.clone();
+    mock_repo.expect_find_by_id()
+        .times(1)
+        .returning(move |_| Ok(Some(ledger_clone.clone())));
+    let caching_repo = CachingLedgerRepository::with_config(Arc::new(mock_repo), &CacheConfig::new(10).with_time_to_live(Duration::from_secs(60)));
+
+    // Act
+    let first = caching_repo.find_by_id(ledger.id).await.unwrap();
+    let second = caching_repo.find_by_id(ledger.id).await.unwrap();
+
+    // Assert
+    assert_eq!(first, Some(ledger));
+    assert_eq!(first, second);
+    assert_eq!(caching_repo.stats(), CacheStats { hits: 1, l2_hits: 0, misses: 1 });
+    assert_eq!(caching_repo.stats().hit_ratio(), 0.5);
+}
+
+#[tokio::test]
+async fn test_ledger_cache_is_shared_through_l2() {
+    // Arrange
+    let mut mock_repo = MockLedgerRepository::new();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: Uuid::new_v4() };
+    let ledger_clone = ledger.clone();
+    mock_repo.expect_find_by_id()
+        .times(1)
+        .returning(move |_| Ok(Some(ledger_clone.clone())));
+    let inner: Arc<dyn LedgerRepository + Send + Sync> = Arc::new(mock_repo);
+    let l2: Arc<dyn L2Cache + Send + Sync> = Arc::new(LocalL2Cache::new());
+    let first_node = CachingLedgerRepository::new(inner.clone()).with_l2(l2.clone());
+    let second_node = CachingLedgerRepository::new(inner).with_l2(l2);
+
+    // Act
+    let first = first_node.find_by_id(ledger.id).await.unwrap();
+    let second = second_node.find_by_id(ledger.id).await.unwrap();
+    let third = second_node.find_by_id(ledger.id).await.unwrap();
+
+    // Assert
+    assert_eq!(first, Some(ledger));
+    assert_eq!(second, first);
+    assert_eq!(third, first);
+    assert_eq!(first_node.stats(), CacheStats { hits: 0, l2_hits: 0, misses: 1 });
+    assert_eq!(second_node.stats(), CacheStats { hits: 1, l2_hits: 1, misses: 0 });
+}
+
+#[tokio::test]
+async fn test_ledger_save_invalidates_l2() {
+    // Arrange
+    let mut mock_repo = MockLedgerRepository::new();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: Uuid::new_v4() };
+    let ledger_clone = ledger.clone();
+    mock_repo.expect_find_by_id()
+        .times(2)
+        .returning(move |_| Ok(Some(ledger_clone.clone())));
+    mock_repo.expect_save().times(1).returning(|_, _| Ok(()));
+    let inner: Arc<dyn LedgerRepository + Send + Sync> = Arc::new(mock_repo);
+    let l2 = Arc::new(LocalL2Cache::new());
+    let reader = CachingLedgerRepository::new(inner.clone()).with_l2(l2.clone());
+    let writer = CachingLedgerRepository::new(inner).with_l2(l2.clone());
+    reader.find_by_id(ledger.id).await.unwrap();
+    assert_eq!(l2.len(), 1);
+
+    // Act
+    writer.save(&ledger, Utc::now()).await.unwrap();
+    let emptied = l2.is_empty();
+    writer.find_by_id(ledger.id).await.unwrap();
+
+    // Assert
+    assert!(emptied);
+    assert_eq!(writer.stats(), CacheStats { hits: 0, l2_hits: 0, misses: 1 });
+}
+
+#[tokio::test]
+async fn test_save_on_one_stack_invalidates_the_other() {
+    // Arrange
+    let mut mock_repo = MockLedgerRepository::new();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: Uuid::new_v4() };
+    let ledger_clone = ledger.clone();
+    mock_repo.expect_find_by_id()
+        .times(2)
+        .returning(move |_| Ok(Some(ledger_clone.clone())));
+    mock_repo.expect_save().times(1).returning(|_, _| Ok(()));
+    let inner: Arc<dyn LedgerRepository + Send + Sync> = Arc::new(mock_repo);
+    let bus = Arc::new(LocalInvalidationBus::default());
+    let reader = CachingLedgerRepository::new(inner.clone()).with_invalidation_bus(bus.clone());
+    let writer = CachingLedgerRepository::new(inner).with_invalidation_bus(bus.clone());
+    let mut subscription = bus.subscribe().await.unwrap();
+    reader.find_by_id(ledger.id).await.unwrap();
+    reader.find_by_id(ledger.id).await.unwrap();
+
+    // Act
+    writer.save(&ledger, Utc::now()).await.unwrap();
+    let event = subscription.next().await.unwrap();
+    reader.apply(&event).await;
+    reader.find_by_id(ledger.id).await.unwrap();
+
+    // Assert
+    assert_eq!(event, InvalidationEvent::Ledger(ledger.id));
+    assert_eq!(reader.stats(), CacheStats { hits: 1, l2_hits: 0, misses: 2 });
+}
+
+/// Bus whose first subscription ends right away, like one whose connection was lost.
+struct FlakyBus {
+    inner: LocalInvalidationBus,
+    subscriptions: AtomicUsize,
+}
+
+struct EndedSubscription;
+
+#[async_trait]
+impl InvalidationSubscription for EndedSubscription {
+    async fn next(&mut self) -> Option<InvalidationEvent> {
+        None
+    }
+}
+
+#[async_trait]
+impl InvalidationBus for FlakyBus {
+    async fn publish(&self, event: &InvalidationEvent) -> Result<(), DbError> {
+        self.inner.publish(event).await
+    }
+
+    async fn subscribe(&self) -> Result<Box<dyn InvalidationSubscription + Send>, DbError> {
+        match self.subscriptions.fetch_add(1, Ordering::SeqCst) {
+            0 => Ok(Box::new(EndedSubscription)),
+            _ => self.inner.subscribe().await,
+        }
+    }
+}
+
+/// Reads the ledger until the cache has missed `misses` times, failing after two seconds.
+async fn read_until_misses(caching: &CachingRepositories, id: Uuid, misses: u64) {
+    let deadline = Instant::now() + Duration::from_secs(2);
+    while caching.ledger_repo.stats().misses < misses {
+        assert!(Instant::now() < deadline, "cache was not cleared");
+        caching.ledger_repo.find_by_id(id).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_listener_resubscribes_after_subscription_ends() {
+    // Arrange
+    let repositories = postings_db_memory::repositories();
+    let ledger = Ledger { id: Uuid::new_v4(), coa_id: Uuid::new_v4() };
+    repositories.ledger.save(&ledger, Utc::now()).await.unwrap();
+    let caching = CachingRepositories::builder().wrap(repositories.coa, repositories.ledger, repositories.ledger_account, repositories.named);
+    caching.ledger_repo.find_by_id(ledger.id).await.unwrap();
+    let bus = Arc::new(FlakyBus { inner: LocalInvalidationBus::default(), subscriptions: AtomicUsize::new(0) });
+
+    // Act
+    caching.spawn_listener(bus.clone()).await.unwrap();
+    read_until_misses(&caching, ledger.id, 2).await;
+    while bus.subscriptions.load(Ordering::SeqCst) < 2 {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    read_until_misses(&caching, ledger.id, 3).await;
+    bus.publish(&InvalidationEvent::Ledger(ledger.id)).await.unwrap();
+    read_until_misses(&caching, ledger.id, 4).await;
+
+    // Assert
+    assert_eq!(bus.subscriptions.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_invalidation_events_roundtrip_through_text() {
+    let id = Uuid::new_v4();
+    let events = [
+        InvalidationEvent::ChartOfAccount(id),
+        InvalidationEvent::Ledger(id),
+        InvalidationEvent::LedgerAccount(id),
+        InvalidationEvent::AccountState(id),
+        InvalidationEvent::Named { id, container: Uuid::new_v4() },
+        InvalidationEvent::All,
+    ];
+    for event in events {
+        assert_eq!(event.to_string().parse::<InvalidationEvent>(), Ok(event));
+    }
+    assert!("ledger".parse::<InvalidationEvent>().is_err());
+    assert!(format!("ledger:{}:{}", id, id).parse::<InvalidationEvent>().is_err());
+}
+
+#[cfg(feature = "redis_cache")]
+mod redis_tests {
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use postings_logic::caching::l2_cache::L2Cache;
+    use postings_logic::caching::redis_l2_cache::RedisL2Cache;
+    use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::sync::Mutex;
+
+    type Store = Arc<Mutex<HashMap<Vec<u8>, (Vec<u8>, Option<Instant>)>>>;
+
+    /// Minimal stand-in for a Redis server: enough of RESP for GET, SET [PX ms] and DEL.
+    async fn start_stand_in() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let store: Store = Arc::new(Mutex::new(HashMap::new()));
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(serve(stream, store.clone()));
+            }
+        });
+        address
+    }
+
+    async fn read_command(reader: &mut BufReader<TcpStream>) -> Option<Vec<Vec<u8>>> {
+        let mut line = String::new();
+        reader.read_line(&mut line).await.ok().filter(|n| *n > 0)?;
+        let count: usize = line.trim_end().strip_prefix('*')?.parse().ok()?;
+        let mut args = Vec::with_capacity(count);
+        for _ in 0..count {
+            line.clear();
+            reader.read_line(&mut line).await.ok()?;
+            let len: usize = line.trim_end().strip_prefix('$')?.parse().ok()?;
+            let mut arg = vec![0; len + 2];
+            reader.read_exact(&mut arg).await.ok()?;
+            arg.truncate(len);
+            args.push(arg);
+        }
+        Some(args)
+    }
+
+    async fn serve(stream: TcpStream, store: Store) {
+        let mut reader = BufReader::new(stream);
+        while let Some(args) = read_command(&mut reader).await {
+            let mut store = store.lock().await;
+            let reply = match args[0].as_slice() {
+                b"GET" => match store.get(&args[1]) {
+                    Some((value, expiry)) if expiry.is_none_or(|e| e > Instant::now()) => {
+                        let mut reply = format!("${}\r\n", value.len()).into_bytes();
+                        reply.extend_from_slice(value);
+                        reply.extend_from_slice(b"\r\n");
+                        reply
+                    }
+                    _ => b"$-1\r\n".to_vec(),
+                },
+                b"SET" => {
+                    let expiry = match args.get(3).map(Vec::as_slice) {
+                        Some(b"PX") => {
+                            let millis: u64 = String::from_utf8_lossy(&args[4]).parse().unwrap();
+                            Some(Instant::now() + Duration::from_millis(millis))
+                        }
+                        _ => None,
+                    };
+                    store.insert(args[1].clone(), (args[2].clone(), expiry));
+                    b"+OK\r\n".to_vec()
+                }
+                b"DEL" => format!(":{}\r\n", store.remove(&args[1]).map_or(0, |_| 1)).into_bytes(),
+                _ => b"-ERR unknown command\r\n".to_vec(),
+            };
+            if reader.get_mut().write_all(&reply).await.is_err() {
+                return;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_redis_cache_roundtrip_and_invalidate() {
+        // Arrange
+        let cache = RedisL2Cache::new(start_stand_in().await);
+        let value = [0u8, 1, 2, 255, b'\r', b'\n'];
+
+        // Act
+        let missing = cache.get("postings:ledger:a").await.unwrap();
+        cache.set("postings:ledger:a", &value, None).await.unwrap();
+        let stored = cache.get("postings:ledger:a").await.unwrap();
+        cache.invalidate("postings:ledger:a").await.unwrap();
+        let invalidated = cache.get("postings:ledger:a").await.unwrap();
+
+        // Assert
+        assert_eq!(missing, None);
+        assert_eq!(stored, Some(value.to_vec()));
+        assert_eq!(invalidated, None);
+    }
+
+    #[tokio::test]
+    async fn test_redis_cache_expires_entries() {
+        // Arrange
+        let cache = RedisL2Cache::new(start_stand_in().await);
+        cache.set("postings:ledger:b", b"value", Some(Duration::from_millis(50))).await.unwrap();
+
+        // Act
+        let before = cache.get("postings:ledger:b").await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let after = cache.get("postings:ledger:b").await.unwrap();
+
+        // Assert
+        assert_eq!(before, Some(b"value".to_vec()));
+        assert_eq!(after, None);
+    }
+
+    #[tokio::test]
+    async fn test_redis_cache_reports_unreachable_server() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        drop(listener);
+        let cache = RedisL2Cache::new(address);
+
+        // Act
+        let result = cache.get("postings:ledger:c").await;
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_redis_cache_times_out_on_hung_server() {
+        // Arrange
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        // Accepts connections and reads nothing, like a hung server.
+        tokio::spawn(async move {
+            let mut streams = Vec::new();
+            while let Ok((stream, _)) = listener.accept().await {
+                streams.push(stream);
+            }
+        });
+        let cache = RedisL2Cache::new(address).with_timeout(Duration::from_millis(100));
+        let started = Instant::now();
+
+        // Act
+        let (first, second) = tokio::join!(cache.get("postings:ledger:d"), cache.set("postings:ledger:d", b"value", None));
+
+        // Assert
+        assert!(first.is_err());
+        assert!(second.is_err());
+        assert!(started.elapsed() < Duration::from_secs(1));
+    }
+}
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::sync::Arc;
+    use sqlx::PgPool;
+    use uuid::Uuid;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::balance_side::BalanceSide;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::domain::named::{ContainerType, Named};
+    use std::time::Duration;
+    use chrono::Utc;
+    use postings_api::domain::account_state::AccountState;
+    use postings_api::service::account_lifecycle_service::AccountLifecycleService;
+    use postings_api::service::chart_of_account_service::ChartOfAccountService;
+    use postings_api::service::ledger_service::LedgerService;
+    use postings_db_postgres::invalidation_bus::PostgresInvalidationBus;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_logic::caching::cache_config::CacheConfig;
+    use postings_logic::caching::caching_repositories::CachingRepositories;
+    use postings_logic::services::account_lifecycle_service::AccountLifecycleServiceImpl;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: PgPool, caching: &CachingRepositories) -> SharedService {
+        SharedService::new(
+            caching.coa_repo.clone(),
+            caching.ledger_repo.clone(),
+            caching.ledger_account_repo.clone(),
+            caching.named_repo.clone(),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    fn account_name(name: &str) -> Named {
+        Named {
+            id: Uuid::new_v4(),
+            container: Uuid::nil(),
+            context: Uuid::nil(),
+            name: name.to_string(),
+            language: "en".to_string(),
+            created: chrono::Utc::now(),
+            user_details: [0; 34],
+            short_desc: None,
+            long_desc: None,
+            container_type: ContainerType::LedgerAccount,
+            retired: None,
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_caching_repositories_serve_ledger_service(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let caching = CachingRepositories::builder().config(CacheConfig::new(100)).wrap(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+        );
+        let coa_service = ChartOfAccountServiceImpl::new(create_shared_service(pool.clone(), &caching));
+        let service = LedgerServiceImpl::new(
+            create_shared_service(pool.clone(), &caching),
+            ChartOfAccountServiceImpl::new(create_shared_service(pool.clone(), &caching)),
+        );
+        let (coa, _) = coa_service.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+        let (ledger, _) = service.new_ledger(Ledger { id: Uuid::new_v4(), coa: coa.clone() }, vec![]).await?;
+        let before = service.find_ledger_account_by_name(&ledger, "Cash").await?;
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa,
+            balance_side: BalanceSide::Dr,
+            category: AccountCategory::AS,
+            code: None,
+        };
+        let (account, _) = service.new_ledger_account(account, vec![account_name("Cash")]).await?;
+
+        // Act
+        let after = service.find_ledger_account_by_name(&ledger, "Cash").await?;
+        let first = service.find_ledger_account_by_id(account.id).await?;
+        let second = service.find_ledger_account_by_id(account.id).await?;
+
+        // Assert
+        assert!(before.is_empty());
+        assert_eq!(after.iter().map(|a| a.id).collect::<Vec<_>>(), vec![account.id]);
+        assert_eq!(first, second);
+        let stats = caching.stats();
+        assert!(stats.ledger_account.hits >= 1);
+        assert!(stats.ledger.hits >= 1);
+        assert!(stats.coa.hits >= 1);
+
+        Ok(())
+    }
+
+    /// One application instance: caching repositories over the shared database, publishing to and
+    /// listening on their own connection to the invalidation channel.
+    struct Stack {
+        caching: CachingRepositories,
+        ledger_service: LedgerServiceImpl,
+        lifecycle_service: AccountLifecycleServiceImpl,
+    }
+
+    async fn create_stack(pool: PgPool) -> anyhow::Result<Stack> {
+        let bus = Arc::new(PostgresInvalidationBus::new(pool.clone()));
+        let caching = CachingRepositories::builder().invalidation_bus(bus.clone()).wrap(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+        );
+        caching.spawn_listener(bus.clone()).await?;
+        Ok(Stack {
+            ledger_service: LedgerServiceImpl::new(
+                create_shared_service(pool.clone(), &caching),
+                ChartOfAccountServiceImpl::new(create_shared_service(pool.clone(), &caching)),
+            ),
+            lifecycle_service: AccountLifecycleServiceImpl::new(create_shared_service(pool, &caching)),
+            caching,
+        })
+    }
+
+    /// Polls until `check` holds, as notifications arrive asynchronously.
+    async fn eventually<F, Fut>(mut check: F) -> anyhow::Result<bool>
+    where
+        F: FnMut() -> Fut,
+        Fut: std::future::Future<Output = anyhow::Result<bool>>,
+    {
+        for _ in 0..100 {
+            if check().await? {
+                return Ok(true);
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        Ok(false)
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_saves_on_one_stack_invalidate_the_other(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let first = create_stack(pool.clone()).await?;
+        let second = create_stack(pool.clone()).await?;
+        let coa_service = ChartOfAccountServiceImpl::new(create_shared_service(pool.clone(), &second.caching));
+        let (coa, _) = coa_service.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+        let (ledger, _) = second.ledger_service.new_ledger(Ledger { id: Uuid::new_v4(), coa: coa.clone() }, vec![]).await?;
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa,
+            balance_side: BalanceSide::Dr,
+            category: AccountCategory::AS,
+            code: None,
+        };
+        let before = first.ledger_service.find_ledger_account_by_name(&ledger, "Cash").await?;
+
+        // Act
+        let (account, _) = second.ledger_service.new_ledger_account(account, vec![account_name("Cash")]).await?;
+        let name_seen = eventually(|| async {
+            Ok(!first.ledger_service.find_ledger_account_by_name(&ledger, "Cash").await?.is_empty())
+        })
+        .await?;
+        let open = first.lifecycle_service.find_account_state(account.id, Utc::now()).await?;
+        second
+            .lifecycle_service
+            .change_account_state(account.id, AccountState::Frozen, Utc::now(), [0; 34], None)
+            .await?;
+        let freeze_seen = eventually(|| async {
+            Ok(first.lifecycle_service.find_account_state(account.id, Utc::now()).await? == AccountState::Frozen)
+        })
+        .await?;
+        let hits = first.caching.stats().named.hits;
+        first.ledger_service.find_ledger_account_by_name(&ledger, "Cash").await?;
+
+        // Assert
+        assert!(before.is_empty());
+        assert!(name_seen);
+        assert_eq!(open, AccountState::Open);
+        assert!(freeze_seen);
+        assert!(first.caching.stats().named.hits > hits);
+
+        Ok(())
+    }
+}
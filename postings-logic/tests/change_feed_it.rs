@@ -0,0 +1,277 @@
+#![cfg(test)]
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use futures_util::StreamExt;
+    use bigdecimal::BigDecimal;
+    use chrono::Utc;
+    use sqlx::PgPool;
+    use uuid::Uuid;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::account_movement::FeedCursor;
+    use postings_api::domain::approval_policy::ApprovalPolicy;
+    use postings_api::domain::balance_side::BalanceSide;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::domain::posting::Posting;
+    use postings_api::domain::posting_line::PostingLine;
+    use postings_api::domain::posting_status::PostingStatus;
+    use postings_api::domain::posting_type::PostingType;
+    use postings_api::service::change_feed_service::{ChangeFeedService, MovementStream};
+    use postings_api::service::chart_of_account_service::ChartOfAccountService;
+    use postings_api::service::ledger_service::LedgerService;
+    use postings_api::service::posting_service::PostingService;
+    use postings_db_postgres::line_feed::PostgresLineNotifier;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_logic::services::change_feed_service::ChangeFeedServiceImpl;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::posting_service::PostingServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    struct Services {
+        ledger: LedgerServiceImpl,
+        posting: PostingServiceImpl,
+    }
+
+    fn create_services(pool: PgPool) -> Services {
+        Services {
+            ledger: LedgerServiceImpl::new(
+                create_shared_service(pool.clone()),
+                ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())),
+            ),
+            posting: PostingServiceImpl::new(create_shared_service(pool)),
+        }
+    }
+
+    fn create_feed(pool: &PgPool, poll_interval: Duration) -> ChangeFeedServiceImpl {
+        let ledger_service = LedgerServiceImpl::new(
+            create_shared_service(pool.clone()),
+            ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())),
+        );
+        ChangeFeedServiceImpl::new(create_shared_service(pool.clone()), ledger_service)
+            .with_poll_interval(poll_interval)
+            .with_batch_size(2)
+    }
+
+    async fn next_cursor(stream: &mut MovementStream) -> anyhow::Result<FeedCursor> {
+        let movement = tokio::time::timeout(Duration::from_secs(5), stream.next()).await?.expect("feed ended")?;
+        Ok(movement.cursor)
+    }
+
+    async fn setup_ledger(pool: &PgPool, services: &Services) -> anyhow::Result<Ledger> {
+        let coa_service = ChartOfAccountServiceImpl::new(create_shared_service(pool.clone()));
+        let (coa, _) = coa_service.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+        let (ledger, _) = services.ledger.new_ledger(Ledger { id: Uuid::new_v4(), coa }, vec![]).await?;
+        Ok(ledger)
+    }
+
+    async fn setup_account(services: &Services, ledger: &Ledger, balance_side: BalanceSide, category: AccountCategory) -> anyhow::Result<LedgerAccount> {
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side,
+            category,
+            code: None,
+        };
+        Ok(services.ledger.new_ledger_account(account, vec![]).await?.0)
+    }
+
+    fn line(account: &LedgerAccount, debit: i64, credit: i64) -> PostingLine {
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: account.clone(),
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [0; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            val_time: None,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: Some([0; 34]),
+            additional_information: None,
+            discarded_time: None,
+        }
+    }
+
+    /// Moves `amount` from `credited` to `debited`.
+    fn transfer(debited: &LedgerAccount, credited: &LedgerAccount, amount: i64) -> Posting {
+        let mut opr_id = [0u8; 34];
+        opr_id[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+        Posting {
+            id: Uuid::nil(),
+            record_user: [0; 34],
+            record_time: Utc::now(),
+            opr_id,
+            opr_time: Utc::now(),
+            opr_type: [0; 34],
+            opr_details: None,
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: debited.ledger.clone(),
+            val_time: None,
+            lines: vec![line(debited, amount, 0), line(credited, 0, amount)],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_feed_streams_lines_in_order_and_resumes(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        for amount in 1..=5 {
+            services.posting.new_posting(transfer(&bank, &wallet, amount)).await?;
+        }
+        let feed = create_feed(&pool, Duration::from_millis(50));
+
+        // Act
+        let mut stream = feed.subscribe(wallet.id, None).await?;
+        let mut movements = vec![];
+        for _ in 0..5 {
+            movements.push(tokio::time::timeout(Duration::from_secs(5), stream.next()).await?.expect("feed ended")?);
+        }
+        let mut resumed = feed.subscribe(wallet.id, Some(movements[2].cursor)).await?;
+
+        // Assert
+        assert!(movements.windows(2).all(|pair| pair[0].cursor < pair[1].cursor));
+        assert!(movements.iter().all(|m| m.line.account.id == wallet.id));
+        let credits: Vec<_> = movements.iter().map(|m| m.line.credit_amount.clone()).collect();
+        assert_eq!(credits, (1..=5).map(BigDecimal::from).collect::<Vec<_>>());
+        assert_eq!(next_cursor(&mut resumed).await?, movements[3].cursor);
+        assert_eq!(feed.current_cursor(wallet.id).await?, movements[4].cursor);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_feed_picks_up_new_lines_by_polling(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let other = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let feed = create_feed(&pool, Duration::from_millis(50));
+        let mut stream = feed.subscribe(wallet.id, Some(feed.current_cursor(wallet.id).await?)).await?;
+
+        // Act
+        services.posting.new_posting(transfer(&bank, &other, 10)).await?;
+        services.posting.new_posting(transfer(&bank, &wallet, 20)).await?;
+
+        // Assert
+        let movement = tokio::time::timeout(Duration::from_secs(5), stream.next()).await?.expect("feed ended")?;
+        assert_eq!(movement.line.account.id, wallet.id);
+        assert_eq!(movement.line.credit_amount, BigDecimal::from(20));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_feed_is_woken_by_notifications(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        // Polling alone would not find the line within the timeout.
+        let feed = create_feed(&pool, Duration::from_secs(60)).with_notifier(Arc::new(PostgresLineNotifier::new(pool.clone())));
+        let mut stream = feed.subscribe(wallet.id, None).await?;
+        let waiting = tokio::spawn(async move { next_cursor(&mut stream).await });
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // Act
+        services.posting.new_posting(transfer(&bank, &wallet, 30)).await?;
+
+        // Assert
+        let cursor = waiting.await??;
+        assert_eq!(cursor, feed.current_cursor(wallet.id).await?);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_feed_streams_lines_once_they_are_posted(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        services.posting.set_approval_policy(ApprovalPolicy { ledger: ledger.clone(), amount_threshold: Some(BigDecimal::from(100)) }).await?;
+        services.posting.new_posting(transfer(&bank, &wallet, 10)).await?;
+        let approved = services.posting.new_posting(transfer(&bank, &wallet, 500)).await?;
+        let cancelled = services.posting.new_posting(transfer(&bank, &wallet, 600)).await?;
+        services.posting.new_posting(transfer(&bank, &wallet, 20)).await?;
+        let feed = create_feed(&pool, Duration::from_millis(50));
+        let mut stream = feed.subscribe(wallet.id, None).await?;
+        let mut movements = vec![];
+        for _ in 0..2 {
+            movements.push(tokio::time::timeout(Duration::from_secs(5), stream.next()).await?.expect("feed ended")?);
+        }
+
+        // Act
+        services.posting.cancel_posting(cancelled.id).await?;
+        services.posting.approve_posting(approved.id, [1; 34]).await?;
+        movements.push(tokio::time::timeout(Duration::from_secs(5), stream.next()).await?.expect("feed ended")?);
+
+        // Assert
+        assert_eq!(approved.pst_status, PostingStatus::Proposed);
+        assert!(movements.windows(2).all(|pair| pair[0].cursor < pair[1].cursor));
+        assert!(movements.iter().all(|m| m.line.pst_status == PostingStatus::Posted));
+        let credits: Vec<_> = movements.iter().map(|m| m.line.credit_amount.clone()).collect();
+        assert_eq!(credits, [10, 20, 500].map(BigDecimal::from).to_vec());
+        assert_eq!(movements[2].cursor, feed.current_cursor(wallet.id).await?);
+
+        Ok(())
+    }
+}
@@ -16,6 +16,10 @@ mod postgres_tests {
     use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
     use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
     use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
     use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
     use postings_logic::services::shared_service::SharedService;
 
@@ -91,6 +95,10 @@ mod postgres_tests {
         let stmt_repo = Arc::new(PostgresAccountStmtRepository::new(pool.clone()));
         let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(PostgresPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(PostgresPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(PostgresHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(PostgresScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(PostgresOutboxRepository::new(pool.clone()));
 
         let shared_service = SharedService::new(
             coa_repo,
@@ -101,6 +109,10 @@ mod postgres_tests {
             stmt_repo,
             line_repo,
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
         );
         let service = ChartOfAccountServiceImpl::new(shared_service);
 
@@ -125,6 +137,10 @@ mod postgres_tests {
         let stmt_repo = Arc::new(PostgresAccountStmtRepository::new(pool.clone()));
         let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(PostgresPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(PostgresPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(PostgresHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(PostgresScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(PostgresOutboxRepository::new(pool.clone()));
 
         let shared_service = SharedService::new(
             coa_repo,
@@ -135,6 +151,10 @@ mod postgres_tests {
             stmt_repo,
             line_repo,
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
         );
         let service = ChartOfAccountServiceImpl::new(shared_service);
 
@@ -154,6 +174,7 @@ mod postgres_tests {
             short_desc: Some("Test Chart of Account".to_string()),
             long_desc: None,
             container_type: postings_api::domain::named::ContainerType::ChartOfAccount,
+            retired: None,
         }];
 
         // Act
@@ -183,6 +204,10 @@ mod postgres_tests {
         let stmt_repo = Arc::new(PostgresAccountStmtRepository::new(pool.clone()));
         let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(PostgresPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(PostgresPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(PostgresHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(PostgresScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(PostgresOutboxRepository::new(pool.clone()));
 
         let shared_service = SharedService::new(
             coa_repo,
@@ -193,6 +218,10 @@ mod postgres_tests {
             stmt_repo,
             line_repo,
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
         );
         let service = ChartOfAccountServiceImpl::new(shared_service);
 
@@ -231,6 +260,10 @@ mod mariadb_tests {
     use postings_db_mariadb::repositories::account_stmt_repository::MariaDbAccountStmtRepository;
     use postings_db_mariadb::repositories::posting_line_repository::MariaDbPostingLineRepository;
     use postings_db_mariadb::repositories::posting_trace_repository::MariaDbPostingTraceRepository;
+    use postings_db_mariadb::repositories::posting_approval_repository::MariaDbPostingApprovalRepository;
+    use postings_db_mariadb::repositories::hold_repository::MariaDbHoldRepository;
+    use postings_db_mariadb::repositories::schedule_repository::MariaDbScheduleRepository;
+    use postings_db_mariadb::repositories::outbox_repository::MariaDbOutboxRepository;
     use postings_db_mariadb::repositories::named_repository::MariaDbNamedRepository;
     use postings_logic::services::shared_service::SharedService;
 
@@ -306,6 +339,10 @@ mod mariadb_tests {
         let stmt_repo = Arc::new(MariaDbAccountStmtRepository::new(pool.clone()));
         let line_repo = Arc::new(MariaDbPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(MariaDbPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(MariaDbPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(MariaDbHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(MariaDbScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(MariaDbOutboxRepository::new(pool.clone()));
 
         let shared_service = SharedService::new(
             coa_repo,
@@ -316,6 +353,10 @@ mod mariadb_tests {
             stmt_repo,
             line_repo,
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
         );
         let service = ChartOfAccountServiceImpl::new(shared_service);
 
@@ -340,6 +381,10 @@ mod mariadb_tests {
         let stmt_repo = Arc::new(MariaDbAccountStmtRepository::new(pool.clone()));
         let line_repo = Arc::new(MariaDbPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(MariaDbPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(MariaDbPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(MariaDbHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(MariaDbScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(MariaDbOutboxRepository::new(pool.clone()));
 
         let shared_service = SharedService::new(
             coa_repo,
@@ -350,6 +395,10 @@ mod mariadb_tests {
             stmt_repo,
             line_repo,
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
         );
         let service = ChartOfAccountServiceImpl::new(shared_service);
 
@@ -369,6 +418,7 @@ mod mariadb_tests {
             short_desc: Some("Test Chart of Account".to_string()),
             long_desc: None,
             container_type: postings_api::domain::named::ContainerType::ChartOfAccount,
+            retired: None,
         }];
 
         // Act
@@ -398,6 +448,10 @@ mod mariadb_tests {
         let stmt_repo = Arc::new(MariaDbAccountStmtRepository::new(pool.clone()));
         let line_repo = Arc::new(MariaDbPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(MariaDbPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(MariaDbPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(MariaDbHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(MariaDbScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(MariaDbOutboxRepository::new(pool.clone()));
 
         let shared_service = SharedService::new(
             coa_repo,
@@ -408,6 +462,10 @@ mod mariadb_tests {
             stmt_repo,
             line_repo,
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
         );
         let service = ChartOfAccountServiceImpl::new(shared_service);
 
@@ -0,0 +1,145 @@
+#![cfg(test)]
+
+use postings_api::ServiceError;
+use postings_logic::coa_format::CoaFormat;
+
+fn read_fixture() -> postings_api::domain::coa_document::CoaDocument {
+    let yaml = std::fs::read_to_string("tests/data/coa_import.yml").unwrap();
+    CoaFormat::Yaml.read(&yaml).unwrap()
+}
+
+#[test]
+fn test_formats_round_trip() {
+    let document = read_fixture();
+    for format in [CoaFormat::Yaml, CoaFormat::Json, CoaFormat::Csv] {
+        let written = format.write(&document).unwrap();
+        assert_eq!(format.read(&written).unwrap(), document, "{format:?}");
+    }
+}
+
+#[test]
+fn test_read_csv_groups_names_by_code() {
+    let csv = "kind,code,parent_code,category,balance_side,language,name\n\
+               chart,,,,,en,SME Chart\n\
+               ledger,,,,,en,Main Ledger\n\
+               account,1000,,AS,Dr,en,Assets\n\
+               account,1000,,,,de,Aktiva\n\
+               account,1200,1000,AS,Dr,en,Cash\n";
+
+    let document = CoaFormat::Csv.read(csv).unwrap();
+
+    assert_eq!(document.accounts.len(), 2);
+    assert_eq!(document.accounts[0].names.len(), 2);
+    assert_eq!(document.accounts[1].parent_code.as_deref(), Some("1000"));
+}
+
+#[test]
+fn test_read_csv_rejects_conflicting_rows() {
+    let csv = "kind,code,parent_code,category,balance_side,language,name\n\
+               account,1000,,AS,Dr,en,Assets\n\
+               account,1000,,LI,,de,Aktiva\n";
+
+    assert!(matches!(CoaFormat::Csv.read(csv), Err(ServiceError::InvalidCoaFile)));
+}
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::sync::Arc;
+    use sqlx::PgPool;
+    use postings_api::service::coa_exchange_service::CoaExchangeService;
+    use postings_api::service::named_service::NamedService;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_logic::coa_format::CoaFormat;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::coa_exchange_service::CoaExchangeServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::named_service::NamedServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    fn create_service(pool: PgPool) -> CoaExchangeServiceImpl {
+        CoaExchangeServiceImpl::new(
+            create_shared_service(pool.clone()),
+            ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())),
+            LedgerServiceImpl::new(
+                create_shared_service(pool.clone()),
+                ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())),
+            ),
+            NamedServiceImpl::new(create_shared_service(pool)),
+        )
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_import_then_export_round_trips(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let service = create_service(pool);
+        let document = super::read_fixture();
+
+        // Act
+        let (ledger, accounts) = service.import_coa(document.clone()).await?;
+        let exported = service.export_coa(ledger.id).await?;
+
+        // Assert
+        assert_eq!(accounts.len(), 4);
+        assert_eq!(accounts[1].parent.as_ref().map(|p| p.id), Some(accounts[0].id));
+        assert_eq!(exported, document);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_import_is_idempotent(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let service = create_service(pool.clone());
+        let named_service = NamedServiceImpl::new(create_shared_service(pool));
+        let mut document = super::read_fixture();
+        let (ledger, accounts) = service.import_coa(document.clone()).await?;
+        document.accounts[2].names.push(postings_api::domain::coa_document::CoaName {
+            language: "de".to_string(),
+            name: "Passiva".to_string(),
+        });
+
+        // Act
+        let csv = CoaFormat::Csv.write(&document)?;
+        let (reimported_ledger, reimported) = service.import_coa(CoaFormat::Csv.read(&csv)?).await?;
+
+        // Assert
+        assert_eq!(reimported_ledger.id, ledger.id);
+        let ids = |accounts: &[postings_api::domain::ledger_account::LedgerAccount]| accounts.iter().map(|a| a.id).collect::<Vec<_>>();
+        assert_eq!(ids(&reimported), ids(&accounts));
+        assert_eq!(named_service.find_by_container_id(ledger.id).await?.len(), 1);
+        assert_eq!(named_service.find_by_container_id(accounts[2].id).await?.len(), 2);
+        assert_eq!(service.export_coa(ledger.id).await?, document);
+
+        Ok(())
+    }
+}
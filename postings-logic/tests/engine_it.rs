@@ -0,0 +1,235 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+use bigdecimal::BigDecimal;
+use chrono::{TimeZone, Utc};
+use uuid::Uuid;
+use postings_api::domain::account_category::AccountCategory;
+use postings_api::domain::account_limit::AccountLimit;
+use postings_api::domain::chart_of_account::ChartOfAccount;
+use postings_api::domain::domain_event::DomainEventPayload;
+use postings_api::domain::ledger::Ledger;
+use postings_api::domain::ledger_account::LedgerAccount;
+use postings_api::domain::named::{ContainerType, Named};
+use postings_api::domain::posting::Posting;
+use postings_api::domain::posting_line::PostingLine;
+use postings_api::domain::posting_status::PostingStatus;
+use postings_api::domain::posting_type::PostingType;
+use postings_api::service::account_stmt_service::AccountStmtService;
+use postings_api::service::chart_of_account_service::ChartOfAccountService;
+use postings_api::service::hold_service::HoldService;
+use postings_api::service::ledger_service::LedgerService;
+use postings_api::service::outbox_service::OutboxService;
+use postings_api::service::posting_service::PostingService;
+use postings_api::{DbFailure, ServiceError};
+use postings_logic::caching::caching_repositories::CachingRepositories;
+use postings_logic::clock::FixedClock;
+use postings_logic::engine::PostingsEngine;
+use postings_logic::id_generator::SequentialIdGenerator;
+
+fn create_engine() -> PostingsEngine {
+    PostingsEngine::builder(postings_db_memory::repositories()).build()
+}
+
+async fn setup_ledger(engine: &PostingsEngine) -> anyhow::Result<Ledger> {
+    let (coa, _) = engine.chart_of_account.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+    let (ledger, _) = engine.ledger.new_ledger(Ledger { id: Uuid::new_v4(), coa }, vec![]).await?;
+    Ok(ledger)
+}
+
+fn named(name: &str) -> Named {
+    Named {
+        id: Uuid::new_v4(),
+        container: Uuid::nil(),
+        context: Uuid::nil(),
+        name: name.to_string(),
+        language: "en".to_string(),
+        created: Utc::now(),
+        user_details: [0; 34],
+        short_desc: None,
+        long_desc: None,
+        container_type: ContainerType::LedgerAccount,
+        retired: None,
+    }
+}
+
+async fn setup_account(engine: &PostingsEngine, ledger: &Ledger, category: AccountCategory, code: &str) -> anyhow::Result<LedgerAccount> {
+    let account = LedgerAccount {
+        id: Uuid::new_v4(),
+        ledger: ledger.clone(),
+        parent: None,
+        coa: ledger.coa.clone(),
+        balance_side: category.default_bs(),
+        category,
+        code: Some(code.to_string()),
+    };
+    Ok(engine.ledger.new_ledger_account(account, vec![named(code)]).await?.0)
+}
+
+fn line(account: &LedgerAccount, debit: i64, credit: i64) -> PostingLine {
+    PostingLine {
+        id: Uuid::new_v4(),
+        account: account.clone(),
+        debit_amount: BigDecimal::from(debit),
+        credit_amount: BigDecimal::from(credit),
+        details: None,
+        src_account: None,
+        base_line: None,
+        sub_opr_src_id: None,
+        record_time: Utc::now(),
+        opr_id: [0; 34],
+        opr_src: None,
+        pst_time: Utc::now(),
+        val_time: None,
+        pst_type: PostingType::BusiTx,
+        pst_status: PostingStatus::Posted,
+        hash: None,
+        additional_information: None,
+        discarded_time: None,
+    }
+}
+
+/// Moves `amount` from `credited` to `debited`.
+fn transfer(debited: &LedgerAccount, credited: &LedgerAccount, amount: i64) -> Posting {
+    let mut opr_id = [0u8; 34];
+    opr_id[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+    Posting {
+        id: Uuid::nil(),
+        record_user: [0; 34],
+        record_time: Utc::now(),
+        opr_id,
+        opr_time: Utc::now(),
+        opr_type: [0; 34],
+        opr_details: None,
+        opr_src: None,
+        pst_time: Utc::now(),
+        pst_type: PostingType::BusiTx,
+        pst_status: PostingStatus::Posted,
+        ledger: debited.ledger.clone(),
+        val_time: None,
+        lines: vec![line(debited, amount, 0), line(credited, 0, amount)],
+        discarded_id: None,
+        discarded_time: None,
+        discarding_id: None,
+        hash_record: Default::default(),
+    }
+}
+
+#[tokio::test]
+async fn test_services_share_one_store() -> anyhow::Result<()> {
+    // Arrange
+    let engine = create_engine();
+    let ledger = setup_ledger(&engine).await?;
+    let cash = setup_account(&engine, &ledger, AccountCategory::AS, "1000").await?;
+    let loan = setup_account(&engine, &ledger, AccountCategory::LI, "2000").await?;
+
+    // Act
+    let first = engine.posting.new_posting(transfer(&cash, &loan, 100)).await?;
+    let second = engine.posting.new_posting(transfer(&cash, &loan, 50)).await?;
+    let stmt = engine.stmt.read_stmt(loan.clone(), Utc::now()).await?;
+    let balance = engine.hold.find_account_balance(cash.id, Utc::now()).await?;
+    let verification = engine.posting.verify_hash_chain(ledger.id).await?;
+    let events = engine.outbox.find_ledger_events(ledger.id, 0, 100).await?;
+
+    // Assert
+    assert_eq!(stmt.total_credit, BigDecimal::from(150));
+    assert_eq!(balance.booked, BigDecimal::from(150));
+    assert!(verification.is_intact());
+    assert_eq!(second.hash_record.antecedent_id, Some(first.id));
+    assert_eq!(events.iter().map(|e| e.sequence).collect::<Vec<_>>(), vec![1, 2, 3, 4, 5]);
+    assert!(matches!(events[0].payload, DomainEventPayload::LedgerCreated { .. }));
+    assert!(matches!(events[4].payload, DomainEventPayload::PostingCreated { posting_id, .. } if posting_id == second.id));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_rejected_writes_leave_nothing_behind() -> anyhow::Result<()> {
+    // Arrange
+    let engine = create_engine();
+    let ledger = setup_ledger(&engine).await?;
+    let bank = setup_account(&engine, &ledger, AccountCategory::AS, "1000").await?;
+    let wallet = setup_account(&engine, &ledger, AccountCategory::LI, "2000").await?;
+    let limit = AccountLimit { account_id: wallet.id, min_balance: Some(BigDecimal::from(0)), max_balance: None };
+    engine.ledger.set_account_limit(limit).await?;
+    engine.posting.new_posting(transfer(&bank, &wallet, 100)).await?;
+
+    // Act
+    let overdraft = engine.posting.new_posting(transfer(&wallet, &bank, 150)).await;
+    let duplicate_code = setup_account(&engine, &ledger, AccountCategory::AS, "1000").await;
+    let taken_name = LedgerAccount { id: Uuid::new_v4(), code: Some("3000".to_string()), ..bank.clone() };
+    let duplicate_name = engine.ledger.new_ledger_account(taken_name, vec![named("1000")]).await;
+    let stmt = engine.stmt.read_stmt(wallet.clone(), Utc::now()).await?;
+
+    // Assert
+    match overdraft {
+        Err(ServiceError::LimitExceeded { account_id, shortfall }) => {
+            assert_eq!(account_id, wallet.id);
+            assert_eq!(shortfall, BigDecimal::from(50));
+        }
+        other => panic!("expected LimitExceeded, got {other:?}"),
+    }
+    assert!(duplicate_code.is_err());
+    assert!(matches!(duplicate_name, Err(ServiceError::DuplicateName)));
+    assert_eq!(engine.ledger.find_ledger_account_by_code(&ledger, "3000").await?, None);
+    assert_eq!(stmt.total_debit, BigDecimal::from(0));
+    assert_eq!(stmt.total_credit, BigDecimal::from(100));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_builder_applies_caching_clock_and_ids() -> anyhow::Result<()> {
+    // Arrange
+    let now = Utc.with_ymd_and_hms(2025, 3, 1, 12, 0, 0).unwrap();
+    let engine = PostingsEngine::builder(postings_db_memory::repositories())
+        .caching(CachingRepositories::builder())
+        .clock(Arc::new(FixedClock::new(now)))
+        .id_generator(Arc::new(SequentialIdGenerator::default()))
+        .build();
+    let ledger = setup_ledger(&engine).await?;
+    let cash = setup_account(&engine, &ledger, AccountCategory::AS, "1000").await?;
+    let loan = setup_account(&engine, &ledger, AccountCategory::LI, "2000").await?;
+
+    // Act
+    let posting = engine.posting.new_posting(transfer(&cash, &loan, 10)).await?;
+    engine.ledger.find_ledger_by_id(ledger.id).await?;
+    engine.ledger.find_ledger_by_id(ledger.id).await?;
+    let stats = engine.caches.as_ref().expect("caching configured").stats();
+
+    // Assert
+    assert_eq!(ledger.coa.id, Uuid::from_u128(1));
+    assert_eq!(posting.record_time, now);
+    assert!(stats.ledger.hits >= 1);
+    assert_eq!(engine.hold.find_account_balance(cash.id, Utc::now()).await?.booked, BigDecimal::from(10));
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_database_errors_keep_their_classification() -> anyhow::Result<()> {
+    // Arrange
+    let engine = create_engine();
+    let ledger = setup_ledger(&engine).await?;
+    let cash = setup_account(&engine, &ledger, AccountCategory::AS, "1000").await?;
+    let stmt = engine.stmt.create_stmt(cash.clone(), Utc::now()).await?;
+    let mut orphaned = stmt.clone();
+    orphaned.account.ledger.id = Uuid::new_v4();
+
+    // Act
+    let taken_id = engine.ledger.new_ledger(ledger.clone(), vec![]).await;
+    let missing_ledger = engine.stmt.close_stmt(orphaned).await;
+
+    // Assert
+    match taken_id {
+        Err(ServiceError::Db { failure, constraint, context, .. }) => {
+            assert_eq!(failure, DbFailure::UniqueViolation);
+            assert_eq!(constraint.as_deref(), Some("ledger_pkey"));
+            assert_eq!(context, format!("saving ledger {}", ledger.id));
+        }
+        other => panic!("expected Db, got {other:?}"),
+    }
+    assert!(matches!(missing_ledger, Err(ServiceError::LedgerNotFound)));
+
+    Ok(())
+}
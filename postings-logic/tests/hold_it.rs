@@ -0,0 +1,278 @@
+#![cfg(test)]
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::sync::Arc;
+    use bigdecimal::BigDecimal;
+    use chrono::{Duration, Utc};
+    use sqlx::PgPool;
+    use uuid::Uuid;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::account_limit::AccountLimit;
+    use postings_api::domain::approval_policy::ApprovalPolicy;
+    use postings_api::domain::balance_side::BalanceSide;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::hold::HoldStatus;
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::domain::posting::Posting;
+    use postings_api::domain::posting_line::PostingLine;
+    use postings_api::domain::posting_status::PostingStatus;
+    use postings_api::domain::posting_type::PostingType;
+    use postings_api::service::chart_of_account_service::ChartOfAccountService;
+    use postings_api::service::hold_service::HoldService;
+    use postings_api::service::ledger_service::LedgerService;
+    use postings_api::service::posting_service::PostingService;
+    use postings_api::ServiceError;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::hold_service::HoldServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::posting_service::PostingServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    struct Services {
+        ledger: LedgerServiceImpl,
+        posting: PostingServiceImpl,
+        hold: HoldServiceImpl,
+    }
+
+    fn wallet_limit(account: &LedgerAccount) -> AccountLimit {
+        AccountLimit { account_id: account.id, min_balance: Some(BigDecimal::from(0)), max_balance: None }
+    }
+
+    fn create_services(pool: PgPool) -> Services {
+        Services {
+            ledger: LedgerServiceImpl::new(
+                create_shared_service(pool.clone()),
+                ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())),
+            ),
+            posting: PostingServiceImpl::new(create_shared_service(pool.clone())),
+            hold: HoldServiceImpl::new(
+                create_shared_service(pool.clone()),
+                LedgerServiceImpl::new(
+                    create_shared_service(pool.clone()),
+                    ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())),
+                ),
+                PostingServiceImpl::new(create_shared_service(pool)),
+            ),
+        }
+    }
+
+    async fn setup_ledger(pool: &PgPool, services: &Services) -> anyhow::Result<Ledger> {
+        let coa_service = ChartOfAccountServiceImpl::new(create_shared_service(pool.clone()));
+        let (coa, _) = coa_service.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+        let (ledger, _) = services.ledger.new_ledger(Ledger { id: Uuid::new_v4(), coa }, vec![]).await?;
+        Ok(ledger)
+    }
+
+    async fn setup_account(services: &Services, ledger: &Ledger, balance_side: BalanceSide, category: AccountCategory) -> anyhow::Result<LedgerAccount> {
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side,
+            category,
+            code: None,
+        };
+        Ok(services.ledger.new_ledger_account(account, vec![]).await?.0)
+    }
+
+    fn line(account: &LedgerAccount, debit: i64, credit: i64) -> PostingLine {
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: account.clone(),
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [0; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            val_time: None,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+        }
+    }
+
+    /// Moves `amount` from `credited` to `debited`.
+    fn transfer(debited: &LedgerAccount, credited: &LedgerAccount, amount: i64) -> Posting {
+        let mut opr_id = [0u8; 34];
+        opr_id[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+        Posting {
+            id: Uuid::nil(),
+            record_user: [0; 34],
+            record_time: Utc::now(),
+            opr_id,
+            opr_time: Utc::now(),
+            opr_type: [0; 34],
+            opr_details: None,
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: debited.ledger.clone(),
+            val_time: None,
+            lines: vec![line(debited, amount, 0), line(credited, 0, amount)],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_capture_hold(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let card = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let merchant = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        services.posting.new_posting(transfer(&bank, &card, 100)).await?;
+        let hold = services.hold.place_hold(card.id, BigDecimal::from(60), Utc::now() + Duration::days(7), [7; 34]).await?;
+        let before_capture = services.hold.find_account_balance(card.id, Utc::now()).await?;
+
+        // Act
+        let too_much = services.hold.capture_hold(hold.id, merchant.id, Some(BigDecimal::from(61)), [1; 34]).await;
+        let (captured, posting) = services.hold.capture_hold(hold.id, merchant.id, Some(BigDecimal::from(45)), [1; 34]).await?;
+        let again = services.hold.release_hold(hold.id).await;
+
+        // Assert
+        assert_eq!(before_capture.booked, BigDecimal::from(100));
+        assert_eq!(before_capture.held, BigDecimal::from(60));
+        assert_eq!(before_capture.available, BigDecimal::from(40));
+        assert!(matches!(too_much, Err(ServiceError::InvalidHoldAmount)));
+        assert_eq!(captured.status, HoldStatus::Captured);
+        assert_eq!(captured.captured_amount, Some(BigDecimal::from(45)));
+        assert_eq!(captured.posting_id, Some(posting.id));
+        assert_eq!(posting.opr_id, [7; 34]);
+        assert!(matches!(again, Err(ServiceError::HoldNotActive)));
+        let after_capture = services.hold.find_account_balance(card.id, Utc::now()).await?;
+        assert_eq!(after_capture.booked, BigDecimal::from(55));
+        assert_eq!(after_capture.available, BigDecimal::from(55));
+        assert_eq!(services.hold.find_account_balance(merchant.id, Utc::now()).await?.booked, BigDecimal::from(45));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_capture_needing_approval_is_rejected(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let card = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let merchant = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        services.posting.new_posting(transfer(&bank, &card, 100)).await?;
+        let hold = services.hold.place_hold(card.id, BigDecimal::from(60), Utc::now() + Duration::days(7), [7; 34]).await?;
+        services.posting.set_approval_policy(ApprovalPolicy { ledger: ledger.clone(), amount_threshold: Some(BigDecimal::from(50)) }).await?;
+
+        // Act
+        let above_threshold = services.hold.capture_hold(hold.id, merchant.id, None, [1; 34]).await;
+
+        // Assert
+        assert!(matches!(above_threshold, Err(ServiceError::CaptureRequiresApproval)));
+        let unchanged = services.hold.find_hold_by_id(hold.id).await?.unwrap();
+        assert_eq!(unchanged.status, HoldStatus::Active);
+        assert_eq!(services.hold.find_account_balance(card.id, Utc::now()).await?.held, BigDecimal::from(60));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_holds_count_against_limit(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        services.ledger.set_account_limit(wallet_limit(&wallet)).await?;
+        services.posting.new_posting(transfer(&bank, &wallet, 100)).await?;
+        let hold = services.hold.place_hold(wallet.id, BigDecimal::from(80), Utc::now() + Duration::days(7), [7; 34]).await?;
+
+        // Act
+        let second_hold = services.hold.place_hold(wallet.id, BigDecimal::from(30), Utc::now() + Duration::days(7), [8; 34]).await;
+        let spending_held_funds = services.posting.new_posting(transfer(&wallet, &bank, 30)).await;
+        let released = services.hold.release_hold(hold.id).await?;
+        let spending_after_release = services.posting.new_posting(transfer(&wallet, &bank, 30)).await;
+
+        // Assert
+        assert!(matches!(second_hold, Err(ServiceError::LimitExceeded { shortfall, .. }) if shortfall == BigDecimal::from(10)));
+        assert!(matches!(spending_held_funds, Err(ServiceError::LimitExceeded { shortfall, .. }) if shortfall == BigDecimal::from(10)));
+        assert_eq!(released.status, HoldStatus::Released);
+        assert!(spending_after_release.is_ok());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_expire_holds(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let card = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let merchant = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let expiry = Utc::now() + Duration::hours(1);
+        let short = services.hold.place_hold(card.id, BigDecimal::from(20), expiry, [7; 34]).await?;
+        let long = services.hold.place_hold(card.id, BigDecimal::from(30), expiry + Duration::days(1), [8; 34]).await?;
+        let after_expiry = expiry + Duration::minutes(1);
+
+        // Act
+        let held_before = services.hold.find_account_balance(card.id, Utc::now()).await?.held;
+        let held_after = services.hold.find_account_balance(card.id, after_expiry).await?.held;
+        let expired = services.hold.expire_holds(after_expiry).await?;
+        let capture_expired = services.hold.capture_hold(short.id, merchant.id, None, [1; 34]).await;
+
+        // Assert
+        assert_eq!(held_before, BigDecimal::from(50));
+        assert_eq!(held_after, BigDecimal::from(30));
+        assert_eq!(expired, 1);
+        assert_eq!(services.hold.find_hold_by_id(short.id).await?.unwrap().status, HoldStatus::Expired);
+        assert!(matches!(capture_expired, Err(ServiceError::HoldNotActive)));
+        let active = services.hold.find_active_holds(card.id, Utc::now()).await?;
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id, long.id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,286 @@
+#![cfg(test)]
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::str::FromStr;
+    use std::sync::Arc;
+    use bigdecimal::BigDecimal;
+    use chrono::{NaiveDate, Utc};
+    use sqlx::{PgPool, Type};
+    use uuid::Uuid;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::balance_side::BalanceSide;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::day_count_convention::DayCountConvention;
+    use postings_api::domain::interest_accrual::InterestAccrualRule;
+    use postings_api::domain::interest_rate_schedule::{InterestRateSchedule, InterestRateTier};
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::domain::posting::Posting;
+    use postings_api::domain::posting_line::PostingLine;
+    use postings_api::domain::posting_status::PostingStatus;
+    use postings_api::domain::posting_type::PostingType;
+    use postings_api::service::interest_accrual_service::InterestAccrualService;
+    use postings_api::service::posting_service::PostingService;
+    use postings_api::ServiceError;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_logic::services::interest_accrual_service::InterestAccrualServiceImpl;
+    use postings_logic::services::posting_service::PostingServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    #[derive(Type)]
+    #[sqlx(type_name = "balance_side")]
+    pub enum TestBalanceSide {
+        Dr,
+        Cr,
+    }
+
+    #[derive(Type)]
+    #[sqlx(type_name = "account_category")]
+    pub enum TestAccountCategory {
+        AS,
+        LI,
+        EX,
+    }
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    fn create_service(pool: PgPool) -> InterestAccrualServiceImpl {
+        InterestAccrualServiceImpl::new(
+            create_shared_service(pool.clone()),
+            PostingServiceImpl::new(create_shared_service(pool)),
+        )
+    }
+
+    async fn setup_ledger(pool: &PgPool) -> anyhow::Result<Ledger> {
+        let coa = ChartOfAccount { id: Uuid::new_v4() };
+        sqlx::query("INSERT INTO chart_of_account (id) VALUES ($1)")
+            .bind(coa.id)
+            .execute(pool)
+            .await?;
+
+        let ledger = Ledger { id: Uuid::new_v4(), coa };
+        sqlx::query("INSERT INTO ledger (id, coa_id) VALUES ($1, $2)")
+            .bind(ledger.id)
+            .bind(ledger.coa.id)
+            .execute(pool)
+            .await?;
+        Ok(ledger)
+    }
+
+    async fn setup_ledger_account(pool: &PgPool, ledger: &Ledger, category: AccountCategory) -> anyhow::Result<LedgerAccount> {
+        let (balance_side, test_side, test_category) = match category {
+            AccountCategory::LI => (BalanceSide::Cr, TestBalanceSide::Cr, TestAccountCategory::LI),
+            AccountCategory::EX => (BalanceSide::Dr, TestBalanceSide::Dr, TestAccountCategory::EX),
+            _ => (BalanceSide::Dr, TestBalanceSide::Dr, TestAccountCategory::AS),
+        };
+        let ledger_account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side,
+            category,
+            code: None,
+        };
+        sqlx::query("INSERT INTO ledger_account (id, ledger_id, coa_id, balance_side, category) VALUES ($1, $2, $3, $4, $5)")
+            .bind(ledger_account.id)
+            .bind(ledger.id)
+            .bind(ledger.coa.id)
+            .bind(test_side)
+            .bind(test_category)
+            .execute(pool)
+            .await?;
+        Ok(ledger_account)
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn line(account: &LedgerAccount, debit: &BigDecimal, credit: &BigDecimal) -> PostingLine {
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: account.clone(),
+            debit_amount: debit.clone(),
+            credit_amount: credit.clone(),
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [0; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            val_time: None,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: None,
+            additional_information: None,
+            discarded_time: None,
+        }
+    }
+
+    /// Books a customer deposit: debit cash, credit the deposit account, value-dated at `value_date`.
+    fn deposit(cash: &LedgerAccount, deposit_account: &LedgerAccount, amount: &str, value_date: NaiveDate) -> Posting {
+        let amount = BigDecimal::from_str(amount).unwrap();
+        let zero = BigDecimal::from(0);
+        let mut opr_id = [0u8; 34];
+        opr_id[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+        Posting {
+            id: Uuid::nil(),
+            record_user: [0; 34],
+            record_time: Utc::now(),
+            opr_id,
+            opr_time: Utc::now(),
+            opr_type: [0; 34],
+            opr_details: None,
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: cash.ledger.clone(),
+            val_time: Some(value_date.and_hms_opt(12, 0, 0).unwrap().and_utc()),
+            lines: vec![line(cash, &amount, &zero), line(deposit_account, &zero, &amount)],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        }
+    }
+
+    fn one_percent(convention: DayCountConvention) -> InterestRateSchedule {
+        InterestRateSchedule {
+            convention,
+            tiers: vec![InterestRateTier { from_balance: BigDecimal::from(0), rate: BigDecimal::from_str("0.01").unwrap() }],
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_compute_accrual_follows_value_dated_balance(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let cash = setup_ledger_account(&pool, &ledger, AccountCategory::AS).await?;
+        let deposit_account = setup_ledger_account(&pool, &ledger, AccountCategory::LI).await?;
+        let posting_service = PostingServiceImpl::new(create_shared_service(pool.clone()));
+        posting_service.new_posting(deposit(&cash, &deposit_account, "36500", date(2025, 1, 1))).await?;
+        posting_service.new_posting(deposit(&cash, &deposit_account, "36500", date(2025, 1, 6))).await?;
+        let service = create_service(pool);
+
+        // Act
+        let accrual = service
+            .compute_accrual(deposit_account, &one_percent(DayCountConvention::Act365), date(2025, 1, 1), date(2025, 1, 11))
+            .await?;
+
+        // Assert
+        assert_eq!(accrual.days.len(), 10);
+        assert_eq!(accrual.days[0].interest, BigDecimal::from(1));
+        assert_eq!(accrual.days[5].balance, BigDecimal::from(73000));
+        assert_eq!(accrual.days[5].interest, BigDecimal::from(2));
+        assert_eq!(accrual.amount, BigDecimal::from(15));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_book_accruals_books_each_period_once(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let cash = setup_ledger_account(&pool, &ledger, AccountCategory::AS).await?;
+        let deposit_account = setup_ledger_account(&pool, &ledger, AccountCategory::LI).await?;
+        let interest_expense = setup_ledger_account(&pool, &ledger, AccountCategory::EX).await?;
+        let posting_service = PostingServiceImpl::new(create_shared_service(pool.clone()));
+        posting_service.new_posting(deposit(&cash, &deposit_account, "36000", date(2025, 1, 1))).await?;
+        let service = create_service(pool);
+        let rule = InterestAccrualRule {
+            account: deposit_account.clone(),
+            schedule: one_percent(DayCountConvention::Thirty360),
+            interest_account: interest_expense.clone(),
+        };
+
+        // Act
+        let booked = service.book_accruals(vec![rule.clone()], date(2025, 1, 1), date(2025, 2, 1), [1; 34]).await?;
+        let repeated = service.book_accruals(vec![rule], date(2025, 1, 1), date(2025, 2, 1), [1; 34]).await?;
+        let next_day = service
+            .compute_accrual(deposit_account.clone(), &one_percent(DayCountConvention::Thirty360), date(2025, 2, 1), date(2025, 2, 2))
+            .await?;
+
+        // Assert
+        assert_eq!(booked[0].amount, BigDecimal::from(30));
+        let posting = booked[0].posting.as_ref().expect("accrual should be booked");
+        assert_eq!(posting.lines[0].account.id, interest_expense.id);
+        assert_eq!(posting.lines[0].debit_amount, BigDecimal::from(30));
+        assert_eq!(posting.lines[1].account.id, deposit_account.id);
+        assert_eq!(posting.lines[1].credit_amount, BigDecimal::from(30));
+        assert!(repeated[0].posting.is_none());
+        assert_eq!(next_day.days[0].balance, BigDecimal::from(36030));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_book_accruals_rejects_overlapping_periods(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let cash = setup_ledger_account(&pool, &ledger, AccountCategory::AS).await?;
+        let deposit_account = setup_ledger_account(&pool, &ledger, AccountCategory::LI).await?;
+        let interest_expense = setup_ledger_account(&pool, &ledger, AccountCategory::EX).await?;
+        let posting_service = PostingServiceImpl::new(create_shared_service(pool.clone()));
+        posting_service.new_posting(deposit(&cash, &deposit_account, "36000", date(2025, 1, 1))).await?;
+        let service = create_service(pool.clone());
+        let rule = InterestAccrualRule {
+            account: deposit_account.clone(),
+            schedule: one_percent(DayCountConvention::Thirty360),
+            interest_account: interest_expense,
+        };
+
+        // Act
+        let (first, second) = tokio::join!(
+            service.book_accruals(vec![rule.clone()], date(2025, 1, 1), date(2025, 2, 1), [1; 34]),
+            service.book_accruals(vec![rule.clone()], date(2025, 1, 1), date(2025, 2, 1), [1; 34]),
+        );
+        let overlapping = service.book_accruals(vec![rule.clone()], date(2025, 1, 15), date(2025, 2, 15), [1; 34]).await;
+        let adjacent = service.book_accruals(vec![rule], date(2025, 2, 1), date(2025, 3, 1), [1; 34]).await?;
+
+        // Assert
+        assert_eq!([first?, second?].iter().filter(|booked| booked[0].posting.is_some()).count(), 1);
+        assert!(matches!(overlapping, Err(ServiceError::AccrualPeriodOverlap)));
+        assert!(adjacent[0].posting.is_some());
+        let accrual_postings: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM posting_line WHERE account_id = $1 AND credit_amount > 0 AND val_time >= '2025-02-01'")
+            .bind(deposit_account.id)
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(accrual_postings, 2);
+
+        Ok(())
+    }
+}
@@ -7,18 +7,29 @@ mod postgres_tests {
     use postings_logic::services::ledger_service::LedgerServiceImpl;
     use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
     use postings_api::service::ledger_service::LedgerService;
+    use postings_api::{DbFailure, ServiceError};
     use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
     use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
     use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
     use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db::repositories::named_repository::NamedRepository;
     use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
     use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
     use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
     use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
     use postings_logic::services::shared_service::SharedService;
     use postings_api::domain::ledger::Ledger;
     use postings_api::domain::chart_of_account::ChartOfAccount;
     use postings_api::domain::named::{Named, ContainerType};
+    use postings_api::domain::coa_account_definition::CoaAccountDefinition;
+    use postings_api::domain::account_code_scheme::AccountCodeScheme;
+    use postings_api::service::chart_of_account_service::ChartOfAccountService;
+    use postings_db::repositories::ledger_repository::LedgerRepository;
+    use postings_db::DbError;
     use uuid::Uuid;
     use postings_api::domain::{
         account_category::AccountCategory, balance_side::BalanceSide, ledger_account::LedgerAccount,
@@ -79,6 +90,7 @@ mod postgres_tests {
             coa: ledger.coa.clone(),
             balance_side,
             category,
+            code: None,
         };
         sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category) VALUES ($1, $2, $3, $4, $5, $6)")
             .bind(ledger_account.id)
@@ -115,28 +127,43 @@ mod postgres_tests {
         let stmt_repo = Arc::new(PostgresAccountStmtRepository::new(pool.clone()));
         let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(PostgresPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(PostgresPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(PostgresHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(PostgresScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(PostgresOutboxRepository::new(pool.clone()));
 
         let shared_service = SharedService::new(
-            coa_repo.clone(),
+            coa_repo,
             ledger_repo,
             ledger_account_repo,
-            named_repo.clone(),
+            named_repo,
             posting_repo,
             stmt_repo,
             line_repo,
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
         );
-        let coa_service = ChartOfAccountServiceImpl::new(SharedService::new(
-            coa_repo,
+        LedgerServiceImpl::new(shared_service, create_coa_service(pool))
+    }
+
+    fn create_coa_service(pool: PgPool) -> ChartOfAccountServiceImpl {
+        ChartOfAccountServiceImpl::new(SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
             Arc::new(PostgresLedgerRepository::new(pool.clone())),
             Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
-            named_repo,
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
             Arc::new(PostgresPostingRepository::new(pool.clone())),
             Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
             Arc::new(PostgresPostingLineRepository::new(pool.clone())),
             Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
-        ));
-        LedgerServiceImpl::new(shared_service, coa_service)
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        ))
     }
 
     #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
@@ -161,6 +188,7 @@ mod postgres_tests {
             short_desc: Some("Short desc".to_string()),
             long_desc: Some("Long desc".to_string()),
             container_type: ContainerType::Ledger,
+            retired: None,
         }];
 
         // Act
@@ -171,4 +199,332 @@ mod postgres_tests {
         
         Ok(())
     }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_new_ledger_assigns_ids_and_times(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let coa = setup_coa(&pool).await?;
+        let service = create_service(pool);
+        let named_bo = vec![Named {
+            id: Uuid::nil(),
+            container: Uuid::nil(),
+            context: Uuid::nil(),
+            name: "Test Ledger".to_string(),
+            language: "en".to_string(),
+            created: chrono::DateTime::UNIX_EPOCH,
+            user_details: [0; 34],
+            short_desc: None,
+            long_desc: None,
+            container_type: ContainerType::Ledger,
+            retired: None,
+        }];
+        let before = chrono::Utc::now();
+
+        // Act
+        let (ledger, named_result) = service.new_ledger(Ledger { id: Uuid::nil(), coa }, named_bo).await?;
+
+        // Assert
+        assert!(!ledger.id.is_nil());
+        assert!(!named_result[0].id.is_nil());
+        assert_eq!(named_result[0].container, ledger.id);
+        assert!(named_result[0].created >= before);
+        assert_eq!(service.find_ledger_by_id(ledger.id).await?, Some(ledger));
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_new_ledger_reports_taken_id_as_unique_violation(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let coa = setup_coa(&pool).await?;
+        let service = create_service(pool);
+        let (ledger, _) = service.new_ledger(Ledger { id: Uuid::new_v4(), coa: coa.clone() }, vec![]).await?;
+
+        // Act
+        let result = service.new_ledger(Ledger { id: ledger.id, coa }, vec![]).await;
+
+        // Assert
+        match result {
+            Err(error @ ServiceError::Db { .. }) => {
+                assert!(!error.is_retryable());
+                let ServiceError::Db { failure, constraint, context, .. } = error else { unreachable!() };
+                assert_eq!(failure, DbFailure::UniqueViolation);
+                assert_eq!(constraint.as_deref(), Some("ledger_pkey"));
+                assert!(context.contains(&ledger.id.to_string()));
+            }
+            other => panic!("expected Db, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_ledger_repository_reports_missing_coa_as_foreign_key_violation(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let repository = PostgresLedgerRepository::new(pool);
+        let ledger = postings_db::models::ledger::Ledger { id: Uuid::new_v4(), coa_id: Uuid::new_v4() };
+
+        // Act
+        let result = repository.save_with_named(&ledger, &[], chrono::Utc::now()).await;
+
+        // Assert
+        match result {
+            Err(error @ DbError::ForeignKey { .. }) => {
+                assert_eq!(error.constraint(), Some("ledger_coa_id_fkey"));
+                assert!(!error.is_retryable());
+            }
+            other => panic!("expected ForeignKey, got {other:?}"),
+        }
+
+        Ok(())
+    }
+
+    fn account_name(name: &str) -> Named {
+        Named {
+            id: Uuid::new_v4(),
+            container: Uuid::nil(),
+            context: Uuid::nil(),
+            name: name.to_string(),
+            language: "en".to_string(),
+            created: chrono::Utc::now(),
+            user_details: [0; 34],
+            short_desc: None,
+            long_desc: None,
+            container_type: ContainerType::LedgerAccount,
+            retired: None,
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_new_ledger_account_rejects_duplicate_name(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let coa = setup_coa(&pool).await?;
+        let service = create_service(pool);
+        let (ledger, _) = service.new_ledger(Ledger { id: Uuid::new_v4(), coa: coa.clone() }, vec![]).await?;
+        let account = |id| LedgerAccount {
+            id,
+            ledger: ledger.clone(),
+            parent: None,
+            coa: coa.clone(),
+            balance_side: BalanceSide::Dr,
+            category: AccountCategory::AS,
+            code: None,
+        };
+        service.new_ledger_account(account(Uuid::new_v4()), vec![account_name("Cash")]).await?;
+        let duplicate_id = Uuid::new_v4();
+
+        // Act
+        let result = service.new_ledger_account(account(duplicate_id), vec![account_name("Cash")]).await;
+
+        // Assert
+        assert!(matches!(result, Err(ServiceError::DuplicateName)));
+        assert!(service.find_ledger_account_by_id(duplicate_id).await?.is_none());
+        assert_eq!(service.find_ledger_account_by_name(&ledger, "Cash").await?.len(), 1);
+
+        Ok(())
+    }
+
+    fn definition(coa: &ChartOfAccount, code: &str, name: &str, category: AccountCategory, balance_side: BalanceSide, parent_code: Option<&str>) -> CoaAccountDefinition {
+        CoaAccountDefinition {
+            id: Uuid::nil(),
+            coa: coa.clone(),
+            code: code.to_string(),
+            name: name.to_string(),
+            language: "en".to_string(),
+            category,
+            balance_side,
+            parent_code: parent_code.map(str::to_string),
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_instantiate_ledger_from_coa(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let coa = setup_coa(&pool).await?;
+        let coa_service = create_coa_service(pool.clone());
+        let service = create_service(pool);
+        // Children listed before their parent still get created parents first.
+        coa_service.add_account_definitions(coa.id, vec![
+            definition(&coa, "1200", "Cash", AccountCategory::AS, BalanceSide::Dr, Some("1000")),
+            definition(&coa, "1000", "Assets", AccountCategory::AS, BalanceSide::Dr, None),
+            definition(&coa, "2000", "Liabilities", AccountCategory::LI, BalanceSide::Cr, None),
+        ]).await?;
+
+        // Act
+        let (ledger, accounts) = service.instantiate_ledger_from_coa(coa.id, vec![]).await?;
+
+        // Assert
+        let codes: Vec<_> = accounts.iter().map(|a| a.code.clone().unwrap()).collect();
+        assert_eq!(codes, vec!["1000", "2000", "1200"]);
+        let cash = service.find_ledger_account_by_name(&ledger, "Cash").await?;
+        assert_eq!(cash.len(), 1);
+        assert_eq!(cash[0].code.as_deref(), Some("1200"));
+        assert_eq!(cash[0].parent.as_ref().map(|p| p.id), Some(accounts[0].id));
+        assert_eq!(service.find_ledger_account_by_id(accounts[2].id).await?, Some(cash[0].clone()));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_instantiate_ledgers_from_coa_assigns_name_ids(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let coa = setup_coa(&pool).await?;
+        let coa_service = create_coa_service(pool.clone());
+        let service = create_service(pool.clone());
+        let named_repo = PostgresNamedRepository::new(pool);
+        coa_service.add_account_definitions(coa.id, vec![
+            definition(&coa, "1000", "Assets", AccountCategory::AS, BalanceSide::Dr, None),
+        ]).await?;
+        // Names as the HTTP layer sends them, without id or creation time.
+        let named = |name: &str| Named {
+            id: Uuid::nil(),
+            container: Uuid::nil(),
+            context: Uuid::nil(),
+            name: name.to_string(),
+            language: "en".to_string(),
+            created: chrono::DateTime::UNIX_EPOCH,
+            user_details: [0; 34],
+            short_desc: None,
+            long_desc: None,
+            container_type: ContainerType::Ledger,
+            retired: None,
+        };
+        let before = chrono::Utc::now();
+
+        // Act
+        let (first, _) = service.instantiate_ledger_from_coa(coa.id, vec![named("First")]).await?;
+        let (second, _) = service.instantiate_ledger_from_coa(coa.id, vec![named("Second")]).await?;
+
+        // Assert
+        assert_ne!(first.id, second.id);
+        assert_eq!(service.find_ledger_by_name("First", coa.id).await?, vec![first]);
+        assert_eq!(service.find_ledger_by_name("Second", coa.id).await?, vec![second.clone()]);
+        let names = named_repo.find_by_container(second.id).await?;
+        assert_eq!(names.len(), 1);
+        assert!(!names[0].id.is_nil());
+        assert!(names[0].created >= before);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_add_account_definitions_rejects_unknown_parent(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let coa = setup_coa(&pool).await?;
+        let coa_service = create_coa_service(pool);
+
+        // Act
+        let result = coa_service.add_account_definitions(coa.id, vec![
+            definition(&coa, "1200", "Cash", AccountCategory::AS, BalanceSide::Dr, Some("1000")),
+        ]).await;
+
+        // Assert
+        assert!(matches!(result, Err(ServiceError::InvalidAccountDefinition)));
+        assert!(coa_service.find_account_definitions(coa.id).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_new_ledger_account_must_conform_to_coa(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let coa = setup_coa(&pool).await?;
+        let coa_service = create_coa_service(pool.clone());
+        let service = create_service(pool);
+        coa_service.add_account_definitions(coa.id, vec![
+            definition(&coa, "1000", "Assets", AccountCategory::AS, BalanceSide::Dr, None),
+        ]).await?;
+        let (ledger, _) = service.new_ledger(Ledger { id: Uuid::new_v4(), coa: coa.clone() }, vec![]).await?;
+        let account = |code: Option<&str>, category| LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: coa.clone(),
+            balance_side: BalanceSide::Dr,
+            category,
+            code: code.map(str::to_string),
+        };
+
+        // Act
+        let without_code = service.new_ledger_account(account(None, AccountCategory::AS), vec![]).await;
+        let wrong_category = service.new_ledger_account(account(Some("1000"), AccountCategory::EX), vec![]).await;
+        let conforming = service.new_ledger_account(account(Some("1000"), AccountCategory::AS), vec![]).await;
+
+        // Assert
+        assert!(matches!(without_code, Err(ServiceError::NonConformingAccount)));
+        assert!(matches!(wrong_category, Err(ServiceError::NonConformingAccount)));
+        assert_eq!(conforming?.0.code.as_deref(), Some("1000"));
+
+        Ok(())
+    }
+
+    fn coded_account(ledger: &Ledger, code: &str) -> LedgerAccount {
+        LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side: BalanceSide::Cr,
+            category: AccountCategory::RE,
+            code: Some(code.to_string()),
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_find_ledger_accounts_by_code(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let coa = setup_coa(&pool).await?;
+        let service = create_service(pool);
+        let (ledger, _) = service.new_ledger(Ledger { id: Uuid::new_v4(), coa }, vec![]).await?;
+        for code in ["3999", "45", "4000", "4000.10", "4000.10.5", "4999", "4999.1", "5000"] {
+            service.new_ledger_account(coded_account(&ledger, code), vec![]).await?;
+        }
+
+        // Act
+        let revenue = service.find_ledger_account_by_code(&ledger, "4000").await?.unwrap();
+        let sub_account = service.find_ledger_account_by_code(&ledger, "4000.10").await?.unwrap();
+        let nested = service.find_ledger_account_by_code(&ledger, "4000.10.5").await?.unwrap();
+        let missing = service.find_ledger_account_by_code(&ledger, "4001").await?;
+        let range = service.find_ledger_accounts_by_code_range(&ledger, "4000", "4999").await?;
+
+        // Assert
+        assert_eq!(sub_account.parent.map(|p| p.id), Some(revenue.id));
+        assert_eq!(nested.parent.map(|p| p.id), Some(sub_account.id));
+        assert!(missing.is_none());
+        let codes: Vec<_> = range.iter().map(|a| a.code.clone().unwrap()).collect();
+        assert_eq!(codes, vec!["4000", "4000.10", "4000.10.5", "4999", "4999.1"]);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_new_ledger_account_validates_code(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let coa = setup_coa(&pool).await?;
+        let service = create_service(pool.clone());
+        let (ledger, _) = service.new_ledger(Ledger { id: Uuid::new_v4(), coa }, vec![]).await?;
+        service.new_ledger_account(coded_account(&ledger, "1000"), vec![]).await?;
+        let lettered = create_service(pool).with_code_scheme(AccountCodeScheme::new(r"^[A-Z]{2}[0-9]{2}$")?);
+
+        // Act
+        let invalid = service.new_ledger_account(coded_account(&ledger, "Cash"), vec![]).await;
+        let duplicate = service.new_ledger_account(coded_account(&ledger, "1000"), vec![]).await;
+        let custom = lettered.new_ledger_account(coded_account(&ledger, "RE10"), vec![]).await;
+
+        // Assert
+        assert!(matches!(invalid, Err(ServiceError::InvalidAccountCode)));
+        assert!(matches!(duplicate, Err(ServiceError::DuplicateAccountCode)));
+        assert!(custom.is_ok());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,322 @@
+#![cfg(test)]
+
+use std::sync::Arc;
+use async_trait::async_trait;
+use chrono::Utc;
+use uuid::Uuid;
+use postings_api::domain::named::{ContainerType, Named};
+use postings_api::service::named_service::NamedService;
+use postings_db::models::named::{ContainerType as ContainerTypeModel, Named as NamedModel};
+use postings_db::repositories::named_repository::NamedRepository;
+use postings_db::DbError;
+use postings_logic::engine::PostingsEngine;
+
+/// A store whose name lookups also return retired names.
+struct RetiredInLookups {
+    inner: Arc<dyn NamedRepository + Send + Sync>,
+}
+
+#[async_trait]
+impl NamedRepository for RetiredInLookups {
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<NamedModel>, DbError> {
+        self.inner.find_by_id(id).await
+    }
+
+    async fn find_by_container(&self, container_id: Uuid) -> Result<Vec<NamedModel>, DbError> {
+        self.inner.find_by_container(container_id).await
+    }
+
+    async fn find_by_name_and_type(&self, name: &str, container_type: ContainerTypeModel) -> Result<Vec<NamedModel>, DbError> {
+        let mut named = Vec::new();
+        for n in self.inner.find_by_name_and_type(name, container_type).await? {
+            named.extend(self.inner.find_by_container(n.container).await?.into_iter().filter(|m| m.name == name));
+        }
+        Ok(named)
+    }
+
+    async fn find_by_name_and_type_and_context(&self, name: &str, container_type: ContainerTypeModel, context: Uuid) -> Result<Vec<NamedModel>, DbError> {
+        self.inner.find_by_name_and_type_and_context(name, container_type, context).await
+    }
+
+    async fn save(&self, named: NamedModel) -> Result<NamedModel, DbError> {
+        self.inner.save(named).await
+    }
+}
+
+#[tokio::test]
+async fn test_find_by_name_and_type_skips_retired_names() -> anyhow::Result<()> {
+    // Arrange
+    let mut repositories = postings_db_memory::repositories();
+    repositories.named = Arc::new(RetiredInLookups { inner: repositories.named.clone() });
+    let engine = PostingsEngine::builder(repositories).build();
+    let container = Uuid::new_v4();
+    let name = |language: &str| Named {
+        id: Uuid::nil(),
+        container,
+        context: Uuid::nil(),
+        name: "Cash".to_string(),
+        language: language.to_string(),
+        created: Utc::now(),
+        user_details: [0u8; 34],
+        short_desc: None,
+        long_desc: None,
+        container_type: ContainerType::LedgerAccount,
+        retired: None,
+    };
+    let retired = engine.named.add_named(name("en")).await?;
+    let active = engine.named.add_named(name("de")).await?;
+    engine.named.retire_named(retired.id).await?;
+
+    // Act
+    let found = engine.named.find_by_name_and_type("Cash", ContainerType::LedgerAccount).await?;
+
+    // Assert
+    assert_eq!(found.iter().map(|n| n.id).collect::<Vec<_>>(), vec![active.id]);
+
+    Ok(())
+}
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::sync::Arc;
+    use chrono::Utc;
+    use sqlx::PgPool;
+    use uuid::Uuid;
+    use postings_api::domain::named::{ContainerType, Named};
+    use postings_api::service::named_service::NamedService;
+    use postings_api::ServiceError;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_logic::services::named_service::NamedServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_service(pool: PgPool) -> NamedServiceImpl {
+        let shared_service = SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        );
+        NamedServiceImpl::new(shared_service)
+    }
+
+    fn named(container: Uuid, name: &str, language: &str) -> Named {
+        Named {
+            id: Uuid::nil(),
+            container,
+            context: Uuid::new_v4(),
+            name: name.to_string(),
+            language: language.to_string(),
+            created: Utc::now(),
+            user_details: [0u8; 34],
+            short_desc: None,
+            long_desc: None,
+            container_type: ContainerType::LedgerAccount,
+            retired: None,
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_resolve_name_follows_language_chain(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let service = create_service(pool);
+        let container = Uuid::new_v4();
+        let english = service.add_named(named(container, "Cash", "en")).await?;
+        let german = service.add_named(named(container, "Kasse", "de")).await?;
+
+        // Act
+        let preferred = service.resolve_name(container, &["fr".to_string(), "de".to_string()]).await?;
+        let fallback = service.resolve_name(container, &["fr".to_string()]).await?;
+        let all = service.find_by_container_id(container).await?;
+
+        // Assert
+        assert_eq!(preferred.map(|n| n.id), Some(german.id));
+        assert_eq!(fallback.map(|n| n.id), Some(english.id));
+        assert_eq!(all.len(), 2);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_update_and_retire_named(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let service = create_service(pool);
+        let container = Uuid::new_v4();
+        let mut cash = service.add_named(named(container, "Cash", "en")).await?;
+        cash.name = "Petty Cash".to_string();
+
+        // Act
+        let updated = service.update_named(cash).await?;
+        let retired = service.retire_named(updated.id).await?;
+        let second_retire = service.retire_named(updated.id).await;
+
+        // Assert
+        assert_eq!(updated.name, "Petty Cash");
+        assert!(retired.retired.is_some());
+        assert!(matches!(second_retire, Err(ServiceError::NamedNotFound)));
+        assert!(service.find_by_container_id(container).await?.is_empty());
+        assert!(service.find_by_name_and_type("Petty Cash", ContainerType::LedgerAccount).await?.is_empty());
+        assert!(service.resolve_name(container, &["en".to_string()]).await?.is_none());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_add_named_rejects_invalid_language(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let service = create_service(pool);
+
+        // Act
+        let result = service.add_named(named(Uuid::new_v4(), "Cash", "english")).await;
+
+        // Assert
+        assert!(matches!(result, Err(ServiceError::InvalidName)));
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mariadb_tests")]
+mod mariadb_tests {
+    use std::sync::Arc;
+    use chrono::Utc;
+    use sqlx::MySqlPool;
+    use uuid::Uuid;
+    use postings_api::domain::named::{ContainerType, Named};
+    use postings_api::service::named_service::NamedService;
+    use postings_api::ServiceError;
+    use postings_db_mariadb::repositories::account_stmt_repository::MariaDbAccountStmtRepository;
+    use postings_db_mariadb::repositories::chart_of_account_repository::MariaDbChartOfAccountRepository;
+    use postings_db_mariadb::repositories::ledger_account_repository::MariaDbLedgerAccountRepository;
+    use postings_db_mariadb::repositories::ledger_repository::MariaDbLedgerRepository;
+    use postings_db_mariadb::repositories::named_repository::MariaDbNamedRepository;
+    use postings_db_mariadb::repositories::posting_approval_repository::MariaDbPostingApprovalRepository;
+    use postings_db_mariadb::repositories::hold_repository::MariaDbHoldRepository;
+    use postings_db_mariadb::repositories::schedule_repository::MariaDbScheduleRepository;
+    use postings_db_mariadb::repositories::outbox_repository::MariaDbOutboxRepository;
+    use postings_db_mariadb::repositories::posting_line_repository::MariaDbPostingLineRepository;
+    use postings_db_mariadb::repositories::posting_repository::MariaDbPostingRepository;
+    use postings_db_mariadb::repositories::posting_trace_repository::MariaDbPostingTraceRepository;
+    use postings_logic::services::named_service::NamedServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_service(pool: MySqlPool) -> NamedServiceImpl {
+        let shared_service = SharedService::new(
+            Arc::new(MariaDbChartOfAccountRepository::new(pool.clone())),
+            Arc::new(MariaDbLedgerRepository::new(pool.clone())),
+            Arc::new(MariaDbLedgerAccountRepository::new(pool.clone())),
+            Arc::new(MariaDbNamedRepository::new(pool.clone())),
+            Arc::new(MariaDbPostingRepository::new(pool.clone())),
+            Arc::new(MariaDbAccountStmtRepository::new(pool.clone())),
+            Arc::new(MariaDbPostingLineRepository::new(pool.clone())),
+            Arc::new(MariaDbPostingTraceRepository::new(pool.clone())),
+            Arc::new(MariaDbPostingApprovalRepository::new(pool.clone())),
+            Arc::new(MariaDbHoldRepository::new(pool.clone())),
+            Arc::new(MariaDbScheduleRepository::new(pool.clone())),
+            Arc::new(MariaDbOutboxRepository::new(pool)),
+        );
+        NamedServiceImpl::new(shared_service)
+    }
+
+    fn named(container: Uuid, name: &str, language: &str) -> Named {
+        Named {
+            id: Uuid::nil(),
+            container,
+            context: Uuid::new_v4(),
+            name: name.to_string(),
+            language: language.to_string(),
+            created: Utc::now(),
+            user_details: [0u8; 34],
+            short_desc: None,
+            long_desc: None,
+            container_type: ContainerType::Ledger,
+            retired: None,
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-mariadb/migrations")]
+    async fn test_resolve_name_follows_language_chain(pool: MySqlPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.mariadb").ok();
+        // Arrange
+        let service = create_service(pool);
+        let container = Uuid::new_v4();
+        let english = service.add_named(named(container, "Main", "en")).await?;
+        let german = service.add_named(named(container, "Hauptbuch", "de")).await?;
+
+        // Act
+        let preferred = service.resolve_name(container, &["de".to_string(), "en".to_string()]).await?;
+        let fallback = service.resolve_name(container, &[]).await?;
+
+        // Assert
+        assert_eq!(preferred.map(|n| n.id), Some(german.id));
+        assert_eq!(fallback.map(|n| n.id), Some(english.id));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-mariadb/migrations")]
+    async fn test_update_and_retire_named(pool: MySqlPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.mariadb").ok();
+        // Arrange
+        let service = create_service(pool);
+        let container = Uuid::new_v4();
+        let mut main = service.add_named(named(container, "Main", "en")).await?;
+        main.short_desc = Some("Main ledger".to_string());
+
+        // Act
+        let updated = service.update_named(main).await?;
+        service.retire_named(updated.id).await?;
+        let update_retired = service.update_named(updated.clone()).await;
+
+        // Assert
+        assert_eq!(updated.short_desc.as_deref(), Some("Main ledger"));
+        assert!(matches!(update_retired, Err(ServiceError::NamedNotFound)));
+        assert!(service.find_by_container_id(container).await?.is_empty());
+        assert!(service.find_by_name_and_type("Main", ContainerType::Ledger).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-mariadb/migrations")]
+    async fn test_add_named_rejects_duplicate_in_context(pool: MySqlPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.mariadb").ok();
+        // Arrange
+        let service = create_service(pool);
+        let first = service.add_named(named(Uuid::new_v4(), "Main", "en")).await?;
+        let mut duplicate = named(Uuid::new_v4(), "Main", "en");
+        duplicate.context = first.context;
+
+        // Act
+        let result = service.add_named(duplicate.clone()).await;
+        service.retire_named(first.id).await?;
+        let after_retirement = service.add_named(duplicate).await;
+
+        // Assert
+        assert!(matches!(result, Err(ServiceError::DuplicateName)));
+        assert!(after_retirement.is_ok());
+        assert_eq!(service.find_by_container_id(first.container).await?.len(), 0);
+
+        Ok(())
+    }
+}
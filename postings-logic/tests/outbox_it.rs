@@ -0,0 +1,354 @@
+#![cfg(test)]
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+    use async_trait::async_trait;
+    use bigdecimal::BigDecimal;
+    use chrono::Utc;
+    use sqlx::PgPool;
+    use uuid::Uuid;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::account_limit::AccountLimit;
+    use postings_api::domain::balance_side::BalanceSide;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::domain_event::{DomainEvent, DomainEventPayload};
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::domain::posting::Posting;
+    use postings_api::domain::posting_line::PostingLine;
+    use postings_api::domain::posting_status::PostingStatus;
+    use postings_api::domain::posting_type::PostingType;
+    use postings_api::service::account_stmt_service::AccountStmtService;
+    use postings_api::service::chart_of_account_service::ChartOfAccountService;
+    use postings_api::service::ledger_service::LedgerService;
+    use postings_api::service::outbox_service::{DeliveryError, EventSink, OutboxService};
+    use postings_api::service::posting_service::PostingService;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_db::models::account_stmt::AccountStmt;
+    use postings_db::models::stmt_status::StmtStatus;
+    use postings_db::repositories::account_stmt_repository::AccountStmtRepository;
+    use postings_logic::mappers::posting::PostingMapper;
+    use postings_logic::services::account_stmt_service::AccountStmtServiceImpl;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::outbox_service::OutboxServiceImpl;
+    use postings_logic::services::posting_service::PostingServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    struct Services {
+        ledger: LedgerServiceImpl,
+        posting: PostingServiceImpl,
+        stmt: AccountStmtServiceImpl,
+        outbox: OutboxServiceImpl,
+    }
+
+    /// Records delivered events and fails the delivery of one chosen event.
+    #[derive(Default)]
+    struct RecordingSink {
+        delivered: Mutex<Vec<DomainEvent>>,
+        fail_on: Mutex<Option<(Uuid, u64)>>,
+    }
+
+    impl RecordingSink {
+        fn failing_on(ledger_id: Uuid, sequence: u64) -> Self {
+            Self {
+                delivered: Mutex::new(vec![]),
+                fail_on: Mutex::new(Some((ledger_id, sequence))),
+            }
+        }
+
+        fn sequences(&self, ledger_id: Uuid) -> Vec<u64> {
+            self.delivered.lock().unwrap().iter().filter(|e| e.ledger_id == ledger_id).map(|e| e.sequence).collect()
+        }
+    }
+
+    #[async_trait]
+    impl EventSink for RecordingSink {
+        async fn deliver(&self, event: &DomainEvent) -> Result<(), DeliveryError> {
+            if *self.fail_on.lock().unwrap() == Some((event.ledger_id, event.sequence)) {
+                return Err(DeliveryError("broker unavailable".to_string()));
+            }
+            self.delivered.lock().unwrap().push(event.clone());
+            Ok(())
+        }
+    }
+
+    fn create_services(pool: PgPool) -> Services {
+        Services {
+            ledger: LedgerServiceImpl::new(
+                create_shared_service(pool.clone()),
+                ChartOfAccountServiceImpl::new(create_shared_service(pool.clone())),
+            ),
+            posting: PostingServiceImpl::new(create_shared_service(pool.clone())),
+            stmt: AccountStmtServiceImpl::new(create_shared_service(pool.clone())),
+            outbox: OutboxServiceImpl::new(create_shared_service(pool)),
+        }
+    }
+
+    async fn setup_ledger(pool: &PgPool, services: &Services) -> anyhow::Result<Ledger> {
+        let coa_service = ChartOfAccountServiceImpl::new(create_shared_service(pool.clone()));
+        let (coa, _) = coa_service.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+        let (ledger, _) = services.ledger.new_ledger(Ledger { id: Uuid::new_v4(), coa }, vec![]).await?;
+        Ok(ledger)
+    }
+
+    async fn setup_account(services: &Services, ledger: &Ledger, balance_side: BalanceSide, category: AccountCategory) -> anyhow::Result<LedgerAccount> {
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side,
+            category,
+            code: None,
+        };
+        Ok(services.ledger.new_ledger_account(account, vec![]).await?.0)
+    }
+
+    fn line(account: &LedgerAccount, debit: i64, credit: i64) -> PostingLine {
+        PostingLine {
+            id: Uuid::new_v4(),
+            account: account.clone(),
+            debit_amount: BigDecimal::from(debit),
+            credit_amount: BigDecimal::from(credit),
+            details: None,
+            src_account: None,
+            base_line: None,
+            sub_opr_src_id: None,
+            record_time: Utc::now(),
+            opr_id: [0; 34],
+            opr_src: None,
+            pst_time: Utc::now(),
+            val_time: None,
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            hash: Some([0; 34]),
+            additional_information: None,
+            discarded_time: None,
+        }
+    }
+
+    /// Moves `amount` from `credited` to `debited`.
+    fn transfer(debited: &LedgerAccount, credited: &LedgerAccount, amount: i64) -> Posting {
+        let mut opr_id = [0u8; 34];
+        opr_id[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+        Posting {
+            id: Uuid::nil(),
+            record_user: [0; 34],
+            record_time: Utc::now(),
+            opr_id,
+            opr_time: Utc::now(),
+            opr_type: [0; 34],
+            opr_details: None,
+            opr_src: None,
+            pst_time: Utc::now(),
+            pst_type: PostingType::BusiTx,
+            pst_status: PostingStatus::Posted,
+            ledger: debited.ledger.clone(),
+            val_time: None,
+            lines: vec![line(debited, amount, 0), line(credited, 0, amount)],
+            discarded_id: None,
+            discarded_time: None,
+            discarding_id: None,
+            hash_record: Default::default(),
+        }
+    }
+
+    fn event_types(events: &[DomainEvent]) -> Vec<&'static str> {
+        events
+            .iter()
+            .map(|e| match e.payload {
+                DomainEventPayload::LedgerCreated { .. } => "LedgerCreated",
+                DomainEventPayload::LedgerAccountCreated { .. } => "LedgerAccountCreated",
+                DomainEventPayload::AccountStateChanged { .. } => "AccountStateChanged",
+                DomainEventPayload::PostingCreated { .. } => "PostingCreated",
+                DomainEventPayload::PostingStatusChanged { .. } => "PostingStatusChanged",
+                DomainEventPayload::PostingDiscarded { .. } => "PostingDiscarded",
+                DomainEventPayload::StatementClosed { .. } => "StatementClosed",
+            })
+            .collect()
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_changes_write_events_in_ledger_order(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+
+        // Act
+        let posting = services.posting.new_posting(transfer(&bank, &wallet, 100)).await?;
+        let stmt = services.stmt.create_stmt(wallet.clone(), Utc::now()).await?;
+        let stmt = services.stmt.close_stmt(stmt).await?;
+
+        // Assert
+        let events = services.outbox.find_ledger_events(ledger.id, 0, 100).await?;
+        let types = event_types(&events);
+        assert_eq!(types[..4], ["LedgerCreated", "LedgerAccountCreated", "LedgerAccountCreated", "PostingCreated"]);
+        assert_eq!(types.last(), Some(&"StatementClosed"));
+        assert_eq!(events.iter().map(|e| e.sequence).collect::<Vec<_>>(), (1..=events.len() as u64).collect::<Vec<_>>());
+        assert!(matches!(events[3].payload, DomainEventPayload::PostingCreated { posting_id, .. } if posting_id == posting.id));
+        assert!(matches!(events.last().unwrap().payload, DomainEventPayload::StatementClosed { stmt_id, account_id, .. }
+            if stmt_id == stmt.financial_stmt.id && account_id == wallet.id));
+        assert!(events.iter().all(|e| e.dispatched_at.is_none()));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_failed_delivery_holds_back_only_its_ledger(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let blocked = setup_ledger(&pool, &services).await?;
+        let healthy = setup_ledger(&pool, &services).await?;
+        for ledger in [&blocked, &healthy] {
+            let bank = setup_account(&services, ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+            let wallet = setup_account(&services, ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+            services.posting.new_posting(transfer(&bank, &wallet, 100)).await?;
+        }
+        let failing_sink = RecordingSink::failing_on(blocked.id, 2);
+        let sink = RecordingSink::default();
+
+        // Act
+        let first = services.outbox.relay(&failing_sink, 100).await?;
+        let second = services.outbox.relay(&sink, 100).await?;
+        let third = services.outbox.relay(&sink, 100).await?;
+
+        // Assert
+        assert_eq!(first.blocked_ledgers, vec![blocked.id]);
+        assert_eq!(failing_sink.sequences(blocked.id), vec![1]);
+        assert_eq!(failing_sink.sequences(healthy.id), vec![1, 2, 3, 4]);
+        assert_eq!(first.delivered, 5);
+        assert!(second.blocked_ledgers.is_empty());
+        assert_eq!(sink.sequences(blocked.id), vec![2, 3, 4]);
+        assert!(sink.sequences(healthy.id).is_empty());
+        assert_eq!(third.delivered, 0);
+        assert!(services.outbox.find_pending_events(100).await?.is_empty());
+        assert!(services.outbox.find_ledger_events(blocked.id, 0, 100).await?.iter().all(|e| e.dispatched_at.is_some()));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_blocked_ledger_backlog_does_not_starve_others(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let blocked = setup_ledger(&pool, &services).await?;
+        setup_account(&services, &blocked, BalanceSide::Dr, AccountCategory::AS).await?;
+        setup_account(&services, &blocked, BalanceSide::Cr, AccountCategory::LI).await?;
+        let healthy = setup_ledger(&pool, &services).await?;
+        setup_account(&services, &healthy, BalanceSide::Dr, AccountCategory::AS).await?;
+        let failing_sink = RecordingSink::failing_on(blocked.id, 1);
+
+        // Act
+        let first = services.outbox.relay(&failing_sink, 2).await?;
+        let second = services.outbox.relay(&failing_sink, 2).await?;
+
+        // Assert
+        assert_eq!(first.blocked_ledgers, vec![blocked.id]);
+        assert_eq!(second.blocked_ledgers, vec![blocked.id]);
+        assert!(failing_sink.sequences(blocked.id).is_empty());
+        assert_eq!(failing_sink.sequences(healthy.id), vec![1, 2]);
+        assert_eq!(first.delivered + second.delivered, 2);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_failed_statement_close_keeps_no_closing_posting(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let mut closing = transfer(&bank, &wallet, 0);
+        closing.id = Uuid::new_v4();
+        closing.lines.clear();
+        let posting = PostingMapper::to_model(closing);
+        // The statement's account does not exist, so saving it fails after the posting is inserted.
+        let stmt = AccountStmt {
+            id: Uuid::new_v4(),
+            account_id: Uuid::new_v4(),
+            youngest_pst_id: None,
+            total_debit: BigDecimal::from(0),
+            total_credit: BigDecimal::from(0),
+            posting_id: Some(posting.id),
+            pst_time: Utc::now(),
+            stmt_status: StmtStatus::Closed,
+            latest_pst_id: None,
+            stmt_seq_nbr: 0,
+        };
+        let repo = PostgresAccountStmtRepository::new(pool.clone());
+
+        // Act
+        let result = repo.close(stmt, &posting, Utc::now()).await;
+
+        // Assert
+        assert!(result.is_err());
+        let postings: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM posting WHERE id = $1").bind(posting.id).fetch_one(&pool).await?;
+        assert_eq!(postings, 0);
+        let events = services.outbox.find_ledger_events(ledger.id, 0, 100).await?;
+        assert!(!event_types(&events).contains(&"PostingCreated"));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_rejected_posting_writes_no_event(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let bank = setup_account(&services, &ledger, BalanceSide::Dr, AccountCategory::AS).await?;
+        let wallet = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        services
+            .ledger
+            .set_account_limit(AccountLimit { account_id: wallet.id, min_balance: Some(BigDecimal::from_str("0")?), max_balance: None })
+            .await?;
+        let before = services.outbox.find_ledger_events(ledger.id, 0, 100).await?.len();
+
+        // Act
+        let overdraft = services.posting.new_posting(transfer(&wallet, &bank, 50)).await;
+
+        // Assert
+        assert!(overdraft.is_err());
+        assert_eq!(services.outbox.find_ledger_events(ledger.id, 0, 100).await?.len(), before);
+
+        Ok(())
+    }
+}
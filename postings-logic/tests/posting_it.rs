@@ -6,9 +6,13 @@ mod postgres_tests {
     use sqlx::{PgPool, Type};
     use postings_logic::services::posting_service::PostingServiceImpl;
     use postings_api::service::posting_service::PostingService;
+    use postings_api::ServiceError;
     use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
     use postings_logic::services::shared_service::SharedService;
+    use postings_api::domain::approval_policy::ApprovalPolicy;
+    use postings_api::domain::hash_chain::ChainBreak;
     use postings_api::domain::posting::Posting;
+    use postings_api::domain::posting_status::PostingStatus;
     use postings_api::domain::ledger::Ledger;
     use postings_api::domain::chart_of_account::ChartOfAccount;
     use uuid::Uuid;
@@ -24,7 +28,13 @@ mod postgres_tests {
     use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
     use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
     use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
     use postings_db::repositories::posting_line_repository::PostingLineRepository;
+    use postings_logic::clock::FixedClock;
+    use postings_logic::id_generator::SequentialIdGenerator;
 
     #[derive(Type)]
     #[sqlx(type_name = "balance_side")]
@@ -55,6 +65,7 @@ mod postgres_tests {
             coa: ledger.coa.clone(),
             balance_side,
             category,
+            code: None,
         };
         sqlx::query("INSERT INTO ledger_account (id, ledger_id, parent_id, coa_id, balance_side, category) VALUES ($1, $2, $3, $4, $5, $6)")
             .bind(ledger_account.id)
@@ -108,6 +119,10 @@ mod postgres_tests {
     }
 
     fn create_service(pool: PgPool) -> PostingServiceImpl {
+        PostingServiceImpl::new(create_shared_service(pool))
+    }
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
         let posting_repo = Arc::new(PostgresPostingRepository::new(pool.clone()));
         let ledger_repo = Arc::new(PostgresLedgerRepository::new(pool.clone()));
         let coa_repo = Arc::new(PostgresChartOfAccountRepository::new(pool.clone()));
@@ -116,8 +131,12 @@ mod postgres_tests {
         let stmt_repo = Arc::new(PostgresAccountStmtRepository::new(pool.clone()));
         let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(PostgresPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(PostgresPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(PostgresHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(PostgresScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(PostgresOutboxRepository::new(pool.clone()));
 
-        let shared_service = SharedService::new(
+        SharedService::new(
             coa_repo,
             ledger_repo,
             ledger_account_repo,
@@ -126,8 +145,11 @@ mod postgres_tests {
             stmt_repo,
             line_repo,
             trace_repo,
-        );
-        PostingServiceImpl::new(shared_service)
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
+        )
     }
 
     struct TestContext {
@@ -146,6 +168,10 @@ mod postgres_tests {
         let named_repo = Arc::new(PostgresNamedRepository::new(pool.clone()));
         let stmt_repo = Arc::new(PostgresAccountStmtRepository::new(pool.clone()));
         let trace_repo = Arc::new(PostgresPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(PostgresPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(PostgresHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(PostgresScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(PostgresOutboxRepository::new(pool.clone()));
 
         let shared_service = SharedService::new(
             coa_repo,
@@ -156,6 +182,10 @@ mod postgres_tests {
             stmt_repo,
             line_repo.clone(),
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
         );
         let service = PostingServiceImpl::new(shared_service);
 
@@ -197,6 +227,7 @@ mod postgres_tests {
                     opr_id: [0; 34],
                     opr_src: None,
                     pst_time: chrono::Utc::now(),
+                    val_time: None,
                     pst_type: postings_api::domain::posting_type::PostingType::BusiTx,
                     pst_status: postings_api::domain::posting_status::PostingStatus::Posted,
                     hash: Some([0; 34]),
@@ -216,6 +247,7 @@ mod postgres_tests {
                     opr_id: [0; 34],
                     opr_src: None,
                     pst_time: chrono::Utc::now(),
+                    val_time: None,
                     pst_type: postings_api::domain::posting_type::PostingType::BusiTx,
                     pst_status: postings_api::domain::posting_status::PostingStatus::Posted,
                     hash: Some([0; 34]),
@@ -247,6 +279,397 @@ mod postgres_tests {
         
         Ok(())
     }
+
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_new_posting_with_injected_clock_and_ids(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let start = chrono::DateTime::parse_from_rfc3339("2025-06-01T12:00:00Z")?.with_timezone(&chrono::Utc);
+        let clock = Arc::new(FixedClock::new(start));
+        let service = PostingServiceImpl::new(
+            create_shared_service(pool.clone())
+                .with_clock(clock.clone())
+                .with_id_generator(Arc::new(SequentialIdGenerator::default())),
+        );
+        let first = create_test_posting(&pool, ledger.clone(), 100, 100).await?;
+        let second = create_test_posting(&pool, ledger, 50, 50).await?;
+
+        // Act
+        let first = service.new_posting(first).await?;
+        clock.advance(chrono::Duration::hours(1));
+        let second = service.new_posting(second).await?;
+
+        // Assert
+        assert_eq!(first.id, Uuid::from_u128(1));
+        assert_eq!(first.record_time, start);
+        assert_eq!(second.id, Uuid::from_u128(2));
+        assert_eq!(second.record_time, start + chrono::Duration::hours(1));
+        assert_eq!(second.hash_record.antecedent_id, Some(first.id));
+        let stored: chrono::DateTime<chrono::Utc> = sqlx::query_scalar("SELECT record_time FROM posting WHERE id = $1")
+            .bind(second.id)
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(stored, second.record_time);
+
+        Ok(())
+    }
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_new_postings_chains_batch_in_order(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
+        let context = create_test_context(pool.clone(), line_repo.clone());
+        let first = context.service.new_posting(create_test_posting(&pool, ledger.clone(), 50, 50).await?).await?;
+        let mut batch = Vec::new();
+        for amount in [100, 200, 300] {
+            batch.push(create_test_posting(&pool, ledger.clone(), amount, amount).await?);
+        }
+        let line_ids: Vec<Uuid> = batch.iter().flat_map(|p| p.lines.iter().map(|l| l.id)).collect();
+
+        // Act
+        let result = context.service.new_postings(batch).await?;
+
+        // Assert
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].hash_record.antecedent_id, Some(first.id));
+        assert_eq!(result[0].hash_record.antecedent_hash, first.hash_record.hash);
+        for pair in result.windows(2) {
+            assert_eq!(pair[1].hash_record.antecedent_id, Some(pair[0].id));
+            assert_eq!(pair[1].hash_record.antecedent_hash, pair[0].hash_record.hash);
+            assert!(pair[1].record_time > pair[0].record_time);
+        }
+        for line_id in line_ids {
+            assert!(context.posting_line_repo.find_by_id(line_id).await?.is_some());
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_new_posting_after_batch_links_to_batch_head(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let start = chrono::DateTime::parse_from_rfc3339("2025-06-01T12:00:00Z")?.with_timezone(&chrono::Utc);
+        let service = PostingServiceImpl::new(create_shared_service(pool.clone()).with_clock(Arc::new(FixedClock::new(start))));
+        let mut batch = Vec::new();
+        for amount in [100, 200, 300] {
+            batch.push(create_test_posting(&pool, ledger.clone(), amount, amount).await?);
+        }
+        let batch = service.new_postings(batch).await?;
+
+        // Act
+        let single = service.new_posting(create_test_posting(&pool, ledger.clone(), 50, 50).await?).await?;
+        let next = service.new_posting(create_test_posting(&pool, ledger.clone(), 60, 60).await?).await?;
+
+        // Assert
+        let head = batch.last().unwrap();
+        assert_eq!(single.hash_record.antecedent_id, Some(head.id));
+        assert!(single.record_time > head.record_time);
+        assert_eq!(next.hash_record.antecedent_id, Some(single.id));
+        let verification = service.verify_hash_chain(ledger.id).await?;
+        assert!(verification.breaks.is_empty(), "{:?}", verification.breaks);
+        assert_eq!(verification.head, Some(next.id));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_new_postings_unbalanced_batch_persists_nothing(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
+        let context = create_test_context(pool.clone(), line_repo.clone());
+        let balanced = create_test_posting(&pool, ledger.clone(), 100, 100).await?;
+        let unbalanced = create_test_posting(&pool, ledger, 100, 99).await?;
+        let line_ids: Vec<Uuid> = balanced.lines.iter().map(|l| l.id).collect();
+
+        // Act
+        let result = context.service.new_postings(vec![balanced, unbalanced]).await;
+
+        // Assert
+        assert!(matches!(result, Err(ServiceError::DoubleEntry)));
+        for line_id in line_ids {
+            assert!(context.posting_line_repo.find_by_id(line_id).await?.is_none());
+        }
+
+        Ok(())
+    }
+
+    fn with_status(mut posting: Posting, pst_status: PostingStatus) -> Posting {
+        // A distinct operation id keeps the lines of each posting apart.
+        posting.opr_id[..16].copy_from_slice(Uuid::new_v4().as_bytes());
+        posting.pst_status = pst_status;
+        posting
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_proposed_posting_is_chained_on_approval(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
+        let context = create_test_context(pool.clone(), line_repo.clone());
+        let proposed = with_status(create_test_posting(&pool, ledger.clone(), 100, 100).await?, PostingStatus::Proposed);
+        let proposed = context.service.new_posting(proposed).await?;
+        let posted = context.service.new_posting(create_test_posting(&pool, ledger, 50, 50).await?).await?;
+
+        // Act
+        let approved = context.service.approve_posting(proposed.id, [9; 34]).await?;
+
+        // Assert
+        assert_eq!(proposed.hash_record.hash, None);
+        assert_eq!(posted.hash_record.antecedent_id, None);
+        assert_eq!(approved.pst_status, PostingStatus::Posted);
+        assert_eq!(approved.lines.len(), 2);
+        assert_eq!(approved.hash_record.antecedent_id, Some(posted.id));
+        assert_eq!(approved.hash_record.antecedent_hash, posted.hash_record.hash);
+        assert!(approved.hash_record.hash.is_some());
+        let record_time: chrono::DateTime<chrono::Utc> = sqlx::query_scalar("SELECT record_time FROM posting WHERE id = $1")
+            .bind(approved.id)
+            .fetch_one(&pool)
+            .await?;
+        for line in approved.lines {
+            let stored = context.posting_line_repo.find_by_id(line.id).await?.unwrap();
+            assert_eq!(stored.pst_status, postings_db::models::posting_status::PostingStatus::Posted);
+            assert_eq!(stored.hash, approved.hash_record.hash);
+            assert_eq!(stored.record_time, record_time);
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_approval_moves_only_lines_of_the_posting_sharing_its_operation(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let line_repo = Arc::new(PostgresPostingLineRepository::new(pool.clone()));
+        let context = create_test_context(pool.clone(), line_repo.clone());
+        let first = with_status(create_test_posting(&pool, ledger.clone(), 100, 100).await?, PostingStatus::Proposed);
+        let mut second = with_status(create_test_posting(&pool, ledger, 50, 50).await?, PostingStatus::Proposed);
+        second.opr_id = first.opr_id;
+        let first = context.service.new_posting(first).await?;
+        let second = context.service.new_posting(second).await?;
+
+        // Act
+        let approved = context.service.approve_posting(first.id, [9; 34]).await?;
+
+        // Assert
+        let approved_lines: Vec<Uuid> = approved.lines.iter().map(|l| l.id).collect();
+        assert_eq!(approved_lines, first.lines.iter().map(|l| l.id).collect::<Vec<_>>());
+        for line in second.lines {
+            let stored = context.posting_line_repo.find_by_id(line.id).await?.unwrap();
+            assert_eq!(stored.pst_status, postings_db::models::posting_status::PostingStatus::Proposed);
+            assert_eq!(stored.posting_id, Some(second.id));
+        }
+        let pending = context.service.find_pending_postings(approved.ledger.id).await?;
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, second.id);
+        assert_eq!(pending[0].lines.len(), 2);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_concurrent_approvals_post_once(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let service = create_service(pool.clone());
+        service.new_posting(create_test_posting(&pool, ledger.clone(), 50, 50).await?).await?;
+        let proposed = with_status(create_test_posting(&pool, ledger.clone(), 100, 100).await?, PostingStatus::Proposed);
+        let proposed = service.new_posting(proposed).await?;
+
+        // Act
+        let (first, second) = tokio::join!(service.approve_posting(proposed.id, [8; 34]), service.approve_posting(proposed.id, [9; 34]));
+
+        // Assert
+        assert_eq!([&first, &second].iter().filter(|r| r.is_ok()).count(), 1);
+        assert!([first, second].into_iter().any(|r| matches!(r, Err(ServiceError::InvalidStatusTransition))));
+        assert_eq!(service.find_posting_approvals(proposed.id).await?.len(), 1);
+        assert!(service.verify_hash_chain(ledger.id).await?.is_intact());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_deferred_posting_cannot_be_approved(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let service = create_service(pool.clone());
+        let mut deferred = with_status(create_test_posting(&pool, ledger, 100, 100).await?, PostingStatus::Deferred);
+        deferred.val_time = Some(chrono::Utc::now() + chrono::Duration::days(1));
+        let deferred = service.new_posting(deferred).await?;
+
+        // Act
+        let approved = service.approve_posting(deferred.id, [9; 34]).await;
+
+        // Assert
+        assert!(matches!(approved, Err(ServiceError::InvalidStatusTransition)));
+        assert!(service.find_posting_approvals(deferred.id).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_approved_deferred_posting_waits_for_value_time(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let service = create_service(pool.clone());
+        service.set_approval_policy(ApprovalPolicy { ledger: ledger.clone(), amount_threshold: None }).await?;
+        let now = chrono::Utc::now();
+        let mut approved = with_status(create_test_posting(&pool, ledger.clone(), 100, 100).await?, PostingStatus::Deferred);
+        approved.val_time = Some(now + chrono::Duration::days(1));
+        let mut unapproved = with_status(create_test_posting(&pool, ledger.clone(), 100, 100).await?, PostingStatus::Deferred);
+        unapproved.val_time = Some(now - chrono::Duration::hours(1));
+        let approved = service.new_posting(approved).await?;
+        let unapproved = service.new_posting(unapproved).await?;
+
+        // Act
+        let approval = service.approve_posting(approved.id, [9; 34]).await?;
+        let posted_before = service.post_deferred_postings(now).await?;
+        let posted_when_due = service.post_deferred_postings(now + chrono::Duration::days(2)).await?;
+
+        // Assert
+        assert_eq!(approved.pst_status, PostingStatus::Deferred);
+        assert_eq!(approval.pst_status, PostingStatus::Deferred);
+        assert_eq!(service.find_posting_approvals(approved.id).await?.len(), 1);
+        assert!(posted_before.is_empty());
+        assert_eq!(posted_when_due.len(), 1);
+        assert_eq!(posted_when_due[0].id, approved.id);
+        assert_eq!(posted_when_due[0].pst_status, PostingStatus::Posted);
+        let status: String = sqlx::query_scalar("SELECT pst_status::text FROM posting WHERE id = $1").bind(unapproved.id).fetch_one(&pool).await?;
+        assert_eq!(status, "DEFERRED");
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_cancel_posted_posting_is_rejected(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let service = create_service(pool.clone());
+        let posted = service.new_posting(create_test_posting(&pool, ledger.clone(), 100, 100).await?).await?;
+        let proposed = with_status(create_test_posting(&pool, ledger, 100, 100).await?, PostingStatus::Proposed);
+        let proposed = service.new_posting(proposed).await?;
+
+        // Act
+        let rejected = service.cancel_posting(posted.id).await;
+        let cancelled = service.cancel_posting(proposed.id).await?;
+        let reapproved = service.approve_posting(proposed.id, [9; 34]).await;
+
+        // Assert
+        assert!(matches!(rejected, Err(ServiceError::InvalidStatusTransition)));
+        assert_eq!(cancelled.pst_status, PostingStatus::Cancelled);
+        assert_eq!(cancelled.hash_record.hash, None);
+        assert!(matches!(reapproved, Err(ServiceError::InvalidStatusTransition)));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_post_deferred_postings_posts_due_postings_only(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let service = create_service(pool.clone());
+        let now = chrono::Utc::now();
+        let mut due = with_status(create_test_posting(&pool, ledger.clone(), 100, 100).await?, PostingStatus::Deferred);
+        due.val_time = Some(now - chrono::Duration::hours(1));
+        let mut later = with_status(create_test_posting(&pool, ledger.clone(), 100, 100).await?, PostingStatus::Deferred);
+        later.val_time = Some(now + chrono::Duration::days(1));
+        let due = service.new_posting(due).await?;
+        service.new_posting(later).await?;
+        let missing_val_time = with_status(create_test_posting(&pool, ledger, 100, 100).await?, PostingStatus::Deferred);
+
+        // Act
+        let posted = service.post_deferred_postings(now).await?;
+        let rejected = service.new_posting(missing_val_time).await;
+
+        // Assert
+        assert_eq!(posted.len(), 1);
+        assert_eq!(posted[0].id, due.id);
+        assert_eq!(posted[0].pst_status, PostingStatus::Posted);
+        assert!(posted[0].hash_record.hash.is_some());
+        assert!(matches!(rejected, Err(ServiceError::NotEnoughInfo)));
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_approval_policy_requires_second_user_above_threshold(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let service = create_service(pool.clone());
+        service.set_approval_policy(ApprovalPolicy { ledger: ledger.clone(), amount_threshold: Some(BigDecimal::from(1000)) }).await?;
+        let small = with_status(create_test_posting(&pool, ledger.clone(), 500, 500).await?, PostingStatus::Posted);
+        let large = with_status(create_test_posting(&pool, ledger.clone(), 1500, 1500).await?, PostingStatus::Posted);
+        let small = service.new_posting(small).await?;
+        let large = service.new_posting(large).await?;
+        let pending = service.find_pending_postings(ledger.id).await?;
+
+        // Act
+        let self_approved = service.approve_posting(large.id, large.record_user).await;
+        let approved = service.approve_posting(large.id, [9; 34]).await?;
+
+        // Assert
+        assert_eq!(small.pst_status, PostingStatus::Posted);
+        assert_eq!(large.pst_status, PostingStatus::Proposed);
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, large.id);
+        assert!(matches!(self_approved, Err(ServiceError::SelfApproval)));
+        assert_eq!(approved.pst_status, PostingStatus::Posted);
+        assert_eq!(approved.hash_record.antecedent_id, Some(small.id));
+        let approvals = service.find_posting_approvals(large.id).await?;
+        assert_eq!(approvals.len(), 1);
+        assert_eq!(approvals[0].approver, [9; 34]);
+        assert!(service.find_pending_postings(ledger.id).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_verify_hash_chain_reports_tampered_link(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let service = create_service(pool.clone());
+        let mut postings = Vec::new();
+        for (i, amount) in [100, 200, 300].into_iter().enumerate() {
+            let mut posting = create_test_posting(&pool, ledger.clone(), amount, amount).await?;
+            posting.opr_id = [i as u8 + 1; 34];
+            postings.push(service.new_posting(posting).await?);
+        }
+
+        // Act
+        let intact = service.verify_hash_chain(ledger.id).await?;
+        let listed = service.find_postings_by_ledger(ledger.id, chrono::DateTime::UNIX_EPOCH, chrono::Utc::now()).await?;
+        sqlx::query("UPDATE posting SET antecedent_hash = $1 WHERE id = $2")
+            .bind(vec![7u8; 34])
+            .bind(postings[2].id)
+            .execute(&pool)
+            .await?;
+        let tampered = service.verify_hash_chain(ledger.id).await?;
+
+        // Assert
+        assert!(intact.is_intact());
+        assert_eq!(intact.postings, 3);
+        assert_eq!(intact.head, Some(postings[2].id));
+        assert_eq!(listed.len(), 3);
+        assert!(listed.iter().all(|p| p.lines.len() == 2));
+        assert_eq!(tampered.breaks, vec![ChainBreak::HashMismatch { posting_id: postings[2].id, antecedent_id: postings[1].id }]);
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "mariadb_tests")]
@@ -259,6 +682,7 @@ mod mariadb_tests {
     use postings_db_mariadb::repositories::posting_repository::MariaDbPostingRepository;
     use postings_logic::services::shared_service::SharedService;
     use postings_api::domain::posting::Posting;
+    use postings_api::domain::posting_status::PostingStatus;
     use postings_api::domain::ledger::Ledger;
     use postings_api::domain::chart_of_account::ChartOfAccount;
     use uuid::Uuid;
@@ -273,6 +697,10 @@ mod mariadb_tests {
     use postings_db_mariadb::repositories::account_stmt_repository::MariaDbAccountStmtRepository;
     use postings_db_mariadb::repositories::posting_line_repository::MariaDbPostingLineRepository;
     use postings_db_mariadb::repositories::posting_trace_repository::MariaDbPostingTraceRepository;
+    use postings_db_mariadb::repositories::posting_approval_repository::MariaDbPostingApprovalRepository;
+    use postings_db_mariadb::repositories::hold_repository::MariaDbHoldRepository;
+    use postings_db_mariadb::repositories::schedule_repository::MariaDbScheduleRepository;
+    use postings_db_mariadb::repositories::outbox_repository::MariaDbOutboxRepository;
     use postings_db_mariadb::repositories::named_repository::MariaDbNamedRepository;
     use postings_db::repositories::posting_line_repository::PostingLineRepository;
 
@@ -285,6 +713,7 @@ mod mariadb_tests {
             coa: ledger.coa.clone(),
             balance_side,
             category,
+            code: None,
         };
         
         // Insert into simplified ledger_account table
@@ -399,6 +828,10 @@ mod mariadb_tests {
         let stmt_repo = Arc::new(MariaDbAccountStmtRepository::new(pool.clone()));
         let line_repo = Arc::new(MariaDbPostingLineRepository::new(pool.clone()));
         let trace_repo = Arc::new(MariaDbPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(MariaDbPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(MariaDbHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(MariaDbScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(MariaDbOutboxRepository::new(pool.clone()));
 
         let shared_service = SharedService::new(
             coa_repo,
@@ -409,6 +842,10 @@ mod mariadb_tests {
             stmt_repo,
             line_repo,
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
         );
         PostingServiceImpl::new(shared_service)
     }
@@ -429,6 +866,10 @@ mod mariadb_tests {
         let named_repo = Arc::new(MariaDbNamedRepository::new(pool.clone()));
         let stmt_repo = Arc::new(MariaDbAccountStmtRepository::new(pool.clone()));
         let trace_repo = Arc::new(MariaDbPostingTraceRepository::new(pool.clone()));
+        let approval_repo = Arc::new(MariaDbPostingApprovalRepository::new(pool.clone()));
+        let hold_repo = Arc::new(MariaDbHoldRepository::new(pool.clone()));
+        let schedule_repo = Arc::new(MariaDbScheduleRepository::new(pool.clone()));
+        let outbox_repo = Arc::new(MariaDbOutboxRepository::new(pool.clone()));
 
         let shared_service = SharedService::new(
             coa_repo,
@@ -439,6 +880,10 @@ mod mariadb_tests {
             stmt_repo,
             line_repo.clone(),
             trace_repo,
+            approval_repo,
+            hold_repo,
+            schedule_repo,
+            outbox_repo,
         );
         let service = PostingServiceImpl::new(shared_service);
 
@@ -480,6 +925,7 @@ mod mariadb_tests {
                     opr_id: [5; 34],
                     opr_src: None,
                     pst_time: chrono::Utc::now(),
+                    val_time: None,
                     pst_type: postings_api::domain::posting_type::PostingType::BusiTx,
                     pst_status: postings_api::domain::posting_status::PostingStatus::Posted,
                     hash: Some([1; 34]),
@@ -499,6 +945,7 @@ mod mariadb_tests {
                     opr_id: [7; 34],
                     opr_src: None,
                     pst_time: chrono::Utc::now(),
+                    val_time: None,
                     pst_type: postings_api::domain::posting_type::PostingType::BusiTx,
                     pst_status: postings_api::domain::posting_status::PostingStatus::Posted,
                     hash: Some([2; 34]),
@@ -627,4 +1074,65 @@ mod mariadb_tests {
         
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[sqlx::test(migrations = "../postings-db-mariadb/migrations")]
+    async fn test_new_postings_chains_batch_in_order(pool: MySqlPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.mariadb").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let line_repo = Arc::new(MariaDbPostingLineRepository::new(pool.clone()));
+        let context = create_test_context(pool.clone(), line_repo.clone());
+        let mut batch = Vec::new();
+        for amount in [100, 200, 300] {
+            batch.push(create_test_posting(&pool, ledger.clone(), amount, amount).await?);
+        }
+        let line_ids: Vec<Uuid> = batch.iter().flat_map(|p| p.lines.iter().map(|l| l.id)).collect();
+
+        // Act
+        let result = context.service.new_postings(batch).await?;
+
+        // Assert
+        assert_eq!(result.len(), 3);
+        assert!(result[0].hash_record.antecedent_id.is_none());
+        for pair in result.windows(2) {
+            assert_eq!(pair[1].hash_record.antecedent_id, Some(pair[0].id));
+            assert_eq!(pair[1].hash_record.antecedent_hash, pair[0].hash_record.hash);
+        }
+        for line_id in line_ids {
+            assert!(context.posting_line_repo.find_by_id(line_id).await?.is_some());
+        }
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-mariadb/migrations")]
+    async fn test_proposed_posting_is_chained_on_approval(pool: MySqlPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.mariadb").ok();
+        // Arrange
+        let ledger = setup_ledger(&pool).await?;
+        let line_repo = Arc::new(MariaDbPostingLineRepository::new(pool.clone()));
+        let context = create_test_context(pool.clone(), line_repo.clone());
+        let mut proposed = create_test_posting(&pool, ledger.clone(), 100, 100).await?;
+        proposed.opr_id = [4; 34];
+        proposed.pst_status = PostingStatus::Proposed;
+        let proposed = context.service.new_posting(proposed).await?;
+        let posted = context.service.new_posting(create_test_posting(&pool, ledger, 50, 50).await?).await?;
+
+        // Act
+        let approved = context.service.approve_posting(proposed.id, [9; 34]).await?;
+        let rejected = context.service.cancel_posting(proposed.id).await;
+
+        // Assert
+        assert_eq!(proposed.hash_record.hash, None);
+        assert_eq!(approved.pst_status, PostingStatus::Posted);
+        assert_eq!(approved.lines.len(), 2);
+        assert_eq!(approved.hash_record.antecedent_id, Some(posted.id));
+        assert!(matches!(rejected, Err(ServiceError::InvalidStatusTransition)));
+        for line in approved.lines {
+            let stored = context.posting_line_repo.find_by_id(line.id).await?.unwrap();
+            assert_eq!(stored.pst_status, postings_db::models::posting_status::PostingStatus::Posted);
+        }
+
+        Ok(())
+    }
+}
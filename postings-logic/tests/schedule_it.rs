@@ -0,0 +1,277 @@
+#![cfg(test)]
+
+#[cfg(feature = "postgres_tests")]
+mod postgres_tests {
+    use std::sync::Arc;
+    use bigdecimal::BigDecimal;
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+    use sqlx::PgPool;
+    use uuid::Uuid;
+    use postings_api::domain::account_category::AccountCategory;
+    use postings_api::domain::account_limit::AccountLimit;
+    use postings_api::domain::balance_side::BalanceSide;
+    use postings_api::domain::chart_of_account::ChartOfAccount;
+    use postings_api::domain::ledger::Ledger;
+    use postings_api::domain::ledger_account::LedgerAccount;
+    use postings_api::domain::posting_schedule::{CatchUp, PostingSchedule, ScheduleStatus, ScheduledLine};
+    use postings_api::domain::posting_type::PostingType;
+    use postings_api::domain::recurrence::{Frequency, Recurrence};
+    use postings_api::service::chart_of_account_service::ChartOfAccountService;
+    use postings_api::service::ledger_service::LedgerService;
+    use postings_api::service::schedule_service::ScheduleService;
+    use postings_api::ServiceError;
+    use postings_db_postgres::repositories::account_stmt_repository::PostgresAccountStmtRepository;
+    use postings_db_postgres::repositories::chart_of_account_repository::PostgresChartOfAccountRepository;
+    use postings_db_postgres::repositories::ledger_account_repository::PostgresLedgerAccountRepository;
+    use postings_db_postgres::repositories::ledger_repository::PostgresLedgerRepository;
+    use postings_db_postgres::repositories::named_repository::PostgresNamedRepository;
+    use postings_db_postgres::repositories::posting_approval_repository::PostgresPostingApprovalRepository;
+    use postings_db_postgres::repositories::hold_repository::PostgresHoldRepository;
+    use postings_db_postgres::repositories::schedule_repository::PostgresScheduleRepository;
+    use postings_db_postgres::repositories::outbox_repository::PostgresOutboxRepository;
+    use postings_db_postgres::repositories::posting_line_repository::PostgresPostingLineRepository;
+    use postings_db_postgres::repositories::posting_repository::PostgresPostingRepository;
+    use postings_db_postgres::repositories::posting_trace_repository::PostgresPostingTraceRepository;
+    use postings_logic::services::chart_of_account_service::ChartOfAccountServiceImpl;
+    use postings_logic::services::ledger_service::LedgerServiceImpl;
+    use postings_logic::services::posting_service::PostingServiceImpl;
+    use postings_logic::services::schedule_service::ScheduleServiceImpl;
+    use postings_logic::services::shared_service::SharedService;
+
+    fn create_shared_service(pool: PgPool) -> SharedService {
+        SharedService::new(
+            Arc::new(PostgresChartOfAccountRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerRepository::new(pool.clone())),
+            Arc::new(PostgresLedgerAccountRepository::new(pool.clone())),
+            Arc::new(PostgresNamedRepository::new(pool.clone())),
+            Arc::new(PostgresPostingRepository::new(pool.clone())),
+            Arc::new(PostgresAccountStmtRepository::new(pool.clone())),
+            Arc::new(PostgresPostingLineRepository::new(pool.clone())),
+            Arc::new(PostgresPostingTraceRepository::new(pool.clone())),
+            Arc::new(PostgresPostingApprovalRepository::new(pool.clone())),
+            Arc::new(PostgresHoldRepository::new(pool.clone())),
+            Arc::new(PostgresScheduleRepository::new(pool.clone())),
+            Arc::new(PostgresOutboxRepository::new(pool)),
+        )
+    }
+
+    struct Services {
+        ledger: LedgerServiceImpl,
+        schedule: ScheduleServiceImpl,
+    }
+
+    fn create_ledger_service(pool: PgPool) -> LedgerServiceImpl {
+        LedgerServiceImpl::new(
+            create_shared_service(pool.clone()),
+            ChartOfAccountServiceImpl::new(create_shared_service(pool)),
+        )
+    }
+
+    fn create_services(pool: PgPool) -> Services {
+        Services {
+            ledger: create_ledger_service(pool.clone()),
+            schedule: ScheduleServiceImpl::new(
+                create_shared_service(pool.clone()),
+                create_ledger_service(pool.clone()),
+                PostingServiceImpl::new(create_shared_service(pool)),
+            ),
+        }
+    }
+
+    async fn setup_ledger(pool: &PgPool, services: &Services) -> anyhow::Result<Ledger> {
+        let coa_service = ChartOfAccountServiceImpl::new(create_shared_service(pool.clone()));
+        let (coa, _) = coa_service.new_chart_of_account(ChartOfAccount { id: Uuid::nil() }, vec![]).await?;
+        let (ledger, _) = services.ledger.new_ledger(Ledger { id: Uuid::new_v4(), coa }, vec![]).await?;
+        Ok(ledger)
+    }
+
+    async fn setup_account(services: &Services, ledger: &Ledger, balance_side: BalanceSide, category: AccountCategory) -> anyhow::Result<LedgerAccount> {
+        let account = LedgerAccount {
+            id: Uuid::new_v4(),
+            ledger: ledger.clone(),
+            parent: None,
+            coa: ledger.coa.clone(),
+            balance_side,
+            category,
+            code: None,
+        };
+        Ok(services.ledger.new_ledger_account(account, vec![]).await?.0)
+    }
+
+    fn at(y: i32, m: u32, d: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, 6, 0, 0).unwrap()
+    }
+
+    /// Books `amount` from `credited` to `debited` on every occurrence of `recurrence`.
+    fn fee_schedule(debited: &LedgerAccount, credited: &LedgerAccount, amount: i64, recurrence: Recurrence, start_time: DateTime<Utc>, catch_up: CatchUp) -> PostingSchedule {
+        PostingSchedule {
+            id: Uuid::nil(),
+            ledger: debited.ledger.clone(),
+            recurrence,
+            start_time,
+            catch_up,
+            record_user: [0; 34],
+            opr_type: [3; 34],
+            pst_type: PostingType::BusiTx,
+            lines: vec![
+                ScheduledLine { account_id: debited.id, debit_amount: BigDecimal::from(amount), credit_amount: BigDecimal::from(0) },
+                ScheduledLine { account_id: credited.id, debit_amount: BigDecimal::from(0), credit_amount: BigDecimal::from(amount) },
+            ],
+            status: ScheduleStatus::Active,
+            next_index: 0,
+            next_time: None,
+            record_time: Utc::now(),
+        }
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_run_due_catches_up_end_of_month(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let customer = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let fees = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::RE).await?;
+        let schedule = services
+            .schedule
+            .new_schedule(fee_schedule(&customer, &fees, 5, Recurrence::end_of_month(), at(2025, 1, 15), CatchUp::All))
+            .await?;
+
+        // Act
+        let before = services.schedule.run_due(at(2025, 1, 30)).await?;
+        let caught_up = services.schedule.run_due(at(2025, 4, 5)).await?;
+        let repeated = services.schedule.run_due(at(2025, 4, 5)).await?;
+
+        // Assert
+        assert_eq!(schedule.next_time, Some(at(2025, 1, 31)));
+        assert!(before.is_empty());
+        let pst_times: Vec<_> = caught_up.iter().map(|p| p.pst_time).collect();
+        assert_eq!(pst_times, vec![at(2025, 1, 31), at(2025, 2, 28), at(2025, 3, 31)]);
+        assert!(caught_up.iter().all(|p| p.opr_type == [3; 34] && p.lines.len() == 2));
+        assert!(repeated.is_empty());
+        let runs = services.schedule.find_schedule_runs(schedule.id).await?;
+        assert_eq!(runs.iter().map(|r| r.occurrence_index).collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(runs.iter().map(|r| r.posting_id).collect::<Vec<_>>(), caught_up.iter().map(|p| p.id).collect::<Vec<_>>());
+        let stored = services.schedule.find_schedule_by_id(schedule.id).await?.unwrap();
+        assert_eq!(stored.next_index, 3);
+        assert_eq!(stored.next_time, Some(at(2025, 4, 30)));
+        assert_eq!(stored.recurrence, Recurrence::end_of_month());
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_latest_catch_up_finishes_schedule(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let customer = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let fees = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::RE).await?;
+        let recurrence: Recurrence = "FREQ=DAILY;COUNT=5".parse()?;
+        let schedule = services
+            .schedule
+            .new_schedule(fee_schedule(&customer, &fees, 1, recurrence, at(2025, 3, 1), CatchUp::Latest))
+            .await?;
+
+        // Act
+        let posted = services.schedule.run_due(at(2025, 3, 20)).await?;
+
+        // Assert
+        assert_eq!(posted.len(), 1);
+        assert_eq!(posted[0].pst_time, at(2025, 3, 5));
+        let stored = services.schedule.find_schedule_by_id(schedule.id).await?.unwrap();
+        assert_eq!(stored.status, ScheduleStatus::Finished);
+        assert_eq!(stored.next_time, None);
+        assert_eq!(services.schedule.find_schedule_runs(schedule.id).await?[0].occurrence_index, 4);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_overlapping_runs_post_once(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let other = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let customer = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let fees = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::RE).await?;
+        let schedule = services
+            .schedule
+            .new_schedule(fee_schedule(&customer, &fees, 2, Recurrence::new(Frequency::Weekly), at(2025, 1, 1), CatchUp::All))
+            .await?;
+
+        // Act
+        let (first, second) = tokio::join!(services.schedule.run_due(at(2025, 3, 1)), other.schedule.run_due(at(2025, 3, 1)));
+
+        // Assert
+        let runs = services.schedule.find_schedule_runs(schedule.id).await?;
+        assert_eq!(runs.len(), 9);
+        assert_eq!(first?.len() + second?.len(), 9);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_failing_schedule_does_not_stop_others(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let overdrawn = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let customer = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let fees = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::RE).await?;
+        services
+            .ledger
+            .set_account_limit(AccountLimit { account_id: overdrawn.id, min_balance: Some(BigDecimal::from(0)), max_balance: None })
+            .await?;
+        let failing = services
+            .schedule
+            .new_schedule(fee_schedule(&overdrawn, &fees, 1, Recurrence::new(Frequency::Weekly), at(2025, 1, 1), CatchUp::All))
+            .await?;
+        let healthy = services
+            .schedule
+            .new_schedule(fee_schedule(&customer, &fees, 1, Recurrence::new(Frequency::Weekly), at(2025, 1, 1), CatchUp::All))
+            .await?;
+
+        // Act
+        let posted = services.schedule.run_due(at(2025, 1, 10)).await?;
+
+        // Assert
+        assert_eq!(posted.len(), 2);
+        assert_eq!(services.schedule.find_schedule_runs(healthy.id).await?.len(), 2);
+        assert!(services.schedule.find_schedule_runs(failing.id).await?.is_empty());
+        assert_eq!(services.schedule.find_schedule_by_id(failing.id).await?.unwrap().next_index, 0);
+
+        Ok(())
+    }
+
+    #[sqlx::test(migrations = "../postings-db-postgres/migrations")]
+    async fn test_cancel_schedule(pool: PgPool) -> anyhow::Result<()> {
+        dotenvy::from_filename(".env.postgres").ok();
+        // Arrange
+        let services = create_services(pool.clone());
+        let ledger = setup_ledger(&pool, &services).await?;
+        let customer = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::LI).await?;
+        let fees = setup_account(&services, &ledger, BalanceSide::Cr, AccountCategory::RE).await?;
+        let schedule = services
+            .schedule
+            .new_schedule(fee_schedule(&customer, &fees, 1, Recurrence::end_of_month(), Utc::now() - Duration::days(40), CatchUp::All))
+            .await?;
+
+        // Act
+        let cancelled = services.schedule.cancel_schedule(schedule.id).await?;
+        let again = services.schedule.cancel_schedule(schedule.id).await;
+        let unknown = services.schedule.cancel_schedule(Uuid::new_v4()).await;
+        let posted = services.schedule.run_due(Utc::now()).await?;
+
+        // Assert
+        assert_eq!(cancelled.status, ScheduleStatus::Cancelled);
+        assert!(matches!(again, Err(ServiceError::ScheduleNotActive)));
+        assert!(matches!(unknown, Err(ServiceError::ScheduleNotFound)));
+        assert!(posted.is_empty());
+
+        Ok(())
+    }
+}